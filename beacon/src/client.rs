@@ -1,4 +1,5 @@
 use crate::Error;
+use crate::core::image;
 use crate::core::time::{Duration, SystemTime};
 use crate::span;
 use crate::theme;
@@ -51,6 +52,7 @@ pub enum Event {
     CommandsSpawned(usize),
     SubscriptionsTracked(usize),
     LayersRendered(usize),
+    ImagesCached(image::Stats),
 }
 
 impl Client {
@@ -10,6 +10,7 @@ mod stream;
 pub use client::Client;
 pub use span::Span;
 
+use crate::core::image;
 use crate::core::theme;
 use crate::core::time::{Duration, SystemTime};
 use crate::error::Error;
@@ -130,6 +131,7 @@ pub fn run() -> impl Stream<Item = Event> {
             let mut last_tasks = 0;
             let mut last_subscriptions = 0;
             let mut last_present_layers = 0;
+            let mut last_images = image::Stats::default();
             let mut last_prepare = present::Stage::default();
             let mut last_render = present::Stage::default();
 
@@ -211,6 +213,9 @@ pub fn run() -> impl Stream<Item = Event> {
                                     client::Event::LayersRendered(layers) => {
                                         last_present_layers = layers;
                                     }
+                                    client::Event::ImagesCached(stats) => {
+                                        last_images = stats;
+                                    }
                                     client::Event::SpanStarted(
                                         span::Stage::Update,
                                     ) => {
@@ -275,6 +280,7 @@ pub fn run() -> impl Stream<Item = Event> {
                                                     prepare: last_prepare,
                                                     render: last_render,
                                                     layers: last_present_layers,
+                                                    images: last_images,
                                                 };
 
                                                 last_prepare =
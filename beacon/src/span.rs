@@ -1,3 +1,4 @@
+use crate::core::image;
 use crate::core::window;
 
 use serde::{Deserialize, Serialize};
@@ -28,6 +29,7 @@ pub enum Span {
         prepare: present::Stage,
         render: present::Stage,
         layers: usize,
+        images: image::Stats,
     },
     Custom {
         name: String,
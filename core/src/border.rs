@@ -12,6 +12,19 @@ pub struct Border {
 
     /// The [`Radius`] of the border.
     pub radius: Radius,
+
+    /// The [`Widths`] of each side of the border, if they differ.
+    ///
+    /// When set, they take precedence over `width`.
+    pub widths: Option<Widths>,
+
+    /// The [`Colors`] of each side of the border, if they differ.
+    ///
+    /// When set, they take precedence over `color`.
+    pub colors: Option<Colors>,
+
+    /// The [`Style`] of the lines of the border.
+    pub style: Style,
 }
 
 /// Creates a new [`Border`] with the given [`Radius`].
@@ -49,6 +62,42 @@ pub fn width(width: impl Into<Pixels>) -> Border {
     Border::default().width(width)
 }
 
+/// Creates a new [`Border`] with the given [`Widths`] for each side.
+///
+/// ```
+/// # use iced_core::border::{self, Border, Widths};
+/// #
+/// assert_eq!(
+///     border::widths(Widths::default().bottom(1)),
+///     Border::default().widths(Widths::default().bottom(1)),
+/// );
+/// ```
+pub fn widths(widths: impl Into<Widths>) -> Border {
+    Border::default().widths(widths)
+}
+
+/// Creates a new dashed [`Border`].
+///
+/// ```
+/// # use iced_core::border::{self, Border, Style};
+/// #
+/// assert_eq!(border::dashed().style, Style::Dashed);
+/// ```
+pub fn dashed() -> Border {
+    Border::default().style(Style::Dashed)
+}
+
+/// Creates a new dotted [`Border`].
+///
+/// ```
+/// # use iced_core::border::{self, Border, Style};
+/// #
+/// assert_eq!(border::dotted().style, Style::Dotted);
+/// ```
+pub fn dotted() -> Border {
+    Border::default().style(Style::Dotted)
+}
+
 impl Border {
     /// Sets the [`Color`] of the [`Border`].
     pub fn color(self, color: impl Into<Color>) -> Self {
@@ -73,6 +122,320 @@ impl Border {
             ..self
         }
     }
+
+    /// Sets the [`Widths`] of each side of the [`Border`].
+    pub fn widths(self, widths: impl Into<Widths>) -> Self {
+        Self {
+            widths: Some(widths.into()),
+            ..self
+        }
+    }
+
+    /// Sets the [`Colors`] of each side of the [`Border`].
+    pub fn colors(self, colors: impl Into<Colors>) -> Self {
+        Self {
+            colors: Some(colors.into()),
+            ..self
+        }
+    }
+
+    /// Sets the [`Style`] of the [`Border`].
+    pub fn style(self, style: Style) -> Self {
+        Self { style, ..self }
+    }
+
+    /// Returns the effective [`Widths`] of each side of the [`Border`].
+    pub fn side_widths(&self) -> Widths {
+        self.widths.unwrap_or(Widths::new(self.width))
+    }
+
+    /// Returns the effective [`Colors`] of each side of the [`Border`].
+    pub fn side_colors(&self) -> Colors {
+        self.colors.unwrap_or(Colors::new(self.color))
+    }
+
+    /// Returns the length of the dashes and the gaps between them along
+    /// every side of the [`Border`], or `None` if it is solid.
+    ///
+    /// The pattern is given by the widest side; so every renderer dashes
+    /// all the sides the same way.
+    pub fn dash(&self) -> Option<[f32; 2]> {
+        self.style.dash(self.side_widths().max())
+    }
+
+    /// Returns true if any side of the [`Border`] has a non-zero width.
+    pub fn has_width(&self) -> bool {
+        self.side_widths().max() > 0.0
+    }
+
+    /// Returns true if every side of the [`Border`] has the same width and
+    /// color.
+    pub fn is_uniform(&self) -> bool {
+        self.side_widths().is_uniform() && self.side_colors().is_uniform()
+    }
+}
+
+/// The style of the lines of a [`Border`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    /// A continuous line.
+    #[default]
+    Solid,
+    /// A series of short line segments.
+    Dashed,
+    /// A series of square dots.
+    Dotted,
+}
+
+impl Style {
+    /// Returns the alternating lengths of the dashes and the gaps of a
+    /// one pixel wide line of this [`Style`], or an empty slice if it is
+    /// solid.
+    ///
+    /// These are the same `segments` a `LineDash` of a geometry stroke takes;
+    /// so renderers can dash borders with their stroking logic.
+    ///
+    /// ```
+    /// # use iced_core::border::Style;
+    /// #
+    /// assert!(Style::Solid.segments().is_empty());
+    /// assert_eq!(Style::Dashed.segments(), &[3.0, 2.0]);
+    /// assert_eq!(Style::Dotted.segments(), &[1.0, 1.0]);
+    /// ```
+    pub fn segments(self) -> &'static [f32] {
+        match self {
+            Style::Solid => &[],
+            Style::Dashed => &[3.0, 2.0],
+            Style::Dotted => &[1.0, 1.0],
+        }
+    }
+
+    /// Returns the length of the dashes and the gaps between them for a
+    /// border of the given width, or `None` if the [`Style`] is solid.
+    ///
+    /// The [`Style::segments`] are scaled by the width of the border.
+    ///
+    /// ```
+    /// # use iced_core::border::Style;
+    /// #
+    /// assert_eq!(Style::Solid.dash(2.0), None);
+    /// assert_eq!(Style::Dashed.dash(2.0), Some([6.0, 4.0]));
+    /// assert_eq!(Style::Dotted.dash(2.0), Some([2.0, 2.0]));
+    /// ```
+    pub fn dash(self, width: f32) -> Option<[f32; 2]> {
+        let &[length, gap] = self.segments() else {
+            return None;
+        };
+
+        let width = width.max(1.0);
+
+        Some([length * width, gap * width])
+    }
+}
+
+/// The widths of each side of a [`Border`] in the order:
+/// top, right, bottom, left.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Widths {
+    /// Top width
+    pub top: f32,
+    /// Right width
+    pub right: f32,
+    /// Bottom width
+    pub bottom: f32,
+    /// Left width
+    pub left: f32,
+}
+
+impl Widths {
+    /// Creates new [`Widths`] with the same value for each side.
+    pub fn new(value: impl Into<Pixels>) -> Self {
+        let value = value.into().0;
+
+        Self {
+            top: value,
+            right: value,
+            bottom: value,
+            left: value,
+        }
+    }
+
+    /// Sets the top width of the [`Widths`].
+    pub fn top(self, value: impl Into<Pixels>) -> Self {
+        Self {
+            top: value.into().0,
+            ..self
+        }
+    }
+
+    /// Sets the right width of the [`Widths`].
+    pub fn right(self, value: impl Into<Pixels>) -> Self {
+        Self {
+            right: value.into().0,
+            ..self
+        }
+    }
+
+    /// Sets the bottom width of the [`Widths`].
+    pub fn bottom(self, value: impl Into<Pixels>) -> Self {
+        Self {
+            bottom: value.into().0,
+            ..self
+        }
+    }
+
+    /// Sets the left width of the [`Widths`].
+    pub fn left(self, value: impl Into<Pixels>) -> Self {
+        Self {
+            left: value.into().0,
+            ..self
+        }
+    }
+
+    /// Returns the largest width of the [`Widths`].
+    pub fn max(&self) -> f32 {
+        self.top.max(self.right).max(self.bottom).max(self.left)
+    }
+
+    /// Returns true if all the sides of the [`Widths`] are equal.
+    pub fn is_uniform(&self) -> bool {
+        self.top == self.right
+            && self.top == self.bottom
+            && self.top == self.left
+    }
+}
+
+impl From<f32> for Widths {
+    fn from(width: f32) -> Self {
+        Self::new(width)
+    }
+}
+
+impl From<u8> for Widths {
+    fn from(width: u8) -> Self {
+        Self::new(f32::from(width))
+    }
+}
+
+impl From<u32> for Widths {
+    fn from(width: u32) -> Self {
+        Self::new(width as f32)
+    }
+}
+
+impl From<i32> for Widths {
+    fn from(width: i32) -> Self {
+        Self::new(width as f32)
+    }
+}
+
+impl From<[f32; 4]> for Widths {
+    fn from([top, right, bottom, left]: [f32; 4]) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}
+
+impl From<Widths> for [f32; 4] {
+    fn from(widths: Widths) -> Self {
+        [widths.top, widths.right, widths.bottom, widths.left]
+    }
+}
+
+impl std::ops::Mul<f32> for Widths {
+    type Output = Self;
+
+    fn mul(self, scale: f32) -> Self::Output {
+        Self {
+            top: self.top * scale,
+            right: self.right * scale,
+            bottom: self.bottom * scale,
+            left: self.left * scale,
+        }
+    }
+}
+
+/// The colors of each side of a [`Border`] in the order:
+/// top, right, bottom, left.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Colors {
+    /// Top color
+    pub top: Color,
+    /// Right color
+    pub right: Color,
+    /// Bottom color
+    pub bottom: Color,
+    /// Left color
+    pub left: Color,
+}
+
+impl Colors {
+    /// Creates new [`Colors`] with the same [`Color`] for each side.
+    pub fn new(color: impl Into<Color>) -> Self {
+        let color = color.into();
+
+        Self {
+            top: color,
+            right: color,
+            bottom: color,
+            left: color,
+        }
+    }
+
+    /// Sets the top [`Color`] of the [`Colors`].
+    pub fn top(self, color: impl Into<Color>) -> Self {
+        Self {
+            top: color.into(),
+            ..self
+        }
+    }
+
+    /// Sets the right [`Color`] of the [`Colors`].
+    pub fn right(self, color: impl Into<Color>) -> Self {
+        Self {
+            right: color.into(),
+            ..self
+        }
+    }
+
+    /// Sets the bottom [`Color`] of the [`Colors`].
+    pub fn bottom(self, color: impl Into<Color>) -> Self {
+        Self {
+            bottom: color.into(),
+            ..self
+        }
+    }
+
+    /// Sets the left [`Color`] of the [`Colors`].
+    pub fn left(self, color: impl Into<Color>) -> Self {
+        Self {
+            left: color.into(),
+            ..self
+        }
+    }
+
+    /// Returns true if all the sides of the [`Colors`] are equal.
+    pub fn is_uniform(&self) -> bool {
+        self.top == self.right
+            && self.top == self.bottom
+            && self.top == self.left
+    }
+}
+
+impl From<Color> for Colors {
+    fn from(color: Color) -> Self {
+        Self::new(color)
+    }
+}
+
+impl From<Colors> for [Color; 4] {
+    fn from(colors: Colors) -> Self {
+        [colors.top, colors.right, colors.bottom, colors.left]
+    }
 }
 
 /// The border radii for the corners of a graphics primitive in the order:
@@ -315,3 +678,56 @@ impl std::ops::Mul<f32> for Radius {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn side_widths() {
+        let border = Border::default().width(2);
+
+        assert_eq!(border.side_widths(), Widths::new(2));
+        assert!(border.side_widths().is_uniform());
+
+        let border = border.widths(Widths::default().bottom(1));
+
+        assert_eq!(border.side_widths(), Widths::default().bottom(1));
+        assert!(!border.side_widths().is_uniform());
+        assert_eq!(border.side_widths().max(), 1.0);
+        assert!(border.has_width());
+        assert!(!Border::default().has_width());
+    }
+
+    #[test]
+    fn side_colors() {
+        let border = Border::default().color(Color::BLACK);
+
+        assert_eq!(border.side_colors(), Colors::new(Color::BLACK));
+        assert!(border.is_uniform());
+
+        let border =
+            border.colors(Colors::new(Color::BLACK).left(Color::WHITE));
+
+        assert_eq!(border.side_colors().top, Color::BLACK);
+        assert_eq!(border.side_colors().left, Color::WHITE);
+        assert!(!border.is_uniform());
+    }
+
+    #[test]
+    fn dash() {
+        assert_eq!(Style::Solid.dash(3.0), None);
+        assert_eq!(Style::Dashed.dash(3.0), Some([9.0, 6.0]));
+        assert_eq!(Style::Dotted.dash(3.0), Some([3.0, 3.0]));
+
+        // Thin borders are dashed as if they were one pixel wide
+        assert_eq!(Style::Dashed.dash(0.5), Some([3.0, 2.0]));
+
+        // Every side follows the widest one
+        let border = dashed().widths(Widths::default().top(1).left(4));
+
+        assert_eq!(border.dash(), Some([12.0, 8.0]));
+        assert_eq!(border.width(4).dash(), Some([12.0, 8.0]));
+        assert_eq!(Border::default().width(4).dash(), None);
+    }
+}
@@ -1,5 +1,6 @@
 //! Load and draw raster graphics.
 use crate::border;
+use crate::time::Duration;
 use crate::{Bytes, Radians, Rectangle, Size};
 
 use rustc_hash::FxHasher;
@@ -227,6 +228,74 @@ impl Id {
     }
 }
 
+/// The frames of an animated image (e.g. a GIF, APNG, or animated WebP).
+///
+/// Cloning [`Frames`] is cheap, since the frames are reference counted.
+#[derive(Debug, Clone)]
+pub struct Frames {
+    id: Id,
+    frames: Arc<[Frame]>,
+}
+
+impl Frames {
+    /// Creates new [`Frames`] from the given list of [`Frame`].
+    pub fn new(frames: impl IntoIterator<Item = Frame>) -> Self {
+        Self {
+            id: Id::unique(),
+            frames: frames.into_iter().collect(),
+        }
+    }
+
+    /// Returns the unique identifier of the [`Frames`].
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Returns the amount of frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns true if there are no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the [`Frame`] at the given index, if any.
+    pub fn get(&self, index: usize) -> Option<&Frame> {
+        self.frames.get(index)
+    }
+
+    /// Returns an iterator over all the frames.
+    pub fn iter(&self) -> impl Iterator<Item = &Frame> {
+        self.frames.iter()
+    }
+
+    /// Returns the total [`Duration`] of a single loop of the animation.
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+}
+
+impl PartialEq for Frames {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+/// A single frame of an animated image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    /// The [`Handle`] of the image of the frame.
+    ///
+    /// It should generally be a [`Handle::Rgba`], since decoding
+    /// a frame at drawing time would stall the animation.
+    pub handle: Handle,
+
+    /// The amount of time the frame should be displayed.
+    pub delay: Duration,
+}
+
 /// Image filtering strategy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum FilterMethod {
@@ -321,6 +390,20 @@ pub trait Renderer: crate::Renderer {
     /// If you need a measurement right away, consider using [`Renderer::load_image`].
     fn measure_image(&self, handle: &Self::Handle) -> Option<Size<u32>>;
 
+    /// Returns the loading [`Status`] of the image for the given [`Handle`].
+    ///
+    /// Like [`Renderer::measure_image`], this method should never block. If the
+    /// image is not already loaded, the [`Renderer`] may start loading it in the
+    /// background and report [`Status::Loading`] in the meantime.
+    ///
+    /// By default, the status is derived from [`Renderer::measure_image`].
+    fn image_status(&self, handle: &Self::Handle) -> Status {
+        match self.measure_image(handle) {
+            Some(size) => Status::Loaded(size),
+            None => Status::Loading,
+        }
+    }
+
     /// Draws an [`Image`] inside the provided `bounds`.
     ///
     /// If the image is not already loaded, the [`Renderer`] may choose to render
@@ -336,6 +419,76 @@ pub trait Renderer: crate::Renderer {
     );
 }
 
+/// The statistics of the image cache of a renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats {
+    /// The amount of images currently in the cache.
+    pub images: usize,
+
+    /// The memory used by the images in the cache, in bytes.
+    pub memory: u64,
+
+    /// The amount of times an image was found in the cache.
+    pub hits: u64,
+
+    /// The amount of times an image was not found in the cache and
+    /// had to be loaded.
+    pub misses: u64,
+
+    /// The amount of images that have been evicted from the cache.
+    pub evictions: u64,
+}
+
+/// The loading status of an image.
+#[derive(Debug, Clone)]
+pub enum Status {
+    /// The image is still being loaded or decoded.
+    Loading,
+    /// The image is ready to be drawn and has the given dimensions.
+    Loaded(Size<u32>),
+    /// The image could not be loaded.
+    Failed(Error),
+}
+
+impl Status {
+    /// Returns true if the [`Status`] is [`Status::Loading`].
+    pub fn is_loading(&self) -> bool {
+        matches!(self, Self::Loading)
+    }
+
+    /// Returns true if both statuses represent the same stage of loading.
+    ///
+    /// Two [`Status::Failed`] values are considered the same stage,
+    /// regardless of their [`Error`].
+    pub fn is_same_stage(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Loading, Self::Loading)
+            | (Self::Failed(_), Self::Failed(_)) => true,
+            (Self::Loaded(a), Self::Loaded(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// The progress of an image allocation.
+#[derive(Debug, Clone)]
+pub enum Progress {
+    /// The image is waiting to be decoded.
+    Queued,
+    /// The image is being decoded.
+    Decoding,
+    /// The image allocation has finished.
+    Finished(Result<Allocation, Error>),
+}
+
+impl Progress {
+    /// Returns true if the [`Progress`] is [`Progress::Finished`].
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Finished(_))
+    }
+}
+
 /// An image loading error.
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum Error {
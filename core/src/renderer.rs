@@ -72,6 +72,29 @@ pub trait Renderer {
         + Send
         + 'static,
     );
+
+    /// Creates an [`image::Allocation`] for the given [`image::Handle`],
+    /// calling the given callback with its [`image::Progress`] along the way.
+    ///
+    /// By default, only [`image::Progress::Finished`] is reported.
+    fn allocate_image_with_progress(
+        &mut self,
+        handle: &image::Handle,
+        mut on_progress: impl FnMut(image::Progress) + Send + 'static,
+    ) {
+        self.allocate_image(handle, move |result| {
+            on_progress(image::Progress::Finished(result));
+        });
+    }
+
+    /// Evicts the given [`image::Handle`] from the image cache of the
+    /// [`Renderer`], if present.
+    ///
+    /// The [`Renderer`] may choose to keep images that are still held by
+    /// an [`image::Allocation`].
+    ///
+    /// By default, this does nothing.
+    fn evict_image(&mut self, _handle: &image::Handle) {}
 }
 
 /// A polygon with four sides.
@@ -169,6 +169,10 @@ impl text::Editor for () {
         text::editor::Cursor::Caret(Point::ORIGIN)
     }
 
+    fn cursors(&self) -> Vec<text::editor::Cursor> {
+        vec![self.cursor()]
+    }
+
     fn cursor_position(&self) -> (usize, usize) {
         (0, 0)
     }
@@ -177,6 +181,18 @@ impl text::Editor for () {
         None
     }
 
+    fn range_bounds(
+        &self,
+        _start: (usize, usize),
+        _end: (usize, usize),
+    ) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
+    fn visible_lines(&self) -> Vec<(usize, Rectangle)> {
+        Vec::new()
+    }
+
     fn line(&self, _index: usize) -> Option<text::editor::Line<'_>> {
         None
     }
@@ -185,8 +201,27 @@ impl text::Editor for () {
         0
     }
 
+    fn selection_anchor(&self) -> Option<(usize, usize)> {
+        None
+    }
+
+    fn set_cursor_position(
+        &mut self,
+        _position: (usize, usize),
+        _anchor: Option<(usize, usize)>,
+    ) {
+    }
+
     fn perform(&mut self, _action: text::editor::Action) {}
 
+    fn start_change(&mut self) {}
+
+    fn finish_change(&mut self) -> Option<text::editor::Change> {
+        None
+    }
+
+    fn apply_change(&mut self, _change: &text::editor::Change) {}
+
     fn bounds(&self) -> Size {
         Size::ZERO
     }
@@ -40,6 +40,15 @@ pub struct Settings {
     ///
     /// By default, it is enabled.
     pub vsync: bool,
+
+    /// The maximum amount of memory, in bytes, that the image cache of the
+    /// renderer may use.
+    ///
+    /// When set, images that are not drawn anymore will be kept in the cache
+    /// until the budget is exceeded; evicting the least recently used first.
+    ///
+    /// By default, it is `None`; and unused images are evicted right away.
+    pub image_cache_budget: Option<u64>,
 }
 
 impl Default for Settings {
@@ -51,6 +60,7 @@ impl Default for Settings {
             default_text_size: Pixels(16.0),
             antialiasing: true,
             vsync: true,
+            image_cache_budget: None,
         }
     }
 }
@@ -18,25 +18,115 @@ pub trait Editor: Sized + Default {
     fn is_empty(&self) -> bool;
 
     /// Returns the current [`Cursor`] of the [`Editor`].
+    ///
+    /// This is the primary cursor when there are multiple cursors.
     fn cursor(&self) -> Cursor;
 
+    /// Returns all the cursors of the [`Editor`], starting with the
+    /// primary one.
+    ///
+    /// By default, only the primary cursor is returned.
+    fn cursors(&self) -> Vec<Cursor> {
+        vec![self.cursor()]
+    }
+
     /// Returns the current cursor position of the [`Editor`].
     ///
     /// Line and column, respectively.
     fn cursor_position(&self) -> (usize, usize);
 
     /// Returns the current selected text of the [`Editor`].
+    ///
+    /// Multiple selections are joined with new lines, in document order.
     fn selection(&self) -> Option<String>;
 
+    /// Returns the regions covered by the text between the given positions
+    /// of the [`Editor`], relative to its visible area.
+    ///
+    /// Positions are line and column, respectively.
+    ///
+    /// By default, no regions are returned; so search matches are not
+    /// highlighted.
+    fn range_bounds(
+        &self,
+        _start: (usize, usize),
+        _end: (usize, usize),
+    ) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
+    /// Returns the index and the bounds of every line of the [`Editor`]
+    /// that is currently visible, relative to its visible area.
+    ///
+    /// The bounds of a line cover all of its wrapped visual lines.
+    ///
+    /// By default, no lines are returned; so the gutter shows no line
+    /// numbers.
+    fn visible_lines(&self) -> Vec<(usize, Rectangle)> {
+        Vec::new()
+    }
+
     /// Returns the text of the given line in the [`Editor`], if it exists.
     fn line(&self, index: usize) -> Option<Line<'_>>;
 
     /// Returns the amount of lines in the [`Editor`].
     fn line_count(&self) -> usize;
 
+    /// Returns the position of the start of the current selection of the
+    /// [`Editor`], if any.
+    ///
+    /// The anchor of a selection is the end opposite to the cursor.
+    ///
+    /// Line and column, respectively.
+    ///
+    /// By default, no anchor is reported; so undoing a change will not
+    /// restore its selection.
+    fn selection_anchor(&self) -> Option<(usize, usize)> {
+        None
+    }
+
+    /// Moves the cursor of the [`Editor`] to the given position, selecting
+    /// the text between the given `anchor` and the cursor, if any.
+    ///
+    /// Any additional cursors are removed.
+    ///
+    /// Line and column, respectively.
+    ///
+    /// By default, this does nothing.
+    fn set_cursor_position(
+        &mut self,
+        _position: (usize, usize),
+        _anchor: Option<(usize, usize)>,
+    ) {
+    }
+
     /// Performs an [`Action`] on the [`Editor`].
+    ///
+    /// An [`Editor`] does not keep any history by itself; [`Action::Undo`]
+    /// and [`Action::Redo`] may be ignored.
     fn perform(&mut self, action: Action);
 
+    /// Starts recording the changes performed on the contents of
+    /// the [`Editor`].
+    ///
+    /// By default, changes are not recorded; so there is nothing to undo
+    /// unless [`Editor::finish_change`] and [`Editor::apply_change`] are
+    /// implemented as well.
+    fn start_change(&mut self) {}
+
+    /// Stops recording changes and returns the [`Change`] performed on the
+    /// contents of the [`Editor`] since [`Editor::start_change`], if any.
+    ///
+    /// By default, no [`Change`] is ever returned.
+    fn finish_change(&mut self) -> Option<Change> {
+        None
+    }
+
+    /// Applies the given [`Change`] to the contents of the [`Editor`].
+    ///
+    /// By default, this does nothing.
+    fn apply_change(&mut self, _change: &Change) {}
+
     /// Returns the current boundaries of the [`Editor`].
     fn bounds(&self) -> Size;
 
@@ -83,17 +173,37 @@ pub enum Action {
     Click(Point),
     /// Drag the mouse on the [`Editor`] to the given [`Point`].
     Drag(Point),
+    /// Add a new cursor at the given [`Point`] of the [`Editor`], keeping
+    /// the current ones.
+    ///
+    /// If a cursor already exists at that position, it is removed instead.
+    AddCursor(Point),
+    /// Select the word at the current cursor or, if there is a selection
+    /// already, add a new selection for its next occurrence.
+    SelectNextOccurrence,
+    /// Select the rectangular region between the given points of the
+    /// [`Editor`], adding a cursor for every line in between.
+    SelectColumn {
+        /// The [`Point`] where the selection starts.
+        from: Point,
+        /// The [`Point`] where the selection ends.
+        to: Point,
+    },
     /// Scroll the [`Editor`] a certain amount of lines.
     Scroll {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Undo the last change.
+    Undo,
+    /// Redo the last undone change.
+    Redo,
 }
 
 impl Action {
     /// Returns whether the [`Action`] is an editing action.
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(self, Self::Edit(_) | Self::Undo | Self::Redo)
     }
 }
 
@@ -116,6 +226,53 @@ pub enum Edit {
     Delete,
 }
 
+/// A reversible change to the contents of an [`Editor`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Change {
+    /// The items of the [`Change`], in the order they were performed.
+    pub items: Vec<ChangeItem>,
+}
+
+impl Change {
+    /// Returns true if the [`Change`] does not modify any contents.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Appends the items of another [`Change`] to this one.
+    pub fn extend(&mut self, change: Change) {
+        self.items.extend(change.items);
+    }
+
+    /// Returns the [`Change`] that undoes this one.
+    pub fn reversed(&self) -> Self {
+        Self {
+            items: self
+                .items
+                .iter()
+                .rev()
+                .map(|item| ChangeItem {
+                    is_insertion: !item.is_insertion,
+                    ..item.clone()
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A single insertion or deletion of text in an [`Editor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeItem {
+    /// The start position of the text; line and column, respectively.
+    pub start: (usize, usize),
+    /// The end position of the text; line and column, respectively.
+    pub end: (usize, usize),
+    /// The inserted or deleted text.
+    pub text: String,
+    /// Whether the text was inserted or deleted.
+    pub is_insertion: bool,
+}
+
 /// A cursor movement.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Motion {
@@ -618,11 +618,19 @@ impl Danger {
     }
 }
 
-struct Oklch {
-    l: f32,
-    c: f32,
-    h: f32,
-    a: f32,
+/// A [`Color`] in the OKLCH color space.
+///
+/// See <https://bottosson.github.io/posts/oklab/>.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    /// The perceived lightness, from 0 to 1.
+    pub l: f32,
+    /// The chroma, or colorfulness, from 0 to roughly 0.37 within sRGB.
+    pub c: f32,
+    /// The hue angle, in radians.
+    pub h: f32,
+    /// The alpha channel.
+    pub a: f32,
 }
 
 /// Darkens a [`Color`] by the given factor.
@@ -724,8 +732,9 @@ pub fn is_dark(color: Color) -> bool {
     to_oklch(color).l < 0.6
 }
 
+/// Converts a [`Color`] into [`Oklch`].
 // https://en.wikipedia.org/wiki/Oklab_color_space#Conversions_between_color_spaces
-fn to_oklch(color: Color) -> Oklch {
+pub fn to_oklch(color: Color) -> Oklch {
     let [r, g, b, alpha] = color.into_linear();
 
     // linear RGB → LMS
@@ -750,8 +759,9 @@ fn to_oklch(color: Color) -> Oklch {
     Oklch { l, c, h, a: alpha }
 }
 
+/// Converts [`Oklch`] into a [`Color`], clipping it to the sRGB gamut.
 // https://en.wikipedia.org/wiki/Oklab_color_space#Conversions_between_color_spaces
-fn from_oklch(oklch: Oklch) -> Color {
+pub fn from_oklch(oklch: Oklch) -> Color {
     let Oklch { l, c, h, a: alpha } = oklch;
 
     let a = c * h.cos();
@@ -1,6 +1,7 @@
 pub use iced_core as core;
 pub use iced_futures as futures;
 
+use crate::core::image;
 use crate::core::theme;
 use crate::core::window;
 use crate::futures::Subscription;
@@ -62,6 +63,10 @@ pub fn layers_rendered(amount: impl FnOnce() -> usize) {
     internal::layers_rendered(amount);
 }
 
+pub fn images_cached(stats: impl FnOnce() -> image::Stats) {
+    internal::images_cached(stats);
+}
+
 pub fn boot() -> Span {
     internal::boot()
 }
@@ -128,6 +133,7 @@ pub fn is_stale() -> bool {
 
 #[cfg(all(feature = "enable", not(target_arch = "wasm32")))]
 mod internal {
+    use crate::core::image;
     use crate::core::theme;
     use crate::core::time::Instant;
     use crate::core::window;
@@ -191,6 +197,10 @@ mod internal {
         log(client::Event::LayersRendered(amount()));
     }
 
+    pub fn images_cached(stats: impl FnOnce() -> image::Stats) {
+        log(client::Event::ImagesCached(stats()));
+    }
+
     pub fn boot() -> Span {
         span(span::Stage::Boot)
     }
@@ -339,6 +349,7 @@ mod internal {
 
 #[cfg(any(not(feature = "enable"), target_arch = "wasm32"))]
 mod internal {
+    use crate::core::image;
     use crate::core::theme;
     use crate::core::window;
     use crate::futures::Subscription;
@@ -361,6 +372,8 @@ mod internal {
 
     pub fn layers_rendered(_amount: impl FnOnce() -> usize) {}
 
+    pub fn images_cached(_stats: impl FnOnce() -> image::Stats) {}
+
     pub fn boot() -> Span {
         Span
     }
@@ -67,6 +67,7 @@ impl IcedCubes {
                         radius: 24.0.into(),
                         width: 1.0,
                         color: Color::WHITE,
+                        ..iced::Border::default()
                     },
                     shadow: iced::Shadow::default(),
                     snap: false,
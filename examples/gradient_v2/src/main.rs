@@ -169,6 +169,7 @@ fn gradient_container<'a>(gradient: Gradient) -> iced::Element<'a, Message> {
                     radius: 24.0.into(),
                     width: 0.0,
                     color: Color::WHITE,
+                    ..iced::Border::default()
                 },
                 shadow: iced::Shadow::default(),
                 snap: false,
@@ -2,10 +2,10 @@ use iced::event::{self, Event};
 use iced::keyboard;
 use iced::keyboard::key;
 use iced::widget::{
-    button, center, column, container, mouse_area, opaque, operation,
-    pick_list, row, space, stack, text, text_input,
+    button, center, column, container, modal, operation, pick_list, row, space,
+    text, text_input,
 };
-use iced::{Bottom, Color, Element, Fill, Subscription, Task};
+use iced::{Bottom, Element, Fill, Subscription, Task};
 
 use std::fmt;
 
@@ -43,7 +43,7 @@ impl App {
         match message {
             Message::ShowModal => {
                 self.show_modal = true;
-                operation::focus_next()
+                Task::none()
             }
             Message::HideModal => {
                 self.hide_modal();
@@ -68,27 +68,16 @@ impl App {
 
                 Task::none()
             }
-            Message::Event(event) => match event {
-                Event::Keyboard(keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(key::Named::Tab),
-                    modifiers,
-                    ..
-                }) => {
-                    if modifiers.shift() {
-                        operation::focus_previous()
-                    } else {
-                        operation::focus_next()
-                    }
-                }
-                Event::Keyboard(keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(key::Named::Escape),
-                    ..
-                }) => {
-                    self.hide_modal();
-                    Task::none()
-                }
-                _ => Task::none(),
-            },
+            Message::Event(Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            })) if modifiers.shift() => operation::focus_previous(),
+            Message::Event(Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                ..
+            })) => operation::focus_next(),
+            Message::Event(_) => Task::none(),
         }
     }
 
@@ -110,8 +99,8 @@ impl App {
         )
         .padding(10);
 
-        if self.show_modal {
-            let signup = container(
+        let signup = self.show_modal.then(|| {
+            container(
                 column![
                     text("Sign Up").size(24),
                     column![
@@ -150,12 +139,10 @@ impl App {
             )
             .width(300)
             .padding(10)
-            .style(container::rounded_box);
+            .style(container::rounded_box)
+        });
 
-            modal(content, signup, Message::HideModal)
-        } else {
-            content.into()
-        }
+        modal(content, signup, Message::HideModal).into()
     }
 }
 
@@ -190,32 +177,3 @@ impl fmt::Display for Plan {
         .fmt(f)
     }
 }
-
-fn modal<'a, Message>(
-    base: impl Into<Element<'a, Message>>,
-    content: impl Into<Element<'a, Message>>,
-    on_blur: Message,
-) -> Element<'a, Message>
-where
-    Message: Clone + 'a,
-{
-    stack![
-        base.into(),
-        opaque(
-            mouse_area(center(opaque(content)).style(|_theme| {
-                container::Style {
-                    background: Some(
-                        Color {
-                            a: 0.8,
-                            ..Color::BLACK
-                        }
-                        .into(),
-                    ),
-                    ..container::Style::default()
-                }
-            }))
-            .on_press(on_blur)
-        )
-    ]
-    .into()
-}
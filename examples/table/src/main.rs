@@ -16,12 +16,26 @@ struct Table {
     events: Vec<Event>,
     padding: (f32, f32),
     separator: (f32, f32),
+    sort: Option<(Column, table::Order)>,
+    widths: [Option<f32>; 4],
+    selected: Vec<usize>,
 }
 
 #[derive(Debug, Clone)]
 enum Message {
     PaddingChanged(f32, f32),
     SeparatorChanged(f32, f32),
+    Sort(Column, table::Order),
+    ColumnResized(usize, f32),
+    Selected(Vec<usize>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Name,
+    Time,
+    Price,
+    Rating,
 }
 
 impl Table {
@@ -30,6 +44,9 @@ impl Table {
             events: Event::list(),
             padding: (10.0, 5.0),
             separator: (1.0, 1.0),
+            sort: None,
+            widths: [None; 4],
+            selected: Vec::new(),
         }
     }
 
@@ -37,6 +54,28 @@ impl Table {
         match message {
             Message::PaddingChanged(x, y) => self.padding = (x, y),
             Message::SeparatorChanged(x, y) => self.separator = (x, y),
+            Message::Sort(column, order) => {
+                self.events.sort_by(|a, b| {
+                    let ordering = match column {
+                        Column::Name => a.name.cmp(&b.name),
+                        Column::Time => a.duration.cmp(&b.duration),
+                        Column::Price => a.price.total_cmp(&b.price),
+                        Column::Rating => a.rating.total_cmp(&b.rating),
+                    };
+
+                    match order {
+                        table::Order::Ascending => ordering,
+                        table::Order::Descending => ordering.reverse(),
+                    }
+                });
+
+                self.sort = Some((column, order));
+                self.selected.clear();
+            }
+            Message::ColumnResized(column, width) => {
+                self.widths[column] = Some(width.max(50.0));
+            }
+            Message::Selected(selected) => self.selected = selected,
         }
     }
 
@@ -49,6 +88,12 @@ impl Table {
                 })
             };
 
+            let sort = |column| {
+                self.sort
+                    .filter(|(sorted, _)| *sorted == column)
+                    .map(|(_, order)| order)
+            };
+
             let columns = [
                 table::column(bold("Name"), |event: &Event| text(&event.name)),
                 table::column(bold("Time"), |event: &Event| {
@@ -90,11 +135,37 @@ impl Table {
                 .align_y(Center),
             ];
 
+            let columns = columns
+                .into_iter()
+                .zip([
+                    Column::Name,
+                    Column::Time,
+                    Column::Price,
+                    Column::Rating,
+                ])
+                .zip(self.widths)
+                .map(|((table_column, column), width)| {
+                    let table_column = table_column
+                        .sort(sort(column))
+                        .on_sort(move |order| Message::Sort(column, order));
+
+                    if let Some(width) = width {
+                        table_column.width(width)
+                    } else {
+                        table_column
+                    }
+                });
+
             table(columns, &self.events)
                 .padding_x(self.padding.0)
                 .padding_y(self.padding.1)
                 .separator_x(self.separator.0)
                 .separator_y(self.separator.1)
+                .selected(self.selected.iter().copied())
+                .on_select(Message::Selected)
+                .multi_select(true)
+                .on_column_resize(Message::ColumnResized)
+                .sticky_header(true)
         };
 
         let controls = {
@@ -250,3 +321,115 @@ impl Event {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::{Point, keyboard, mouse};
+    use iced_test::{Error, simulator};
+
+    fn modifiers(modifiers: keyboard::Modifiers) -> iced::Event {
+        iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers))
+    }
+
+    #[test]
+    fn it_toggles_the_sort_order() -> Result<(), Error> {
+        let mut table = Table::new();
+
+        for order in [
+            table::Order::Ascending,
+            table::Order::Descending,
+            table::Order::Ascending,
+        ] {
+            let mut ui = simulator(table.view());
+            let _ = ui.click("Price")?;
+
+            for message in ui.into_messages() {
+                table.update(message);
+            }
+
+            assert_eq!(table.sort, Some((Column::Price, order)));
+        }
+
+        assert!(table.events.is_sorted_by(|a, b| a.price <= b.price));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_selects_multiple_rows() -> Result<(), Error> {
+        let mut table = Table::new();
+
+        let mut ui = simulator(table.view());
+        let _ = ui.click("Eat a questionable hot dog at 2AM")?;
+
+        let _ = ui.simulate([modifiers(keyboard::Modifiers::SHIFT)]);
+        let _ = ui.click("Buy overpriced coffee and feel things")?;
+
+        for message in ui.into_messages() {
+            table.update(message);
+        }
+
+        assert_eq!(table.selected, vec![2, 3, 4, 5]);
+
+        for (row, selected) in [
+            ("Ride the MUNI for the story", vec![2, 4, 5]),
+            ("Get a tattoo of the Git logo", vec![2, 4, 5, 14]),
+        ] {
+            let mut ui = simulator(table.view());
+
+            let _ = ui.simulate([modifiers(keyboard::Modifiers::COMMAND)]);
+            let _ = ui.click(row)?;
+
+            for message in ui.into_messages() {
+                table.update(message);
+            }
+
+            assert_eq!(table.selected, selected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_clamps_resized_columns() -> Result<(), Error> {
+        let mut table = Table::new();
+        let mut ui = simulator(table.view());
+
+        // The divider of the price column is past its header, which is
+        // aligned to the right before the sort indicator (16px) and the
+        // padding (10px)
+        let header = ui.find("Price")?.bounds();
+        let divider = Point::new(header.x + header.width + 26.5, header.y);
+        let target = Point::new(divider.x - 1_000.0, divider.y);
+
+        ui.point_at(divider);
+        let _ = ui.simulate([iced::Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        ))]);
+
+        ui.point_at(target);
+        let _ = ui.simulate([
+            iced::Event::Mouse(mouse::Event::CursorMoved { position: target }),
+            iced::Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Left,
+            )),
+        ]);
+
+        let messages: Vec<_> = ui.into_messages().collect();
+
+        assert!(matches!(
+            messages.as_slice(),
+            [Message::ColumnResized(2, width)] if *width == 0.0
+        ));
+
+        for message in messages {
+            table.update(message);
+        }
+
+        assert_eq!(table.widths, [None, None, Some(50.0), None]);
+        assert_eq!(table.sort, None);
+
+        Ok(())
+    }
+}
@@ -1,12 +1,14 @@
 use iced::event::{self, Event};
 use iced::keyboard;
 use iced::keyboard::key;
+use iced::time::Duration;
 use iced::widget::{
-    button, center, column, operation, pick_list, row, slider, text, text_input,
+    button, center, column, operation, pick_list, row, slider, text,
+    text_input, toast, toasts,
 };
 use iced::{Center, Element, Fill, Subscription, Task};
 
-use toast::{Status, Toast};
+use std::fmt;
 
 pub fn main() -> iced::Result {
     iced::application(App::default, App::update, App::view)
@@ -15,19 +17,29 @@ pub fn main() -> iced::Result {
 }
 
 struct App {
-    toasts: Vec<Toast>,
-    editing: Toast,
+    toasts: Vec<Notification>,
+    editing: Notification,
+    position: Position,
     timeout_secs: u64,
 }
 
+#[derive(Debug, Clone, Default)]
+struct Notification {
+    title: String,
+    body: String,
+    severity: Severity,
+}
+
 #[derive(Debug, Clone)]
 #[allow(clippy::enum_variant_names)]
 enum Message {
     Add,
-    Close(usize),
+    Dismiss(usize),
+    Edit(Notification),
     Title(String),
     Body(String),
-    Status(Status),
+    Severity(Severity),
+    Position(Position),
     Timeout(f64),
     Event(Event),
 }
@@ -35,13 +47,14 @@ enum Message {
 impl App {
     fn new() -> Self {
         App {
-            toasts: vec![Toast {
+            toasts: vec![Notification {
                 title: "Example Toast".into(),
                 body: "Add more toasts in the form below!".into(),
-                status: Status::Primary,
+                severity: Severity::Info,
             }],
-            timeout_secs: toast::DEFAULT_TIMEOUT,
-            editing: Toast::default(),
+            editing: Notification::default(),
+            position: Position::BottomRight,
+            timeout_secs: 5,
         }
     }
 
@@ -52,17 +65,19 @@ impl App {
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::Add => {
-                if !self.editing.title.is_empty()
-                    && !self.editing.body.is_empty()
-                {
+                if !self.editing.title.is_empty() {
                     self.toasts.push(std::mem::take(&mut self.editing));
                 }
                 Task::none()
             }
-            Message::Close(index) => {
+            Message::Dismiss(index) => {
                 self.toasts.remove(index);
                 Task::none()
             }
+            Message::Edit(notification) => {
+                self.editing = notification;
+                Task::none()
+            }
             Message::Title(title) => {
                 self.editing.title = title;
                 Task::none()
@@ -71,8 +86,12 @@ impl App {
                 self.editing.body = body;
                 Task::none()
             }
-            Message::Status(status) => {
-                self.editing.status = status;
+            Message::Severity(severity) => {
+                self.editing.severity = severity;
+                Task::none()
+            }
+            Message::Position(position) => {
+                self.position = position;
                 Task::none()
             }
             Message::Timeout(timeout) => {
@@ -98,8 +117,7 @@ impl App {
         };
 
         let add_toast = button("Add Toast").on_press_maybe(
-            (!self.editing.body.is_empty() && !self.editing.title.is_empty())
-                .then_some(Message::Add),
+            (!self.editing.title.is_empty()).then_some(Message::Add),
         );
 
         let content = center(
@@ -119,11 +137,21 @@ impl App {
                         .into()
                 ),
                 subtitle(
-                    "Status",
+                    "Severity",
+                    pick_list(
+                        Severity::ALL,
+                        Some(self.editing.severity),
+                        Message::Severity
+                    )
+                    .width(Fill)
+                    .into()
+                ),
+                subtitle(
+                    "Position",
                     pick_list(
-                        toast::Status::ALL,
-                        Some(self.editing.status),
-                        Message::Status
+                        Position::ALL,
+                        Some(self.position),
+                        Message::Position
                     )
                     .width(Fill)
                     .into()
@@ -148,8 +176,21 @@ impl App {
             .max_width(200),
         );
 
-        toast::Manager::new(content, &self.toasts, Message::Close)
-            .timeout(self.timeout_secs)
+        let notifications = self.toasts.iter().map(|notification| {
+            let item = toast(notification.title.as_str())
+                .severity(notification.severity.into())
+                .action("Edit", Message::Edit(notification.clone()));
+
+            if notification.body.is_empty() {
+                item
+            } else {
+                item.body(notification.body.as_str())
+            }
+        });
+
+        toasts(content, notifications, Message::Dismiss)
+            .position(self.position.into())
+            .timeout(Duration::from_secs(self.timeout_secs))
             .into()
     }
 }
@@ -160,483 +201,188 @@ impl Default for App {
     }
 }
 
-mod toast {
-    use std::fmt;
-
-    use iced::advanced::layout::{self, Layout};
-    use iced::advanced::overlay;
-    use iced::advanced::renderer;
-    use iced::advanced::widget::{self, Operation, Tree};
-    use iced::advanced::{Clipboard, Shell, Widget};
-    use iced::mouse;
-    use iced::time::{self, Duration, Instant};
-    use iced::widget::{button, column, container, row, rule, space, text};
-    use iced::window;
-    use iced::{
-        Alignment, Center, Element, Event, Fill, Length, Point, Rectangle,
-        Renderer, Size, Theme, Vector,
-    };
-
-    pub const DEFAULT_TIMEOUT: u64 = 5;
-
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-    pub enum Status {
-        #[default]
-        Primary,
-        Secondary,
-        Success,
-        Danger,
-        Warning,
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Severity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Danger,
+}
 
-    impl Status {
-        pub const ALL: &'static [Self] = &[
-            Self::Primary,
-            Self::Secondary,
-            Self::Success,
-            Self::Danger,
-            Self::Warning,
-        ];
-    }
+impl Severity {
+    const ALL: &'static [Self] =
+        &[Self::Info, Self::Success, Self::Warning, Self::Danger];
+}
 
-    impl fmt::Display for Status {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            match self {
-                Status::Primary => "Primary",
-                Status::Secondary => "Secondary",
-                Status::Success => "Success",
-                Status::Danger => "Danger",
-                Status::Warning => "Warning",
-            }
-            .fmt(f)
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => "Info",
+            Severity::Success => "Success",
+            Severity::Warning => "Warning",
+            Severity::Danger => "Danger",
         }
+        .fmt(f)
     }
+}
 
-    #[derive(Debug, Clone, Default)]
-    pub struct Toast {
-        pub title: String,
-        pub body: String,
-        pub status: Status,
+impl From<Severity> for toast::Severity {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Info => toast::Severity::Info,
+            Severity::Success => toast::Severity::Success,
+            Severity::Warning => toast::Severity::Warning,
+            Severity::Danger => toast::Severity::Danger,
+        }
     }
+}
 
-    pub struct Manager<'a, Message> {
-        content: Element<'a, Message>,
-        toasts: Vec<Element<'a, Message>>,
-        timeout_secs: u64,
-        on_close: Box<dyn Fn(usize) -> Message + 'a>,
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    TopLeft,
+    Top,
+    TopRight,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
 
-    impl<'a, Message> Manager<'a, Message>
-    where
-        Message: 'a + Clone,
-    {
-        pub fn new(
-            content: impl Into<Element<'a, Message>>,
-            toasts: &'a [Toast],
-            on_close: impl Fn(usize) -> Message + 'a,
-        ) -> Self {
-            let toasts = toasts
-                .iter()
-                .enumerate()
-                .map(|(index, toast)| {
-                    container(column![
-                        container(
-                            row![
-                                text(toast.title.as_str()),
-                                space::horizontal(),
-                                button("X")
-                                    .on_press((on_close)(index))
-                                    .padding(3),
-                            ]
-                            .align_y(Center)
-                        )
-                        .width(Fill)
-                        .padding(5)
-                        .style(match toast.status {
-                            Status::Primary => container::primary,
-                            Status::Secondary => container::secondary,
-                            Status::Success => container::success,
-                            Status::Danger => container::danger,
-                            Status::Warning => container::warning,
-                        }),
-                        rule::horizontal(1),
-                        container(text(toast.body.as_str()))
-                            .width(Fill)
-                            .padding(5)
-                            .style(container::rounded_box),
-                    ])
-                    .max_width(200)
-                    .into()
-                })
-                .collect();
-
-            Self {
-                content: content.into(),
-                toasts,
-                timeout_secs: DEFAULT_TIMEOUT,
-                on_close: Box::new(on_close),
-            }
-        }
+impl Position {
+    const ALL: &'static [Self] = &[
+        Self::TopLeft,
+        Self::Top,
+        Self::TopRight,
+        Self::BottomLeft,
+        Self::Bottom,
+        Self::BottomRight,
+    ];
+}
 
-        pub fn timeout(self, seconds: u64) -> Self {
-            Self {
-                timeout_secs: seconds,
-                ..self
-            }
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Position::TopLeft => "Top left",
+            Position::Top => "Top",
+            Position::TopRight => "Top right",
+            Position::BottomLeft => "Bottom left",
+            Position::Bottom => "Bottom",
+            Position::BottomRight => "Bottom right",
         }
+        .fmt(f)
     }
+}
 
-    impl<Message> Widget<Message, Theme, Renderer> for Manager<'_, Message> {
-        fn size(&self) -> Size<Length> {
-            self.content.as_widget().size()
+impl From<Position> for toast::Position {
+    fn from(position: Position) -> Self {
+        match position {
+            Position::TopLeft => toast::Position::TopLeft,
+            Position::Top => toast::Position::Top,
+            Position::TopRight => toast::Position::TopRight,
+            Position::BottomLeft => toast::Position::BottomLeft,
+            Position::Bottom => toast::Position::Bottom,
+            Position::BottomRight => toast::Position::BottomRight,
         }
+    }
+}
 
-        fn layout(
-            &mut self,
-            tree: &mut Tree,
-            renderer: &Renderer,
-            limits: &layout::Limits,
-        ) -> layout::Node {
-            self.content.as_widget_mut().layout(
-                &mut tree.children[0],
-                renderer,
-                limits,
-            )
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::time::Instant;
+    use iced::{Point, window};
+    use iced_test::{Error, simulator};
+
+    /// Returns a redraw request the given amount of seconds after `start`.
+    fn redraw(start: Instant, seconds: u64) -> Event {
+        Event::Window(window::Event::RedrawRequested(
+            start + Duration::from_secs(seconds),
+        ))
+    }
 
-        fn tag(&self) -> widget::tree::Tag {
-            struct Marker;
-            widget::tree::Tag::of::<Marker>()
-        }
+    #[test]
+    fn it_dismisses_toasts_after_their_timeout() -> Result<(), Error> {
+        let mut app = App::new();
+        let start = Instant::now();
 
-        fn state(&self) -> widget::tree::State {
-            widget::tree::State::new(Vec::<Option<Instant>>::new())
-        }
+        let mut ui = simulator(app.view());
 
-        fn children(&self) -> Vec<Tree> {
-            std::iter::once(Tree::new(&self.content))
-                .chain(self.toasts.iter().map(Tree::new))
-                .collect()
+        for seconds in [1, 4, 6] {
+            let _ = ui.simulate([redraw(start, seconds)]);
+            let _ = ui.find("Example Toast")?;
         }
 
-        fn diff(&self, tree: &mut Tree) {
-            let instants = tree.state.downcast_mut::<Vec<Option<Instant>>>();
+        let _ = ui.simulate([redraw(start, 7)]);
+        assert!(ui.find("Example Toast").is_err());
 
-            // Invalidating removed instants to None allows us to remove
-            // them here so that diffing for removed / new toast instants
-            // is accurate
-            instants.retain(Option::is_some);
-
-            match (instants.len(), self.toasts.len()) {
-                (old, new) if old > new => {
-                    instants.truncate(new);
-                }
-                (old, new) if old < new => {
-                    instants.extend(std::iter::repeat_n(
-                        Some(Instant::now()),
-                        new - old,
-                    ));
-                }
-                _ => {}
-            }
+        let messages: Vec<_> = ui.into_messages().collect();
+        assert!(matches!(messages.as_slice(), [Message::Dismiss(0)]));
 
-            tree.diff_children(
-                &std::iter::once(&self.content)
-                    .chain(self.toasts.iter())
-                    .collect::<Vec<_>>(),
-            );
+        for message in messages {
+            let _ = app.update(message);
         }
 
-        fn operate(
-            &mut self,
-            tree: &mut Tree,
-            layout: Layout<'_>,
-            renderer: &Renderer,
-            operation: &mut dyn Operation,
-        ) {
-            operation.container(None, layout.bounds());
-            operation.traverse(&mut |operation| {
-                self.content.as_widget_mut().operate(
-                    &mut tree.children[0],
-                    layout,
-                    renderer,
-                    operation,
-                );
-            });
-        }
+        let mut ui = simulator(app.view());
+        assert!(ui.find("Example Toast").is_err());
 
-        fn update(
-            &mut self,
-            tree: &mut Tree,
-            event: &Event,
-            layout: Layout<'_>,
-            cursor: mouse::Cursor,
-            renderer: &Renderer,
-            clipboard: &mut dyn Clipboard,
-            shell: &mut Shell<'_, Message>,
-            viewport: &Rectangle,
-        ) {
-            self.content.as_widget_mut().update(
-                &mut tree.children[0],
-                event,
-                layout,
-                cursor,
-                renderer,
-                clipboard,
-                shell,
-                viewport,
-            );
-        }
+        Ok(())
+    }
 
-        fn draw(
-            &self,
-            tree: &Tree,
-            renderer: &mut Renderer,
-            theme: &Theme,
-            style: &renderer::Style,
-            layout: Layout<'_>,
-            cursor: mouse::Cursor,
-            viewport: &Rectangle,
-        ) {
-            self.content.as_widget().draw(
-                &tree.children[0],
-                renderer,
-                theme,
-                style,
-                layout,
-                cursor,
-                viewport,
-            );
-        }
+    #[test]
+    fn it_pauses_toasts_while_hovered() -> Result<(), Error> {
+        let app = App::new();
+        let start = Instant::now();
 
-        fn mouse_interaction(
-            &self,
-            tree: &Tree,
-            layout: Layout<'_>,
-            cursor: mouse::Cursor,
-            viewport: &Rectangle,
-            renderer: &Renderer,
-        ) -> mouse::Interaction {
-            self.content.as_widget().mouse_interaction(
-                &tree.children[0],
-                layout,
-                cursor,
-                viewport,
-                renderer,
-            )
-        }
+        let mut ui = simulator(app.view());
+        let _ = ui.simulate([redraw(start, 1)]);
+
+        // Hovered with roughly 4 seconds left
+        let toast = ui.find("Example Toast")?.bounds();
+        ui.point_at(toast.center());
 
-        fn overlay<'b>(
-            &'b mut self,
-            tree: &'b mut Tree,
-            layout: Layout<'b>,
-            renderer: &Renderer,
-            viewport: &Rectangle,
-            translation: Vector,
-        ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-            let instants = tree.state.downcast_mut::<Vec<Option<Instant>>>();
-
-            let (content_state, toasts_state) = tree.children.split_at_mut(1);
-
-            let content = self.content.as_widget_mut().overlay(
-                &mut content_state[0],
-                layout,
-                renderer,
-                viewport,
-                translation,
-            );
-
-            let toasts = (!self.toasts.is_empty()).then(|| {
-                overlay::Element::new(Box::new(Overlay {
-                    position: layout.bounds().position() + translation,
-                    viewport: *viewport,
-                    toasts: &mut self.toasts,
-                    trees: toasts_state,
-                    instants,
-                    on_close: &self.on_close,
-                    timeout_secs: self.timeout_secs,
-                }))
-            });
-            let overlays =
-                content.into_iter().chain(toasts).collect::<Vec<_>>();
-
-            (!overlays.is_empty())
-                .then(|| overlay::Group::with_children(overlays).overlay())
+        for seconds in [1, 10] {
+            let _ = ui.simulate([redraw(start, seconds)]);
+            let _ = ui.find("Example Toast")?;
         }
-    }
 
-    struct Overlay<'a, 'b, Message> {
-        position: Point,
-        viewport: Rectangle,
-        toasts: &'b mut [Element<'a, Message>],
-        trees: &'b mut [Tree],
-        instants: &'b mut [Option<Instant>],
-        on_close: &'b dyn Fn(usize) -> Message,
-        timeout_secs: u64,
-    }
+        // Resumed with the remaining time
+        ui.point_at(Point::ORIGIN);
 
-    impl<Message> overlay::Overlay<Message, Theme, Renderer>
-        for Overlay<'_, '_, Message>
-    {
-        fn layout(
-            &mut self,
-            renderer: &Renderer,
-            bounds: Size,
-        ) -> layout::Node {
-            let limits = layout::Limits::new(Size::ZERO, bounds);
-
-            layout::flex::resolve(
-                layout::flex::Axis::Vertical,
-                renderer,
-                &limits,
-                Fill,
-                Fill,
-                10.into(),
-                10.0,
-                Alignment::End,
-                self.toasts,
-                self.trees,
-            )
-            .translate(Vector::new(self.position.x, self.position.y))
+        for seconds in [11, 14, 16] {
+            let _ = ui.simulate([redraw(start, seconds)]);
+            let _ = ui.find("Example Toast")?;
         }
 
-        fn update(
-            &mut self,
-            event: &Event,
-            layout: Layout<'_>,
-            cursor: mouse::Cursor,
-            renderer: &Renderer,
-            clipboard: &mut dyn Clipboard,
-            shell: &mut Shell<'_, Message>,
-        ) {
-            if let Event::Window(window::Event::RedrawRequested(now)) = &event {
-                self.instants.iter_mut().enumerate().for_each(
-                    |(index, maybe_instant)| {
-                        if let Some(instant) = maybe_instant.as_mut() {
-                            let remaining = time::seconds(self.timeout_secs)
-                                .saturating_sub(instant.elapsed());
-
-                            if remaining == Duration::ZERO {
-                                maybe_instant.take();
-                                shell.publish((self.on_close)(index));
-                            } else {
-                                shell.request_redraw_at(*now + remaining);
-                            }
-                        }
-                    },
-                );
-            }
+        let _ = ui.simulate([redraw(start, 17)]);
+        assert!(ui.find("Example Toast").is_err());
 
-            let viewport = layout.bounds();
-
-            for (((child, state), layout), instant) in self
-                .toasts
-                .iter_mut()
-                .zip(self.trees.iter_mut())
-                .zip(layout.children())
-                .zip(self.instants.iter_mut())
-            {
-                let mut local_messages = vec![];
-                let mut local_shell = Shell::new(&mut local_messages);
-
-                child.as_widget_mut().update(
-                    state,
-                    event,
-                    layout,
-                    cursor,
-                    renderer,
-                    clipboard,
-                    &mut local_shell,
-                    &viewport,
-                );
-
-                if !local_shell.is_empty() {
-                    instant.take();
-                }
+        assert!(matches!(
+            ui.into_messages().collect::<Vec<_>>().as_slice(),
+            [Message::Dismiss(0)]
+        ));
 
-                shell.merge(local_shell, std::convert::identity);
-            }
-        }
+        Ok(())
+    }
 
-        fn draw(
-            &self,
-            renderer: &mut Renderer,
-            theme: &Theme,
-            style: &renderer::Style,
-            layout: Layout<'_>,
-            cursor: mouse::Cursor,
-        ) {
-            let viewport = layout.bounds();
-
-            for ((child, tree), layout) in self
-                .toasts
-                .iter()
-                .zip(self.trees.iter())
-                .zip(layout.children())
-            {
-                child.as_widget().draw(
-                    tree, renderer, theme, style, layout, cursor, &viewport,
-                );
-            }
-        }
+    #[test]
+    fn it_edits_toasts_with_their_action() -> Result<(), Error> {
+        let mut app = App::new();
+        let start = Instant::now();
 
-        fn operate(
-            &mut self,
-            layout: Layout<'_>,
-            renderer: &Renderer,
-            operation: &mut dyn widget::Operation,
-        ) {
-            operation.container(None, layout.bounds());
-            operation.traverse(&mut |operation| {
-                self.toasts
-                    .iter_mut()
-                    .zip(self.trees.iter_mut())
-                    .zip(layout.children())
-                    .for_each(|((child, state), layout)| {
-                        child
-                            .as_widget_mut()
-                            .operate(state, layout, renderer, operation);
-                    });
-            });
-        }
+        let mut ui = simulator(app.view());
+        let _ = ui.simulate([redraw(start, 1)]);
+        let _ = ui.click("Edit")?;
 
-        fn mouse_interaction(
-            &self,
-            layout: Layout<'_>,
-            cursor: mouse::Cursor,
-            renderer: &Renderer,
-        ) -> mouse::Interaction {
-            self.toasts
-                .iter()
-                .zip(self.trees.iter())
-                .zip(layout.children())
-                .map(|((child, state), layout)| {
-                    child
-                        .as_widget()
-                        .mouse_interaction(
-                            state,
-                            layout,
-                            cursor,
-                            &self.viewport,
-                            renderer,
-                        )
-                        .max(if cursor.is_over(layout.bounds()) {
-                            mouse::Interaction::Idle
-                        } else {
-                            Default::default()
-                        })
-                })
-                .max()
-                .unwrap_or_default()
-        }
-    }
+        let _ = ui.simulate([redraw(start, 2)]);
+        assert!(ui.find("Example Toast").is_err());
 
-    impl<'a, Message> From<Manager<'a, Message>> for Element<'a, Message>
-    where
-        Message: 'a,
-    {
-        fn from(manager: Manager<'a, Message>) -> Self {
-            Element::new(manager)
+        for message in ui.into_messages() {
+            let _ = app.update(message);
         }
+
+        assert!(app.toasts.is_empty());
+        assert_eq!(app.editing.title, "Example Toast");
+
+        Ok(())
     }
 }
@@ -1,7 +1,9 @@
 //! Manage colors for shaders.
 use crate::core::Color;
+use crate::gradient::pack_f16s;
 
 use bytemuck::{Pod, Zeroable};
+use half::f16;
 
 /// A color packed as 4 floats representing RGBA channels.
 #[derive(Debug, Clone, Copy, PartialEq, Zeroable, Pod)]
@@ -23,6 +25,14 @@ pub fn pack(color: impl Into<Color>) -> Packed {
     Packed(internal::pack(color.into()))
 }
 
+/// Packs a [`Color`] as pairs of half-precision floats; halving the size
+/// of a [`Packed`] color.
+pub fn pack_half(color: impl Into<Color>) -> [u32; 2] {
+    let [r, g, b, a] = internal::pack(color.into()).map(f16::from_f32);
+
+    [pack_f16s([r, g]), pack_f16s([b, a])]
+}
+
 #[cfg(not(feature = "web-colors"))]
 mod internal {
     use crate::core::Color;
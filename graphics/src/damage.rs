@@ -42,6 +42,32 @@ pub fn list<T>(
     })
 }
 
+/// Computes the grouped damage regions between the layers of two frames
+/// inside the given bounds.
+///
+/// If there is no `previous` frame, the whole `bounds` are damaged.
+pub fn frame<T>(
+    previous: Option<&[T]>,
+    current: &[T],
+    bounds: Rectangle,
+    layer_bounds: impl Fn(&T) -> Rectangle,
+    layer_damage: impl Fn(&T, &T) -> Vec<Rectangle>,
+) -> Vec<Rectangle> {
+    let Some(previous) = previous else {
+        return vec![bounds];
+    };
+
+    group(
+        diff(
+            previous,
+            current,
+            |layer| vec![layer_bounds(layer)],
+            layer_damage,
+        ),
+        bounds,
+    )
+}
+
 /// Groups the given damage regions that are close together inside the given
 /// bounds.
 pub fn group(mut damage: Vec<Rectangle>, bounds: Rectangle) -> Vec<Rectangle> {
@@ -76,3 +102,141 @@ pub fn group(mut damage: Vec<Rectangle>, bounds: Rectangle) -> Vec<Rectangle> {
 
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Size;
+
+    fn rectangle(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(width, height))
+    }
+
+    #[test]
+    fn list_ignores_equal_primitives() {
+        let a = [rectangle(0.0, 0.0, 10.0, 10.0)];
+
+        let damage = list(&a, &a, |bounds| vec![*bounds], PartialEq::eq);
+
+        assert!(damage.is_empty());
+    }
+
+    #[test]
+    fn list_damages_changed_primitives() {
+        let previous = [rectangle(0.0, 0.0, 10.0, 10.0)];
+        let current = [rectangle(5.0, 0.0, 10.0, 10.0)];
+
+        let damage =
+            list(&previous, &current, |bounds| vec![*bounds], PartialEq::eq);
+
+        assert_eq!(damage, vec![previous[0], current[0]]);
+    }
+
+    #[test]
+    fn diff_damages_added_and_removed_primitives() {
+        let a = rectangle(0.0, 0.0, 10.0, 10.0);
+        let b = rectangle(20.0, 20.0, 10.0, 10.0);
+
+        let added = list(&[a], &[a, b], |bounds| vec![*bounds], PartialEq::eq);
+        let removed =
+            list(&[a, b], &[a], |bounds| vec![*bounds], PartialEq::eq);
+
+        assert_eq!(added, vec![b]);
+        assert_eq!(removed, vec![b]);
+    }
+
+    #[test]
+    fn group_merges_nearby_regions() {
+        let bounds = rectangle(0.0, 0.0, 1000.0, 1000.0);
+
+        let damage = group(
+            vec![
+                rectangle(0.0, 0.0, 10.0, 10.0),
+                rectangle(10.0, 0.0, 10.0, 10.0),
+            ],
+            bounds,
+        );
+
+        assert_eq!(damage, vec![rectangle(0.0, 0.0, 20.0, 10.0)]);
+    }
+
+    #[test]
+    fn group_keeps_distant_regions_apart() {
+        let bounds = rectangle(0.0, 0.0, 1000.0, 1000.0);
+
+        let damage = group(
+            vec![
+                rectangle(900.0, 900.0, 10.0, 10.0),
+                rectangle(0.0, 0.0, 10.0, 10.0),
+            ],
+            bounds,
+        );
+
+        assert_eq!(
+            damage,
+            vec![
+                rectangle(0.0, 0.0, 10.0, 10.0),
+                rectangle(900.0, 900.0, 10.0, 10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn group_clips_and_discards_regions() {
+        let bounds = rectangle(0.0, 0.0, 100.0, 100.0);
+
+        let damage = group(
+            vec![
+                rectangle(90.0, 90.0, 20.0, 20.0),
+                rectangle(200.0, 200.0, 10.0, 10.0),
+                rectangle(50.0, 50.0, 0.5, 0.5),
+            ],
+            bounds,
+        );
+
+        assert_eq!(damage, vec![rectangle(90.0, 90.0, 10.0, 10.0)]);
+    }
+
+    #[test]
+    fn frame_without_previous_damages_everything() {
+        let bounds = rectangle(0.0, 0.0, 100.0, 100.0);
+
+        let damage =
+            frame(None, &[bounds], bounds, |layer| *layer, |_, _| vec![]);
+
+        assert_eq!(damage, vec![bounds]);
+    }
+
+    #[test]
+    fn frame_with_unchanged_layers_is_empty() {
+        let bounds = rectangle(0.0, 0.0, 100.0, 100.0);
+        let layers = [bounds, rectangle(10.0, 10.0, 10.0, 10.0)];
+
+        let damage = frame(
+            Some(&layers),
+            &layers,
+            bounds,
+            |layer| *layer,
+            |_, _| vec![],
+        );
+
+        assert!(damage.is_empty());
+    }
+
+    #[test]
+    fn frame_damages_new_layers_and_changes() {
+        let bounds = rectangle(0.0, 0.0, 1000.0, 1000.0);
+        let changed = rectangle(10.0, 10.0, 10.0, 10.0);
+        let added = rectangle(900.0, 900.0, 10.0, 10.0);
+
+        let damage = frame(
+            Some(&[bounds]),
+            &[bounds, added],
+            bounds,
+            |layer| *layer,
+            |_, _| vec![changed],
+        );
+
+        assert_eq!(damage, vec![changed, added]);
+    }
+}
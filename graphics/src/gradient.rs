@@ -269,6 +269,6 @@ pub fn pack(gradient: &Gradient, bounds: Rectangle) -> Packed {
 }
 
 /// Pack two f16 into u32
-fn pack_f16s(f: [f16; 2]) -> u32 {
+pub(crate) fn pack_f16s(f: [f16; 2]) -> u32 {
     ((f[0].to_bits() as u32) << 16) | (f[1].to_bits() as u32)
 }
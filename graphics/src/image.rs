@@ -1,4 +1,6 @@
 //! Load and operate on images.
+pub mod cache;
+
 #[cfg(feature = "image")]
 use crate::core::Bytes;
 
@@ -160,6 +162,113 @@ pub fn load(handle: &image::Handle) -> Result<Buffer, image::Error> {
     }
 }
 
+#[cfg(feature = "animated-image")]
+/// Tries to load all the frames of an animated image by its [`Handle`].
+///
+/// GIF, APNG, and animated WebP images are supported. Any other image is
+/// loaded with [`load`] as a single frame without delay.
+///
+/// Decoding every frame of an animation can be expensive; consider calling
+/// this function in a background thread.
+///
+/// [`Handle`]: image::Handle
+pub fn load_frames(
+    handle: &image::Handle,
+) -> Result<image::Frames, image::Error> {
+    use ::image::codecs::gif::GifDecoder;
+    use ::image::codecs::png::PngDecoder;
+    use ::image::codecs::webp::WebPDecoder;
+    use ::image::{AnimationDecoder, ImageFormat};
+    use std::io::Cursor;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn collect(
+        frames: ::image::Frames<'_>,
+    ) -> Result<Vec<image::Frame>, image::Error> {
+        frames
+            .map(|frame| {
+                let frame = frame.map_err(to_error)?;
+                let delay = Duration::from(frame.delay());
+                let buffer = frame.into_buffer();
+
+                Ok(image::Frame {
+                    handle: image::Handle::from_rgba(
+                        buffer.width(),
+                        buffer.height(),
+                        buffer.into_raw(),
+                    ),
+                    // Follow the convention of web browsers and slow down
+                    // frames with tiny delays
+                    delay: if delay <= Duration::from_millis(10) {
+                        Duration::from_millis(100)
+                    } else {
+                        delay
+                    },
+                })
+            })
+            .collect()
+    }
+
+    let bytes = match handle {
+        image::Handle::Path(_, path) => std::fs::read(path)
+            .map(Bytes::from)
+            .map_err(|error| image::Error::Inaccessible(Arc::new(error)))?,
+        image::Handle::Bytes(_, bytes) => bytes.clone(),
+        image::Handle::Rgba { .. } => {
+            return Ok(image::Frames::new([image::Frame {
+                handle: handle.clone(),
+                delay: Duration::ZERO,
+            }]));
+        }
+    };
+
+    let frames = match ::image::guess_format(&bytes) {
+        Ok(ImageFormat::Gif) => Some(collect(
+            GifDecoder::new(Cursor::new(&bytes))
+                .map_err(to_error)?
+                .into_frames(),
+        )?),
+        Ok(ImageFormat::Png) => {
+            let decoder =
+                PngDecoder::new(Cursor::new(&bytes)).map_err(to_error)?;
+
+            if decoder.is_apng().map_err(to_error)? {
+                Some(collect(decoder.apng().map_err(to_error)?.into_frames())?)
+            } else {
+                None
+            }
+        }
+        Ok(ImageFormat::WebP) => {
+            let decoder =
+                WebPDecoder::new(Cursor::new(&bytes)).map_err(to_error)?;
+
+            if decoder.has_animation() {
+                Some(collect(decoder.into_frames())?)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+
+    match frames {
+        Some(frames) if !frames.is_empty() => Ok(image::Frames::new(frames)),
+        _ => {
+            let image = load(handle)?;
+
+            Ok(image::Frames::new([image::Frame {
+                handle: image::Handle::from_rgba(
+                    image.width(),
+                    image.height(),
+                    image.into_raw(),
+                ),
+                delay: Duration::ZERO,
+            }]))
+        }
+    }
+}
+
 #[cfg(feature = "image")]
 fn to_error(error: ::image::ImageError) -> image::Error {
     use std::sync::Arc;
@@ -171,3 +280,91 @@ fn to_error(error: ::image::ImageError) -> image::Error {
         error => image::Error::Invalid(Arc::new(error)),
     }
 }
+
+#[cfg(all(test, feature = "animated-image"))]
+mod tests {
+    use super::*;
+
+    use ::image::codecs::gif::{GifEncoder, Repeat};
+    use ::image::codecs::png::PngEncoder;
+    use ::image::{Delay, ImageEncoder, RgbaImage};
+    use std::time::Duration;
+
+    fn gif(delays: &[u32]) -> image::Handle {
+        let mut bytes = Vec::new();
+
+        {
+            let mut encoder = GifEncoder::new(&mut bytes);
+            encoder.set_repeat(Repeat::Infinite).unwrap();
+
+            encoder
+                .encode_frames(delays.iter().enumerate().map(|(i, delay)| {
+                    ::image::Frame::from_parts(
+                        RgbaImage::from_pixel(2, 2, [i as u8 * 50; 4].into()),
+                        0,
+                        0,
+                        Delay::from_numer_denom_ms(*delay, 1),
+                    )
+                }))
+                .unwrap();
+        }
+
+        image::Handle::from_bytes(bytes)
+    }
+
+    fn delays(frames: &image::Frames) -> Vec<Duration> {
+        frames.iter().map(|frame| frame.delay).collect()
+    }
+
+    #[test]
+    fn load_frames_of_gif() {
+        let frames = load_frames(&gif(&[50, 200, 30])).unwrap();
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(
+            delays(&frames),
+            [50, 200, 30].map(Duration::from_millis).to_vec()
+        );
+        assert_eq!(frames.duration(), Duration::from_millis(280));
+    }
+
+    #[test]
+    fn load_frames_slows_down_tiny_delays() {
+        let frames = load_frames(&gif(&[0, 10, 20])).unwrap();
+
+        assert_eq!(
+            delays(&frames),
+            [100, 100, 20].map(Duration::from_millis).to_vec()
+        );
+    }
+
+    #[test]
+    fn load_frames_of_still_image() {
+        let mut bytes = Vec::new();
+
+        PngEncoder::new(&mut bytes)
+            .write_image(
+                &[255; 3 * 2 * 4],
+                3,
+                2,
+                ::image::ExtendedColorType::Rgba8,
+            )
+            .unwrap();
+
+        let frames = load_frames(&image::Handle::from_bytes(bytes)).unwrap();
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(delays(&frames), [Duration::ZERO]);
+
+        let rgba = image::Handle::from_rgba(1, 1, vec![0; 4]);
+        let frames = load_frames(&rgba).unwrap();
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames.get(0).map(|frame| &frame.handle), Some(&rgba));
+    }
+
+    #[test]
+    fn load_frames_of_invalid_image() {
+        assert!(load_frames(&image::Handle::from_bytes(&b"nope"[..])).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+//! Keep track of the memory used by an image cache.
+use crate::core::image::{Id, Stats};
+
+use rustc_hash::FxHashMap;
+
+/// Tracks the usage of the entries of an image cache and decides which
+/// ones must be evicted to respect a memory budget.
+///
+/// Entries used since the last trim are never evicted. The rest are
+/// evicted in least recently used order until the memory of the cache
+/// fits in the budget.
+#[derive(Debug, Default)]
+pub struct Tracker {
+    budget: Option<u64>,
+    frame: u64,
+    entries: FxHashMap<Id, Entry>,
+    memory: u64,
+    stats: Stats,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    size: u64,
+    last_used: u64,
+}
+
+impl Tracker {
+    /// Creates a new [`Tracker`] with the given memory budget, in bytes.
+    ///
+    /// If no budget is provided, unused entries will be evicted on every
+    /// trim.
+    pub fn new(budget: Option<u64>) -> Self {
+        Self {
+            budget,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the memory budget of the [`Tracker`], in bytes.
+    pub fn budget(&self) -> Option<u64> {
+        self.budget
+    }
+
+    /// Sets the memory budget of the [`Tracker`], in bytes.
+    pub fn set_budget(&mut self, budget: Option<u64>) {
+        self.budget = budget;
+    }
+
+    /// Records a lookup of the given [`Id`] in the cache.
+    ///
+    /// Returns true if the entry was present; that is, a hit.
+    pub fn lookup(&mut self, id: Id) -> bool {
+        if let Some(entry) = self.entries.get_mut(&id) {
+            entry.last_used = self.frame;
+            self.stats.hits += 1;
+
+            true
+        } else {
+            self.stats.misses += 1;
+
+            false
+        }
+    }
+
+    /// Marks the entry with the given [`Id`] as used.
+    pub fn touch(&mut self, id: Id) {
+        if let Some(entry) = self.entries.get_mut(&id) {
+            entry.last_used = self.frame;
+        }
+    }
+
+    /// Inserts or replaces an entry of the given size, in bytes, and marks
+    /// it as used.
+    pub fn insert(&mut self, id: Id, size: u64) {
+        let entry = Entry {
+            size,
+            last_used: self.frame,
+        };
+
+        if let Some(old) = self.entries.insert(id, entry) {
+            self.memory -= old.size;
+        }
+
+        self.memory += size;
+    }
+
+    /// Removes the entry with the given [`Id`], if present.
+    ///
+    /// Removed entries count as evictions.
+    pub fn remove(&mut self, id: Id) {
+        if let Some(entry) = self.entries.remove(&id) {
+            self.memory -= entry.size;
+            self.stats.evictions += 1;
+        }
+    }
+
+    /// Returns the entries that must be evicted to respect the budget and
+    /// starts a new usage period.
+    ///
+    /// The entries for which `is_retained` returns true will be kept.
+    ///
+    /// The returned entries are removed from the [`Tracker`]; so they must
+    /// be removed from the cache as well.
+    pub fn trim(&mut self, is_retained: impl Fn(Id) -> bool) -> Vec<Id> {
+        let mut unused: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|(id, entry)| {
+                entry.last_used < self.frame && !is_retained(**id)
+            })
+            .map(|(id, entry)| (*id, *entry))
+            .collect();
+
+        unused.sort_by_key(|(_, entry)| entry.last_used);
+
+        let mut memory = self.memory;
+        let mut evicted = Vec::new();
+
+        for (id, entry) in unused {
+            if self.budget.is_some_and(|budget| memory <= budget) {
+                break;
+            }
+
+            memory -= entry.size;
+            evicted.push(id);
+        }
+
+        for id in &evicted {
+            self.remove(*id);
+        }
+
+        self.frame += 1;
+
+        evicted
+    }
+
+    /// Returns the current [`Stats`] of the [`Tracker`].
+    pub fn stats(&self) -> Stats {
+        Stats {
+            images: self.entries.len(),
+            memory: self.memory,
+            ..self.stats
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::image::Handle;
+
+    fn id(n: u8) -> Id {
+        Handle::from_path(format!("image-{n}.png")).id()
+    }
+
+    #[test]
+    fn trim_without_budget_evicts_unused() {
+        let mut tracker = Tracker::new(None);
+
+        tracker.insert(id(0), 4);
+        tracker.insert(id(1), 4);
+        assert!(tracker.trim(|_| false).is_empty());
+
+        assert!(tracker.lookup(id(0)));
+        assert_eq!(tracker.trim(|_| false), vec![id(1)]);
+        assert_eq!(tracker.stats().images, 1);
+    }
+
+    #[test]
+    fn trim_with_budget_evicts_least_recently_used() {
+        let mut tracker = Tracker::new(Some(8));
+
+        tracker.insert(id(0), 4);
+        let _ = tracker.trim(|_| false);
+
+        tracker.insert(id(1), 4);
+        let _ = tracker.trim(|_| false);
+
+        tracker.insert(id(2), 4);
+        assert_eq!(tracker.trim(|_| false), vec![id(0)]);
+
+        let stats = tracker.stats();
+
+        assert_eq!(stats.images, 2);
+        assert_eq!(stats.memory, 8);
+        assert_eq!(stats.evictions, 1);
+    }
+
+    #[test]
+    fn trim_keeps_retained_entries() {
+        let mut tracker = Tracker::new(Some(0));
+
+        tracker.insert(id(0), 4);
+        tracker.insert(id(1), 4);
+        let _ = tracker.trim(|_| false);
+
+        assert_eq!(tracker.trim(|id| id == self::id(0)), vec![id(1)]);
+        assert!(!tracker.lookup(id(1)));
+        assert_eq!(tracker.stats().misses, 1);
+    }
+}
@@ -21,6 +21,14 @@ pub struct Settings {
     ///
     /// By default, it is `true`.
     pub vsync: bool,
+
+    /// The maximum amount of memory, in bytes, that the image cache may use.
+    ///
+    /// Images that are not drawn anymore will be kept until the budget is
+    /// exceeded; evicting the least recently used first.
+    ///
+    /// By default, it is `None`; and unused images are evicted right away.
+    pub image_cache_budget: Option<u64>,
 }
 
 impl Default for Settings {
@@ -30,6 +38,7 @@ impl Default for Settings {
             default_text_size: Pixels(16.0),
             antialiasing: None,
             vsync: true,
+            image_cache_budget: None,
         }
     }
 }
@@ -49,6 +58,7 @@ impl From<core::Settings> for Settings {
             default_text_size: settings.default_text_size,
             antialiasing: settings.antialiasing.then_some(Antialiasing::MSAAx4),
             vsync: settings.vsync,
+            image_cache_budget: settings.image_cache_budget,
         }
     }
 }
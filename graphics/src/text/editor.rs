@@ -23,6 +23,8 @@ struct Internal {
     font: Font,
     bounds: Size,
     topmost_line_changed: Option<usize>,
+    cursors: Vec<Selection>,
+    pending_change: Option<cosmic_text::Change>,
     version: text::Version,
 }
 
@@ -56,6 +58,26 @@ impl Editor {
             .as_ref()
             .expect("Editor should always be initialized")
     }
+
+    fn internal_mut(&mut self) -> &mut Internal {
+        let internal = self
+            .0
+            .as_mut()
+            .expect("Editor should always be initialized");
+
+        // TODO: Handle multiple strong references somehow
+        let internal = Arc::get_mut(internal)
+            .expect("Editor cannot have multiple strong references");
+
+        // Clear cursor cache
+        let _ = internal
+            .cursor
+            .get_mut()
+            .expect("Write to cursor cache")
+            .take();
+
+        internal
+    }
 }
 
 impl editor::Editor for Editor {
@@ -110,7 +132,77 @@ impl editor::Editor for Editor {
     }
 
     fn selection(&self) -> Option<String> {
-        self.internal().editor.copy_selection()
+        let internal = self.internal();
+
+        if internal.cursors.is_empty() {
+            return internal.editor.copy_selection();
+        }
+
+        let buffer = buffer_from_editor(&internal.editor);
+
+        let mut selections: Vec<_> = internal
+            .cursors
+            .iter()
+            .copied()
+            .chain(std::iter::once(Selection::of(&internal.editor)))
+            .filter(|selection| selection.anchor.is_some())
+            .map(Selection::bounds)
+            .collect();
+
+        if selections.is_empty() {
+            return None;
+        }
+
+        selections.sort_by_key(|(start, _)| (start.line, start.index));
+
+        Some(
+            selections
+                .into_iter()
+                .map(|(start, end)| text_between(buffer, start, end))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    fn range_bounds(
+        &self,
+        (start_line, start_column): (usize, usize),
+        (end_line, end_column): (usize, usize),
+    ) -> Vec<Rectangle> {
+        let buffer = self.buffer();
+        let last_line = buffer.lines.len().saturating_sub(1);
+
+        range_bounds(
+            buffer,
+            cosmic_text::Cursor::new(start_line.min(last_line), start_column),
+            cosmic_text::Cursor::new(end_line.min(last_line), end_column),
+        )
+    }
+
+    fn visible_lines(&self) -> Vec<(usize, Rectangle)> {
+        let buffer = self.buffer();
+        let width = self.bounds().width;
+
+        let mut lines: Vec<(usize, Rectangle)> = Vec::new();
+
+        for run in buffer.layout_runs() {
+            match lines.last_mut() {
+                Some((line, bounds)) if *line == run.line_i => {
+                    bounds.height = run.line_top + run.line_height - bounds.y;
+                }
+                _ => lines.push((
+                    run.line_i,
+                    Rectangle {
+                        x: 0.0,
+                        y: run.line_top,
+                        width,
+                        height: run.line_height,
+                    },
+                )),
+            }
+        }
+
+        lines
     }
 
     fn cursor(&self) -> editor::Cursor {
@@ -125,122 +217,11 @@ impl editor::Editor for Editor {
 
         let cursor = match internal.editor.selection_bounds() {
             Some((start, end)) => {
-                let line_height = buffer.metrics().line_height;
-                let selected_lines = end.line - start.line + 1;
-
-                let visual_lines_offset =
-                    visual_lines_offset(start.line, buffer);
-
-                let regions = buffer
-                    .lines
-                    .iter()
-                    .skip(start.line)
-                    .take(selected_lines)
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        highlight_line(
-                            line,
-                            if i == 0 { start.index } else { 0 },
-                            if i == selected_lines - 1 {
-                                end.index
-                            } else {
-                                line.text().len()
-                            },
-                        )
-                    })
-                    .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(Rectangle {
-                                x,
-                                width,
-                                y: (visual_line as i32 + visual_lines_offset)
-                                    as f32
-                                    * line_height
-                                    - buffer.scroll().vertical,
-                                height: line_height,
-                            })
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+                let regions = range_bounds(buffer, start, end);
 
                 Cursor::Selection(regions)
             }
-            _ => {
-                let line_height = buffer.metrics().line_height;
-
-                let visual_lines_offset =
-                    visual_lines_offset(cursor.line, buffer);
-
-                let line = buffer
-                    .lines
-                    .get(cursor.line)
-                    .expect("Cursor line should be present");
-
-                let layout =
-                    line.layout_opt().expect("Line layout should be cached");
-
-                let mut lines = layout.iter().enumerate();
-
-                let (visual_line, offset) = lines
-                    .find_map(|(i, line)| {
-                        let start = line
-                            .glyphs
-                            .first()
-                            .map(|glyph| glyph.start)
-                            .unwrap_or(0);
-                        let end = line
-                            .glyphs
-                            .last()
-                            .map(|glyph| glyph.end)
-                            .unwrap_or(0);
-
-                        let is_cursor_before_start = start > cursor.index;
-
-                        let is_cursor_before_end = match cursor.affinity {
-                            cosmic_text::Affinity::Before => {
-                                cursor.index <= end
-                            }
-                            cosmic_text::Affinity::After => cursor.index < end,
-                        };
-
-                        if is_cursor_before_start {
-                            // Sometimes, the glyph we are looking for is right
-                            // between lines. This can happen when a line wraps
-                            // on a space.
-                            // In that case, we can assume the cursor is at the
-                            // end of the previous line.
-                            // i is guaranteed to be > 0 because `start` is always
-                            // 0 for the first line, so there is no way for the
-                            // cursor to be before it.
-                            Some((i - 1, layout[i - 1].w))
-                        } else if is_cursor_before_end {
-                            let offset = line
-                                .glyphs
-                                .iter()
-                                .take_while(|glyph| cursor.index > glyph.start)
-                                .map(|glyph| glyph.w)
-                                .sum();
-
-                            Some((i, offset))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((
-                        layout.len().saturating_sub(1),
-                        layout.last().map(|line| line.w).unwrap_or(0.0),
-                    ));
-
-                Cursor::Caret(Point::new(
-                    offset,
-                    (visual_lines_offset + visual_line as i32) as f32
-                        * line_height
-                        - buffer.scroll().vertical,
-                ))
-            }
+            _ => Cursor::Caret(caret_position(buffer, cursor)),
         };
 
         *internal.cursor.write().expect("Write to cursor cache") =
@@ -249,220 +230,150 @@ impl editor::Editor for Editor {
         cursor
     }
 
+    fn cursors(&self) -> Vec<editor::Cursor> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        std::iter::once(self.cursor())
+            .chain(internal.cursors.iter().map(|selection| {
+                if selection.anchor.is_some() {
+                    let (start, end) = selection.bounds();
+
+                    Cursor::Selection(range_bounds(buffer, start, end))
+                } else {
+                    Cursor::Caret(caret_position(buffer, selection.cursor))
+                }
+            }))
+            .collect()
+    }
+
     fn cursor_position(&self) -> (usize, usize) {
         let cursor = self.internal().editor.cursor();
 
         (cursor.line, cursor.index)
     }
 
-    fn perform(&mut self, action: Action) {
-        let mut font_system =
-            text::font_system().write().expect("Write font system");
+    fn selection_anchor(&self) -> Option<(usize, usize)> {
+        let editor = &self.internal().editor;
+        let (start, end) = editor.selection_bounds()?;
+        let cursor = editor.cursor();
 
-        let editor =
-            self.0.take().expect("Editor should always be initialized");
+        let anchor = if cursor.line == start.line && cursor.index == start.index
+        {
+            end
+        } else {
+            start
+        };
 
-        // TODO: Handle multiple strong references somehow
-        let mut internal = Arc::try_unwrap(editor)
-            .expect("Editor cannot have multiple strong references");
+        Some((anchor.line, anchor.index))
+    }
+
+    fn set_cursor_position(
+        &mut self,
+        (line, column): (usize, usize),
+        anchor: Option<(usize, usize)>,
+    ) {
+        let internal = self.internal_mut();
+        internal.cursors.clear();
 
         let editor = &mut internal.editor;
 
-        // Clear cursor cache
-        let _ = internal
-            .cursor
-            .write()
-            .expect("Write to cursor cache")
-            .take();
+        editor.set_cursor(cosmic_text::Cursor::new(line, column));
+        editor.set_selection(anchor.map_or(
+            cosmic_text::Selection::None,
+            |(line, column)| {
+                cosmic_text::Selection::Normal(cosmic_text::Cursor::new(
+                    line, column,
+                ))
+            },
+        ));
+    }
 
-        match action {
-            // Motion events
-            Action::Move(motion) => {
-                if let Some((start, end)) = editor.selection_bounds() {
-                    editor.set_selection(cosmic_text::Selection::None);
-
-                    match motion {
-                        // These motions are performed as-is even when a selection
-                        // is present
-                        Motion::Home
-                        | Motion::End
-                        | Motion::DocumentStart
-                        | Motion::DocumentEnd => {
-                            editor.action(
-                                font_system.raw(),
-                                cosmic_text::Action::Motion(to_motion(motion)),
-                            );
-                        }
-                        // Other motions simply move the cursor to one end of the selection
-                        _ => editor.set_cursor(match motion.direction() {
-                            Direction::Left => start,
-                            Direction::Right => end,
-                        }),
-                    }
-                } else {
-                    editor.action(
-                        font_system.raw(),
-                        cosmic_text::Action::Motion(to_motion(motion)),
-                    );
-                }
-            }
+    fn start_change(&mut self) {
+        let internal = self.internal_mut();
 
-            // Selection events
-            Action::Select(motion) => {
-                let cursor = editor.cursor();
+        internal.pending_change = None;
+        internal.editor.start_change();
+    }
 
-                if editor.selection_bounds().is_none() {
-                    editor
-                        .set_selection(cosmic_text::Selection::Normal(cursor));
-                }
+    fn finish_change(&mut self) -> Option<editor::Change> {
+        let internal = self.internal_mut();
+        let change = internal.editor.finish_change()?;
+
+        // Changes performed on multiple cursors are recorded separately
+        let pending = internal.pending_change.take().unwrap_or_default();
+
+        let change = editor::Change {
+            items: pending
+                .items
+                .into_iter()
+                .chain(change.items)
+                .map(|item| editor::ChangeItem {
+                    start: (item.start.line, item.start.index),
+                    end: (item.end.line, item.end.index),
+                    text: item.text,
+                    is_insertion: item.insert,
+                })
+                .collect(),
+        };
 
-                editor.action(
-                    font_system.raw(),
-                    cosmic_text::Action::Motion(to_motion(motion)),
-                );
+        (!change.is_empty()).then_some(change)
+    }
 
-                // Deselect if selection matches cursor position
-                if let Some((start, end)) = editor.selection_bounds()
-                    && start.line == end.line
-                    && start.index == end.index
-                {
-                    editor.set_selection(cosmic_text::Selection::None);
-                }
-            }
-            Action::SelectWord => {
-                let cursor = editor.cursor();
+    fn apply_change(&mut self, change: &editor::Change) {
+        let internal = self.internal_mut();
 
-                editor.set_selection(cosmic_text::Selection::Word(cursor));
-            }
-            Action::SelectLine => {
-                let cursor = editor.cursor();
+        let change = cosmic_text::Change {
+            items: change
+                .items
+                .iter()
+                .map(|item| cosmic_text::ChangeItem {
+                    start: cosmic_text::Cursor::new(item.start.0, item.start.1),
+                    end: cosmic_text::Cursor::new(item.end.0, item.end.1),
+                    text: item.text.clone(),
+                    insert: item.is_insertion,
+                })
+                .collect(),
+        };
 
-                editor.set_selection(cosmic_text::Selection::Line(cursor));
-            }
-            Action::SelectAll => {
-                let buffer = buffer_from_editor(editor);
-
-                if buffer.lines.len() > 1
-                    || buffer
-                        .lines
-                        .first()
-                        .is_some_and(|line| !line.text().is_empty())
-                {
-                    let cursor = editor.cursor();
-
-                    editor.set_selection(cosmic_text::Selection::Normal(
-                        cosmic_text::Cursor {
-                            line: 0,
-                            index: 0,
-                            ..cursor
-                        },
-                    ));
+        let topmost_line =
+            change.items.iter().map(|item| item.start.line).min();
 
-                    editor.action(
-                        font_system.raw(),
-                        cosmic_text::Action::Motion(
-                            cosmic_text::Motion::BufferEnd,
-                        ),
-                    );
-                }
-            }
+        internal.cursors.clear();
+        internal.editor.set_selection(cosmic_text::Selection::None);
+        let _ = internal.editor.apply_change(&change);
 
-            // Editing events
-            Action::Edit(edit) => {
-                let topmost_line_before_edit = editor
-                    .selection_bounds()
-                    .map(|(start, _)| start)
-                    .unwrap_or_else(|| editor.cursor())
-                    .line;
+        if let Some(line) = topmost_line {
+            internal.topmost_line_changed = Some(
+                internal
+                    .topmost_line_changed
+                    .map_or(line, |current| current.min(line)),
+            );
+        }
+    }
 
-                match edit {
-                    Edit::Insert(c) => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Insert(c),
-                        );
-                    }
-                    Edit::Paste(text) => {
-                        editor.insert_string(&text, None);
-                    }
-                    Edit::Indent => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Indent,
-                        );
-                    }
-                    Edit::Unindent => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Unindent,
-                        );
-                    }
-                    Edit::Enter => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Enter,
-                        );
-                    }
-                    Edit::Backspace => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Backspace,
-                        );
-                    }
-                    Edit::Delete => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Delete,
-                        );
-                    }
-                }
+    fn perform(&mut self, action: Action) {
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
 
-                let cursor = editor.cursor();
-                let selection_start = editor
-                    .selection_bounds()
-                    .map(|(start, _)| start)
-                    .unwrap_or(cursor);
+        let editor =
+            self.0.take().expect("Editor should always be initialized");
 
-                internal.topmost_line_changed =
-                    Some(selection_start.line.min(topmost_line_before_edit));
-            }
+        // TODO: Handle multiple strong references somehow
+        let mut internal = Arc::try_unwrap(editor)
+            .expect("Editor cannot have multiple strong references");
 
-            // Mouse events
-            Action::Click(position) => {
-                editor.action(
-                    font_system.raw(),
-                    cosmic_text::Action::Click {
-                        x: position.x as i32,
-                        y: position.y as i32,
-                    },
-                );
-            }
-            Action::Drag(position) => {
-                editor.action(
-                    font_system.raw(),
-                    cosmic_text::Action::Drag {
-                        x: position.x as i32,
-                        y: position.y as i32,
-                    },
-                );
+        // Clear cursor cache
+        let _ = internal
+            .cursor
+            .write()
+            .expect("Write to cursor cache")
+            .take();
 
-                // Deselect if selection matches cursor position
-                if let Some((start, end)) = editor.selection_bounds()
-                    && start.line == end.line
-                    && start.index == end.index
-                {
-                    editor.set_selection(cosmic_text::Selection::None);
-                }
-            }
-            Action::Scroll { lines } => {
-                editor.action(
-                    font_system.raw(),
-                    cosmic_text::Action::Scroll {
-                        pixels: lines as f32
-                            * buffer_from_editor(editor).metrics().line_height,
-                    },
-                );
-            }
+        if !internal.cursors.is_empty() && is_per_cursor(&action) {
+            perform_each(&mut internal, font_system.raw(), action);
+        } else {
+            perform(&mut internal, font_system.raw(), action);
         }
 
         self.0 = Some(Arc::new(internal));
@@ -689,6 +600,8 @@ impl Default for Internal {
             font: Font::default(),
             bounds: Size::ZERO,
             topmost_line_changed: None,
+            cursors: Vec::new(),
+            pending_change: None,
             version: text::Version::default(),
         }
     }
@@ -727,6 +640,727 @@ impl PartialEq for Weak {
     }
 }
 
+fn perform(
+    internal: &mut Internal,
+    font_system: &mut cosmic_text::FontSystem,
+    action: Action,
+) {
+    let editor = &mut internal.editor;
+
+    match action {
+        // Motion events
+        Action::Move(motion) => {
+            if let Some((start, end)) = editor.selection_bounds() {
+                editor.set_selection(cosmic_text::Selection::None);
+
+                match motion {
+                    // These motions are performed as-is even when a selection
+                    // is present
+                    Motion::Home
+                    | Motion::End
+                    | Motion::DocumentStart
+                    | Motion::DocumentEnd => {
+                        editor.action(
+                            font_system,
+                            cosmic_text::Action::Motion(to_motion(motion)),
+                        );
+                    }
+                    // Other motions simply move the cursor to one end of the selection
+                    _ => editor.set_cursor(match motion.direction() {
+                        Direction::Left => start,
+                        Direction::Right => end,
+                    }),
+                }
+            } else {
+                editor.action(
+                    font_system,
+                    cosmic_text::Action::Motion(to_motion(motion)),
+                );
+            }
+        }
+
+        // Selection events
+        Action::Select(motion) => {
+            let cursor = editor.cursor();
+
+            if editor.selection_bounds().is_none() {
+                editor.set_selection(cosmic_text::Selection::Normal(cursor));
+            }
+
+            editor.action(
+                font_system,
+                cosmic_text::Action::Motion(to_motion(motion)),
+            );
+
+            // Deselect if selection matches cursor position
+            if let Some((start, end)) = editor.selection_bounds()
+                && start.line == end.line
+                && start.index == end.index
+            {
+                editor.set_selection(cosmic_text::Selection::None);
+            }
+        }
+        Action::SelectWord => {
+            let cursor = editor.cursor();
+
+            editor.set_selection(cosmic_text::Selection::Word(cursor));
+        }
+        Action::SelectLine => {
+            let cursor = editor.cursor();
+
+            editor.set_selection(cosmic_text::Selection::Line(cursor));
+        }
+        Action::SelectAll => {
+            internal.cursors.clear();
+
+            let buffer = buffer_from_editor(editor);
+
+            if buffer.lines.len() > 1
+                || buffer
+                    .lines
+                    .first()
+                    .is_some_and(|line| !line.text().is_empty())
+            {
+                let cursor = editor.cursor();
+
+                editor.set_selection(cosmic_text::Selection::Normal(
+                    cosmic_text::Cursor {
+                        line: 0,
+                        index: 0,
+                        ..cursor
+                    },
+                ));
+
+                editor.action(
+                    font_system,
+                    cosmic_text::Action::Motion(cosmic_text::Motion::BufferEnd),
+                );
+            }
+        }
+
+        // Editing events
+        Action::Edit(edit) => {
+            let topmost_line_before_edit = editor
+                .selection_bounds()
+                .map(|(start, _)| start)
+                .unwrap_or_else(|| editor.cursor())
+                .line;
+
+            match edit {
+                Edit::Insert(c) => {
+                    editor.action(font_system, cosmic_text::Action::Insert(c));
+                }
+                Edit::Paste(text) => {
+                    editor.insert_string(&text, None);
+                }
+                Edit::Indent => {
+                    editor.action(font_system, cosmic_text::Action::Indent);
+                }
+                Edit::Unindent => {
+                    editor.action(font_system, cosmic_text::Action::Unindent);
+                }
+                Edit::Enter => {
+                    editor.action(font_system, cosmic_text::Action::Enter);
+                }
+                Edit::Backspace => {
+                    editor.action(font_system, cosmic_text::Action::Backspace);
+                }
+                Edit::Delete => {
+                    editor.action(font_system, cosmic_text::Action::Delete);
+                }
+            }
+
+            let cursor = editor.cursor();
+            let selection_start = editor
+                .selection_bounds()
+                .map(|(start, _)| start)
+                .unwrap_or(cursor);
+
+            let topmost_line =
+                selection_start.line.min(topmost_line_before_edit);
+
+            internal.topmost_line_changed = Some(
+                internal
+                    .topmost_line_changed
+                    .map_or(topmost_line, |line| line.min(topmost_line)),
+            );
+        }
+
+        // Mouse events
+        Action::Click(position) => {
+            internal.cursors.clear();
+
+            editor.action(
+                font_system,
+                cosmic_text::Action::Click {
+                    x: position.x as i32,
+                    y: position.y as i32,
+                },
+            );
+        }
+        Action::Drag(position) => {
+            editor.action(
+                font_system,
+                cosmic_text::Action::Drag {
+                    x: position.x as i32,
+                    y: position.y as i32,
+                },
+            );
+
+            // Deselect if selection matches cursor position
+            if let Some((start, end)) = editor.selection_bounds()
+                && start.line == end.line
+                && start.index == end.index
+            {
+                editor.set_selection(cosmic_text::Selection::None);
+            }
+        }
+        Action::Scroll { lines } => {
+            editor.action(
+                font_system,
+                cosmic_text::Action::Scroll {
+                    pixels: lines as f32
+                        * buffer_from_editor(editor).metrics().line_height,
+                },
+            );
+        }
+
+        // Multiple cursors
+        Action::AddCursor(position) => {
+            add_cursor(internal, font_system, position);
+        }
+        Action::SelectNextOccurrence => {
+            select_next_occurrence(internal);
+        }
+        Action::SelectColumn { from, to } => {
+            select_column(internal, from, to);
+        }
+
+        // History is kept by the owner of the editor
+        Action::Undo | Action::Redo => {}
+    }
+}
+
+/// Returns whether the [`Action`] must be performed on every cursor of
+/// an [`Editor`].
+fn is_per_cursor(action: &Action) -> bool {
+    matches!(
+        action,
+        Action::Move(_)
+            | Action::Select(_)
+            | Action::SelectWord
+            | Action::SelectLine
+            | Action::Edit(_)
+    )
+}
+
+/// Performs the given [`Action`] on every cursor of the [`Editor`].
+///
+/// The changes produced by the edits on one cursor are used to shift the
+/// rest of the cursors accordingly.
+fn perform_each(
+    internal: &mut Internal,
+    font_system: &mut cosmic_text::FontSystem,
+    action: Action,
+) {
+    let mut selections = std::mem::take(&mut internal.cursors);
+    selections.push(Selection::of(&internal.editor));
+
+    // Paste one line on every cursor if they match
+    let lines: Option<Vec<String>> = match &action {
+        Action::Edit(Edit::Paste(text)) => {
+            let lines: Vec<_> = text.lines().map(str::to_owned).collect();
+
+            (lines.len() == selections.len()).then_some(lines)
+        }
+        _ => None,
+    };
+
+    let ongoing_change = internal.editor.finish_change();
+    let mut items = internal
+        .pending_change
+        .take()
+        .into_iter()
+        .chain(ongoing_change.clone())
+        .flat_map(|change| change.items)
+        .collect::<Vec<_>>();
+
+    for i in 0..selections.len() {
+        selections[i].restore(&mut internal.editor);
+        internal.editor.start_change();
+
+        let action = match &lines {
+            Some(lines) => {
+                Action::Edit(Edit::Paste(Arc::new(lines[i].clone())))
+            }
+            None => action.clone(),
+        };
+
+        perform(internal, font_system, action);
+
+        let change = internal.editor.finish_change().unwrap_or_default();
+        selections[i] = Selection::of(&internal.editor);
+
+        for (j, selection) in selections.iter_mut().enumerate() {
+            if i != j {
+                for item in &change.items {
+                    selection.shift(item);
+                }
+            }
+        }
+
+        items.extend(change.items);
+    }
+
+    if ongoing_change.is_some() {
+        internal.editor.start_change();
+        internal.pending_change = Some(cosmic_text::Change { items });
+    }
+
+    let mut selections = merge(selections);
+    let primary = selections.pop().expect("Primary cursor should exist");
+
+    primary.restore(&mut internal.editor);
+    internal.cursors = selections;
+}
+
+fn add_cursor(
+    internal: &mut Internal,
+    font_system: &mut cosmic_text::FontSystem,
+    position: Point,
+) {
+    let previous = Selection::of(&internal.editor);
+
+    internal.editor.action(
+        font_system,
+        cosmic_text::Action::Click {
+            x: position.x as i32,
+            y: position.y as i32,
+        },
+    );
+
+    let clicked = Selection::of(&internal.editor);
+
+    if let Some(i) = internal
+        .cursors
+        .iter()
+        .position(|selection| selection.is_caret_at(clicked.cursor))
+    {
+        // Clicking an existing cursor removes it
+        let _ = internal.cursors.remove(i);
+        previous.restore(&mut internal.editor);
+    } else if previous.is_caret_at(clicked.cursor) {
+        if let Some(selection) = internal.cursors.pop() {
+            selection.restore(&mut internal.editor);
+        }
+    } else {
+        internal.cursors.push(previous);
+    }
+}
+
+fn select_next_occurrence(internal: &mut Internal) {
+    let editor = &mut internal.editor;
+
+    let Some((start, end)) = editor.selection_bounds() else {
+        let cursor = editor.cursor();
+        editor.set_selection(cosmic_text::Selection::Word(cursor));
+
+        if let Some((start, end)) = editor.selection_bounds() {
+            Selection {
+                cursor: end,
+                anchor: Some(start),
+            }
+            .restore(editor);
+        }
+
+        return;
+    };
+
+    if start.line != end.line || start.index == end.index {
+        return;
+    }
+
+    let buffer = buffer_from_editor(editor);
+    let needle = &buffer.lines[start.line].text()[start.index..end.index];
+
+    let is_selected = |line: usize, index: usize| {
+        (start.line, start.index) == (line, index)
+            || internal.cursors.iter().any(|selection| {
+                let (start, _) = selection.bounds();
+
+                (start.line, start.index) == (line, index)
+            })
+    };
+
+    let line_count = buffer.lines.len();
+
+    // Search forward from the current selection, wrapping around
+    let next = (0..=line_count).find_map(|offset| {
+        let line = (end.line + offset) % line_count;
+        let from = if offset == 0 { end.index } else { 0 };
+
+        buffer.lines[line].text()[from..]
+            .match_indices(needle)
+            .map(|(index, _)| from + index)
+            .find(|index| !is_selected(line, *index))
+            .map(|index| (line, index))
+    });
+
+    if let Some((line, index)) = next {
+        internal.cursors.push(Selection::of(editor));
+
+        Selection {
+            cursor: cosmic_text::Cursor::new(line, index + needle.len()),
+            anchor: Some(cosmic_text::Cursor::new(line, index)),
+        }
+        .restore(editor);
+    }
+}
+
+fn select_column(internal: &mut Internal, from: Point, to: Point) {
+    let editor = &mut internal.editor;
+    let buffer = buffer_from_editor(editor);
+
+    let horizontal = buffer.scroll().horizontal;
+    let top = from.y.min(to.y);
+    let bottom = from.y.max(to.y);
+
+    // Every visual line between the points gets a cursor, so wrapped
+    // lines may get more than one
+    let mut selections: Vec<Selection> = buffer
+        .layout_runs()
+        .filter(|run| {
+            run.line_top + run.line_height > top && run.line_top <= bottom
+        })
+        .map(|run| {
+            let anchor = index_at(run.glyphs, from.x + horizontal);
+            let cursor = index_at(run.glyphs, to.x + horizontal);
+
+            Selection {
+                cursor: cosmic_text::Cursor::new(run.line_i, cursor),
+                anchor: (anchor != cursor)
+                    .then(|| cosmic_text::Cursor::new(run.line_i, anchor)),
+            }
+        })
+        .collect();
+
+    if from.y > to.y {
+        selections.reverse();
+    }
+
+    let Some(primary) = selections.pop() else {
+        return;
+    };
+
+    primary.restore(editor);
+    internal.cursors = selections;
+}
+
+/// Returns the index of the text closest to the given horizontal
+/// position in a visual line.
+fn index_at(glyphs: &[cosmic_text::LayoutGlyph], x: f32) -> usize {
+    glyphs
+        .iter()
+        .find(|glyph| x < glyph.x + glyph.w / 2.0)
+        .map(|glyph| glyph.start)
+        .unwrap_or_else(|| glyphs.last().map(|glyph| glyph.end).unwrap_or(0))
+}
+
+/// A secondary cursor of an [`Editor`], with an optional selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Selection {
+    cursor: cosmic_text::Cursor,
+    anchor: Option<cosmic_text::Cursor>,
+}
+
+impl Selection {
+    fn of(editor: &cosmic_text::Editor<'_>) -> Self {
+        let cursor = editor.cursor();
+
+        let Some((start, end)) = editor.selection_bounds() else {
+            return Self {
+                cursor,
+                anchor: None,
+            };
+        };
+
+        if position(cursor) == position(start) {
+            Self {
+                cursor: start,
+                anchor: Some(end),
+            }
+        } else {
+            // Word and line selections may not have their cursor
+            // at either end
+            Self {
+                cursor: end,
+                anchor: Some(start),
+            }
+        }
+    }
+
+    fn restore(self, editor: &mut cosmic_text::Editor<'_>) {
+        editor.set_cursor(self.cursor);
+        editor.set_selection(
+            self.anchor.map_or(cosmic_text::Selection::None, |anchor| {
+                cosmic_text::Selection::Normal(anchor)
+            }),
+        );
+    }
+
+    fn bounds(self) -> (cosmic_text::Cursor, cosmic_text::Cursor) {
+        match self.anchor {
+            Some(anchor) if position(anchor) < position(self.cursor) => {
+                (anchor, self.cursor)
+            }
+            Some(anchor) => (self.cursor, anchor),
+            None => (self.cursor, self.cursor),
+        }
+    }
+
+    fn is_caret_at(&self, cursor: cosmic_text::Cursor) -> bool {
+        self.anchor.is_none() && position(self.cursor) == position(cursor)
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        let (start, end) = self.bounds();
+        let (other_start, other_end) = other.bounds();
+
+        let (start, end) = (position(start), position(end));
+        let (other_start, other_end) =
+            (position(other_start), position(other_end));
+
+        (start == end && start == other_start && other_start == other_end)
+            || (start < other_end && other_start < end)
+    }
+
+    fn union(self, other: Self) -> Self {
+        let (start, end) = self.bounds();
+        let (other_start, other_end) = other.bounds();
+
+        let start = start.min(other_start);
+        let end = end.max(other_end);
+
+        if start == end {
+            return Self {
+                cursor: start,
+                anchor: None,
+            };
+        }
+
+        let is_reversed = self
+            .anchor
+            .is_some_and(|anchor| position(self.cursor) < position(anchor));
+
+        if is_reversed {
+            Self {
+                cursor: start,
+                anchor: Some(end),
+            }
+        } else {
+            Self {
+                cursor: end,
+                anchor: Some(start),
+            }
+        }
+    }
+
+    /// Shifts the [`Selection`] to account for the given change.
+    fn shift(&mut self, item: &cosmic_text::ChangeItem) {
+        self.cursor = shift(self.cursor, item);
+        self.anchor = self.anchor.map(|anchor| shift(anchor, item));
+    }
+}
+
+fn position(cursor: cosmic_text::Cursor) -> (usize, usize) {
+    (cursor.line, cursor.index)
+}
+
+fn shift(
+    cursor: cosmic_text::Cursor,
+    item: &cosmic_text::ChangeItem,
+) -> cosmic_text::Cursor {
+    let (line, index) = position(cursor);
+    let start = position(item.start);
+    let end = position(item.end);
+
+    let (line, index) = if item.insert {
+        if (line, index) < start {
+            (line, index)
+        } else if line == start.0 {
+            (end.0, end.1 + index - start.1)
+        } else {
+            (line + end.0 - start.0, index)
+        }
+    } else if (line, index) <= start {
+        (line, index)
+    } else if (line, index) < end {
+        start
+    } else if line == end.0 {
+        (start.0, start.1 + index - end.1)
+    } else {
+        (line - (end.0 - start.0), index)
+    };
+
+    cosmic_text::Cursor {
+        line,
+        index,
+        ..cursor
+    }
+}
+
+/// Merges the overlapping selections, keeping the last one as the
+/// primary selection.
+fn merge(selections: Vec<Selection>) -> Vec<Selection> {
+    let mut merged: Vec<Selection> = Vec::with_capacity(selections.len());
+
+    for selection in selections.into_iter().rev() {
+        if let Some(existing) = merged
+            .iter_mut()
+            .find(|existing| existing.overlaps(&selection))
+        {
+            *existing = existing.union(selection);
+        } else {
+            merged.push(selection);
+        }
+    }
+
+    merged.reverse();
+    merged
+}
+
+fn text_between(
+    buffer: &cosmic_text::Buffer,
+    start: cosmic_text::Cursor,
+    end: cosmic_text::Cursor,
+) -> String {
+    (start.line..=end.line)
+        .map(|line| {
+            let text = buffer.lines[line].text();
+
+            let from = if line == start.line { start.index } else { 0 };
+            let to = if line == end.line {
+                end.index
+            } else {
+                text.len()
+            };
+
+            &text[from..to]
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn caret_position(
+    buffer: &cosmic_text::Buffer,
+    cursor: cosmic_text::Cursor,
+) -> Point {
+    let line_height = buffer.metrics().line_height;
+
+    let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
+
+    let line = buffer
+        .lines
+        .get(cursor.line)
+        .expect("Cursor line should be present");
+
+    let layout = line.layout_opt().expect("Line layout should be cached");
+
+    let mut lines = layout.iter().enumerate();
+
+    let (visual_line, offset) = lines
+        .find_map(|(i, line)| {
+            let start =
+                line.glyphs.first().map(|glyph| glyph.start).unwrap_or(0);
+            let end = line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
+
+            let is_cursor_before_start = start > cursor.index;
+
+            let is_cursor_before_end = match cursor.affinity {
+                cosmic_text::Affinity::Before => cursor.index <= end,
+                cosmic_text::Affinity::After => cursor.index < end,
+            };
+
+            if is_cursor_before_start {
+                // Sometimes, the glyph we are looking for is right
+                // between lines. This can happen when a line wraps
+                // on a space.
+                // In that case, we can assume the cursor is at the
+                // end of the previous line.
+                // i is guaranteed to be > 0 because `start` is always
+                // 0 for the first line, so there is no way for the
+                // cursor to be before it.
+                Some((i - 1, layout[i - 1].w))
+            } else if is_cursor_before_end {
+                let offset = line
+                    .glyphs
+                    .iter()
+                    .take_while(|glyph| cursor.index > glyph.start)
+                    .map(|glyph| glyph.w)
+                    .sum();
+
+                Some((i, offset))
+            } else {
+                None
+            }
+        })
+        .unwrap_or((
+            layout.len().saturating_sub(1),
+            layout.last().map(|line| line.w).unwrap_or(0.0),
+        ));
+
+    Point::new(
+        offset,
+        (visual_lines_offset + visual_line as i32) as f32 * line_height
+            - buffer.scroll().vertical,
+    )
+}
+
+fn range_bounds(
+    buffer: &cosmic_text::Buffer,
+    start: cosmic_text::Cursor,
+    end: cosmic_text::Cursor,
+) -> Vec<Rectangle> {
+    let line_height = buffer.metrics().line_height;
+    let selected_lines = (end.line + 1).saturating_sub(start.line);
+
+    let visual_lines_offset = visual_lines_offset(start.line, buffer);
+
+    buffer
+        .lines
+        .iter()
+        .skip(start.line)
+        .take(selected_lines)
+        .enumerate()
+        .flat_map(|(i, line)| {
+            highlight_line(
+                line,
+                if i == 0 { start.index } else { 0 },
+                if i == selected_lines - 1 {
+                    end.index
+                } else {
+                    line.text().len()
+                },
+            )
+        })
+        .enumerate()
+        .filter_map(|(visual_line, (x, width))| {
+            if width > 0.0 {
+                Some(Rectangle {
+                    x,
+                    width,
+                    y: (visual_line as i32 + visual_lines_offset) as f32
+                        * line_height
+                        - buffer.scroll().vertical,
+                    height: line_height,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
@@ -831,3 +1465,240 @@ where
         cosmic_text::BufferRef::Arc(_buffer) => unreachable!(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::editor::Editor as _;
+    use crate::core::text::highlighter::PlainText;
+
+    fn editor(text: &str, width: f32) -> Editor {
+        text::font_system()
+            .write()
+            .expect("Write font system")
+            .load_font(Cow::Borrowed(include_bytes!(
+                "../../fonts/FiraSans-Regular.ttf"
+            )));
+
+        let mut editor = Editor::with_text(text);
+
+        editor.update(
+            Size::new(width, 1000.0),
+            Font::with_name("Fira Sans"),
+            Pixels(10.0),
+            LineHeight::Absolute(Pixels(10.0)),
+            Wrapping::Word,
+            &mut PlainText,
+        );
+
+        editor
+    }
+
+    fn text(editor: &Editor) -> String {
+        editor
+            .buffer()
+            .lines
+            .iter()
+            .map(cosmic_text::BufferLine::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn selections(editor: &Editor) -> Vec<((usize, usize), (usize, usize))> {
+        let internal = editor.internal();
+
+        internal
+            .cursors
+            .iter()
+            .copied()
+            .chain([Selection::of(&internal.editor)])
+            .map(|selection| {
+                let (start, end) = selection.bounds();
+
+                (position(start), position(end))
+            })
+            .collect()
+    }
+
+    fn caret(line: usize, index: usize) -> Selection {
+        Selection {
+            cursor: cosmic_text::Cursor::new(line, index),
+            anchor: None,
+        }
+    }
+
+    fn range(line: usize, start: usize, end: usize) -> Selection {
+        Selection {
+            cursor: cosmic_text::Cursor::new(line, end),
+            anchor: Some(cosmic_text::Cursor::new(line, start)),
+        }
+    }
+
+    #[test]
+    fn merge_joins_overlapping_selections() {
+        assert_eq!(
+            merge(vec![caret(0, 1), caret(0, 1), caret(1, 0)]),
+            vec![caret(0, 1), caret(1, 0)]
+        );
+
+        assert_eq!(
+            merge(vec![range(0, 0, 4), range(0, 2, 6), range(0, 6, 8)]),
+            vec![range(0, 0, 6), range(0, 6, 8)]
+        );
+
+        assert_eq!(
+            merge(vec![caret(0, 3), range(0, 2, 5)]),
+            vec![range(0, 2, 5)]
+        );
+    }
+
+    #[test]
+    fn perform_each_edits_every_cursor() {
+        let mut editor = editor("abc\nabc\nabc", 1000.0);
+
+        editor.perform(Action::Move(Motion::DocumentEnd));
+        editor.perform(Action::Move(Motion::Home));
+        editor.internal_mut().cursors = vec![caret(0, 0), caret(1, 0)];
+
+        editor.perform(Action::Edit(Edit::Insert('_')));
+
+        assert_eq!(text(&editor), "_abc\n_abc\n_abc");
+        assert_eq!(
+            selections(&editor),
+            vec![((0, 1), (0, 1)), ((1, 1), (1, 1)), ((2, 1), (2, 1))]
+        );
+
+        editor
+            .perform(Action::Edit(Edit::Paste(Arc::new("1\n2\n3".to_owned()))));
+
+        assert_eq!(text(&editor), "_1abc\n_2abc\n_3abc");
+
+        editor.perform(Action::Edit(Edit::Backspace));
+        editor.perform(Action::Edit(Edit::Backspace));
+
+        assert_eq!(text(&editor), "abc\nabc\nabc");
+    }
+
+    #[test]
+    fn perform_each_shifts_cursors_on_the_same_line() {
+        let mut editor = editor("a a a", 1000.0);
+
+        editor.perform(Action::Move(Motion::DocumentEnd));
+        editor.internal_mut().cursors = vec![caret(0, 1), caret(0, 3)];
+
+        editor.perform(Action::Edit(Edit::Paste(Arc::new("bc".to_owned()))));
+
+        assert_eq!(text(&editor), "abc abc abc");
+        assert_eq!(
+            selections(&editor),
+            vec![((0, 3), (0, 3)), ((0, 7), (0, 7)), ((0, 11), (0, 11))]
+        );
+
+        // Cursors that end up in the same place are merged
+        editor.perform(Action::Move(Motion::Home));
+
+        assert_eq!(selections(&editor), vec![((0, 0), (0, 0))]);
+    }
+
+    #[test]
+    fn select_next_occurrence_adds_selections() {
+        let mut editor = editor("foo bar\nfoo baz foo", 1000.0);
+
+        editor.perform(Action::SelectNextOccurrence);
+
+        assert_eq!(selections(&editor), vec![((0, 0), (0, 3))]);
+
+        editor.perform(Action::SelectNextOccurrence);
+        editor.perform(Action::SelectNextOccurrence);
+
+        assert_eq!(
+            selections(&editor),
+            vec![((0, 0), (0, 3)), ((1, 0), (1, 3)), ((1, 8), (1, 11))]
+        );
+
+        // Every occurrence is already selected
+        editor.perform(Action::SelectNextOccurrence);
+
+        assert_eq!(selections(&editor).len(), 3);
+
+        editor.perform(Action::Edit(Edit::Insert('x')));
+
+        assert_eq!(text(&editor), "x bar\nx baz x");
+    }
+
+    #[test]
+    fn select_next_occurrence_wraps_around() {
+        let mut editor = editor("one two one", 1000.0);
+
+        editor.perform(Action::Move(Motion::DocumentEnd));
+        editor.perform(Action::SelectNextOccurrence);
+        editor.perform(Action::SelectNextOccurrence);
+
+        assert_eq!(
+            selections(&editor),
+            vec![((0, 8), (0, 11)), ((0, 0), (0, 3))]
+        );
+    }
+
+    #[test]
+    fn select_column_spans_wrapped_lines() {
+        let mut editor = editor("aaaa aaaa aaaa\nbb", 30.0);
+
+        let runs: Vec<_> = editor
+            .buffer()
+            .layout_runs()
+            .map(|run| (run.line_i, run.line_top))
+            .collect();
+
+        assert_eq!(
+            runs,
+            vec![(0, 0.0), (0, 10.0), (0, 20.0), (1, 30.0)],
+            "the first line should wrap into three visual lines"
+        );
+
+        let a = editor.buffer().layout_runs().next().unwrap().glyphs[0].w;
+
+        editor.perform(Action::SelectColumn {
+            from: Point::new(a * 0.25, 1.0),
+            to: Point::new(a * 2.25, 25.0),
+        });
+
+        assert_eq!(
+            selections(&editor),
+            vec![((0, 0), (0, 2)), ((0, 5), (0, 7)), ((0, 10), (0, 12))]
+        );
+
+        editor.perform(Action::Edit(Edit::Insert('x')));
+
+        assert_eq!(text(&editor), "xaa xaa xaa\nbb");
+    }
+
+    #[test]
+    fn select_column_keeps_the_primary_cursor_at_the_end() {
+        let mut editor = editor("abc\nabc\nabc", 1000.0);
+
+        let a = editor.buffer().layout_runs().next().unwrap().glyphs[0].w;
+
+        editor.perform(Action::SelectColumn {
+            from: Point::new(a * 1.25, 25.0),
+            to: Point::new(a * 1.25, 5.0),
+        });
+
+        assert_eq!(
+            selections(&editor),
+            vec![((2, 1), (2, 1)), ((1, 1), (1, 1)), ((0, 1), (0, 1))]
+        );
+        assert_eq!(editor.cursor_position(), (0, 1));
+
+        // Points beyond the last line select up to the last line
+        editor.perform(Action::SelectColumn {
+            from: Point::new(0.0, 15.0),
+            to: Point::new(1000.0, 500.0),
+        });
+
+        assert_eq!(
+            selections(&editor),
+            vec![((1, 0), (1, 3)), ((2, 0), (2, 3))]
+        );
+    }
+}
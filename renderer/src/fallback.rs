@@ -80,6 +80,22 @@ where
     ) {
         delegate!(self, renderer, renderer.allocate_image(handle, callback));
     }
+
+    fn allocate_image_with_progress(
+        &mut self,
+        handle: &image::Handle,
+        on_progress: impl FnMut(image::Progress) + Send + 'static,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.allocate_image_with_progress(handle, on_progress)
+        );
+    }
+
+    fn evict_image(&mut self, handle: &image::Handle) {
+        delegate!(self, renderer, renderer.evict_image(handle));
+    }
 }
 
 impl<A, B> core::text::Renderer for Renderer<A, B>
@@ -183,6 +199,10 @@ where
         delegate!(self, renderer, renderer.measure_image(handle))
     }
 
+    fn image_status(&self, handle: &Self::Handle) -> image::Status {
+        delegate!(self, renderer, renderer.image_status(handle))
+    }
+
     fn draw_image(
         &mut self,
         image: Image<A::Handle>,
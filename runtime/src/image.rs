@@ -1,15 +1,22 @@
-//! Allocate images explicitly to control presentation.
+//! Allocate images explicitly to control presentation and caching.
 use crate::core::image::Handle;
+use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::channel::oneshot;
 use crate::task::{self, Task};
 
-pub use crate::core::image::{Allocation, Error};
+pub use crate::core::image::{Allocation, Error, Progress};
 
 /// An image action.
 #[derive(Debug)]
 pub enum Action {
     /// Allocates the given [`Handle`].
     Allocate(Handle, oneshot::Sender<Result<Allocation, Error>>),
+
+    /// Allocates the given [`Handle`], reporting its [`Progress`].
+    AllocateWithProgress(Handle, mpsc::Sender<Progress>),
+
+    /// Evicts the given [`Handle`] from the image cache.
+    Evict(Handle),
 }
 
 /// Allocates an image [`Handle`].
@@ -22,3 +29,34 @@ pub fn allocate(handle: impl Into<Handle>) -> Task<Result<Allocation, Error>> {
         crate::Action::Image(Action::Allocate(handle.into(), sender))
     })
 }
+
+/// Allocates an image [`Handle`], reporting its [`Progress`] along the way.
+///
+/// The resulting [`Task`] produces [`Progress::Queued`] and
+/// [`Progress::Decoding`] while the renderer decodes the image in the
+/// background, and [`Progress::Finished`] once it is allocated. Renderers
+/// that decode images right away only produce [`Progress::Finished`].
+pub fn allocate_with_progress(handle: impl Into<Handle>) -> Task<Progress> {
+    task::channel(|sender| {
+        crate::Action::Image(Action::AllocateWithProgress(
+            handle.into(),
+            sender,
+        ))
+    })
+}
+
+/// Preloads an image [`Handle`] into the image cache of the renderer.
+///
+/// Unlike [`allocate`], a preloaded image is not guaranteed to stay in
+/// memory; it will be kept only as long as the image cache budget of the
+/// renderer allows it.
+pub fn preload(handle: impl Into<Handle>) -> Task<Result<(), Error>> {
+    allocate(handle).map(|result| result.map(|_allocation| ()))
+}
+
+/// Evicts an image [`Handle`] from the image cache of the renderer.
+///
+/// Images that are still held by an [`Allocation`] may not be evicted.
+pub fn evict<T>(handle: impl Into<Handle>) -> Task<T> {
+    task::effect(crate::Action::Image(Action::Evict(handle.into())))
+}
@@ -230,6 +230,17 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`Settings::image_cache_budget`] of the [`Application`].
+    pub fn image_cache_budget(self, image_cache_budget: u64) -> Self {
+        Self {
+            settings: Settings {
+                image_cache_budget: Some(image_cache_budget),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the default [`Font`] of the [`Application`].
     pub fn default_font(self, default_font: Font) -> Self {
         Self {
@@ -641,7 +641,10 @@ pub mod widget {
     #[cfg(feature = "image")]
     pub mod image {
         //! Images display raster graphics in different formats (PNG, JPG, etc.).
-        pub use iced_runtime::image::{Allocation, Error, allocate};
+        pub use iced_runtime::image::{
+            Allocation, Error, Progress, allocate, allocate_with_progress,
+            evict, preload,
+        };
         pub use iced_widget::image::*;
     }
 
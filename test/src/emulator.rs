@@ -320,22 +320,31 @@ impl<P: Program + 'static> Emulator<P> {
                     return;
                 };
 
-                for event in &events {
-                    if let core::Event::Mouse(mouse::Event::CursorMoved {
-                        position,
-                    }) = event
+                // Interactions spanning multiple locations (like drags) are
+                // split at every cursor movement, so each batch of events
+                // sees its own cursor. Any other interaction moves the
+                // cursor once at most and is still processed in one go.
+                for events in events.chunk_by(|_, event| {
+                    !matches!(
+                        event,
+                        core::Event::Mouse(mouse::Event::CursorMoved { .. })
+                    )
+                }) {
+                    if let Some(core::Event::Mouse(
+                        mouse::Event::CursorMoved { position },
+                    )) = events.first()
                     {
                         self.cursor = mouse::Cursor::Available(*position);
                     }
-                }
 
-                let (_state, _status) = user_interface.update(
-                    &events,
-                    self.cursor,
-                    &mut self.renderer,
-                    &mut self.clipboard,
-                    &mut messages,
-                );
+                    let (_state, _status) = user_interface.update(
+                        events,
+                        self.cursor,
+                        &mut self.renderer,
+                        &mut self.clipboard,
+                        &mut messages,
+                    );
+                }
 
                 self.cache = Some(user_interface.into_cache());
 
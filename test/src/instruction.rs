@@ -103,7 +103,8 @@ impl Interaction {
     ///
     /// This method can turn certain sequences of interactions into a single one.
     /// For instance, a mouse movement, left button press, and left button release
-    /// can all be merged into a single click interaction.
+    /// can all be merged into a single click interaction. Similarly, a press and
+    /// a release at different locations can be merged into a drag interaction.
     ///
     /// Merging is lossy and, therefore, it is not always desirable if you are recording
     /// a test and want full reproducibility.
@@ -165,6 +166,23 @@ impl Interaction {
                             None,
                         )
                     }
+                    (
+                        Mouse::Press {
+                            button: press,
+                            target: Some(from),
+                        },
+                        Mouse::Release {
+                            button: release,
+                            target: Some(to),
+                        },
+                    ) if press == release && from != to => (
+                        Self::Mouse(Mouse::Drag {
+                            button: press,
+                            from,
+                            to,
+                        }),
+                        None,
+                    ),
                     (
                         Mouse::Press {
                             button,
@@ -227,7 +245,7 @@ impl Interaction {
     /// coordinates.
     pub fn events(
         &self,
-        find_target: impl FnOnce(&Target) -> Option<Point>,
+        mut find_target: impl FnMut(&Target) -> Option<Point>,
     ) -> Option<Vec<Event>> {
         let mouse_move_ =
             |to| Event::Mouse(mouse::Event::CursorMoved { position: to });
@@ -283,6 +301,14 @@ impl Interaction {
                 } => {
                     vec![mouse_press(*button), mouse_release(*button)]
                 }
+                Mouse::Drag { button, from, to } => {
+                    vec![
+                        mouse_move_(find_target(from)?),
+                        mouse_press(*button),
+                        mouse_move_(find_target(to)?),
+                        mouse_release(*button),
+                    ]
+                }
             },
             Interaction::Keyboard(keyboard) => match keyboard {
                 Keyboard::Press(key) => vec![key_press(*key)],
@@ -331,6 +357,15 @@ pub enum Mouse {
         /// The location of the click.
         target: Option<Target>,
     },
+    /// A button was pressed at some location and released at another.
+    Drag {
+        /// The button.
+        button: mouse::Button,
+        /// The location where the drag starts.
+        from: Target,
+        /// The location where the drag ends.
+        to: Target,
+    },
 }
 
 impl fmt::Display for Mouse {
@@ -360,6 +395,14 @@ impl fmt::Display for Mouse {
                     format::button_at(*button, target.as_ref())
                 )
             }
+            Mouse::Drag { button, from, to } => {
+                write!(
+                    f,
+                    "drag {} to {}",
+                    format::button_at(*button, Some(from)),
+                    to
+                )
+            }
         }
     }
 }
@@ -550,7 +593,14 @@ mod parser {
     fn mouse(input: &str) -> IResult<&str, Mouse> {
         let mouse_move = preceded(tag("move "), target).map(Mouse::Move);
 
-        alt((mouse_move, mouse_click, mouse_press, mouse_release)).parse(input)
+        alt((
+            mouse_move,
+            mouse_click,
+            mouse_press,
+            mouse_release,
+            mouse_drag,
+        ))
+        .parse(input)
     }
 
     fn mouse_click(input: &str) -> IResult<&str, Mouse> {
@@ -574,6 +624,16 @@ mod parser {
         Ok((input, Mouse::Release { button, target }))
     }
 
+    fn mouse_drag(input: &str) -> IResult<&str, Mouse> {
+        let (input, _) = tag("drag ")(input)?;
+        let (input, button) = mouse_button(input)?;
+        let (input, from) = preceded(multispace0, target).parse(input)?;
+        let (input, _) = whitespace(tag("to")).parse(input)?;
+        let (input, to) = target(input)?;
+
+        Ok((input, Mouse::Drag { button, from, to }))
+    }
+
     fn mouse_button_at(
         input: &str,
     ) -> IResult<&str, (mouse::Button, Option<Target>)> {
@@ -739,3 +799,68 @@ mod parser {
         delimited(char('"'), build_string, char('"')).parse(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(instruction: Instruction) {
+        let text = instruction.to_string();
+
+        assert_eq!(Instruction::parse(&text).ok(), Some(instruction), "{text}");
+    }
+
+    #[test]
+    fn drags_round_trip() {
+        round_trip(Instruction::Interact(Interaction::Mouse(Mouse::Drag {
+            button: mouse::Button::Left,
+            from: Target::Text("Apple".to_owned()),
+            to: Target::Text("Basket".to_owned()),
+        })));
+
+        round_trip(Instruction::Interact(Interaction::Mouse(Mouse::Drag {
+            button: mouse::Button::Right,
+            from: Target::Point(Point::new(10.0, 20.5)),
+            to: Target::Text("Basket".to_owned()),
+        })));
+    }
+
+    #[test]
+    fn drags_parse_from_text() {
+        let instruction = Instruction::parse("drag \"Apple\" to (5.00, 6.00)");
+
+        assert_eq!(
+            instruction.ok(),
+            Some(Instruction::Interact(Interaction::Mouse(Mouse::Drag {
+                button: mouse::Button::Left,
+                from: Target::Text("Apple".to_owned()),
+                to: Target::Point(Point::new(5.0, 6.0)),
+            })))
+        );
+    }
+
+    #[test]
+    fn presses_and_releases_elsewhere_merge_into_drags() {
+        let press = Interaction::Mouse(Mouse::Press {
+            button: mouse::Button::Left,
+            target: Some(Target::Text("Apple".to_owned())),
+        });
+
+        let release = Interaction::Mouse(Mouse::Release {
+            button: mouse::Button::Left,
+            target: Some(Target::Text("Basket".to_owned())),
+        });
+
+        assert_eq!(
+            press.merge(release),
+            (
+                Interaction::Mouse(Mouse::Drag {
+                    button: mouse::Button::Left,
+                    from: Target::Text("Apple".to_owned()),
+                    to: Target::Text("Basket".to_owned()),
+                }),
+                None
+            )
+        );
+    }
+}
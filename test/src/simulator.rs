@@ -160,6 +160,56 @@ where
         Ok(target)
     }
 
+    /// Drags from the [`Bounded`] target found by the `from` [`Selector`] to
+    /// the one found by the `to` [`Selector`], if any.
+    ///
+    /// This consists in:
+    /// - Pointing the mouse cursor at the center of the `from` target.
+    /// - Pressing the left mouse button.
+    /// - Moving the mouse cursor to the center of the `to` target.
+    /// - Releasing the left mouse button.
+    pub fn drag<S, T>(
+        &mut self,
+        from: S,
+        to: T,
+    ) -> Result<(S::Output, T::Output), Error>
+    where
+        S: Selector + Send,
+        S::Output: Bounded + Clone + Send + Sync + 'static,
+        T: Selector + Send,
+        T::Output: Bounded + Clone + Send + Sync + 'static,
+    {
+        let from = self.find(from)?;
+        let to = self.find(to)?;
+
+        let Some(start) = from.visible_bounds() else {
+            return Err(Error::TargetNotVisible {
+                target: Arc::new(from),
+            });
+        };
+
+        let Some(end) = to.visible_bounds() else {
+            return Err(Error::TargetNotVisible {
+                target: Arc::new(to),
+            });
+        };
+
+        self.point_at(start.center());
+        let _ = self.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        ))]);
+
+        self.point_at(end.center());
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: end.center(),
+            }),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok((from, to))
+    }
+
     /// Simulates a key press, followed by a release, in the [`Simulator`].
     pub fn tap_key(&mut self, key: impl Into<keyboard::Key>) -> event::Status {
         self.simulate(tap_key(key, None))
@@ -425,3 +475,29 @@ fn load_font(font: impl Into<Cow<'static, [u8]>>) -> Result<(), Error> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_widget::{column, draggable, drop_zone, text};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Dropped(&'static str),
+    }
+
+    #[test]
+    fn dragging_drops_payloads() {
+        let mut ui: Simulator<'_, Message> = simulator(column![
+            draggable("apple", text("Apple")),
+            drop_zone(text("Basket"), Message::Dropped),
+        ]);
+
+        assert!(ui.drag("Apple", "Basket").is_ok());
+
+        let messages: Vec<_> = ui.into_messages().collect();
+
+        assert_eq!(messages, vec![Message::Dropped("apple")]);
+    }
+}
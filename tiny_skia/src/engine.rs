@@ -1,7 +1,7 @@
 use crate::Primitive;
 use crate::core::renderer::Quad;
 use crate::core::{
-    Background, Color, Gradient, Rectangle, Size, Transformation, Vector,
+    Background, Color, Gradient, Point, Rectangle, Size, Transformation, Vector,
 };
 use crate::graphics::{Image, Text};
 use crate::text;
@@ -21,7 +21,7 @@ impl Engine {
         Self {
             text_pipeline: text::Pipeline::new(),
             #[cfg(feature = "image")]
-            raster_pipeline: crate::raster::Pipeline::new(),
+            raster_pipeline: crate::raster::Pipeline::new(None),
             #[cfg(feature = "svg")]
             vector_pipeline: crate::vector::Pipeline::new(),
         }
@@ -47,10 +47,12 @@ impl Engine {
 
         let transform = into_transform(transformation);
 
+        let widths = quad.border.side_widths();
+        let colors = quad.border.side_colors();
+
         // Make sure the border radius is not larger than the bounds
-        let border_width = quad
-            .border
-            .width
+        let border_width = widths
+            .top
             .min(quad.bounds.width / 2.0)
             .min(quad.bounds.height / 2.0);
 
@@ -152,8 +154,19 @@ impl Engine {
                         tiny_skia::Shader::SolidColor(into_color(*color))
                     }
                     Background::Gradient(gradient) => {
-                        let start = gradient.start_point;
-                        let end = gradient.end_point;
+                        // Gradient points are normalized to the quad bounds
+                        let start = Point::new(
+                            quad.bounds.x
+                                + gradient.start_point.x * quad.bounds.width,
+                            quad.bounds.y
+                                + gradient.start_point.y * quad.bounds.height,
+                        );
+                        let end = Point::new(
+                            quad.bounds.x
+                                + gradient.end_point.x * quad.bounds.width,
+                            quad.bounds.y
+                                + gradient.end_point.y * quad.bounds.height,
+                        );
 
                         let stops: Vec<tiny_skia::GradientStop> = gradient
                             .stops
@@ -201,7 +214,22 @@ impl Engine {
             clip_mask,
         );
 
-        if border_width > 0.0 {
+        if !(widths.is_uniform() && colors.is_uniform()) {
+            draw_border_sides(
+                quad,
+                fill_border_radius,
+                transform,
+                physical_bounds,
+                pixels,
+                clip_mask,
+                clip_bounds,
+            );
+        } else if border_width > 0.0 {
+            let dash = quad
+                .border
+                .dash()
+                .and_then(|segments| into_dash(&segments, 0.0));
+
             // Border path is offset by half the border width
             let border_bounds = Rectangle {
                 x: quad.bounds.x + border_width / 2.0,
@@ -237,13 +265,14 @@ impl Engine {
                     &border_path,
                     &tiny_skia::Paint {
                         shader: tiny_skia::Shader::SolidColor(into_color(
-                            quad.border.color,
+                            colors.top,
                         )),
                         anti_alias: true,
                         ..tiny_skia::Paint::default()
                     },
                     &tiny_skia::Stroke {
                         width: border_width,
+                        dash,
                         ..tiny_skia::Stroke::default()
                     },
                     transform,
@@ -292,13 +321,14 @@ impl Engine {
                     &border_radius_path,
                     &tiny_skia::Paint {
                         shader: tiny_skia::Shader::SolidColor(into_color(
-                            quad.border.color,
+                            colors.top,
                         )),
                         anti_alias: true,
                         ..tiny_skia::Paint::default()
                     },
                     &tiny_skia::Stroke {
                         width: border_width,
+                        dash,
                         ..tiny_skia::Stroke::default()
                     },
                     transform,
@@ -652,6 +682,17 @@ pub fn into_color(color: Color) -> tiny_skia::Color {
         .expect("Convert color from iced to tiny_skia")
 }
 
+pub fn into_dash(
+    segments: &[f32],
+    offset: f32,
+) -> Option<tiny_skia::StrokeDash> {
+    if segments.is_empty() {
+        None
+    } else {
+        tiny_skia::StrokeDash::new(segments.into(), offset)
+    }
+}
+
 fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
     let translation = transformation.translation();
 
@@ -767,6 +808,225 @@ fn rounded_rectangle(
     builder.finish().expect("Build rounded rectangle path")
 }
 
+fn draw_border_sides(
+    quad: &Quad,
+    border_radius: [f32; 4],
+    transform: tiny_skia::Transform,
+    physical_bounds: Rectangle,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: Option<&tiny_skia::Mask>,
+    clip_bounds: Rectangle,
+) {
+    let bounds = quad.bounds;
+    let widths = quad.border.side_widths();
+    let colors = quad.border.side_colors();
+    let dash = quad.border.dash();
+
+    let top = widths.top.clamp(0.0, bounds.height / 2.0);
+    let right = widths.right.clamp(0.0, bounds.width / 2.0);
+    let bottom = widths.bottom.clamp(0.0, bounds.height / 2.0);
+    let left = widths.left.clamp(0.0, bounds.width / 2.0);
+
+    // Each side owns the region between its outer edge and the inner
+    // corners; so corners are split diagonally.
+    let (x0, y0) = (bounds.x, bounds.y);
+    let (x1, y1) = (bounds.x + bounds.width, bounds.y + bounds.height);
+    let (ix0, iy0) = (x0 + left, y0 + top);
+    let (ix1, iy1) = (x1 - right, y1 - bottom);
+
+    let sides = [
+        (
+            top,
+            colors.top,
+            [(x0, y0), (x1, y0), (ix1, iy0), (ix0, iy0)],
+            [(x0, y0 + top / 2.0), (x1, y0 + top / 2.0)],
+        ),
+        (
+            right,
+            colors.right,
+            [(x1, y0), (x1, y1), (ix1, iy1), (ix1, iy0)],
+            [(x1 - right / 2.0, y0), (x1 - right / 2.0, y1)],
+        ),
+        (
+            bottom,
+            colors.bottom,
+            [(x1, y1), (x0, y1), (ix0, iy1), (ix1, iy1)],
+            [(x1, y1 - bottom / 2.0), (x0, y1 - bottom / 2.0)],
+        ),
+        (
+            left,
+            colors.left,
+            [(x0, y1), (x0, y0), (ix0, iy0), (ix0, iy1)],
+            [(x0 + left / 2.0, y1), (x0 + left / 2.0, y0)],
+        ),
+    ]
+    .into_iter()
+    .filter(|(width, color, _, _)| *width > 0.0 && color.a > 0.0);
+
+    let paint = |color| tiny_skia::Paint {
+        shader: tiny_skia::Shader::SolidColor(into_color(color)),
+        anti_alias: true,
+        ..tiny_skia::Paint::default()
+    };
+
+    // Solid borders with sharp corners are just a trapezoid per side
+    if dash.is_none() && border_radius.iter().all(|radius| *radius <= 0.0) {
+        for (_, color, region, _) in sides {
+            let Some(region) = polygon(&region) else {
+                continue;
+            };
+
+            pixels.fill_path(
+                &region,
+                &paint(color),
+                tiny_skia::FillRule::Winding,
+                transform,
+                clip_mask,
+            );
+        }
+
+        return;
+    }
+
+    // Otherwise, the sides are drawn into a layer only as big as the
+    // visible part of the quad, since they need to be masked by the ring
+    // of the border.
+    let pixmap_bounds = Rectangle::with_size(Size::new(
+        pixels.width() as f32,
+        pixels.height() as f32,
+    ));
+
+    let Some(visible) = physical_bounds
+        .intersection(&clip_bounds)
+        .and_then(|bounds| bounds.intersection(&pixmap_bounds))
+    else {
+        return;
+    };
+
+    let (layer_x, layer_y) = (visible.x.floor(), visible.y.floor());
+    let layer_width = ((visible.x + visible.width).ceil() - layer_x) as u32;
+    let layer_height = ((visible.y + visible.height).ceil() - layer_y) as u32;
+
+    let (Some(mut layer), Some(mut ring_mask), Some(mut side_mask)) = (
+        tiny_skia::Pixmap::new(layer_width, layer_height),
+        tiny_skia::Mask::new(layer_width, layer_height),
+        tiny_skia::Mask::new(layer_width, layer_height),
+    ) else {
+        return;
+    };
+
+    let transform = transform.post_translate(-layer_x, -layer_y);
+
+    let outer = rounded_rectangle(bounds, border_radius);
+
+    let ring = {
+        let [top_left, top_right, bottom_right, bottom_left] = border_radius;
+
+        let mut builder = tiny_skia::PathBuilder::new();
+        builder.push_path(&outer);
+
+        if ix1 > ix0 && iy1 > iy0 {
+            let inner = rounded_rectangle(
+                Rectangle {
+                    x: ix0,
+                    y: iy0,
+                    width: ix1 - ix0,
+                    height: iy1 - iy0,
+                },
+                [
+                    (top_left - top.max(left)).max(0.0),
+                    (top_right - top.max(right)).max(0.0),
+                    (bottom_right - bottom.max(right)).max(0.0),
+                    (bottom_left - bottom.max(left)).max(0.0),
+                ],
+            );
+
+            builder.push_path(&inner);
+        }
+
+        builder.finish()
+    };
+
+    let Some(ring) = ring else {
+        return;
+    };
+
+    ring_mask.fill_path(&ring, tiny_skia::FillRule::EvenOdd, true, transform);
+
+    // The regions of the sides are extended towards the center of the quad,
+    // so rounded inner corners are covered too.
+    let center = ((ix0 + ix1) / 2.0, (iy0 + iy1) / 2.0);
+
+    for (width, color, [a, b, c, d], [from, to]) in sides {
+        let Some(region) = polygon(&[a, b, c, center, d]) else {
+            continue;
+        };
+
+        side_mask.data_mut().copy_from_slice(ring_mask.data());
+        side_mask.intersect_path(
+            &region,
+            tiny_skia::FillRule::Winding,
+            false,
+            transform,
+        );
+
+        match dash {
+            None => {
+                layer.fill_path(
+                    &outer,
+                    &paint(color),
+                    tiny_skia::FillRule::Winding,
+                    transform,
+                    Some(&side_mask),
+                );
+            }
+            Some(segments) => {
+                let Some(line) = polygon(&[from, to]) else {
+                    continue;
+                };
+
+                layer.stroke_path(
+                    &line,
+                    &paint(color),
+                    &tiny_skia::Stroke {
+                        width,
+                        dash: into_dash(&segments, 0.0),
+                        ..tiny_skia::Stroke::default()
+                    },
+                    transform,
+                    Some(&side_mask),
+                );
+            }
+        }
+    }
+
+    pixels.draw_pixmap(
+        layer_x as i32,
+        layer_y as i32,
+        layer.as_ref(),
+        &tiny_skia::PixmapPaint::default(),
+        tiny_skia::Transform::identity(),
+        clip_mask,
+    );
+}
+
+fn polygon(points: &[(f32, f32)]) -> Option<tiny_skia::Path> {
+    let ((x, y), rest) = points.split_first()?;
+
+    let mut builder = tiny_skia::PathBuilder::new();
+    builder.move_to(*x, *y);
+
+    for (x, y) in rest {
+        builder.line_to(*x, *y);
+    }
+
+    if rest.len() > 1 {
+        builder.close();
+    }
+
+    builder.finish()
+}
+
 fn maybe_line_to(path: &mut tiny_skia::PathBuilder, x: f32, y: f32) {
     if path.last_point() != Some(tiny_skia::Point { x, y }) {
         path.line_to(x, y);
@@ -858,3 +1118,159 @@ pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
         tiny_skia::Transform::default(),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::border::{self, Border, Colors, Widths};
+    use crate::core::gradient::{ColorStop, GradientType};
+
+    #[test]
+    fn quad_gradients_are_normalized_to_their_bounds() {
+        let mut gradient = Gradient::new(
+            GradientType::Linear,
+            Point::ORIGIN,
+            Point::new(1.0, 0.0),
+        );
+
+        gradient.add_stop(ColorStop {
+            offset: 0.0,
+            color: Color::BLACK,
+        });
+
+        gradient.add_stop(ColorStop {
+            offset: 1.0,
+            color: Color::WHITE,
+        });
+
+        let mut pixmap = tiny_skia::Pixmap::new(200, 10).unwrap();
+        let mut clip_mask = tiny_skia::Mask::new(200, 10).unwrap();
+
+        Engine::new().draw_quad(
+            &Quad {
+                bounds: Rectangle::new(
+                    Point::new(100.0, 0.0),
+                    Size::new(100.0, 10.0),
+                ),
+                ..Quad::default()
+            },
+            &Background::Gradient(gradient),
+            Transformation::IDENTITY,
+            &mut pixmap.as_mut(),
+            &mut clip_mask,
+            Rectangle::new(Point::ORIGIN, Size::new(200.0, 10.0)),
+        );
+
+        let brightness = |x| pixmap.pixel(x, 5).unwrap().red();
+
+        // The gradient spans the quad, instead of the first pixel of the
+        // viewport, so it starts dark and ends bright
+        assert_eq!(brightness(50), 0);
+        assert!(brightness(105) < 32);
+        assert!(brightness(150) > 96 && brightness(150) < 160);
+        assert!(brightness(195) > 224);
+    }
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const GREEN: [u8; 4] = [0, 255, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+    const BLACK: [u8; 4] = [0, 0, 0, 255];
+    const TRANSPARENT: [u8; 4] = [0, 0, 0, 0];
+
+    fn draw_border(border: Border) -> tiny_skia::Pixmap {
+        let mut pixmap = tiny_skia::Pixmap::new(40, 40).unwrap();
+        let mut clip_mask = tiny_skia::Mask::new(40, 40).unwrap();
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(40.0, 40.0));
+
+        Engine::new().draw_quad(
+            &Quad {
+                bounds,
+                border,
+                ..Quad::default()
+            },
+            &Background::Color(Color::TRANSPARENT),
+            Transformation::IDENTITY,
+            &mut pixmap.as_mut(),
+            &mut clip_mask,
+            bounds,
+        );
+
+        pixmap
+    }
+
+    fn color_at(pixmap: &tiny_skia::Pixmap, x: u32, y: u32) -> [u8; 4] {
+        let pixel = pixmap.pixel(x, y).unwrap();
+
+        // Colors are stored as BGRA
+        [pixel.blue(), pixel.green(), pixel.red(), pixel.alpha()]
+    }
+
+    fn colors_per_side() -> Colors {
+        Colors::new(Color::WHITE)
+            .top(Color::from_rgb(1.0, 0.0, 0.0))
+            .right(Color::from_rgb(0.0, 1.0, 0.0))
+            .bottom(Color::from_rgb(0.0, 0.0, 1.0))
+    }
+
+    #[test]
+    fn border_sides_are_colored_per_side() {
+        let pixmap = draw_border(border::width(4).colors(colors_per_side()));
+
+        assert_eq!(color_at(&pixmap, 20, 1), RED);
+        assert_eq!(color_at(&pixmap, 38, 20), GREEN);
+        assert_eq!(color_at(&pixmap, 20, 38), BLUE);
+        assert_eq!(color_at(&pixmap, 1, 20), WHITE);
+        assert_eq!(color_at(&pixmap, 20, 20), TRANSPARENT);
+
+        // Corners are split diagonally between their sides
+        assert_eq!(color_at(&pixmap, 3, 0), RED);
+        assert_eq!(color_at(&pixmap, 0, 3), WHITE);
+    }
+
+    #[test]
+    fn rounded_border_sides_are_masked_per_side() {
+        let pixmap =
+            draw_border(border::width(4).rounded(10).colors(colors_per_side()));
+
+        assert_eq!(color_at(&pixmap, 20, 1), RED);
+        assert_eq!(color_at(&pixmap, 38, 20), GREEN);
+        assert_eq!(color_at(&pixmap, 20, 38), BLUE);
+        assert_eq!(color_at(&pixmap, 1, 20), WHITE);
+        assert_eq!(color_at(&pixmap, 20, 20), TRANSPARENT);
+
+        // The ring of the border is rounded on both edges
+        assert_eq!(color_at(&pixmap, 0, 0), TRANSPARENT);
+        assert_eq!(color_at(&pixmap, 3, 5), WHITE);
+        assert_eq!(color_at(&pixmap, 7, 7), TRANSPARENT);
+    }
+
+    #[test]
+    fn zero_width_sides_are_not_drawn() {
+        let pixmap = draw_border(
+            border::widths(Widths::default().bottom(4)).color(Color::BLACK),
+        );
+
+        assert_eq!(color_at(&pixmap, 20, 38), BLACK);
+        assert_eq!(color_at(&pixmap, 0, 38), BLACK);
+        assert_eq!(color_at(&pixmap, 20, 1), TRANSPARENT);
+        assert_eq!(color_at(&pixmap, 38, 20), TRANSPARENT);
+        assert_eq!(color_at(&pixmap, 1, 20), TRANSPARENT);
+    }
+
+    #[test]
+    fn dashed_border_sides_leave_gaps() {
+        let pixmap =
+            draw_border(border::dashed().width(2).colors(colors_per_side()));
+
+        // Two pixels wide dashes are 6 pixels long with 4 pixel gaps
+        assert_eq!(color_at(&pixmap, 3, 1), RED);
+        assert_eq!(color_at(&pixmap, 8, 1), TRANSPARENT);
+        assert_eq!(color_at(&pixmap, 13, 1), RED);
+        assert_eq!(color_at(&pixmap, 18, 1), TRANSPARENT);
+
+        // Every side is dashed from its own start
+        assert_eq!(color_at(&pixmap, 38, 3), GREEN);
+        assert_eq!(color_at(&pixmap, 38, 8), TRANSPARENT);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::Primitive;
+use crate::engine;
 use crate::core::text::LineHeight;
 use crate::core::{self, Pixels, Point, Radians, Rectangle, Size, Svg, Vector, Gradient};
 use crate::graphics::cache::{self, Cached};
@@ -498,14 +499,10 @@ pub fn into_stroke(stroke: &Stroke<'_>) -> tiny_skia::Stroke {
             stroke::LineJoin::Round => tiny_skia::LineJoin::Round,
             stroke::LineJoin::Bevel => tiny_skia::LineJoin::Bevel,
         },
-        dash: if stroke.line_dash.segments.is_empty() {
-            None
-        } else {
-            tiny_skia::StrokeDash::new(
-                stroke.line_dash.segments.into(),
-                stroke.line_dash.offset as f32,
-            )
-        },
+        dash: engine::into_dash(
+            stroke.line_dash.segments,
+            stroke.line_dash.offset as f32,
+        ),
         ..Default::default()
     }
 }
@@ -58,6 +58,22 @@ impl Renderer {
         }
     }
 
+    /// Sets the maximum amount of memory, in bytes, that the image cache
+    /// of the [`Renderer`] may use.
+    pub fn set_image_cache_budget(&mut self, _budget: Option<u64>) {
+        #[cfg(feature = "image")]
+        self.engine.raster_pipeline.set_budget(_budget);
+    }
+
+    /// Sets the [`Shell`] of the [`Renderer`].
+    ///
+    /// Once set, images are decoded in a background thread and the
+    /// [`Shell`] is notified whenever an image is ready.
+    pub fn set_shell(&mut self, _shell: graphics::Shell) {
+        #[cfg(feature = "image")]
+        self.engine.raster_pipeline.set_shell(_shell);
+    }
+
     pub fn layers(&mut self) -> &[Layer] {
         self.layers.flush();
         self.layers.as_slice()
@@ -196,6 +212,9 @@ impl Renderer {
         }
 
         self.engine.trim();
+
+        #[cfg(feature = "image")]
+        debug::images_cached(|| self.engine.raster_pipeline.stats());
     }
 }
 
@@ -237,13 +256,25 @@ impl core::Renderer for Renderer {
         + 'static,
     ) {
         #[cfg(feature = "image")]
-        #[allow(unsafe_code)]
-        // TODO: Concurrency
         callback(self.engine.raster_pipeline.load(_handle));
 
         #[cfg(not(feature = "image"))]
         callback(Err(core::image::Error::Unsupported))
     }
+
+    #[cfg(feature = "image")]
+    fn allocate_image_with_progress(
+        &mut self,
+        handle: &core::image::Handle,
+        on_progress: impl FnMut(core::image::Progress) + Send + 'static,
+    ) {
+        self.engine.raster_pipeline.allocate(handle, on_progress);
+    }
+
+    fn evict_image(&mut self, _handle: &core::image::Handle) {
+        #[cfg(feature = "image")]
+        self.engine.raster_pipeline.evict(_handle);
+    }
 }
 
 impl core::text::Renderer for Renderer {
@@ -396,6 +427,10 @@ impl core::image::Renderer for Renderer {
         self.engine.raster_pipeline.dimensions(handle)
     }
 
+    fn image_status(&self, handle: &Self::Handle) -> core::image::Status {
+        self.engine.raster_pipeline.status(handle)
+    }
+
     fn draw_image(
         &mut self,
         image: core::Image,
@@ -1,10 +1,19 @@
 use crate::core::image as raster;
 use crate::core::{Rectangle, Size};
-use crate::graphics;
+use crate::graphics::image::cache::Tracker;
+use crate::graphics::{self, Shell};
 
-use rustc_hash::{FxHashMap, FxHashSet};
+#[cfg(not(target_arch = "wasm32"))]
+use worker::Worker;
+
+use rustc_hash::FxHashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use rustc_hash::FxHashSet;
 use std::cell::RefCell;
 use std::collections::hash_map;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fmt;
+use std::sync::Weak;
 
 #[derive(Debug)]
 pub struct Pipeline {
@@ -12,9 +21,31 @@ pub struct Pipeline {
 }
 
 impl Pipeline {
-    pub fn new() -> Self {
+    pub fn new(budget: Option<u64>) -> Self {
         Self {
-            cache: RefCell::new(Cache::default()),
+            cache: RefCell::new(Cache {
+                entries: FxHashMap::default(),
+                allocations: FxHashMap::default(),
+                tracker: Tracker::new(budget),
+                #[cfg(not(target_arch = "wasm32"))]
+                pending: FxHashSet::default(),
+                #[cfg(not(target_arch = "wasm32"))]
+                listeners: FxHashMap::default(),
+                #[cfg(not(target_arch = "wasm32"))]
+                worker: None,
+            }),
+        }
+    }
+
+    /// Decodes images in a background thread from now on, notifying
+    /// the given [`Shell`] whenever an image is ready.
+    ///
+    /// Images keep being decoded synchronously if the thread cannot be
+    /// spawned.
+    pub fn set_shell(&mut self, _shell: Shell) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.cache.get_mut().worker = Worker::new(_shell);
         }
     }
 
@@ -23,21 +54,63 @@ impl Pipeline {
         handle: &raster::Handle,
     ) -> Result<raster::Allocation, raster::Error> {
         let mut cache = self.cache.borrow_mut();
-        let image = cache.allocate(handle)?;
 
-        #[allow(unsafe_code)]
-        Ok(unsafe {
-            raster::allocate(handle, Size::new(image.width(), image.height()))
-        })
+        #[cfg(not(target_arch = "wasm32"))]
+        cache.receive();
+
+        cache.allocate(handle)
+    }
+
+    /// Allocates the given [`raster::Handle`], calling the given callback
+    /// with its [`raster::Progress`] along the way.
+    ///
+    /// Images that are not ready are decoded in the background, if
+    /// possible. Otherwise, they are decoded right away.
+    pub fn allocate(
+        &self,
+        handle: &raster::Handle,
+        mut on_progress: impl FnMut(raster::Progress) + Send + 'static,
+    ) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let mut cache = self.cache.borrow_mut();
+
+            if cache.get(handle, false).is_none() {
+                on_progress(raster::Progress::Queued);
+
+                cache
+                    .listeners
+                    .entry(handle.id())
+                    .or_insert_with(|| (handle.clone(), Vec::new()))
+                    .1
+                    .push(Listener(Box::new(on_progress)));
+
+                return;
+            }
+        }
+
+        on_progress(raster::Progress::Finished(self.load(handle)));
     }
 
     pub fn dimensions(&self, handle: &raster::Handle) -> Option<Size<u32>> {
         let mut cache = self.cache.borrow_mut();
-        let image = cache.allocate(handle).ok()?;
+        let image = cache.get(handle, false)?.ok()?;
 
         Some(Size::new(image.width(), image.height()))
     }
 
+    pub fn status(&self, handle: &raster::Handle) -> raster::Status {
+        let mut cache = self.cache.borrow_mut();
+
+        match cache.get(handle, false) {
+            None => raster::Status::Loading,
+            Some(Ok(image)) => {
+                raster::Status::Loaded(Size::new(image.width(), image.height()))
+            }
+            Some(Err(error)) => raster::Status::Failed(error),
+        }
+    }
+
     pub fn draw(
         &mut self,
         handle: &raster::Handle,
@@ -50,7 +123,7 @@ impl Pipeline {
     ) {
         let mut cache = self.cache.borrow_mut();
 
-        let Ok(image) = cache.allocate(handle) else {
+        let Some(Ok(image)) = cache.get(handle, false) else {
             return;
         };
 
@@ -78,74 +151,222 @@ impl Pipeline {
         );
     }
 
+    pub fn evict(&mut self, handle: &raster::Handle) {
+        self.cache.borrow_mut().evict(handle.id());
+    }
+
+    pub fn set_budget(&mut self, budget: Option<u64>) {
+        self.cache.borrow_mut().tracker.set_budget(budget);
+    }
+
+    pub fn stats(&self) -> raster::Stats {
+        self.cache.borrow().tracker.stats()
+    }
+
     pub fn trim_cache(&mut self) {
         self.cache.borrow_mut().trim();
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 struct Cache {
-    entries: FxHashMap<raster::Id, Option<Entry>>,
-    hits: FxHashSet<raster::Id>,
+    entries: FxHashMap<raster::Id, Result<Entry, raster::Error>>,
+    allocations: FxHashMap<raster::Id, Weak<raster::Memory>>,
+    tracker: Tracker,
+    #[cfg(not(target_arch = "wasm32"))]
+    pending: FxHashSet<raster::Id>,
+    #[cfg(not(target_arch = "wasm32"))]
+    listeners: FxHashMap<raster::Id, (raster::Handle, Vec<Listener>)>,
+    #[cfg(not(target_arch = "wasm32"))]
+    worker: Option<Worker>,
+}
+
+/// A callback waiting for the [`raster::Progress`] of an image.
+#[cfg(not(target_arch = "wasm32"))]
+struct Listener(Box<dyn FnMut(raster::Progress) + Send>);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl fmt::Debug for Listener {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Listener").finish_non_exhaustive()
+    }
 }
 
 impl Cache {
-    pub fn allocate(
+    /// Returns the decoded image of the given [`raster::Handle`], if ready.
+    ///
+    /// If the image is not cached and a [`Worker`] is available, the image
+    /// is decoded in the background and `None` is returned, unless `block`
+    /// is set.
+    fn get(
         &mut self,
         handle: &raster::Handle,
-    ) -> Result<tiny_skia::PixmapRef<'_>, raster::Error> {
+        block: bool,
+    ) -> Option<Result<tiny_skia::PixmapRef<'_>, raster::Error>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.receive();
+
+        self.fetch(handle, block)
+    }
+
+    /// Like [`Cache::get`], but ignores the images decoded by the [`Worker`]
+    /// since the last call.
+    fn fetch(
+        &mut self,
+        handle: &raster::Handle,
+        block: bool,
+    ) -> Option<Result<tiny_skia::PixmapRef<'_>, raster::Error>> {
         let id = handle.id();
 
-        if let hash_map::Entry::Vacant(entry) = self.entries.entry(id) {
-            let image = match graphics::image::load(handle) {
-                Ok(image) => image,
-                Err(error) => {
-                    let _ = entry.insert(None);
+        if !self.tracker.lookup(id) && !self.entries.contains_key(&id) {
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(worker) = &self.worker
+                && !block
+                && !matches!(handle, raster::Handle::Rgba { .. })
+            {
+                if self.pending.insert(id) {
+                    worker.decode(handle);
+                }
+
+                return None;
+            }
+
+            #[cfg(target_arch = "wasm32")]
+            let _ = block;
+
+            self.insert(id, decode(handle));
+        }
+
+        self.entries.get(&id).map(|entry| match entry {
+            Ok(entry) => Ok(entry.pixmap()),
+            Err(error) => Err(error.clone()),
+        })
+    }
+
+    fn allocate(
+        &mut self,
+        handle: &raster::Handle,
+    ) -> Result<raster::Allocation, raster::Error> {
+        let image = self.fetch(handle, true).expect("Decode image")?;
+        let size = Size::new(image.width(), image.height());
+
+        if let Some(allocation) = self
+            .allocations
+            .get(&handle.id())
+            .and_then(raster::Allocation::upgrade)
+        {
+            return Ok(allocation);
+        }
+
+        #[allow(unsafe_code)]
+        let allocation = unsafe { raster::allocate(handle, size) };
+
+        let _ = self.allocations.insert(handle.id(), allocation.downgrade());
+
+        Ok(allocation)
+    }
+
+    fn insert(&mut self, id: raster::Id, entry: Result<Entry, raster::Error>) {
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = self.pending.remove(&id);
+
+        let size = entry
+            .as_ref()
+            .map(|entry| entry.pixels.len() as u64 * 4)
+            .unwrap_or(0);
+
+        if let hash_map::Entry::Vacant(vacant) = self.entries.entry(id) {
+            let _ = vacant.insert(entry);
+            self.tracker.insert(id, size);
+        }
+    }
 
-                    return Err(error);
+    #[cfg(not(target_arch = "wasm32"))]
+    fn receive(&mut self) {
+        while let Some(output) = self.worker.as_ref().and_then(Worker::try_recv)
+        {
+            match output {
+                worker::Output::Decoding(id) => {
+                    if let Some((_handle, listeners)) =
+                        self.listeners.get_mut(&id)
+                    {
+                        for Listener(listener) in listeners {
+                            listener(raster::Progress::Decoding);
+                        }
+                    }
                 }
-            };
+                worker::Output::Decoded(id, entry) => {
+                    let listeners = self.listeners.remove(&id);
 
-            let mut buffer =
-                vec![0u32; image.width() as usize * image.height() as usize];
+                    if self.pending.contains(&id) || listeners.is_some() {
+                        self.insert(id, entry);
+                    }
 
-            for (i, pixel) in image.pixels().enumerate() {
-                let [r, g, b, a] = pixel.0;
+                    if let Some((handle, listeners)) = listeners {
+                        let result = self.allocate(&handle);
 
-                buffer[i] = bytemuck::cast(
-                    tiny_skia::ColorU8::from_rgba(b, g, r, a).premultiply(),
-                );
+                        for Listener(mut listener) in listeners {
+                            listener(raster::Progress::Finished(
+                                result.clone(),
+                            ));
+                        }
+                    }
+                }
             }
+        }
+    }
 
-            let _ = entry.insert(Some(Entry {
-                width: image.width(),
-                height: image.height(),
-                pixels: buffer,
-            }));
+    fn evict(&mut self, id: raster::Id) {
+        // Images held by an allocation are never evicted
+        if self.is_allocated(id) {
+            return;
         }
 
-        let _ = self.hits.insert(id);
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = self.pending.remove(&id);
 
-        Ok(self
-            .entries
-            .get(&id)
-            .unwrap()
-            .as_ref()
-            .map(|entry| {
-                tiny_skia::PixmapRef::from_bytes(
-                    bytemuck::cast_slice(&entry.pixels),
-                    entry.width,
-                    entry.height,
-                )
-                .expect("Build pixmap from image bytes")
-            })
-            .expect("Image should be allocated"))
+        if self.entries.remove(&id).is_some() {
+            self.tracker.remove(id);
+        }
     }
 
     fn trim(&mut self) {
-        self.entries.retain(|key, _| self.hits.contains(key));
-        self.hits.clear();
+        self.allocations
+            .retain(|_, memory| memory.strong_count() > 0);
+
+        let allocations = &self.allocations;
+
+        for id in self.tracker.trim(|id| allocations.contains_key(&id)) {
+            let _ = self.entries.remove(&id);
+        }
     }
+
+    fn is_allocated(&self, id: raster::Id) -> bool {
+        self.allocations
+            .get(&id)
+            .is_some_and(|memory| memory.strong_count() > 0)
+    }
+}
+
+fn decode(handle: &raster::Handle) -> Result<Entry, raster::Error> {
+    let image = graphics::image::load(handle)?;
+
+    let mut buffer =
+        vec![0u32; image.width() as usize * image.height() as usize];
+
+    for (i, pixel) in image.pixels().enumerate() {
+        let [r, g, b, a] = pixel.0;
+
+        buffer[i] = bytemuck::cast(
+            tiny_skia::ColorU8::from_rgba(b, g, r, a).premultiply(),
+        );
+    }
+
+    Ok(Entry {
+        width: image.width(),
+        height: image.height(),
+        pixels: buffer,
+    })
 }
 
 #[derive(Debug)]
@@ -154,3 +375,243 @@ struct Entry {
     height: u32,
     pixels: Vec<u32>,
 }
+
+impl Entry {
+    fn pixmap(&self) -> tiny_skia::PixmapRef<'_> {
+        tiny_skia::PixmapRef::from_bytes(
+            bytemuck::cast_slice(&self.pixels),
+            self.width,
+            self.height,
+        )
+        .expect("Build pixmap from image bytes")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod worker {
+    use super::{Entry, decode};
+    use crate::core::image as raster;
+    use crate::graphics::Shell;
+
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// The progress of the [`Worker`].
+    pub enum Output {
+        /// The image with the given id started decoding.
+        Decoding(raster::Id),
+        /// The image with the given id finished decoding.
+        Decoded(raster::Id, Result<Entry, raster::Error>),
+    }
+
+    /// A background thread that decodes images.
+    ///
+    /// The thread stops once the [`Worker`] is dropped.
+    #[derive(Debug)]
+    pub struct Worker {
+        jobs: mpsc::Sender<raster::Handle>,
+        output: mpsc::Receiver<Output>,
+    }
+
+    impl Worker {
+        pub fn new(shell: Shell) -> Option<Self> {
+            let (jobs_sender, jobs) = mpsc::channel::<raster::Handle>();
+            let (output_sender, output) = mpsc::channel();
+
+            let _handle = thread::Builder::new()
+                .name("iced_tiny_skia::raster::worker".to_owned())
+                .spawn(move || {
+                    for handle in jobs {
+                        if output_sender
+                            .send(Output::Decoding(handle.id()))
+                            .is_err()
+                        {
+                            return;
+                        }
+
+                        shell.request_redraw();
+
+                        let entry = decode(&handle);
+
+                        if output_sender
+                            .send(Output::Decoded(handle.id(), entry))
+                            .is_err()
+                        {
+                            return;
+                        }
+
+                        shell.invalidate_layout();
+                    }
+                })
+                .inspect_err(|error| {
+                    log::warn!(
+                        "Failed to spawn image decoding worker: {error}"
+                    );
+                })
+                .ok()?;
+
+            Some(Self {
+                jobs: jobs_sender,
+                output,
+            })
+        }
+
+        pub fn decode(&self, handle: &raster::Handle) {
+            let _ = self.jobs.send(handle.clone());
+        }
+
+        pub fn try_recv(&self) -> Option<Output> {
+            self.output.try_recv().ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Bytes;
+    use crate::graphics::shell;
+
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    fn rgba(width: u32, height: u32) -> raster::Handle {
+        raster::Handle::from_rgba(
+            width,
+            height,
+            vec![255; (width * height * 4) as usize],
+        )
+    }
+
+    fn invalid() -> raster::Handle {
+        raster::Handle::from_bytes(Bytes::from_static(b"not an image"))
+    }
+
+    #[test]
+    fn decodes_synchronously_without_worker() {
+        let pipeline = Pipeline::new(None);
+
+        assert!(matches!(
+            pipeline.status(&rgba(2, 3)),
+            raster::Status::Loaded(size) if size == Size::new(2, 3)
+        ));
+
+        assert!(matches!(
+            pipeline.status(&invalid()),
+            raster::Status::Failed(_)
+        ));
+    }
+
+    /// Notifies whenever the worker finishes decoding an image.
+    struct Notifier(mpsc::SyncSender<()>);
+
+    impl shell::Notifier for Notifier {
+        fn request_redraw(&self) {}
+
+        fn invalidate_layout(&self) {
+            let _ = self.0.try_send(());
+        }
+    }
+
+    #[test]
+    fn decodes_in_the_background_with_worker() {
+        let (sender, receiver) = mpsc::sync_channel(1);
+
+        let mut pipeline = Pipeline::new(None);
+        pipeline.set_shell(Shell::new(Notifier(sender)));
+
+        // Raw pixels do not need decoding
+        assert!(matches!(
+            pipeline.status(&rgba(1, 1)),
+            raster::Status::Loaded(size) if size == Size::new(1, 1)
+        ));
+
+        let handle = invalid();
+
+        assert!(pipeline.status(&handle).is_loading());
+        assert_eq!(pipeline.dimensions(&handle), None);
+
+        receiver
+            .recv_timeout(Duration::from_secs(5))
+            .expect("Decode image in the background");
+
+        assert!(matches!(
+            pipeline.status(&handle),
+            raster::Status::Failed(_)
+        ));
+
+        // Explicit allocations block until the image is decoded
+        assert!(pipeline.load(&invalid()).is_err());
+    }
+
+    #[test]
+    fn keeps_allocated_images() {
+        let mut pipeline = Pipeline::new(None);
+
+        let pinned = rgba(4, 4);
+        let unpinned = rgba(4, 4);
+
+        let allocation = pipeline.load(&pinned).expect("Load image");
+        let _ = pipeline.status(&unpinned);
+
+        assert_eq!(pipeline.stats().images, 2);
+
+        // Nothing is drawn for a couple of frames
+        pipeline.trim_cache();
+        pipeline.trim_cache();
+
+        assert_eq!(pipeline.stats().images, 1);
+
+        pipeline.evict(&pinned);
+        assert_eq!(pipeline.stats().images, 1);
+
+        drop(allocation);
+        pipeline.trim_cache();
+
+        assert_eq!(pipeline.stats().images, 0);
+    }
+
+    #[test]
+    fn reports_the_progress_of_allocations() {
+        let (sender, receiver) = mpsc::sync_channel(1);
+
+        let mut pipeline = Pipeline::new(None);
+        pipeline.set_shell(Shell::new(Notifier(sender)));
+
+        let (progress_sender, progress) = mpsc::channel();
+        let on_progress = move |progress| {
+            let _ = progress_sender.send(progress);
+        };
+
+        // Ready images are allocated right away
+        pipeline.allocate(&rgba(1, 1), on_progress.clone());
+
+        assert!(matches!(
+            progress.try_iter().collect::<Vec<_>>().as_slice(),
+            [raster::Progress::Finished(Ok(_))]
+        ));
+
+        let handle = invalid();
+        pipeline.allocate(&handle, on_progress);
+
+        assert!(matches!(
+            progress.try_iter().collect::<Vec<_>>().as_slice(),
+            [raster::Progress::Queued]
+        ));
+
+        receiver
+            .recv_timeout(Duration::from_secs(5))
+            .expect("Decode image in the background");
+
+        // The rest of the progress is reported once the worker is polled
+        let _ = pipeline.status(&handle);
+
+        assert!(matches!(
+            progress.try_iter().collect::<Vec<_>>().as_slice(),
+            [
+                raster::Progress::Decoding,
+                raster::Progress::Finished(Err(_))
+            ]
+        ));
+    }
+}
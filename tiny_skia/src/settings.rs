@@ -13,6 +13,14 @@ pub struct Settings {
     ///
     /// By default, it will be set to `16.0`.
     pub default_text_size: Pixels,
+
+    /// The maximum amount of memory, in bytes, that the image cache of
+    /// the [`Renderer`] may use.
+    ///
+    /// By default, it is `None`.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    pub image_cache_budget: Option<u64>,
 }
 
 impl Default for Settings {
@@ -20,6 +28,7 @@ impl Default for Settings {
         Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
+            image_cache_budget: None,
         }
     }
 }
@@ -29,6 +38,7 @@ impl From<graphics::Settings> for Settings {
         Self {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
+            image_cache_budget: settings.image_cache_budget,
         }
     }
 }
@@ -11,6 +11,7 @@ use std::num::NonZeroU32;
 pub struct Compositor {
     context: softbuffer::Context<Box<dyn compositor::Window>>,
     settings: Settings,
+    shell: Shell,
 }
 
 pub struct Surface {
@@ -31,12 +32,12 @@ impl crate::graphics::Compositor for Compositor {
     async fn with_backend<W: compositor::Window>(
         settings: graphics::Settings,
         compatible_window: W,
-        _shell: Shell,
+        shell: Shell,
         backend: Option<&str>,
     ) -> Result<Self, Error> {
         match backend {
             None | Some("tiny-skia") | Some("tiny_skia") => {
-                Ok(new(settings.into(), compatible_window))
+                Ok(new(settings.into(), compatible_window, shell))
             }
             Some(backend) => Err(Error::GraphicsAdapterNotFound {
                 backend: "tiny-skia",
@@ -48,10 +49,14 @@ impl crate::graphics::Compositor for Compositor {
     }
 
     fn create_renderer(&self) -> Self::Renderer {
-        Renderer::new(
+        let mut renderer = Renderer::new(
             self.settings.default_font,
             self.settings.default_text_size,
-        )
+        );
+
+        renderer.set_image_cache_budget(self.settings.image_cache_budget);
+        renderer.set_shell(self.shell.clone());
+        renderer
     }
 
     fn create_surface<W: compositor::Window + Clone>(
@@ -137,12 +142,17 @@ impl crate::graphics::Compositor for Compositor {
 pub fn new<W: compositor::Window>(
     settings: Settings,
     compatible_window: W,
+    shell: Shell,
 ) -> Compositor {
     #[allow(unsafe_code)]
     let context = softbuffer::Context::new(Box::new(compatible_window) as _)
         .expect("Create softbuffer context");
 
-    Compositor { context, settings }
+    Compositor {
+        context,
+        settings,
+        shell,
+    }
 }
 
 pub fn present(
@@ -172,18 +182,15 @@ pub fn present(
         }
     };
 
-    let damage = last_layers
-        .and_then(|last_layers| {
-            (surface.background_color == background_color).then(|| {
-                damage::diff(
-                    last_layers,
-                    renderer.layers(),
-                    |layer| vec![layer.bounds],
-                    Layer::damage,
-                )
-            })
-        })
-        .unwrap_or_else(|| vec![Rectangle::with_size(viewport.logical_size())]);
+    let damage = damage::frame(
+        last_layers
+            .filter(|_| surface.background_color == background_color)
+            .map(Vec::as_slice),
+        renderer.layers(),
+        Rectangle::with_size(viewport.logical_size()),
+        |layer| layer.bounds,
+        Layer::damage,
+    );
 
     if damage.is_empty() {
         return Ok(());
@@ -192,9 +199,6 @@ pub fn present(
     surface.layer_stack.push_front(renderer.layers().to_vec());
     surface.background_color = background_color;
 
-    let damage =
-        damage::group(damage, Rectangle::with_size(viewport.logical_size()));
-
     let mut pixels = tiny_skia::PixmapMut::from_bytes(
         bytemuck::cast_slice_mut(&mut buffer),
         physical_size.width,
@@ -36,7 +36,7 @@ impl Cache {
             atlas: Atlas::new(device, backend, layout),
             #[cfg(feature = "image")]
             raster: Raster {
-                cache: crate::image::raster::Cache::default(),
+                cache: crate::image::raster::Cache::new(None),
                 pending: HashMap::new(),
                 belt: wgpu::util::StagingBelt::new(2 * 1024 * 1024),
             },
@@ -54,13 +54,31 @@ impl Cache {
         callback: impl FnOnce(Result<core::image::Allocation, core::image::Error>)
         + Send
         + 'static,
+    ) {
+        let mut callback = Some(callback);
+
+        self.allocate_image_with_progress(handle, move |progress| {
+            if let core::image::Progress::Finished(result) = progress
+                && let Some(callback) = callback.take()
+            {
+                callback(result);
+            }
+        });
+    }
+
+    #[cfg(feature = "image")]
+    pub fn allocate_image_with_progress(
+        &mut self,
+        handle: &core::image::Handle,
+        on_progress: impl FnMut(core::image::Progress) + Send + 'static,
     ) {
         use crate::image::raster::Memory;
 
-        let callback = Box::new(callback);
+        let mut listener: Listener = Box::new(on_progress);
 
-        if let Some(callbacks) = self.raster.pending.get_mut(&handle.id()) {
-            callbacks.push(callback);
+        if let Some(listeners) = self.raster.pending.get_mut(&handle.id()) {
+            listener(core::image::Progress::Queued);
+            listeners.push(listener);
             return;
         }
 
@@ -72,19 +90,21 @@ impl Cache {
                 .as_ref()
                 .and_then(core::image::Allocation::upgrade)
             {
-                callback(Ok(allocation));
+                listener(core::image::Progress::Finished(Ok(allocation)));
                 return;
             }
 
             #[allow(unsafe_code)]
             let new = unsafe { core::image::allocate(handle, entry.size()) };
             *allocation = Some(new.downgrade());
-            callback(Ok(new));
+            listener(core::image::Progress::Finished(Ok(new)));
 
             return;
         }
 
-        let _ = self.raster.pending.insert(handle.id(), vec![callback]);
+        listener(core::image::Progress::Queued);
+
+        let _ = self.raster.pending.insert(handle.id(), vec![listener]);
 
         #[cfg(not(target_arch = "wasm32"))]
         self.worker.load(handle);
@@ -99,7 +119,7 @@ impl Cache {
     ) -> Result<core::image::Allocation, core::image::Error> {
         use crate::image::raster::Memory;
 
-        if !self.raster.cache.contains(handle) {
+        if !self.raster.cache.lookup(handle) {
             self.raster.cache.insert(handle, Memory::load(handle));
         }
 
@@ -174,6 +194,28 @@ impl Cache {
         }
     }
 
+    #[cfg(feature = "image")]
+    pub fn evict_image(&mut self, handle: &core::image::Handle) {
+        self.receive();
+
+        self.raster
+            .cache
+            .remove(handle, &mut self.atlas, |_bind_group| {
+                #[cfg(not(target_arch = "wasm32"))]
+                self.worker.drop(_bind_group);
+            });
+    }
+
+    #[cfg(feature = "image")]
+    pub fn set_budget(&mut self, budget: Option<u64>) {
+        self.raster.cache.set_budget(budget);
+    }
+
+    #[cfg(feature = "image")]
+    pub fn stats(&self) -> core::image::Stats {
+        self.raster.cache.stats()
+    }
+
     #[cfg(feature = "image")]
     pub fn measure_image(
         &mut self,
@@ -193,6 +235,33 @@ impl Cache {
         Some(image.dimensions())
     }
 
+    #[cfg(feature = "image")]
+    pub fn image_status(
+        &mut self,
+        handle: &core::image::Handle,
+    ) -> core::image::Status {
+        use crate::image::raster::Memory;
+
+        self.receive();
+
+        let image = load_image(
+            &mut self.raster.cache,
+            &mut self.raster.pending,
+            #[cfg(not(target_arch = "wasm32"))]
+            &self.worker,
+            handle,
+            None,
+        );
+
+        match image {
+            None => core::image::Status::Loading,
+            Some(Memory::Error(error)) => {
+                core::image::Status::Failed(error.clone())
+            }
+            Some(image) => core::image::Status::Loaded(image.dimensions()),
+        }
+    }
+
     #[cfg(feature = "svg")]
     pub fn measure_svg(&mut self, handle: &core::svg::Handle) -> Size<u32> {
         // TODO: Concurrency
@@ -313,14 +382,23 @@ impl Cache {
             use crate::image::raster::Memory;
 
             match work {
+                worker::Work::Decoding { handle } => {
+                    if let Some(listeners) =
+                        self.raster.pending.get_mut(&handle.id())
+                    {
+                        for listener in listeners {
+                            listener(core::image::Progress::Decoding);
+                        }
+                    }
+                }
                 worker::Work::Upload {
                     handle,
                     entry,
                     bind_group,
                 } => {
-                    let callbacks = self.raster.pending.remove(&handle.id());
+                    let listeners = self.raster.pending.remove(&handle.id());
 
-                    let allocation = if let Some(callbacks) = callbacks {
+                    let allocation = if let Some(listeners) = listeners {
                         #[allow(unsafe_code)]
                         let allocation = unsafe {
                             core::image::allocate(&handle, entry.size())
@@ -328,8 +406,10 @@ impl Cache {
 
                         let reference = allocation.downgrade();
 
-                        for callback in callbacks {
-                            callback(Ok(allocation.clone()));
+                        for mut listener in listeners {
+                            listener(core::image::Progress::Finished(Ok(
+                                allocation.clone(),
+                            )));
                         }
 
                         Some(reference)
@@ -347,11 +427,13 @@ impl Cache {
                     );
                 }
                 worker::Work::Error { handle, error } => {
-                    let callbacks = self.raster.pending.remove(&handle.id());
+                    let listeners = self.raster.pending.remove(&handle.id());
 
-                    if let Some(callbacks) = callbacks {
-                        for callback in callbacks {
-                            callback(Err(error.clone()));
+                    if let Some(listeners) = listeners {
+                        for mut listener in listeners {
+                            listener(core::image::Progress::Finished(Err(
+                                error.clone(),
+                            )));
                         }
                     }
 
@@ -372,25 +454,24 @@ impl Drop for Cache {
 #[cfg(feature = "image")]
 struct Raster {
     cache: crate::image::raster::Cache,
-    pending: HashMap<core::image::Id, Vec<Callback>>,
+    pending: HashMap<core::image::Id, Vec<Listener>>,
     belt: wgpu::util::StagingBelt,
 }
 
 #[cfg(feature = "image")]
-type Callback =
-    Box<dyn FnOnce(Result<core::image::Allocation, core::image::Error>) + Send>;
+type Listener = Box<dyn FnMut(core::image::Progress) + Send>;
 
 #[cfg(feature = "image")]
 fn load_image<'a>(
     cache: &'a mut crate::image::raster::Cache,
-    pending: &mut HashMap<core::image::Id, Vec<Callback>>,
+    pending: &mut HashMap<core::image::Id, Vec<Listener>>,
     #[cfg(not(target_arch = "wasm32"))] worker: &Worker,
     handle: &core::image::Handle,
-    callback: Option<Callback>,
+    listener: Option<Listener>,
 ) -> Option<&'a mut crate::image::raster::Memory> {
     use crate::image::raster::Memory;
 
-    if !cache.contains(handle) {
+    if !cache.lookup(handle) {
         if cfg!(target_arch = "wasm32") {
             // TODO: Concurrent support for Wasm
             cache.insert(handle, Memory::load(handle));
@@ -398,7 +479,7 @@ fn load_image<'a>(
             // Load RGBA handles synchronously, since it's very cheap
             cache.insert(handle, Memory::load(handle));
         } else if !pending.contains_key(&handle.id()) {
-            let _ = pending.insert(handle.id(), Vec::from_iter(callback));
+            let _ = pending.insert(handle.id(), Vec::from_iter(listener));
 
             #[cfg(not(target_arch = "wasm32"))]
             worker.load(handle);
@@ -515,6 +596,9 @@ mod worker {
     }
 
     pub enum Work {
+        Decoding {
+            handle: image::Handle,
+        },
         Upload {
             handle: image::Handle,
             entry: atlas::Entry,
@@ -539,6 +623,12 @@ mod worker {
 
                 match job {
                     Job::Load(handle) => {
+                        let _ = self.output.send(Work::Decoding {
+                            handle: handle.clone(),
+                        });
+
+                        self.shell.request_redraw();
+
                         match crate::graphics::image::load(&handle) {
                             Ok(image) => self.upload(
                                 handle,
@@ -13,4 +13,8 @@ impl Batch {
     }
 
     pub fn append(&mut self, _batch: &mut Self) {}
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Image> {
+        [].iter()
+    }
 }
@@ -1,9 +1,10 @@
 use crate::core::Size;
 use crate::core::image;
 use crate::graphics;
+use crate::graphics::image::cache::Tracker;
 use crate::image::atlas::{self, Atlas};
 
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 use std::sync::{Arc, Weak};
 
 pub type Image = graphics::image::Buffer;
@@ -42,6 +43,29 @@ impl Memory {
         }
     }
 
+    /// Returns the amount of memory used by the [`Memory`], in bytes.
+    pub fn size(&self) -> u64 {
+        match self {
+            Memory::Host(image) => image.len() as u64,
+            Memory::Device { entry, .. } => {
+                let size = entry.size();
+
+                u64::from(size.width) * u64::from(size.height) * 4
+            }
+            Memory::Error(_) => 0,
+        }
+    }
+
+    /// Returns true if the [`Memory`] is held by an active allocation.
+    pub fn is_allocated(&self) -> bool {
+        match self {
+            Memory::Device { allocation, .. } => allocation
+                .as_ref()
+                .is_some_and(|allocation| allocation.strong_count() > 0),
+            Memory::Host(_) | Memory::Error(_) => false,
+        }
+    }
+
     pub fn host(&self) -> Option<Image> {
         match self {
             Memory::Host(image) => Some(image.clone()),
@@ -53,26 +77,63 @@ impl Memory {
 #[derive(Debug, Default)]
 pub struct Cache {
     map: FxHashMap<image::Id, Memory>,
-    hits: FxHashSet<image::Id>,
+    tracker: Tracker,
     should_trim: bool,
 }
 
 impl Cache {
+    pub fn new(budget: Option<u64>) -> Self {
+        Self {
+            tracker: Tracker::new(budget),
+            ..Self::default()
+        }
+    }
+
+    pub fn set_budget(&mut self, budget: Option<u64>) {
+        self.tracker.set_budget(budget);
+        self.should_trim = true;
+    }
+
+    pub fn stats(&self) -> image::Stats {
+        self.tracker.stats()
+    }
+
+    /// Looks up the given [`image::Handle`] in the [`Cache`], recording
+    /// a hit or a miss.
+    pub fn lookup(&mut self, handle: &image::Handle) -> bool {
+        self.tracker.lookup(handle.id())
+    }
+
     pub fn get_mut(&mut self, handle: &image::Handle) -> Option<&mut Memory> {
-        let _ = self.hits.insert(handle.id());
+        self.tracker.touch(handle.id());
 
         self.map.get_mut(&handle.id())
     }
 
     pub fn insert(&mut self, handle: &image::Handle, memory: Memory) {
+        self.tracker.insert(handle.id(), memory.size());
         let _ = self.map.insert(handle.id(), memory);
-        let _ = self.hits.insert(handle.id());
 
         self.should_trim = true;
     }
 
-    pub fn contains(&self, handle: &image::Handle) -> bool {
-        self.map.contains_key(&handle.id())
+    pub fn remove(
+        &mut self,
+        handle: &image::Handle,
+        atlas: &mut Atlas,
+        on_drop: impl Fn(Arc<wgpu::BindGroup>),
+    ) {
+        let id = handle.id();
+
+        if self.map.get(&id).is_none_or(Memory::is_allocated) {
+            return;
+        }
+
+        if let Some(memory) = self.map.remove(&id) {
+            self.tracker.remove(id);
+
+            drop_memory(memory, atlas, &on_drop);
+        }
     }
 
     pub fn trim(
@@ -85,39 +146,38 @@ impl Cache {
             return;
         }
 
-        let hits = &self.hits;
+        let map = &self.map;
 
-        self.map.retain(|id, memory| {
-            // Retain active allocations
-            if let Memory::Device { allocation, .. } = memory
-                && allocation
-                    .as_ref()
-                    .is_some_and(|allocation| allocation.strong_count() > 0)
-            {
-                return true;
-            }
+        // Retain active allocations
+        let evicted = self
+            .tracker
+            .trim(|id| map.get(&id).is_some_and(Memory::is_allocated));
 
-            let retain = hits.contains(id);
-
-            if !retain {
-                log::debug!("Dropping image allocation: {id:?}");
-
-                if let Memory::Device {
-                    entry, bind_group, ..
-                } = memory
-                {
-                    if let Some(bind_group) = bind_group.take() {
-                        on_drop(bind_group);
-                    } else {
-                        atlas.remove(entry);
-                    }
-                }
-            }
+        for id in evicted {
+            log::debug!("Dropping image allocation: {id:?}");
 
-            retain
-        });
+            if let Some(memory) = self.map.remove(&id) {
+                drop_memory(memory, atlas, &on_drop);
+            }
+        }
 
-        self.hits.clear();
         self.should_trim = false;
     }
 }
+
+fn drop_memory(
+    memory: Memory,
+    atlas: &mut Atlas,
+    on_drop: &impl Fn(Arc<wgpu::BindGroup>),
+) {
+    if let Memory::Device {
+        entry, bind_group, ..
+    } = memory
+    {
+        if let Some(bind_group) = bind_group {
+            on_drop(bind_group);
+        } else {
+            atlas.remove(&entry);
+        }
+    }
+}
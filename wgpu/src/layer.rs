@@ -4,6 +4,7 @@ use crate::core::{
 use crate::graphics;
 use crate::graphics::Mesh;
 use crate::graphics::color;
+use crate::graphics::damage;
 use crate::graphics::layer;
 use crate::graphics::text::{Editor, Paragraph};
 use crate::image::{self, Image};
@@ -48,19 +49,30 @@ impl Layer {
     ) {
         let bounds = quad.bounds * transformation;
 
+        let border_width =
+            quad.border.side_widths() * transformation.scale_factor();
+
         let quad = Quad {
             position: [bounds.x, bounds.y],
             size: [bounds.width, bounds.height],
-            border_color: color::pack(quad.border.color),
+            border_colors: <[Color; 4]>::from(quad.border.side_colors())
+                .map(color::pack_half),
             border_radius: (quad.border.radius * transformation.scale_factor())
                 .into(),
-            border_width: quad.border.width * transformation.scale_factor(),
+            border_width: border_width.into(),
+            border_dash: quad
+                .border
+                .dash()
+                .map(|dash| {
+                    dash.map(|length| length * transformation.scale_factor())
+                })
+                .unwrap_or_default(),
+            snap: quad.snap as u32,
             shadow_color: color::pack(quad.shadow.color),
             shadow_offset: (quad.shadow.offset * transformation.scale_factor())
                 .into(),
             shadow_blur_radius: quad.shadow.blur_radius
                 * transformation.scale_factor(),
-            snap: quad.snap as u32,
         };
 
         self.quads.add(quad, &background);
@@ -303,6 +315,101 @@ impl Layer {
     fn render_independent(&mut self) {
         self.render_independent = true;
     }
+
+    /// Takes a [`Snapshot`] of the contents of the [`Layer`].
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            bounds: self.bounds,
+            quads: self.quads.clone(),
+            triangles: self
+                .triangles
+                .iter()
+                .map(triangle::Item::summary)
+                .collect(),
+            images: self.images.iter().cloned().collect(),
+            text: self.text.clone(),
+            is_trackable: self.primitives.is_empty()
+                && !self.render_independent,
+        }
+    }
+}
+
+/// The contents of a [`Layer`] at a given frame, used to compute the
+/// damage between frames.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    bounds: Rectangle,
+    quads: quad::Batch,
+    triangles: Vec<triangle::Summary>,
+    images: Vec<Image>,
+    text: text::Batch,
+    is_trackable: bool,
+}
+
+impl Snapshot {
+    /// Returns the bounds of the [`Snapshot`].
+    pub fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    /// Returns true if the damage of the [`Snapshot`] can be computed.
+    ///
+    /// Custom primitives and layers rendered independently can change
+    /// at any time; so they are never considered for partial redraws.
+    pub fn is_trackable(&self) -> bool {
+        self.is_trackable
+    }
+
+    /// Computes the regions that differ between two [`Snapshot`]s.
+    pub fn damage(previous: &Self, current: &Self) -> Vec<Rectangle> {
+        if previous.bounds != current.bounds {
+            return vec![previous.bounds, current.bounds];
+        }
+
+        let mut damage = quad::Batch::damage(&previous.quads, &current.quads);
+
+        damage.extend(triangle::Summary::damage(
+            &previous.triangles,
+            &current.triangles,
+        ));
+
+        damage.extend(damage::list(
+            &previous.images,
+            &current.images,
+            |image| vec![image.bounds().expand(1.0)],
+            Image::eq,
+        ));
+
+        damage.extend(text::damage(&previous.text, &current.text));
+
+        damage
+            .into_iter()
+            .filter_map(|region| region.intersection(&current.bounds))
+            .collect()
+    }
+}
+
+/// Computes the damage between the [`Snapshot`]s of the layers of the last
+/// frame and the current one inside the given bounds.
+///
+/// If there is no previous frame, or any of the layers is not trackable in
+/// either frame, the whole `bounds` are damaged.
+pub fn frame_damage(
+    previous: Option<&[Snapshot]>,
+    current: &[Snapshot],
+    bounds: Rectangle,
+) -> Vec<Rectangle> {
+    let previous = previous.filter(|previous| {
+        previous.iter().chain(current).all(Snapshot::is_trackable)
+    });
+
+    damage::frame(
+        previous,
+        current,
+        bounds,
+        Snapshot::bounds,
+        Snapshot::damage,
+    )
 }
 
 impl graphics::Layer for Layer {
@@ -410,3 +517,182 @@ impl Default for Layer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::{Alignment, LineHeight, Shaping, Wrapping};
+    use crate::core::{Font, Pixels, Size, alignment};
+    use crate::graphics::Layer as _;
+    use crate::graphics::mesh;
+
+    fn rectangle(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(width, height))
+    }
+
+    fn layer(quads: &[Rectangle]) -> Layer {
+        let mut layer = Layer::with_bounds(rectangle(0.0, 0.0, 100.0, 100.0));
+
+        for bounds in quads {
+            layer.draw_quad(
+                renderer::Quad {
+                    bounds: *bounds,
+                    ..renderer::Quad::default()
+                },
+                Background::Color(Color::BLACK),
+                Transformation::IDENTITY,
+            );
+        }
+
+        layer
+    }
+
+    fn damage(previous: &Layer, current: &Layer) -> Vec<Rectangle> {
+        Snapshot::damage(&previous.snapshot(), &current.snapshot())
+    }
+
+    #[test]
+    fn unchanged_quads_are_not_damaged() {
+        let quads = [rectangle(10.0, 10.0, 20.0, 20.0)];
+
+        assert!(damage(&layer(&quads), &layer(&quads)).is_empty());
+    }
+
+    #[test]
+    fn moved_quads_damage_both_positions() {
+        let a = rectangle(10.0, 10.0, 20.0, 20.0);
+        let b = rectangle(50.0, 10.0, 20.0, 20.0);
+
+        assert_eq!(
+            damage(&layer(&[a]), &layer(&[b])),
+            vec![a.expand(1.0), b.expand(1.0)]
+        );
+    }
+
+    #[test]
+    fn added_and_removed_quads_are_damaged() {
+        let a = rectangle(10.0, 10.0, 20.0, 20.0);
+        let b = rectangle(50.0, 50.0, 20.0, 20.0);
+
+        assert_eq!(damage(&layer(&[a]), &layer(&[a, b])), vec![b.expand(1.0)]);
+        assert_eq!(damage(&layer(&[a, b]), &layer(&[a])), vec![b.expand(1.0)]);
+    }
+
+    #[test]
+    fn damage_is_clipped_to_the_layer() {
+        let a = rectangle(90.0, 90.0, 20.0, 20.0);
+
+        assert_eq!(
+            damage(&layer(&[]), &layer(&[a])),
+            vec![rectangle(89.0, 89.0, 11.0, 11.0)]
+        );
+    }
+
+    #[test]
+    fn changed_text_is_damaged() {
+        let text = |content: &str| {
+            let mut layer = layer(&[]);
+
+            layer.draw_text(
+                crate::core::Text {
+                    content: content.to_owned(),
+                    bounds: Size::new(50.0, 20.0),
+                    size: Pixels(16.0),
+                    line_height: LineHeight::default(),
+                    font: Font::default(),
+                    align_x: Alignment::Default,
+                    align_y: alignment::Vertical::Top,
+                    shaping: Shaping::Basic,
+                    wrapping: Wrapping::default(),
+                },
+                Point::new(10.0, 10.0),
+                Color::BLACK,
+                Rectangle::INFINITE,
+                Transformation::IDENTITY,
+            );
+
+            layer.flush();
+            layer
+        };
+
+        let bounds = rectangle(10.0, 10.0, 50.0, 20.0);
+
+        assert!(damage(&text("Hello"), &text("Hello")).is_empty());
+        assert_eq!(
+            damage(&text("Hello"), &text("World")),
+            vec![bounds, bounds]
+        );
+    }
+
+    #[test]
+    fn uncached_meshes_are_always_damaged() {
+        let bounds = rectangle(10.0, 10.0, 20.0, 20.0);
+
+        let mesh = || {
+            let mut layer = layer(&[]);
+
+            layer.draw_mesh(
+                Mesh::Solid {
+                    buffers: mesh::Indexed {
+                        vertices: Vec::new(),
+                        indices: Vec::new(),
+                    },
+                    transformation: Transformation::IDENTITY,
+                    clip_bounds: bounds,
+                },
+                Transformation::IDENTITY,
+            );
+
+            layer.flush();
+            layer
+        };
+
+        assert_eq!(damage(&mesh(), &mesh()), vec![bounds, bounds]);
+    }
+
+    #[test]
+    fn resized_layers_damage_both_bounds() {
+        let previous = layer(&[]);
+        let mut current = layer(&[]);
+        current.resize(rectangle(0.0, 0.0, 50.0, 50.0));
+
+        assert_eq!(
+            damage(&previous, &current),
+            vec![previous.bounds, current.bounds]
+        );
+    }
+
+    #[test]
+    fn unchanged_frames_are_not_damaged() {
+        let bounds = rectangle(0.0, 0.0, 100.0, 100.0);
+        let frame = [layer(&[rectangle(10.0, 10.0, 20.0, 20.0)]).snapshot()];
+
+        assert!(frame_damage(Some(&frame), &frame, bounds).is_empty());
+        assert_eq!(frame_damage(None, &frame, bounds), vec![bounds]);
+    }
+
+    #[test]
+    fn untrackable_layers_damage_the_whole_frame() {
+        let bounds = rectangle(0.0, 0.0, 100.0, 100.0);
+        let trackable = [layer(&[]).snapshot()];
+
+        let untrackable = {
+            let mut layer = layer(&[]);
+            layer.render_independent();
+
+            [layer.snapshot()]
+        };
+
+        assert!(!untrackable[0].is_trackable());
+
+        assert_eq!(
+            frame_damage(Some(&trackable), &untrackable, bounds),
+            vec![bounds]
+        );
+
+        assert_eq!(
+            frame_damage(Some(&untrackable), &trackable, bounds),
+            vec![bounds]
+        );
+    }
+}
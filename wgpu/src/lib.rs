@@ -85,6 +85,7 @@ pub struct Renderer {
     layers: layer::Stack,
 
     quad: quad::State,
+    background: quad::Batch,
     triangle: triangle::State,
     text: text::State,
     text_viewport: text::Viewport,
@@ -117,6 +118,7 @@ impl Renderer {
             layers: layer::Stack::new(),
 
             quad: quad::State::new(),
+            background: quad::Batch::default(),
             triangle: triangle::State::new(
                 &engine.device,
                 &engine.triangle_pipeline,
@@ -147,6 +149,7 @@ impl Renderer {
         clear_color: Option<Color>,
         target: &wgpu::TextureView,
         viewport: &Viewport,
+        damage: Option<Rectangle>,
     ) -> wgpu::CommandEncoder {
         let mut encoder = self.engine.device.create_command_encoder(
             &wgpu::CommandEncoderDescriptor {
@@ -154,8 +157,8 @@ impl Renderer {
             },
         );
 
-        self.prepare(&mut encoder, viewport);
-        self.render(&mut encoder, target, clear_color, viewport);
+        self.prepare(&mut encoder, viewport, clear_color, damage);
+        self.render(&mut encoder, target, clear_color, viewport, damage);
 
         self.quad.trim();
         self.triangle.trim();
@@ -170,6 +173,9 @@ impl Renderer {
             self.image_cache.borrow_mut().trim();
         }
 
+        #[cfg(feature = "image")]
+        debug::images_cached(|| self.image_cache.borrow().stats());
+
         encoder
     }
 
@@ -180,7 +186,52 @@ impl Renderer {
         frame: &wgpu::TextureView,
         viewport: &Viewport,
     ) -> wgpu::SubmissionIndex {
-        let encoder = self.draw(clear_color, frame, viewport);
+        let encoder = self.draw(clear_color, frame, viewport, None);
+
+        self.staging_belt.finish();
+        let submission = self.engine.queue.submit([encoder.finish()]);
+        self.staging_belt.recall();
+        submission
+    }
+
+    /// Sets the maximum amount of memory, in bytes, that the image cache
+    /// of the [`Renderer`] may use.
+    pub fn set_image_cache_budget(&mut self, _budget: Option<u64>) {
+        #[cfg(feature = "image")]
+        self.image_cache.get_mut().set_budget(_budget);
+    }
+
+    /// Takes a [`layer::Snapshot`] of all the layers of the [`Renderer`].
+    pub fn snapshot(&mut self) -> Vec<layer::Snapshot> {
+        self.layers.flush();
+        self.layers.as_slice().iter().map(Layer::snapshot).collect()
+    }
+
+    /// Presents the current layers into the given `frame`, only redrawing
+    /// the `damage` region of the `retained` texture.
+    ///
+    /// The `retained` texture must contain the last frame rendered with
+    /// this method, since it is the source of all the undamaged pixels.
+    /// It is copied entirely into the `frame` afterwards.
+    pub fn present_damaged(
+        &mut self,
+        background_color: Color,
+        retained: &wgpu::Texture,
+        frame: &wgpu::Texture,
+        viewport: &Viewport,
+        damage: Rectangle,
+    ) -> wgpu::SubmissionIndex {
+        let view =
+            retained.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder =
+            self.draw(Some(background_color), &view, viewport, Some(damage));
+
+        encoder.copy_texture_to_texture(
+            retained.as_image_copy(),
+            frame.as_image_copy(),
+            retained.size(),
+        );
 
         self.staging_belt.finish();
         let submission = self.engine.queue.submit([encoder.finish()]);
@@ -247,7 +298,8 @@ impl Renderer {
 
         let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
 
-        let mut encoder = self.draw(Some(background_color), &view, viewport);
+        let mut encoder =
+            self.draw(Some(background_color), &view, viewport, None);
 
         let texture = crate::color::convert(
             &self.engine.device,
@@ -310,15 +362,38 @@ impl Renderer {
         &mut self,
         encoder: &mut wgpu::CommandEncoder,
         viewport: &Viewport,
+        background_color: Option<Color>,
+        damage: Option<Rectangle>,
     ) {
         let scale_factor = viewport.scale_factor();
 
         self.text_viewport
             .update(&self.engine.queue, viewport.physical_size());
 
-        let physical_bounds = Rectangle::<f32>::from(Rectangle::with_size(
-            viewport.physical_size(),
-        ));
+        let physical_bounds = damaged_bounds(viewport, damage);
+
+        self.background.clear();
+
+        if let (Some(damage), Some(color)) = (damage, background_color) {
+            self.background.add(
+                quad::Quad {
+                    position: [damage.x, damage.y],
+                    size: [damage.width, damage.height],
+                    ..bytemuck::Zeroable::zeroed()
+                },
+                &Background::Color(color),
+            );
+
+            self.quad.prepare(
+                &self.engine.quad_pipeline,
+                &self.engine.device,
+                &mut self.staging_belt,
+                encoder,
+                &self.background,
+                viewport.projection(),
+                scale_factor,
+            );
+        }
 
         self.layers.merge();
 
@@ -497,9 +572,10 @@ impl Renderer {
         main_frame: &wgpu::TextureView,
         clear_color: Option<Color>,
         viewport: &Viewport,
+        damage: Option<Rectangle>,
     ) {
         use std::mem::ManuallyDrop;
-        let clear_color = match clear_color {
+        let clear_color = match clear_color.filter(|_| damage.is_none()) {
             Some(background_color) => wgpu::LoadOp::Clear({
                 let [r, g, b, a] =
                     graphics::color::pack(background_color)
@@ -537,9 +613,21 @@ impl Renderer {
         let mut image_layer = 0;
 
         let scale_factor = viewport.scale_factor();
-        let physical_bounds = Rectangle::<f32>::from(Rectangle::with_size(
-            viewport.physical_size(),
-        ));
+        let physical_bounds = damaged_bounds(viewport, damage);
+
+        if !self.background.is_empty() {
+            if let Some(scissor_rect) = physical_bounds.snap() {
+                self.quad.render(
+                    &self.engine.quad_pipeline,
+                    quad_layer,
+                    scissor_rect,
+                    &self.background,
+                    &mut frame_render_pass,
+                );
+            }
+
+            quad_layer += 1;
+        }
 
         let scale = Transformation::scale(scale_factor);
         for (index,layer) in self.layers.iter().enumerate() {
@@ -891,6 +979,19 @@ impl Renderer {
     }
 }
 
+/// Returns the physical bounds of the [`Viewport`] that need to be redrawn.
+fn damaged_bounds(viewport: &Viewport, damage: Option<Rectangle>) -> Rectangle {
+    let physical_bounds =
+        Rectangle::<f32>::from(Rectangle::with_size(viewport.physical_size()));
+
+    match damage {
+        Some(damage) => physical_bounds
+            .intersection(&(damage * viewport.scale_factor()))
+            .unwrap_or(Rectangle::new(Point::ORIGIN, Size::ZERO)),
+        None => physical_bounds,
+    }
+}
+
 impl core::Renderer for Renderer {
     fn start_layer(&mut self, bounds: Rectangle) {
         self.layers.push_clip(bounds);
@@ -933,6 +1034,22 @@ impl core::Renderer for Renderer {
             .get_mut()
             .allocate_image(_handle, _callback);
     }
+
+    #[cfg(feature = "image")]
+    fn allocate_image_with_progress(
+        &mut self,
+        handle: &core::image::Handle,
+        on_progress: impl FnMut(core::image::Progress) + Send + 'static,
+    ) {
+        self.image_cache
+            .get_mut()
+            .allocate_image_with_progress(handle, on_progress);
+    }
+
+    fn evict_image(&mut self, _handle: &core::image::Handle) {
+        #[cfg(feature = "image")]
+        self.image_cache.get_mut().evict_image(_handle);
+    }
 }
 
 impl core::text::Renderer for Renderer {
@@ -1024,6 +1141,10 @@ impl core::image::Renderer for Renderer {
         self.image_cache.borrow_mut().measure_image(handle)
     }
 
+    fn image_status(&self, handle: &Self::Handle) -> core::image::Status {
+        self.image_cache.borrow_mut().image_status(handle)
+    }
+
     fn draw_image(
         &mut self,
         image: core::Image,
@@ -1242,3 +1363,41 @@ fn new_render_pass<'a>(
     );
     pass
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn damage_is_redrawn_in_physical_pixels_inside_the_viewport() {
+        let viewport = Viewport::with_physical_size(Size::new(200, 100), 2.0);
+
+        assert_eq!(
+            damaged_bounds(&viewport, None),
+            Rectangle::new(Point::ORIGIN, Size::new(200.0, 100.0))
+        );
+
+        assert_eq!(
+            damaged_bounds(
+                &viewport,
+                Some(Rectangle::new(
+                    Point::new(10.0, 10.0),
+                    Size::new(100.0, 100.0)
+                ))
+            ),
+            Rectangle::new(Point::new(20.0, 20.0), Size::new(180.0, 80.0))
+        );
+
+        // Damage outside of the viewport redraws nothing
+        assert_eq!(
+            damaged_bounds(
+                &viewport,
+                Some(Rectangle::new(
+                    Point::new(150.0, 0.0),
+                    Size::new(10.0, 10.0)
+                ))
+            ),
+            Rectangle::new(Point::ORIGIN, Size::ZERO)
+        );
+    }
+}
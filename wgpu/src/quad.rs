@@ -7,6 +7,7 @@ use solid::Solid;
 use crate::core::{Background, Point, Rectangle, Size, Transformation};
 use crate::graphics;
 use crate::graphics::color;
+use crate::graphics::damage;
 
 use bytemuck::{Pod, Zeroable};
 
@@ -24,14 +25,23 @@ pub struct Quad {
     /// The size of the [`Quad`].
     pub size: [f32; 2],
 
-    /// The border color of the [`Quad`], in __linear RGB__.
-    pub border_color: color::Packed,
+    /// The border colors of the [`Quad`] for each side (top, right,
+    /// bottom, left), packed as half-precision floats in __linear RGB__.
+    pub border_colors: [[u32; 2]; 4],
 
     /// The border radii of the [`Quad`].
     pub border_radius: [f32; 4],
 
-    /// The border width of the [`Quad`].
-    pub border_width: f32,
+    /// The border widths of the [`Quad`] for each side (top, right,
+    /// bottom, left).
+    pub border_width: [f32; 4],
+
+    /// The length of the dashes and gaps of the border of the [`Quad`],
+    /// or zero if the border is solid.
+    pub border_dash: [f32; 2],
+
+    /// Whether the [`Quad`] should be snapped to the pixel grid.
+    pub snap: u32,
 
     /// The shadow color of the [`Quad`].
     pub shadow_color: color::Packed,
@@ -41,9 +51,25 @@ pub struct Quad {
 
     /// The shadow blur radius of the [`Quad`].
     pub shadow_blur_radius: f32,
+}
 
-    /// Whether the [`Quad`] should be snapped to the pixel grid.
-    pub snap: u32,
+impl Quad {
+    /// Returns the bounds of the [`Quad`], including its shadow.
+    pub fn bounds(&self) -> Rectangle {
+        let bounds = Rectangle::new(
+            Point::new(self.position[0], self.position[1]),
+            Size::new(self.size[0], self.size[1]),
+        );
+
+        let shadow = Rectangle {
+            x: bounds.x + self.shadow_offset[0] - self.shadow_blur_radius,
+            y: bounds.y + self.shadow_offset[1] - self.shadow_blur_radius,
+            width: bounds.width + self.shadow_blur_radius * 2.0,
+            height: bounds.height + self.shadow_blur_radius * 2.0,
+        };
+
+        bounds.union(&shadow).expand(1.0)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -244,7 +270,7 @@ impl Layer {
 }
 
 /// A group of [`Quad`]s rendered together.
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct Batch {
     /// The solid quads of the [`Layer`].
     solids: Vec<Solid>,
@@ -302,6 +328,25 @@ impl Batch {
         }
     }
 
+    /// Computes the regions that differ between two [`Batch`]es.
+    pub fn damage(previous: &Self, current: &Self) -> Vec<Rectangle> {
+        let mut damage = damage::list(
+            &previous.solids,
+            &current.solids,
+            |solid| vec![solid.quad.bounds()],
+            |a, b| bytemuck::bytes_of(a) == bytemuck::bytes_of(b),
+        );
+
+        damage.extend(damage::list(
+            &previous.gradients,
+            &current.gradients,
+            |gradient| vec![gradient.quad.bounds()],
+            |a, b| bytemuck::bytes_of(a) == bytemuck::bytes_of(b),
+        ));
+
+        damage
+    }
+
     pub fn clear(&mut self) {
         self.solids.clear();
         self.gradients.clear();
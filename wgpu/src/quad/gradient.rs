@@ -132,14 +132,18 @@ impl Pipeline {
                                 8 => Uint32,
                                 // Position & Scale
                                 9 => Float32x4,
-                                // Border color
-                                10 => Float32x4,
+                                // Border colors 1-2
+                                10 => Uint32x4,
+                                // Border colors 3-4
+                                11 => Uint32x4,
                                 // Border radius
-                                11 => Float32x4,
+                                12 => Float32x4,
                                 // Border width
-                                12 => Float32,
+                                13 => Float32x4,
+                                // Border dash
+                                14 => Float32x2,
                                 // Snap
-                                13 => Uint32,
+                                15 => Uint32,
                             ),
                         }],
                         compilation_options:
@@ -102,20 +102,24 @@ impl Pipeline {
                             1 => Float32x2,
                             // Size
                             2 => Float32x2,
-                            // Border color
-                            3 => Float32x4,
+                            // Border colors 1-2
+                            3 => Uint32x4,
+                            // Border colors 3-4
+                            4 => Uint32x4,
                             // Border radius
-                            4 => Float32x4,
+                            5 => Float32x4,
                             // Border width
-                            5 => Float32,
-                            // Shadow color
                             6 => Float32x4,
-                            // Shadow offset
+                            // Border dash
                             7 => Float32x2,
-                            // Shadow blur radius
-                            8 => Float32,
                             // Snap
-                            9 => Uint32,
+                            8 => Uint32,
+                            // Shadow color
+                            9 => Float32x4,
+                            // Shadow offset
+                            10 => Float32x2,
+                            // Shadow blur radius
+                            11 => Float32,
                         ),
                     }],
                     compilation_options:
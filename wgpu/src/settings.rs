@@ -27,6 +27,14 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The maximum amount of memory, in bytes, that the image cache of
+    /// the [`Renderer`] may use.
+    ///
+    /// By default, it is `None`.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    pub image_cache_budget: Option<u64>,
 }
 
 impl Default for Settings {
@@ -37,6 +45,7 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            image_cache_budget: None,
         }
     }
 }
@@ -52,6 +61,7 @@ impl From<graphics::Settings> for Settings {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
             antialiasing: settings.antialiasing,
+            image_cache_budget: settings.image_cache_budget,
             ..Settings::default()
         }
     }
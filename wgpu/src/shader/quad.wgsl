@@ -11,3 +11,93 @@ fn rounded_box_sdf(p: vec2<f32>, size: vec2<f32>, corners: vec4<f32>) -> f32 {
     var q = abs(p) - size + corner;
     return min(max(q.x, q.y), 0.0) + length(max(q, vec2(0.0))) - corner;
 }
+
+// Returns the widths of each side of a border packed as half-precision floats.
+fn pack_border_width(width: vec4<f32>) -> vec2<u32> {
+    return vec2<u32>(pack2x16float(width.xy), pack2x16float(width.zw));
+}
+
+fn unpack_border_width(data: vec2<u32>) -> vec4<f32> {
+    return vec4<f32>(unpack2x16float(data.x), unpack2x16float(data.y));
+}
+
+// Mixes the given color with the border of a quad.
+//
+// `p` is the position of the fragment relative to the top-left corner of the
+// quad, while `width` and `colors` are given in the order: top, right,
+// bottom, left.
+fn border(
+    color: vec4<f32>,
+    p: vec2<f32>,
+    size: vec2<f32>,
+    radius: vec4<f32>,
+    width: vec4<f32>,
+    colors: array<vec4<f32>, 4>,
+    dash: vec2<f32>,
+) -> vec4<f32> {
+    let missing = width <= vec4<f32>(0.0);
+
+    // Sides without width are pushed outwards, so they do not bleed
+    let inset = select(width, vec4<f32>(-1.0), missing);
+    let inner_min = vec2<f32>(inset.w, inset.x);
+    let inner_size = max(size - inner_min - vec2<f32>(inset.y, inset.z), vec2<f32>(0.0));
+
+    let inner_radius = max(
+        radius - vec4<f32>(
+            max(width.x, width.w),
+            max(width.x, width.y),
+            max(width.z, width.y),
+            max(width.z, width.w),
+        ),
+        vec4<f32>(0.0),
+    );
+
+    let inner_dist = rounded_box_sdf(
+        -(p - inner_min - inner_size * 0.5) * 2.0,
+        inner_size,
+        inner_radius * 2.0
+    ) / 2.0;
+
+    var coverage = clamp(0.5 + inner_dist, 0.0, 1.0);
+
+    if (coverage <= 0.0) {
+        return color;
+    }
+
+    // Every side owns the area closest to it relative to its width,
+    // which splits the corners diagonally
+    let distance = select(
+        vec4<f32>(p.y, size.x - p.x, size.y - p.y, p.x) / max(width, vec4<f32>(0.0001)),
+        vec4<f32>(1e10),
+        missing
+    );
+
+    var side: i32 = 0;
+    var nearest = distance.x;
+
+    if (distance.y < nearest) {
+        side = 1;
+        nearest = distance.y;
+    }
+
+    if (distance.z < nearest) {
+        side = 2;
+        nearest = distance.z;
+    }
+
+    if (distance.w < nearest) {
+        side = 3;
+    }
+
+    if (dash.x > 0.0) {
+        // Dashes run clockwise, starting at each corner
+        var along = array<f32, 4>(p.x, p.y, size.x - p.x, size.y - p.y);
+        let phase = max(along[side], 0.0) % (dash.x + dash.y);
+
+        coverage *= clamp(min(phase, dash.x - phase) + 0.5, 0.0, 1.0);
+    }
+
+    var colors_arr = colors;
+
+    return mix(color, colors_arr[side], coverage);
+}
@@ -21,11 +21,13 @@ struct GradientVertexInput {
 
     @location(9) position_and_scale: vec4<f32>,
 
-    @location(10) border_color: vec4<f32>,
-    @location(11) border_radius: vec4<f32>,
-    @location(12) border_width: f32,
+    @location(10) border_colors_1: vec4<u32>,
+    @location(11) border_colors_2: vec4<u32>,
+    @location(12) border_radius: vec4<f32>,
+    @location(13) border_width: vec4<f32>,
+    @location(14) border_dash: vec2<f32>,
 
-    @location(13) snap: u32,
+    @location(15) snap: u32,
 };
 
 // =========================
@@ -50,9 +52,11 @@ struct GradientVertexOutput {
 
     @location(10) position_and_scale: vec4<f32>,
 
-    @location(11) border_color: vec4<f32>,
-    @location(12) border_radius: vec4<f32>,
-    @location(13) border_width: f32,
+    @location(11) @interpolate(flat) border_colors_1: vec4<u32>,
+    @location(12) @interpolate(flat) border_colors_2: vec4<u32>,
+    @location(13) border_radius: vec4<f32>,
+    @location(14) @interpolate(flat) border_width: vec2<u32>,
+    @location(15) @interpolate(flat) border_dash: u32,
 };
 // =========================
 // Vertex Shader
@@ -109,9 +113,11 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
 
     out.position_and_scale = vec4(pos + pos_snap, scale + scale_snap);
 
-    out.border_color = premultiply(input.border_color);
+    out.border_colors_1 = input.border_colors_1;
+    out.border_colors_2 = input.border_colors_2;
     out.border_radius = border_radius;
-    out.border_width = input.border_width * globals.scale;
+    out.border_width = pack_border_width(input.border_width * globals.scale);
+    out.border_dash = pack2x16float(input.border_dash * globals.scale);
 
     return out;
 }
@@ -164,11 +170,24 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         input.border_radius * 2.0
     ) / 2.0;
 
-    if (input.border_width > 0.0) {
-        color = mix(
+    let border_width = unpack_border_width(input.border_width);
+
+    if (any(border_width > vec4<f32>(0.0))) {
+        let border_colors = array<vec4<f32>, 4>(
+            unpack_color(input.border_colors_1.xy),
+            unpack_color(input.border_colors_1.zw),
+            unpack_color(input.border_colors_2.xy),
+            unpack_color(input.border_colors_2.zw),
+        );
+
+        color = border(
             color,
-            input.border_color,
-            clamp(0.5 + dist + input.border_width, 0.0, 1.0)
+            world - pos,
+            scale,
+            input.border_radius,
+            border_width,
+            border_colors,
+            unpack2x16float(input.border_dash),
         );
     }
 
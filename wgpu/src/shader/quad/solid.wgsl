@@ -3,26 +3,30 @@ struct SolidVertexInput {
     @location(0) color: vec4<f32>,
     @location(1) pos: vec2<f32>,
     @location(2) scale: vec2<f32>,
-    @location(3) border_color: vec4<f32>,
-    @location(4) border_radius: vec4<f32>,
-    @location(5) border_width: f32,
-    @location(6) shadow_color: vec4<f32>,
-    @location(7) shadow_offset: vec2<f32>,
-    @location(8) shadow_blur_radius: f32,
-    @location(9) snap: u32,
+    @location(3) border_colors_1: vec4<u32>,
+    @location(4) border_colors_2: vec4<u32>,
+    @location(5) border_radius: vec4<f32>,
+    @location(6) border_width: vec4<f32>,
+    @location(7) border_dash: vec2<f32>,
+    @location(8) snap: u32,
+    @location(9) shadow_color: vec4<f32>,
+    @location(10) shadow_offset: vec2<f32>,
+    @location(11) shadow_blur_radius: f32,
 }
 
 struct SolidVertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) color: vec4<f32>,
-    @location(1) border_color: vec4<f32>,
-    @location(2) pos: vec2<f32>,
-    @location(3) scale: vec2<f32>,
-    @location(4) border_radius: vec4<f32>,
-    @location(5) border_width: f32,
-    @location(6) shadow_color: vec4<f32>,
-    @location(7) shadow_offset: vec2<f32>,
-    @location(8) shadow_blur_radius: f32,
+    @location(1) @interpolate(flat) border_colors_1: vec4<u32>,
+    @location(2) @interpolate(flat) border_colors_2: vec4<u32>,
+    @location(3) pos: vec2<f32>,
+    @location(4) scale: vec2<f32>,
+    @location(5) border_radius: vec4<f32>,
+    @location(6) @interpolate(flat) border_width: vec2<u32>,
+    @location(7) @interpolate(flat) border_dash: u32,
+    @location(8) shadow_color: vec4<f32>,
+    @location(9) shadow_offset: vec2<f32>,
+    @location(10) shadow_blur_radius: f32,
 }
 
 @vertex
@@ -51,11 +55,13 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
 
     out.position = globals.transform * transform * vec4<f32>(vertex_position(input.vertex_index), 0.0, 1.0);
     out.color = premultiply(input.color);
-    out.border_color = premultiply(input.border_color);
+    out.border_colors_1 = input.border_colors_1;
+    out.border_colors_2 = input.border_colors_2;
     out.pos = input.pos * globals.scale + pos_snap;
     out.scale = input.scale * globals.scale + scale_snap;
     out.border_radius = border_radius * globals.scale;
-    out.border_width = input.border_width * globals.scale;
+    out.border_width = pack_border_width(input.border_width * globals.scale);
+    out.border_dash = pack2x16float(input.border_dash * globals.scale);
     out.shadow_color = premultiply(input.shadow_color);
     out.shadow_offset = input.shadow_offset * globals.scale;
     out.shadow_blur_radius = input.shadow_blur_radius * globals.scale;
@@ -75,11 +81,24 @@ fn solid_fs_main(
         input.border_radius * 2.0
     ) / 2.0;
 
-    if (input.border_width > 0.0) {
-        mixed_color = mix(
+    let border_width = unpack_border_width(input.border_width);
+
+    if (any(border_width > vec4<f32>(0.0))) {
+        let border_colors = array<vec4<f32>, 4>(
+            unpack_color(input.border_colors_1.xy),
+            unpack_color(input.border_colors_1.zw),
+            unpack_color(input.border_colors_2.xy),
+            unpack_color(input.border_colors_2.zw),
+        );
+
+        mixed_color = border(
             input.color,
-            input.border_color,
-            clamp(0.5 + dist + input.border_width, 0.0, 1.0)
+            input.position.xy - input.pos,
+            input.scale,
+            input.border_radius,
+            border_width,
+            border_colors,
+            unpack2x16float(input.border_dash),
         );
     }
 
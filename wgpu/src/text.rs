@@ -3,6 +3,7 @@ use crate::core::text::Alignment;
 use crate::core::{Rectangle, Size, Transformation};
 use crate::graphics::cache;
 use crate::graphics::color;
+use crate::graphics::damage;
 use crate::graphics::text::cache::{self as text_cache, Cache as BufferCache};
 use crate::graphics::text::{Editor, Paragraph, font_system, to_color};
 
@@ -21,7 +22,7 @@ const COLOR_MODE: cryoglyph::ColorMode = if color::GAMMA_CORRECTION {
 
 pub type Batch = Vec<Item>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Item {
     Group {
         transformation: Transformation,
@@ -33,6 +34,58 @@ pub enum Item {
     },
 }
 
+impl Item {
+    fn transformation(&self) -> Transformation {
+        match self {
+            Item::Group { transformation, .. }
+            | Item::Cached { transformation, .. } => *transformation,
+        }
+    }
+
+    fn text(&self) -> &[Text] {
+        match self {
+            Item::Group { text, .. } => text,
+            Item::Cached { cache, .. } => &cache.text,
+        }
+    }
+
+    fn bounds(&self) -> Vec<Rectangle> {
+        self.text()
+            .iter()
+            .filter_map(Text::visible_bounds)
+            .map(|bounds| bounds * self.transformation())
+            .collect()
+    }
+}
+
+/// Computes the regions that differ between two text [`Batch`]es.
+pub fn damage(previous: &Batch, current: &Batch) -> Vec<Rectangle> {
+    damage::diff(previous, current, Item::bounds, |a, b| {
+        if a.transformation() != b.transformation() {
+            return a.bounds().into_iter().chain(b.bounds()).collect();
+        }
+
+        match (a, b) {
+            (Item::Cached { cache: a, .. }, Item::Cached { cache: b, .. })
+                if a.id == b.id && a.version == b.version =>
+            {
+                vec![]
+            }
+            _ => damage::list(
+                a.text(),
+                b.text(),
+                |text| {
+                    text.visible_bounds()
+                        .into_iter()
+                        .map(|bounds| bounds * a.transformation())
+                        .collect()
+                },
+                PartialEq::eq,
+            ),
+        }
+    })
+}
+
 #[derive(Debug, Clone)]
 pub struct Cache {
     id: Id,
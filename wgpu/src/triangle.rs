@@ -4,6 +4,7 @@ mod msaa;
 use crate::Buffer;
 use crate::core::{Point, Rectangle, Size, Transformation, Vector};
 use crate::graphics::Antialiasing;
+use crate::graphics::damage;
 use crate::graphics::mesh::{self, Mesh};
 
 use rustc_hash::FxHashMap;
@@ -28,6 +29,62 @@ pub enum Item {
     },
 }
 
+impl Item {
+    /// Returns a [`Summary`] of the [`Item`] that can be used to
+    /// compute damage between frames.
+    pub fn summary(&self) -> Summary {
+        let (transformation, cache, meshes) = match self {
+            Item::Group {
+                transformation,
+                meshes,
+            } => (*transformation, None, meshes.as_slice()),
+            Item::Cached {
+                transformation,
+                cache,
+            } => (
+                *transformation,
+                Some((cache.id, cache.version)),
+                cache.batch.as_ref(),
+            ),
+        };
+
+        Summary {
+            cache,
+            transformation,
+            bounds: meshes
+                .iter()
+                .map(|mesh| mesh.clip_bounds() * transformation)
+                .collect(),
+        }
+    }
+}
+
+/// The damage-relevant properties of a triangle [`Item`].
+///
+/// Meshes that are not cached are always considered damaged.
+#[derive(Debug, Clone)]
+pub struct Summary {
+    cache: Option<(Id, usize)>,
+    transformation: Transformation,
+    bounds: Vec<Rectangle>,
+}
+
+impl Summary {
+    /// Computes the regions that differ between two lists of [`Summary`].
+    pub fn damage(previous: &[Self], current: &[Self]) -> Vec<Rectangle> {
+        damage::list(
+            previous,
+            current,
+            |summary| summary.bounds.clone(),
+            |a, b| {
+                a.cache.is_some()
+                    && a.cache == b.cache
+                    && a.transformation == b.transformation
+            },
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Cache {
     id: Id,
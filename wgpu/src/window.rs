@@ -1,5 +1,4 @@
 //! Display rendering results on windows.
 pub mod compositor;
 
-pub use compositor::Compositor;
-pub use wgpu::Surface;
+pub use compositor::{Compositor, Surface};
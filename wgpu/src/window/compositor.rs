@@ -1,9 +1,10 @@
 //! Connect a window with a renderer.
-use crate::core::Color;
+use crate::core::{Color, Rectangle};
 use crate::graphics::color;
 use crate::graphics::compositor;
 use crate::graphics::error;
 use crate::graphics::{self, Shell, Viewport};
+use crate::layer;
 use crate::settings::{self, Settings};
 use crate::{Engine, Renderer};
 
@@ -17,6 +18,34 @@ pub struct Compositor {
     settings: Settings,
 }
 
+/// A window surface of a [`Compositor`].
+///
+/// A [`Surface`] keeps track of the last frame presented, so that frames
+/// without changes can be skipped and only the damaged regions of a frame
+/// need to be redrawn.
+pub struct Surface {
+    raw: wgpu::Surface<'static>,
+    supports_retention: bool,
+    retained: Option<wgpu::Texture>,
+    layers: Option<Vec<layer::Snapshot>>,
+    background_color: Color,
+    scale_factor: f32,
+}
+
+impl Surface {
+    /// Returns the raw [`wgpu::Surface`] of the [`Surface`].
+    pub fn raw(&self) -> &wgpu::Surface<'static> {
+        &self.raw
+    }
+
+    /// Forgets the last frame presented, forcing the next one to be fully
+    /// redrawn.
+    pub fn invalidate(&mut self) {
+        self.retained = None;
+        self.layers = None;
+    }
+}
+
 /// A compositor error.
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum Error {
@@ -215,51 +244,120 @@ pub async fn new<W: compositor::Window>(
 }
 
 /// Presents the given primitives with the given [`Compositor`].
+///
+/// Nothing will be presented if the primitives have not changed since
+/// the last frame presented on the [`Surface`].
 pub fn present(
     renderer: &mut Renderer,
-    surface: &mut wgpu::Surface<'static>,
+    surface: &mut Surface,
     viewport: &Viewport,
     background_color: Color,
     on_pre_present: impl FnOnce(),
 ) -> Result<(), compositor::SurfaceError> {
-    match surface.get_current_texture() {
-        Ok(frame) => {
-            let view = &frame
-                .texture
-                .create_view(&wgpu::TextureViewDescriptor::default());
-
-            let _submission = renderer.present(
-                Some(background_color),
-                frame.texture.format(),
-                view,
-                viewport,
-            );
-
-            // Present the frame
-            on_pre_present();
-            frame.present();
-
-            Ok(())
+    let layers = renderer.snapshot();
+    let bounds = Rectangle::with_size(viewport.logical_size());
+
+    let previous = surface.layers.as_deref().filter(|_| {
+        surface.background_color == background_color
+            && surface.scale_factor == viewport.scale_factor()
+    });
+
+    let damage = layer::frame_damage(previous, &layers, bounds);
+
+    if damage.is_empty() {
+        return Ok(());
+    }
+
+    let frame = match surface.raw.get_current_texture() {
+        Ok(frame) => frame,
+        Err(error) => {
+            return Err(match error {
+                wgpu::SurfaceError::Timeout => {
+                    compositor::SurfaceError::Timeout
+                }
+                wgpu::SurfaceError::Outdated => {
+                    compositor::SurfaceError::Outdated
+                }
+                wgpu::SurfaceError::Lost => compositor::SurfaceError::Lost,
+                wgpu::SurfaceError::OutOfMemory => {
+                    compositor::SurfaceError::OutOfMemory
+                }
+                wgpu::SurfaceError::Other => compositor::SurfaceError::Other,
+            });
         }
-        Err(error) => match error {
-            wgpu::SurfaceError::Timeout => {
-                Err(compositor::SurfaceError::Timeout)
-            }
-            wgpu::SurfaceError::Outdated => {
-                Err(compositor::SurfaceError::Outdated)
+    };
+
+    // Partial redraws blend the background over the last frame,
+    // so they are only possible with an opaque background
+    if surface.supports_retention && background_color.a >= 1.0 {
+        let size = frame.texture.size();
+
+        let (retained, damage) = match surface.retained.take() {
+            Some(retained) if retained.size() == size => {
+                let damage = damage
+                    .into_iter()
+                    .reduce(|a, b| a.union(&b))
+                    .unwrap_or(bounds);
+
+                (retained, damage)
             }
-            wgpu::SurfaceError::Lost => Err(compositor::SurfaceError::Lost),
-            wgpu::SurfaceError::OutOfMemory => {
-                Err(compositor::SurfaceError::OutOfMemory)
+            _ => {
+                let retained = renderer.engine.device.create_texture(
+                    &wgpu::TextureDescriptor {
+                        label: Some("iced_wgpu.window.retained_texture"),
+                        size,
+                        mip_level_count: 1,
+                        sample_count: 1,
+                        dimension: wgpu::TextureDimension::D2,
+                        format: frame.texture.format(),
+                        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                            | wgpu::TextureUsages::COPY_SRC,
+                        view_formats: &[],
+                    },
+                );
+
+                (retained, bounds)
             }
-            wgpu::SurfaceError::Other => Err(compositor::SurfaceError::Other),
-        },
+        };
+
+        let _submission = renderer.present_damaged(
+            background_color,
+            &retained,
+            &frame.texture,
+            viewport,
+            damage,
+        );
+
+        surface.retained = Some(retained);
+    } else {
+        let view = &frame
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        let _submission = renderer.present(
+            Some(background_color),
+            frame.texture.format(),
+            view,
+            viewport,
+        );
+
+        surface.retained = None;
     }
+
+    surface.layers = Some(layers);
+    surface.background_color = background_color;
+    surface.scale_factor = viewport.scale_factor();
+
+    // Present the frame
+    on_pre_present();
+    frame.present();
+
+    Ok(())
 }
 
 impl graphics::Compositor for Compositor {
     type Renderer = Renderer;
-    type Surface = wgpu::Surface<'static>;
+    type Surface = Surface;
 
     async fn with_backend<W: compositor::Window>(
         settings: graphics::Settings,
@@ -291,11 +389,14 @@ impl graphics::Compositor for Compositor {
     }
 
     fn create_renderer(&self) -> Self::Renderer {
-        Renderer::new(
+        let mut renderer = Renderer::new(
             self.engine.clone(),
             self.settings.default_font,
             self.settings.default_text_size,
-        )
+        );
+
+        renderer.set_image_cache_budget(self.settings.image_cache_budget);
+        renderer
     }
 
     fn create_surface<W: compositor::Window>(
@@ -304,11 +405,25 @@ impl graphics::Compositor for Compositor {
         width: u32,
         height: u32,
     ) -> Self::Surface {
-        let mut surface = self
+        let raw = self
             .instance
             .create_surface(window)
             .expect("Create surface");
 
+        let supports_retention = raw
+            .get_capabilities(&self.adapter)
+            .usages
+            .contains(wgpu::TextureUsages::COPY_DST);
+
+        let mut surface = Surface {
+            raw,
+            supports_retention,
+            retained: None,
+            layers: None,
+            background_color: Color::BLACK,
+            scale_factor: 1.0,
+        };
+
         if width > 0 && height > 0 {
             self.configure_surface(&mut surface, width, height);
         }
@@ -322,10 +437,18 @@ impl graphics::Compositor for Compositor {
         width: u32,
         height: u32,
     ) {
-        surface.configure(
+        let usage = if surface.supports_retention {
+            wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_DST
+        } else {
+            wgpu::TextureUsages::RENDER_ATTACHMENT
+        };
+
+        surface.invalidate();
+        surface.raw.configure(
             &self.engine.device,
             &wgpu::SurfaceConfiguration {
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                usage,
                 format: self.format,
                 present_mode: self.settings.present_mode,
                 width,
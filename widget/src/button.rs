@@ -376,7 +376,7 @@ where
             theme.style(&self.class, self.status.unwrap_or(Status::Disabled));
 
         if style.background.is_some()
-            || style.border.width > 0.0
+            || style.border.has_width()
             || style.shadow.color.a > 0.0
         {
             renderer.fill_quad(
@@ -0,0 +1,1225 @@
+//! Calendars display the days of a month and let users pick dates.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::calendar;
+//! use iced::widget::calendar::{Date, Weekday};
+//!
+//! struct State {
+//!     date: Option<Date>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     DatePicked(Date),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     calendar(state.date, Message::DatePicked)
+//!         .week_start(Weekday::Sunday)
+//!         .min(Date::new(2025, 1, 1).unwrap())
+//!         .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::time::SystemTime;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length,
+    Padding, Pixels, Point, Rectangle, Shell, Size, Theme, Widget,
+};
+
+use std::fmt;
+
+/// The amount of weeks displayed by a [`Calendar`].
+const WEEKS: usize = 6;
+
+/// A calendar date, without any time zone information.
+///
+/// Dates are ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a new [`Date`] from its year, month (1–12) and day (1–31).
+    ///
+    /// Returns `None` if the date does not exist.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month)
+            || day < 1
+            || day > days_in_month(year, month as u8).into()
+        {
+            return None;
+        }
+
+        Some(Self {
+            year,
+            month: month as u8,
+            day: day as u8,
+        })
+    }
+
+    /// Returns the current [`Date`] in UTC.
+    ///
+    /// No time zone database is used; convert the date yourself if you need
+    /// the local one.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self::from_days(i64::try_from(seconds / 86_400).unwrap_or_default())
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], from 1 to 12.
+    pub fn month(self) -> u32 {
+        self.month.into()
+    }
+
+    /// Returns the day of the month of the [`Date`], from 1 to 31.
+    pub fn day(self) -> u32 {
+        self.day.into()
+    }
+
+    /// Returns the [`Weekday`] of the [`Date`].
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::ALL[(self.days() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Returns the amount of days in the month of the [`Date`].
+    pub fn days_in_month(self) -> u32 {
+        days_in_month(self.year, self.month).into()
+    }
+
+    /// Returns the first day of the month of the [`Date`].
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// Adds the given amount of days to the [`Date`], which may be negative.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.days().saturating_add(days))
+    }
+
+    /// Adds the given amount of months to the [`Date`], which may be
+    /// negative.
+    ///
+    /// The day is clamped to the last day of the resulting month, and the
+    /// year saturates at the bounds of an [`i32`].
+    pub fn add_months(self, months: i32) -> Self {
+        let months = i64::from(self.year) * 12 + i64::from(self.month) - 1
+            + i64::from(months);
+
+        let (year, month) = match i32::try_from(months.div_euclid(12)) {
+            Ok(year) => (year, (months.rem_euclid(12) + 1) as u8),
+            Err(_) if months < 0 => (i32::MIN, 1),
+            Err(_) => (i32::MAX, 12),
+        };
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Returns the amount of days since 1970-01-01.
+    fn days(self) -> i64 {
+        // https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2)
+            / 5
+            + i64::from(self.day)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Creates a [`Date`] from the amount of days since 1970-01-01.
+    fn from_days(days: i64) -> Self {
+        // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days.saturating_add(719_468);
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year.clamp(i32::MIN.into(), i32::MAX.into()) as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Weekday {
+    /// Monday.
+    #[default]
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
+}
+
+impl Weekday {
+    /// All the days of the week, starting with [`Weekday::Monday`].
+    pub const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// Returns the amount of days since Monday.
+    pub fn days_from_monday(self) -> u32 {
+        self as u32
+    }
+}
+
+/// The names used by a [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// The names of the months, starting with January.
+    pub months: [&'static str; 12],
+    /// The short names of the days of the week, starting with Monday.
+    pub weekdays: [&'static str; 7],
+}
+
+impl Locale {
+    /// The English [`Locale`].
+    pub const ENGLISH: Self = Self {
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        weekdays: ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+    };
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
+
+/// A grid displaying the days of a month, where dates can be selected.
+///
+/// The displayed month is remembered by the [`Calendar`] itself, and can be
+/// changed with the arrows of its header.
+///
+/// While focused, the selectable date can be moved with the arrow keys,
+/// `PageUp` and `PageDown` change the month (or the year, with `Shift`),
+/// `Home` and `End` go to the start and end of the week, and `Enter` or
+/// `Space` select it.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::calendar::{Calendar, Date};
+///
+/// struct State {
+///     range: Option<(Date, Date)>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RangePicked(Date, Date),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     Calendar::range(state.range, Message::RangePicked).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Calendar<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    selection: Option<(Date, Date)>,
+    on_select: Box<dyn Fn(Date, Date) -> Message + 'a>,
+    is_range: bool,
+    week_start: Weekday,
+    min: Option<Date>,
+    max: Option<Date>,
+    is_disabled: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    locale: Locale,
+    cell_size: f32,
+    padding: Padding,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Calendar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default size of the cells of a [`Calendar`].
+    pub const DEFAULT_CELL_SIZE: f32 = 32.0;
+
+    /// The default [`Padding`] of a [`Calendar`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(8.0);
+
+    /// Creates a new [`Calendar`] to select a single [`Date`].
+    pub fn new(
+        selected: Option<Date>,
+        on_select: impl Fn(Date) -> Message + 'a,
+    ) -> Self {
+        Self::with_selection(
+            selected.map(|date| (date, date)),
+            Box::new(move |date, _| on_select(date)),
+            false,
+        )
+    }
+
+    /// Creates a new [`Calendar`] to select a range of dates.
+    ///
+    /// The first click selects the start of the range and the second one its
+    /// end. The produced range is always ordered, and the message is also
+    /// produced after the first click with a range of a single day.
+    pub fn range(
+        selected: Option<(Date, Date)>,
+        on_select: impl Fn(Date, Date) -> Message + 'a,
+    ) -> Self {
+        Self::with_selection(selected, Box::new(on_select), true)
+    }
+
+    fn with_selection(
+        selection: Option<(Date, Date)>,
+        on_select: Box<dyn Fn(Date, Date) -> Message + 'a>,
+        is_range: bool,
+    ) -> Self {
+        Self {
+            selection: selection
+                .map(|(start, end)| (start.min(end), start.max(end))),
+            on_select,
+            is_range,
+            week_start: Weekday::default(),
+            min: None,
+            max: None,
+            is_disabled: None,
+            locale: Locale::default(),
+            cell_size: Self::DEFAULT_CELL_SIZE,
+            padding: Self::DEFAULT_PADDING,
+            font: None,
+            text_size: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the first day of the week of the [`Calendar`].
+    ///
+    /// By default, weeks start on [`Weekday::Monday`].
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Sets the earliest [`Date`] that can be selected.
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be selected.
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the function deciding which dates cannot be selected.
+    pub fn disabled(mut self, is_disabled: impl Fn(Date) -> bool + 'a) -> Self {
+        self.is_disabled = Some(Box::new(is_disabled));
+        self
+    }
+
+    /// Sets the [`Locale`] of the [`Calendar`].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the size of the cells of the [`Calendar`].
+    pub fn cell_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.cell_size = size.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Calendar`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Renderer::Font`] of the [`Calendar`].
+    ///
+    /// [`Renderer::Font`]: text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the text size of the [`Calendar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the style of the [`Calendar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Calendar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the [`Calendar`] directly.
+    pub(crate) fn set_class(&mut self, class: Theme::Class<'a>) {
+        self.class = class;
+    }
+
+    /// Returns true if the given [`Date`] can be selected.
+    fn is_enabled(&self, date: Date) -> bool {
+        self.min.is_none_or(|min| date >= min)
+            && self.max.is_none_or(|max| date <= max)
+            && !self.is_disabled.as_ref().is_some_and(|f| f(date))
+    }
+
+    fn clamp(&self, date: Date) -> Date {
+        let date = self.min.map_or(date, |min| date.max(min));
+
+        self.max.map_or(date, |max| date.min(max))
+    }
+
+    /// Returns the first day of the month displayed by default.
+    fn initial_month(&self) -> Date {
+        self.clamp(self.selection.map_or_else(Date::today, |(start, _)| start))
+            .first_of_month()
+    }
+
+    fn has_previous(&self, month: Date) -> bool {
+        self.min.is_none_or(|min| month > min)
+    }
+
+    fn has_next(&self, month: Date) -> bool {
+        self.max.is_none_or(|max| month.add_months(1) <= max)
+    }
+
+    /// Returns the first [`Date`] of the grid of the given month.
+    fn grid_start(&self, month: Date) -> Date {
+        let offset = (month.weekday().days_from_monday() + 7
+            - self.week_start.days_from_monday())
+            % 7;
+
+        month.add_days(-i64::from(offset))
+    }
+
+    fn target(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        position: Point,
+    ) -> Option<Target> {
+        let grid = Grid::new(bounds.shrink(self.padding), self.cell_size);
+        let month = state.month.unwrap_or_else(|| self.initial_month());
+
+        if grid.previous().contains(position) {
+            self.has_previous(month).then_some(Target::Previous)
+        } else if grid.next().contains(position) {
+            self.has_next(month).then_some(Target::Next)
+        } else {
+            let date = self.grid_start(month).add_days(grid.day_at(position)?);
+
+            self.is_enabled(date).then_some(Target::Day(date))
+        }
+    }
+
+    fn select(
+        &self,
+        state: &mut State,
+        date: Date,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let (start, end) = match state.anchor.take() {
+            Some(anchor) => (anchor.min(date), anchor.max(date)),
+            None => {
+                if self.is_range {
+                    state.anchor = Some(date);
+                }
+
+                (date, date)
+            }
+        };
+
+        state.focused = Some(date);
+        state.month = Some(date.first_of_month());
+
+        shell.publish((self.on_select)(start, end));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Previous,
+    Next,
+    Day(Date),
+}
+
+#[derive(Debug, Default)]
+struct State {
+    month: Option<Date>,
+    focused: Option<Date>,
+    anchor: Option<Date>,
+    hovered: Option<Target>,
+    is_focused: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// Focuses the [`Calendar`] with the given [`Tree`].
+pub(crate) fn focus(tree: &mut Tree) {
+    tree.state.downcast_mut::<State>().is_focused = true;
+}
+
+/// The areas of a [`Calendar`].
+#[derive(Debug, Clone, Copy)]
+struct Grid {
+    bounds: Rectangle,
+    cell: f32,
+}
+
+impl Grid {
+    fn new(bounds: Rectangle, cell: f32) -> Self {
+        Self { bounds, cell }
+    }
+
+    fn header(self) -> Rectangle {
+        Rectangle {
+            height: self.cell,
+            ..self.bounds
+        }
+    }
+
+    fn previous(self) -> Rectangle {
+        Rectangle {
+            width: self.cell,
+            ..self.header()
+        }
+    }
+
+    fn next(self) -> Rectangle {
+        Rectangle {
+            x: self.bounds.x + self.bounds.width - self.cell,
+            ..self.previous()
+        }
+    }
+
+    fn weekday(self, column: usize) -> Rectangle {
+        Rectangle {
+            x: self.bounds.x + column as f32 * self.cell,
+            y: self.bounds.y + self.cell,
+            width: self.cell,
+            height: self.cell,
+        }
+    }
+
+    fn day(self, index: usize) -> Rectangle {
+        Rectangle {
+            y: self.bounds.y + (2 + index / 7) as f32 * self.cell,
+            ..self.weekday(index % 7)
+        }
+    }
+
+    fn day_at(self, position: Point) -> Option<i64> {
+        let x = position.x - self.bounds.x;
+        let y = position.y - self.bounds.y - 2.0 * self.cell;
+
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+
+        let (column, row) = ((x / self.cell) as i64, (y / self.cell) as i64);
+
+        (column < 7 && row < WEEKS as i64).then_some(row * 7 + column)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Calendar<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = Size::new(
+            7.0 * self.cell_size,
+            (WEEKS + 2) as f32 * self.cell_size,
+        )
+        .expand(self.padding);
+
+        layout::Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(None, layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.is_focused = cursor.is_over(bounds);
+
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                let month = state.month.unwrap_or_else(|| self.initial_month());
+
+                match self.target(state, bounds, position) {
+                    Some(Target::Previous) => {
+                        state.month = Some(month.add_months(-1));
+                    }
+                    Some(Target::Next) => {
+                        state.month = Some(month.add_months(1));
+                    }
+                    Some(Target::Day(date)) => {
+                        self.select(state, date, shell);
+                    }
+                    None => {}
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let hovered = cursor
+                    .position_over(bounds)
+                    .and_then(|position| self.target(state, bounds, position));
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                modifiers,
+                ..
+            }) if state.is_focused => {
+                let month = state.month.unwrap_or_else(|| self.initial_month());
+
+                let focused = state
+                    .focused
+                    .filter(|focused| focused.first_of_month() == month)
+                    .or_else(|| {
+                        self.selection
+                            .map(|(start, _)| start)
+                            .filter(|start| start.first_of_month() == month)
+                    })
+                    .unwrap_or(month);
+
+                let from_week_start = i64::from(
+                    (focused.weekday().days_from_monday() + 7
+                        - self.week_start.days_from_monday())
+                        % 7,
+                );
+
+                let next = match named {
+                    key::Named::ArrowLeft => focused.add_days(-1),
+                    key::Named::ArrowRight => focused.add_days(1),
+                    key::Named::ArrowUp => focused.add_days(-7),
+                    key::Named::ArrowDown => focused.add_days(7),
+                    key::Named::PageUp if modifiers.shift() => {
+                        focused.add_months(-12)
+                    }
+                    key::Named::PageUp => focused.add_months(-1),
+                    key::Named::PageDown if modifiers.shift() => {
+                        focused.add_months(12)
+                    }
+                    key::Named::PageDown => focused.add_months(1),
+                    key::Named::Home => focused.add_days(-from_week_start),
+                    key::Named::End => focused.add_days(6 - from_week_start),
+                    key::Named::Enter | key::Named::Space => {
+                        if self.is_enabled(focused) {
+                            self.select(state, focused, shell);
+                        }
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                    key::Named::Escape if state.anchor.is_some() => {
+                        state.anchor = None;
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                    _ => return,
+                };
+
+                let next = self.clamp(next);
+
+                state.focused = Some(next);
+                state.month = Some(next.first_of_month());
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if cursor
+            .position_over(bounds)
+            .and_then(|position| self.target(state, bounds, position))
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let grid = Grid::new(bounds.shrink(self.padding), self.cell_size);
+        let month = state.month.unwrap_or_else(|| self.initial_month());
+        let today = Date::today();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let hovered = cursor
+            .position_over(bounds)
+            .and_then(|position| self.target(state, bounds, position));
+
+        if style.background.is_some() || style.border.has_width() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        let text = |content: String, font, bounds: Rectangle| Text {
+            content,
+            bounds: bounds.size(),
+            size,
+            line_height: text::LineHeight::default(),
+            font,
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        };
+
+        // Header
+        for (target, area, icon, is_enabled) in [
+            (
+                Target::Previous,
+                grid.previous(),
+                Renderer::SCROLL_LEFT_ICON,
+                self.has_previous(month),
+            ),
+            (
+                Target::Next,
+                grid.next(),
+                Renderer::SCROLL_RIGHT_ICON,
+                self.has_next(month),
+            ),
+        ] {
+            if hovered == Some(target) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: area,
+                        border: border::rounded(style.cell_radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered_background,
+                );
+            }
+
+            renderer.fill_text(
+                text(icon.to_string(), Renderer::ICON_FONT, area),
+                area.center(),
+                if is_enabled {
+                    style.header_text_color
+                } else {
+                    style.disabled_text_color
+                },
+                *viewport,
+            );
+        }
+
+        let header = grid.header();
+
+        renderer.fill_text(
+            text(
+                format!(
+                    "{} {}",
+                    self.locale.months[month.month as usize - 1],
+                    month.year
+                ),
+                font,
+                header,
+            ),
+            header.center(),
+            style.header_text_color,
+            *viewport,
+        );
+
+        for column in 0..7 {
+            let area = grid.weekday(column);
+            let weekday =
+                (self.week_start.days_from_monday() as usize + column) % 7;
+
+            renderer.fill_text(
+                text(self.locale.weekdays[weekday].to_owned(), font, area),
+                area.center(),
+                style.weekday_text_color,
+                *viewport,
+            );
+        }
+
+        // Days
+        let range = match (state.anchor, hovered) {
+            (Some(anchor), Some(Target::Day(date))) => {
+                Some((anchor.min(date), anchor.max(date)))
+            }
+            (Some(anchor), _) => Some((anchor, anchor)),
+            (None, _) => self.selection,
+        };
+
+        let start = self.grid_start(month);
+
+        for index in 0..WEEKS * 7 {
+            let date = start.add_days(index as i64);
+            let area = grid.day(index);
+
+            let is_enabled = self.is_enabled(date);
+            let is_endpoint =
+                range.is_some_and(|(start, end)| date == start || date == end);
+            let is_in_range =
+                range.is_some_and(|(start, end)| date > start && date < end);
+
+            if is_in_range {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: area,
+                        ..renderer::Quad::default()
+                    },
+                    style.range_background,
+                );
+            }
+
+            if is_endpoint || hovered == Some(Target::Day(date)) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: area,
+                        border: border::rounded(style.cell_radius),
+                        ..renderer::Quad::default()
+                    },
+                    if is_endpoint {
+                        style.selected_background
+                    } else {
+                        style.hovered_background
+                    },
+                );
+            }
+
+            let is_focused = state.is_focused && state.focused == Some(date);
+
+            if date == today || is_focused {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: area.shrink(1.0),
+                        border: Border {
+                            color: if is_focused {
+                                style.focused_border
+                            } else {
+                                style.today_border
+                            },
+                            width: if is_focused { 2.0 } else { 1.0 },
+                            radius: style.cell_radius,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            renderer.fill_text(
+                text(date.day.to_string(), font, area),
+                area.center(),
+                if !is_enabled {
+                    style.disabled_text_color
+                } else if is_endpoint {
+                    style.selected_text_color
+                } else if is_in_range {
+                    style.range_text_color
+                } else if date.month != month.month {
+                    style.muted_text_color
+                } else {
+                    style.text_color
+                },
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Calendar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(calendar: Calendar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(calendar)
+    }
+}
+
+/// The appearance of a [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the calendar, if any.
+    pub background: Option<Background>,
+    /// The [`Border`] of the calendar.
+    pub border: Border,
+    /// The text [`Color`] of the month and the arrows of the header.
+    pub header_text_color: Color,
+    /// The text [`Color`] of the names of the days of the week.
+    pub weekday_text_color: Color,
+    /// The text [`Color`] of the days of the displayed month.
+    pub text_color: Color,
+    /// The text [`Color`] of the days outside of the displayed month.
+    pub muted_text_color: Color,
+    /// The text [`Color`] of the days that cannot be selected.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of a hovered day.
+    pub hovered_background: Background,
+    /// The [`Background`] of the selected days.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected days.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the days inside a selected range.
+    pub range_background: Background,
+    /// The text [`Color`] of the days inside a selected range.
+    pub range_text_color: Color,
+    /// The border [`Color`] of the current day.
+    pub today_border: Color,
+    /// The border [`Color`] of the day focused with the keyboard.
+    pub focused_border: Color,
+    /// The border radius of the days.
+    pub cell_radius: border::Radius,
+}
+
+/// The theme catalog of a [`Calendar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Calendar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Calendar`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.base.color.into()),
+        border: Border {
+            radius: 4.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+            ..Border::default()
+        },
+        header_text_color: palette.background.base.text,
+        weekday_text_color: palette.secondary.base.color,
+        text_color: palette.background.base.text,
+        muted_text_color: palette.background.strong.color,
+        disabled_text_color: palette.background.strong.color.scale_alpha(0.5),
+        hovered_background: palette.background.weak.color.into(),
+        selected_background: palette.primary.strong.color.into(),
+        selected_text_color: palette.primary.strong.text,
+        range_background: palette.primary.weak.color.into(),
+        range_text_color: palette.primary.weak.text,
+        today_border: palette.primary.base.color,
+        focused_border: palette.primary.strong.color,
+        cell_radius: 4.0.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::new(year, month, day).expect("Valid date")
+    }
+
+    #[test]
+    fn days_are_counted_from_the_epoch() {
+        assert_eq!(date(1970, 1, 1).days(), 0);
+        assert_eq!(date(2000, 3, 1).days(), 11_017);
+        assert_eq!(date(2024, 2, 29).days(), 19_782);
+        assert_eq!(date(1, 1, 1).days(), -719_162);
+
+        assert_eq!(Date::from_days(19_782), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 1).weekday(), Weekday::Monday);
+    }
+
+    #[test]
+    fn days_round_trip() {
+        for days in (-1_000_000..1_000_000).step_by(97) {
+            assert_eq!(Date::from_days(days).days(), days);
+        }
+
+        let mut date = date(1999, 12, 31);
+
+        for _ in 0..1_000 {
+            let next = date.add_days(1);
+
+            assert_eq!(next.days(), date.days() + 1);
+            assert!(next > date);
+
+            date = next;
+        }
+    }
+
+    #[test]
+    fn leap_years_have_an_extra_day() {
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(1900, 2, 29).is_none());
+
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 2, 28).add_days(1), date(2023, 3, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-366), date(2023, 3, 1));
+    }
+
+    #[test]
+    fn adding_months_clamps_the_day() {
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 3, 31).add_months(-1), date(2024, 2, 29));
+        assert_eq!(date(2024, 12, 15).add_months(1), date(2025, 1, 15));
+        assert_eq!(date(2024, 1, 15).add_months(-1), date(2023, 12, 15));
+        assert_eq!(date(2024, 5, 15).add_months(-29), date(2021, 12, 15));
+    }
+
+    #[test]
+    fn adding_months_saturates() {
+        assert_eq!(
+            date(i32::MAX, 12, 31).add_months(1),
+            date(i32::MAX, 12, 31)
+        );
+        assert_eq!(
+            date(i32::MIN, 1, 1).add_months(i32::MIN),
+            date(i32::MIN, 1, 1)
+        );
+        assert_eq!(
+            date(i32::MAX - 1, 6, 1).add_months(i32::MAX),
+            date(i32::MAX, 12, 1)
+        );
+    }
+}
@@ -0,0 +1,1178 @@
+//! Collapsibles show or hide some content when their header is toggled.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::{accordion, checkbox, collapsible, column, text};
+//!
+//! struct State {
+//!    notifications: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ToggleNotifications(bool),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     accordion([
+//!         collapsible(
+//!             text("General"),
+//!             checkbox(state.notifications)
+//!                 .label("Enable notifications")
+//!                 .on_toggle(Message::ToggleNotifications),
+//!         ),
+//!         collapsible(
+//!             text("About"),
+//!             column![text("Version 1.0"), text("Made with iced")],
+//!         ),
+//!     ])
+//!     .spacing(10)
+//!     .into()
+//! }
+//! ```
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::Instant;
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Animation, Background, Border, Clipboard, Color, Element, Event, Layout,
+    Length, Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+use std::rc::Rc;
+
+/// A widget with a header that shows or hides some content when toggled.
+///
+/// The header can be toggled by clicking it or, once focused, by pressing
+/// `Space` or `Enter`. The height of the content is animated as it is
+/// expanded or collapsed.
+///
+/// By default, a [`Collapsible`] keeps track of whether it is expanded or
+/// not by itself. Its state can be controlled by the application instead
+/// with [`expanded`](Self::expanded) and [`on_toggle`](Self::on_toggle).
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{collapsible, text};
+///
+/// struct State {
+///    show_details: bool,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ToggleDetails(bool),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     collapsible(text("Details"), text("Some very interesting details"))
+///         .expanded(state.show_details)
+///         .on_toggle(Message::ToggleDetails)
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::ToggleDetails(show_details) => {
+///             state.show_details = show_details;
+///         }
+///     }
+/// }
+/// ```
+pub struct Collapsible<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    header: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    expanded: Option<bool>,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    width: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Collapsible<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Collapsible`] with the given header and content.
+    ///
+    /// The [`Collapsible`] is collapsed initially.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            content: content.into(),
+            expanded: None,
+            on_toggle: None,
+            width: Length::Shrink,
+            class: Theme::default(),
+        }
+    }
+
+    /// Controls whether the [`Collapsible`] is expanded or not.
+    ///
+    /// Once controlled, toggling the header does not expand nor collapse
+    /// the [`Collapsible`] by itself; it only produces the
+    /// [`on_toggle`](Self::on_toggle) message.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.expanded = Some(is_expanded);
+        self
+    }
+
+    /// Sets the message that should be produced when the header of the
+    /// [`Collapsible`] is toggled.
+    ///
+    /// The closure receives whether the [`Collapsible`] should be expanded.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(bool) -> Message + 'a,
+    ) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the width of the [`Collapsible`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the style of the [`Collapsible`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Collapsible`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn toggle(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let is_expanded = !state.expansion.value();
+
+        if self.expanded.is_none() {
+            state.expansion.go_mut(is_expanded, Instant::now());
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        if let Some(on_toggle) = &self.on_toggle {
+            shell.publish(on_toggle(is_expanded));
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    expansion: Animation<bool>,
+    now: Instant,
+    is_focused: bool,
+    is_pressed: bool,
+}
+
+impl State {
+    fn new(is_expanded: bool) -> Self {
+        Self {
+            expansion: Animation::new(is_expanded).quick(),
+            now: Instant::now(),
+            is_focused: false,
+            is_pressed: false,
+        }
+    }
+
+    fn progress(&self) -> f32 {
+        self.expansion.interpolate(0.0, 1.0, self.now)
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Collapsible<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.expanded.unwrap_or(false)))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.header), Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(is_expanded) = self.expanded
+            && state.expansion.value() != is_expanded
+        {
+            state.expansion.go_mut(is_expanded, Instant::now());
+        }
+
+        tree.diff_children(&[&self.header, &self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let progress = tree.state.downcast_ref::<State>().progress();
+        let limits = limits.width(self.width);
+
+        let header = self.header.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits,
+        );
+
+        let header_size = header.size();
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[1], renderer, &limits)
+            .move_to(Point::new(0.0, header_size.height));
+
+        let content_size = content.size();
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(
+                header_size.width.max(content_size.width),
+                header_size.height + content_size.height * progress,
+            ),
+        );
+
+        layout::Node::with_children(size, vec![header, content])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let state = tree.state.downcast_mut::<State>();
+        let is_expanded = state.expansion.value();
+
+        operation.focusable(None, header.bounds(), state);
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.header.as_widget_mut().operate(
+                &mut tree.children[0],
+                header,
+                renderer,
+                operation,
+            );
+
+            if is_expanded {
+                self.content.as_widget_mut().operate(
+                    &mut tree.children[1],
+                    content,
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let bounds = layout.bounds();
+        let visible = visible_content(bounds, header.bounds());
+
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            if let Event::Window(window::Event::RedrawRequested(now)) = event {
+                let was_animating = state.expansion.is_animating(state.now);
+
+                state.now = *now;
+
+                if was_animating || state.expansion.is_animating(*now) {
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+
+            if let Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            {
+                state.is_focused = cursor.is_over(header.bounds());
+            }
+        }
+
+        self.header.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            header,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.progress() > 0.0 || state.expansion.value() {
+            let cursor = if cursor.is_over(visible) {
+                cursor
+            } else {
+                mouse::Cursor::Unavailable
+            };
+
+            self.content.as_widget_mut().update(
+                &mut tree.children[1],
+                event,
+                content,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(header.bounds()) =>
+            {
+                state.is_pressed = true;
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if state.is_pressed =>
+            {
+                state.is_pressed = false;
+
+                if cursor.is_over(header.bounds()) {
+                    self.toggle(state, shell);
+                    shell.capture_event();
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_pressed = false;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                ..
+            }) if state.is_focused => {
+                self.toggle(state, shell);
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let visible = visible_content(layout.bounds(), header.bounds());
+
+        if cursor.is_over(visible) {
+            return self.content.as_widget().mouse_interaction(
+                &tree.children[1],
+                content,
+                cursor,
+                viewport,
+                renderer,
+            );
+        }
+
+        let interaction = self.header.as_widget().mouse_interaction(
+            &tree.children[0],
+            header,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::None
+            && cursor.is_over(header.bounds())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let status = if state.is_focused {
+            Status::Focused
+        } else if cursor.is_over(header.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let appearance = theme.style(&self.class, status);
+
+        if appearance.header_background.is_some()
+            || appearance.header_border.has_width()
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header.bounds(),
+                    border: appearance.header_border,
+                    ..renderer::Quad::default()
+                },
+                appearance
+                    .header_background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        self.header.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            header,
+            cursor,
+            viewport,
+        );
+
+        let visible = visible_content(layout.bounds(), header.bounds());
+
+        let Some(clip_bounds) = visible.intersection(viewport) else {
+            return;
+        };
+
+        let cursor = if cursor.is_over(clip_bounds) {
+            cursor
+        } else {
+            mouse::Cursor::Unavailable
+        };
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            if let Some(background) = appearance.content_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: content.bounds(),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            self.content.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                content,
+                cursor,
+                &clip_bounds,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let is_expanded = tree.state.downcast_ref::<State>().expansion.value();
+
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let (header_tree, content_tree) = tree.children.split_at_mut(1);
+
+        let header = self.header.as_widget_mut().overlay(
+            &mut header_tree[0],
+            header,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let content = if is_expanded {
+            self.content.as_widget_mut().overlay(
+                &mut content_tree[0],
+                content,
+                renderer,
+                viewport,
+                translation,
+            )
+        } else {
+            None
+        };
+
+        let overlays = header.into_iter().chain(content).collect::<Vec<_>>();
+
+        (!overlays.is_empty())
+            .then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<Collapsible<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        collapsible: Collapsible<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(collapsible)
+    }
+}
+
+/// A list of [`Collapsible`] sections where, at most, one of them is
+/// expanded at a time.
+///
+/// Expanding a section collapses the rest. Like a [`Collapsible`], an
+/// [`Accordion`] keeps track of its expanded section by itself, unless
+/// controlled by the application with [`expanded`](Self::expanded) and
+/// [`on_toggle`](Self::on_toggle).
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{accordion, collapsible, text};
+///
+/// struct State {
+///    section: Option<usize>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     SectionToggled(Option<usize>),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     accordion([
+///         collapsible(text("Appearance"), text("...")),
+///         collapsible(text("Shortcuts"), text("...")),
+///         collapsible(text("Privacy"), text("...")),
+///     ])
+///     .expanded(state.section)
+///     .on_toggle(Message::SectionToggled)
+///     .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::SectionToggled(section) => {
+///             state.section = section;
+///         }
+///     }
+/// }
+/// ```
+pub struct Accordion<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    sections: Vec<Collapsible<'a, Message, Theme, Renderer>>,
+    spacing: f32,
+    width: Length,
+}
+
+impl<'a, Message, Theme, Renderer> Accordion<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog,
+{
+    /// Creates a new [`Accordion`] with the given sections.
+    pub fn new(
+        sections: impl IntoIterator<
+            Item = Collapsible<'a, Message, Theme, Renderer>,
+        >,
+    ) -> Self {
+        Self {
+            sections: sections.into_iter().collect(),
+            spacing: 0.0,
+            width: Length::Shrink,
+        }
+    }
+
+    /// Controls which section of the [`Accordion`] is expanded, if any.
+    ///
+    /// Once controlled, toggling a section does not expand nor collapse
+    /// any section by itself; it only produces the
+    /// [`on_toggle`](Self::on_toggle) message.
+    pub fn expanded(mut self, section: Option<usize>) -> Self {
+        for (index, collapsible) in self.sections.iter_mut().enumerate() {
+            collapsible.expanded = Some(section == Some(index));
+        }
+
+        self
+    }
+
+    /// Sets the message that should be produced when a section of the
+    /// [`Accordion`] is toggled.
+    ///
+    /// The closure receives the index of the section that should be
+    /// expanded, if any. It replaces the `on_toggle` message of every
+    /// section.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(Option<usize>) -> Message + 'a,
+    ) -> Self {
+        let on_toggle = Rc::new(on_toggle);
+
+        for (index, collapsible) in self.sections.iter_mut().enumerate() {
+            let on_toggle = on_toggle.clone();
+
+            collapsible.on_toggle = Some(Box::new(move |is_expanded| {
+                on_toggle(is_expanded.then_some(index))
+            }));
+        }
+
+        self
+    }
+
+    /// Sets the vertical spacing between the sections of the [`Accordion`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Accordion`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Accordion<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.sections
+            .iter()
+            .map(|section| Tree::new(section as &dyn Widget<_, _, _>))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children_custom(
+            &self.sections,
+            |tree, section| tree.diff(section as &dyn Widget<_, _, _>),
+            |section| Tree::new(section as &dyn Widget<_, _, _>),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+
+        let mut width: f32 = 0.0;
+        let mut y = 0.0;
+
+        let children = self
+            .sections
+            .iter_mut()
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(index, (section, tree))| {
+                if index > 0 {
+                    y += self.spacing;
+                }
+
+                let node = section
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(0.0, y));
+
+                let size = node.size();
+
+                width = width.max(size.width);
+                y += size.height;
+
+                node
+            })
+            .collect();
+
+        let size =
+            limits.resolve(self.width, Length::Shrink, Size::new(width, y));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((section, tree), layout) in self
+                .sections
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                section.operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let was_expanded = expanded_sections(tree);
+
+        for ((section, tree), layout) in self
+            .sections
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            section.update(
+                tree, event, layout, cursor, renderer, clipboard, shell,
+                viewport,
+            );
+        }
+
+        let expanded = expanded_sections(tree);
+
+        let Some(opened) = expanded.iter().zip(&was_expanded).position(
+            |(is_expanded, was_expanded)| *is_expanded && !was_expanded,
+        ) else {
+            return;
+        };
+
+        let now = Instant::now();
+
+        for (index, tree) in tree.children.iter_mut().enumerate() {
+            let state = tree.state.downcast_mut::<State>();
+
+            if index != opened && state.expansion.value() {
+                state.expansion.go_mut(false, now);
+            }
+        }
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.sections
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((section, tree), layout)| {
+                section
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((section, tree), layout) in self
+            .sections
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            section
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let overlays = self
+            .sections
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((section, tree), layout)| {
+                section.overlay(tree, layout, renderer, viewport, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!overlays.is_empty())
+            .then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Accordion<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        accordion: Accordion<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(accordion)
+    }
+}
+
+fn visible_content(bounds: Rectangle, header: Rectangle) -> Rectangle {
+    Rectangle {
+        y: header.y + header.height,
+        height: (bounds.height - header.height).max(0.0),
+        ..bounds
+    }
+}
+
+fn expanded_sections(tree: &Tree) -> Vec<bool> {
+    tree.children
+        .iter()
+        .map(|tree| tree.state.downcast_ref::<State>().expansion.value())
+        .collect()
+}
+
+/// The possible status of a [`Collapsible`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Collapsible`] can be interacted with.
+    Active,
+    /// The header of the [`Collapsible`] is being hovered.
+    Hovered,
+    /// The header of the [`Collapsible`] is focused.
+    Focused,
+}
+
+/// The appearance of a [`Collapsible`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Background`] of the header.
+    pub header_background: Option<Background>,
+    /// The [`Border`] of the header.
+    pub header_border: Border,
+    /// The [`Background`] of the content.
+    pub content_background: Option<Background>,
+}
+
+/// The theme catalog of a [`Collapsible`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Collapsible`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Collapsible`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Active => Style::default(),
+        Status::Hovered => Style {
+            header_background: Some(palette.background.weak.color.into()),
+            header_border: Border::default().rounded(2),
+            ..Style::default()
+        },
+        Status::Focused => Style {
+            header_border: Border::default()
+                .rounded(2)
+                .width(1)
+                .color(palette.primary.strong.color),
+            ..Style::default()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Space;
+    use crate::core::time::Duration;
+    use crate::harness::Harness;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggle(Option<usize>),
+    }
+
+    fn accordion() -> Accordion<'static, Message> {
+        Accordion::new((0..3).map(|_| {
+            Collapsible::new(
+                Space::new().width(100).height(20),
+                Space::new().width(100).height(50),
+            )
+        }))
+    }
+
+    /// Returns whether each section is expanded, once their animations
+    /// are over.
+    fn expanded(harness: &mut Harness<'_, Message>) -> Vec<bool> {
+        let _ = harness.redraw(Instant::now() + Duration::from_secs(1));
+        let node = harness.layout();
+
+        Layout::new(&node)
+            .children()
+            .map(|section| section.bounds().height > 20.0)
+            .collect()
+    }
+
+    /// Returns the center of the header of the given section.
+    fn header(harness: &mut Harness<'_, Message>, section: usize) -> Point {
+        let node = harness.layout();
+
+        Layout::new(&node)
+            .children()
+            .nth(section)
+            .and_then(|section| section.children().next())
+            .unwrap()
+            .bounds()
+            .center()
+    }
+
+    fn click(
+        harness: &mut Harness<'_, Message>,
+        section: usize,
+    ) -> Vec<Message> {
+        let header = header(harness, section);
+
+        harness.click(header)
+    }
+
+    #[test]
+    fn expanding_a_section_collapses_the_others() {
+        let mut harness = Harness::new(accordion());
+
+        assert_eq!(expanded(&mut harness), vec![false, false, false]);
+
+        let _ = click(&mut harness, 0);
+        assert_eq!(expanded(&mut harness), vec![true, false, false]);
+
+        let _ = click(&mut harness, 2);
+        assert_eq!(expanded(&mut harness), vec![false, false, true]);
+
+        let _ = click(&mut harness, 1);
+        assert_eq!(expanded(&mut harness), vec![false, true, false]);
+
+        let _ = click(&mut harness, 1);
+        assert_eq!(expanded(&mut harness), vec![false, false, false]);
+    }
+
+    #[test]
+    fn keyboard_toggles_keep_one_section_expanded() {
+        let mut harness = Harness::new(accordion());
+
+        let _ = click(&mut harness, 0);
+        assert_eq!(expanded(&mut harness), vec![true, false, false]);
+
+        // Pressing the last header focuses it, but releasing elsewhere does
+        // not toggle it
+        let header = header(&mut harness, 2);
+
+        harness.point_at(header);
+        let _ = harness.update(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+
+        harness.point_at(Point::new(500.0, 500.0));
+        let _ = harness.update(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+
+        assert_eq!(expanded(&mut harness), vec![true, false, false]);
+
+        let _ = harness.press(key::Named::Enter);
+
+        assert_eq!(expanded(&mut harness), vec![false, false, true]);
+    }
+
+    #[test]
+    fn controlled_accordions_only_produce_messages() {
+        let view =
+            |section| accordion().expanded(section).on_toggle(Message::Toggle);
+
+        let mut harness = Harness::new(view(Some(0)));
+        assert_eq!(expanded(&mut harness), vec![true, false, false]);
+
+        assert_eq!(click(&mut harness, 1), vec![Message::Toggle(Some(1))]);
+        assert_eq!(expanded(&mut harness), vec![true, false, false]);
+
+        harness.rebuild(view(Some(1)));
+        assert_eq!(expanded(&mut harness), vec![false, true, false]);
+
+        assert_eq!(click(&mut harness, 1), vec![Message::Toggle(None)]);
+
+        harness.rebuild(view(None));
+        assert_eq!(expanded(&mut harness), vec![false, false, false]);
+    }
+}
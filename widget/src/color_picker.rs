@@ -0,0 +1,1908 @@
+//! Color pickers let users choose a color from a plane, sliders or text.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::Color; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::Color;
+//! use iced::widget::color_picker;
+//!
+//! struct State {
+//!     color: Color,
+//!     recent: Vec<Color>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ColorChanged(Color),
+//!     ColorPicked,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     color_picker(state.color, Message::ColorChanged)
+//!         .on_release(Message::ColorPicked)
+//!         .recent(state.recent.iter().copied())
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::ColorChanged(color) => {
+//!             state.color = color;
+//!         }
+//!         Message::ColorPicked => {
+//!             state.recent.retain(|color| *color != state.color);
+//!             state.recent.insert(0, state.color);
+//!             state.recent.truncate(10);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::gradient::{ColorStop, Gradient, GradientType};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::theme::palette;
+use crate::core::touch;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length,
+    Padding, Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::text_input::{self, TextInput};
+
+use std::rc::Rc;
+
+/// The chroma at the right edge of the plane of a [`ColorPicker`] in
+/// [`Mode::Oklch`].
+const MAX_CHROMA: f32 = 0.37;
+
+/// The size of the preview and the eyedropper button of a [`ColorPicker`].
+const PREVIEW_SIZE: f32 = 32.0;
+
+/// The height of the hue and alpha bars of a [`ColorPicker`].
+const BAR_HEIGHT: f32 = 12.0;
+
+/// The size of the recent swatches of a [`ColorPicker`].
+const SWATCH_SIZE: f32 = 20.0;
+
+/// The spacing between the recent swatches of a [`ColorPicker`].
+const SWATCH_SPACING: f32 = 4.0;
+
+/// The amount of layout children placed before the inputs.
+const INPUTS_OFFSET: usize = 5;
+
+/// A color picker made of a plane, a hue bar, an alpha bar and text inputs
+/// for the hexadecimal, RGB and HSL representations of a [`Color`].
+///
+/// The plane and the bars can be dragged with the mouse, or adjusted with the
+/// arrow keys while hovered. Holding `Shift` makes the steps larger.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::Color; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::Color;
+/// use iced::widget::color_picker;
+/// use iced::widget::color_picker::Mode;
+///
+/// struct State {
+///     accent: Color,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     AccentChanged(Color),
+///     SampleScreen,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     color_picker(state.accent, Message::AccentChanged)
+///         .mode(Mode::Oklch)
+///         .on_eyedropper(Message::SampleScreen)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    color: Color,
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    on_release: Option<Message>,
+    on_eyedropper: Option<Message>,
+    recent: Vec<Color>,
+    mode: Mode,
+    inputs: [TextInput<'a, Edit, Theme, Renderer>; 3],
+    width: Length,
+    plane_height: f32,
+    padding: Padding,
+    spacing: f32,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: <Theme as Catalog>::Class<'a>,
+    last_status: Option<Status>,
+}
+
+impl<'a, Message, Theme, Renderer> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default width of a [`ColorPicker`].
+    pub const DEFAULT_WIDTH: f32 = 240.0;
+
+    /// The default height of the plane of a [`ColorPicker`].
+    pub const DEFAULT_PLANE_HEIGHT: f32 = 150.0;
+
+    /// The default [`Padding`] of a [`ColorPicker`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(8.0);
+
+    /// Creates a new [`ColorPicker`] with the given [`Color`] and the message
+    /// produced when it changes.
+    pub fn new(
+        color: Color,
+        on_change: impl Fn(Color) -> Message + 'a,
+    ) -> Self {
+        let inputs = Field::ALL.map(|field| {
+            TextInput::new("", &field.format(color))
+                .on_input(move |value| Edit::Input(field, value))
+                .on_submit(Edit::Submit(field))
+                .class(Theme::default_input())
+        });
+
+        Self {
+            color,
+            on_change: Box::new(on_change),
+            on_release: None,
+            on_eyedropper: None,
+            recent: Vec::new(),
+            mode: Mode::default(),
+            inputs,
+            width: Length::Shrink,
+            plane_height: Self::DEFAULT_PLANE_HEIGHT,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 8.0,
+            font: None,
+            text_size: None,
+            class: <Theme as Catalog>::default(),
+            last_status: None,
+        }
+    }
+
+    /// Sets the message produced when the user is done picking a [`Color`];
+    /// that is, when a drag ends, an input is submitted or a swatch is
+    /// clicked.
+    ///
+    /// This is a good place to remember the [`Color`] as a recent one.
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the eyedropper button of the
+    /// [`ColorPicker`] is pressed.
+    ///
+    /// The button is only displayed if this message is set. Sampling a
+    /// [`Color`] from the screen is up to the application, which can then
+    /// feed it back to the [`ColorPicker`].
+    pub fn on_eyedropper(mut self, message: Message) -> Self {
+        self.on_eyedropper = Some(message);
+        self
+    }
+
+    /// Sets the recent colors displayed as swatches at the bottom of the
+    /// [`ColorPicker`].
+    pub fn recent(mut self, colors: impl IntoIterator<Item = Color>) -> Self {
+        self.recent = colors.into_iter().collect();
+        self
+    }
+
+    /// Sets the [`Mode`] of the plane and the hue bar of the [`ColorPicker`].
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the width of the [`ColorPicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the plane of the [`ColorPicker`].
+    pub fn plane_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.plane_height = height.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ColorPicker`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the parts of the [`ColorPicker`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Renderer::Font`] of the labels and the inputs of the
+    /// [`ColorPicker`].
+    ///
+    /// [`Renderer::Font`]: text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        let font = font.into();
+
+        self.font = Some(font);
+        self.inputs = self.inputs.map(|input| input.font(font));
+        self
+    }
+
+    /// Sets the text size of the labels and the inputs of the
+    /// [`ColorPicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_size = Some(size);
+        self.inputs = self.inputs.map(|input| input.size(size));
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the text inputs of the [`ColorPicker`].
+    #[must_use]
+    pub fn input_style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        <Theme as text_input::Catalog>::Class<'a>:
+            From<text_input::StyleFn<'a, Theme>>,
+    {
+        let style = Rc::new(style);
+
+        self.inputs = self.inputs.map(|input| {
+            let style = style.clone();
+
+            input.style(move |theme, status| style(theme, status))
+        });
+        self
+    }
+
+    /// Sets the style class of the [`ColorPicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Recomputes the [`Coordinates`] of the [`State`] if the [`Color`] has
+    /// changed outside of the [`ColorPicker`].
+    fn sync(&self, state: &mut State) {
+        if state.color != Some(self.color) || state.mode != self.mode {
+            state.coordinates = Coordinates::from_color(
+                self.mode,
+                self.color,
+                state.coordinates,
+            );
+            state.color = Some(self.color);
+            state.mode = self.mode;
+        }
+    }
+
+    fn coordinates(&self, state: &State) -> Coordinates {
+        if state.color == Some(self.color) && state.mode == self.mode {
+            state.coordinates
+        } else {
+            Coordinates::from_color(self.mode, self.color, state.coordinates)
+        }
+    }
+
+    fn sync_inputs(&mut self, state: &State) {
+        for ((input, field), draft) in
+            self.inputs.iter_mut().zip(Field::ALL).zip(&state.drafts)
+        {
+            match draft {
+                Some(draft) => input.set_value(draft),
+                None => input.set_value(&field.format(self.color)),
+            }
+        }
+    }
+
+    /// Moves the [`State`] to the given [`Coordinates`] and notifies the new
+    /// [`Color`].
+    fn set(
+        &self,
+        state: &mut State,
+        coordinates: Coordinates,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if coordinates == state.coordinates {
+            return;
+        }
+
+        let color = coordinates.color(self.mode);
+
+        state.coordinates = coordinates;
+        state.color = Some(color);
+
+        shell.publish((self.on_change)(color));
+    }
+
+    /// Picks the given [`Color`] directly; from a swatch or an input.
+    fn pick(
+        &self,
+        state: &mut State,
+        color: Color,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.coordinates =
+            Coordinates::from_color(self.mode, color, state.coordinates);
+        state.color = Some(color);
+
+        shell.publish((self.on_change)(color));
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorPicker<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.inputs
+            .iter()
+            .map(|input| Tree::new(input as &dyn Widget<_, _, _>))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(
+            &self
+                .inputs
+                .iter()
+                .map(|input| input as &dyn Widget<_, _, _>)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+
+        self.sync(state);
+        self.sync_inputs(state);
+
+        let limits = limits.width(self.width).shrink(self.padding);
+        let width = limits
+            .resolve(
+                self.width,
+                Length::Shrink,
+                Size::new(Self::DEFAULT_WIDTH - self.padding.x(), 0.0),
+            )
+            .width;
+
+        let origin = Point::new(self.padding.left, self.padding.top);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let label_width = label_width(text_size);
+
+        let mut nodes =
+            Vec::with_capacity(INPUTS_OFFSET + 3 + self.recent.len());
+        let mut y = origin.y;
+
+        // Plane
+        nodes.push(
+            layout::Node::new(Size::new(width, self.plane_height))
+                .move_to(origin),
+        );
+        y += self.plane_height + self.spacing;
+
+        // Preview, bars and eyedropper
+        let eyedropper_size = if self.on_eyedropper.is_some() {
+            PREVIEW_SIZE
+        } else {
+            0.0
+        };
+
+        let bars_x = origin.x + PREVIEW_SIZE + self.spacing;
+        let bars_width = (width
+            - PREVIEW_SIZE
+            - self.spacing
+            - if eyedropper_size > 0.0 {
+                eyedropper_size + self.spacing
+            } else {
+                0.0
+            })
+        .max(0.0);
+
+        nodes.push(
+            layout::Node::new(Size::new(PREVIEW_SIZE, PREVIEW_SIZE))
+                .move_to(Point::new(origin.x, y)),
+        );
+        nodes.push(
+            layout::Node::new(Size::new(bars_width, BAR_HEIGHT))
+                .move_to(Point::new(bars_x, y)),
+        );
+        nodes.push(
+            layout::Node::new(Size::new(bars_width, BAR_HEIGHT))
+                .move_to(Point::new(bars_x, y + PREVIEW_SIZE - BAR_HEIGHT)),
+        );
+        nodes.push(
+            layout::Node::new(Size::new(eyedropper_size, eyedropper_size))
+                .move_to(Point::new(origin.x + width - eyedropper_size, y)),
+        );
+        y += PREVIEW_SIZE + self.spacing;
+
+        // Inputs
+        let input_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new((width - label_width).max(0.0), f32::INFINITY),
+        );
+
+        for (input, tree) in self.inputs.iter_mut().zip(children.iter_mut()) {
+            let node = input.layout(tree, renderer, &input_limits, None);
+            let height = node.size().height;
+
+            nodes.push(node.move_to(Point::new(origin.x + label_width, y)));
+            y += height + self.spacing / 2.0;
+        }
+
+        // Recent swatches
+        if !self.recent.is_empty() {
+            y += self.spacing / 2.0;
+
+            let columns = (((width + SWATCH_SPACING)
+                / (SWATCH_SIZE + SWATCH_SPACING))
+                .floor() as usize)
+                .max(1);
+
+            for index in 0..self.recent.len() {
+                let (row, column) = (index / columns, index % columns);
+
+                nodes.push(
+                    layout::Node::new(Size::new(SWATCH_SIZE, SWATCH_SIZE))
+                        .move_to(Point::new(
+                            origin.x
+                                + column as f32
+                                    * (SWATCH_SIZE + SWATCH_SPACING),
+                            y + row as f32 * (SWATCH_SIZE + SWATCH_SPACING),
+                        )),
+                );
+            }
+
+            let rows = self.recent.len().div_ceil(columns);
+
+            y += rows as f32 * (SWATCH_SIZE + SWATCH_SPACING) - SWATCH_SPACING;
+        } else {
+            y -= self.spacing / 2.0;
+        }
+
+        layout::Node::with_children(
+            Size::new(width + self.padding.x(), y + self.padding.bottom),
+            nodes,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.inputs
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children().skip(INPUTS_OFFSET))
+                .for_each(|((input, tree), layout)| {
+                    input.operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+
+        self.sync(state);
+        self.sync_inputs(state);
+
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        for ((input, tree), layout) in self
+            .inputs
+            .iter_mut()
+            .zip(children.iter_mut())
+            .zip(layout.children().skip(INPUTS_OFFSET))
+        {
+            input.update(
+                tree,
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                viewport,
+            );
+        }
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        for edit in edits {
+            match edit {
+                Edit::Input(field, value) => {
+                    if !field.is_partial(&value)
+                        && let Some(color) = field.parse(&value, self.color.a)
+                    {
+                        self.pick(state, color, shell);
+                    }
+
+                    state.drafts[field as usize] = Some(value);
+                }
+                Edit::Submit(field) => {
+                    if let Some(draft) = state.drafts[field as usize].take()
+                        && let Some(color) = field.parse(&draft, self.color.a)
+                        && color != self.color
+                    {
+                        self.pick(state, color, shell);
+                    }
+
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+                }
+            }
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        // Drafts are discarded once their input loses focus
+        for (draft, tree) in state.drafts.iter_mut().zip(children.iter()) {
+            let input = tree
+                .state
+                .downcast_ref::<text_input::State<Renderer::Paragraph>>();
+
+            if !input.is_focused() && draft.take().is_some() {
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+        }
+
+        self.sync_inputs(state);
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let parts = Parts::new(layout);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds())
+                else {
+                    return;
+                };
+
+                if let Some(target) = parts.target(position) {
+                    state.drag = Some(target);
+
+                    let coordinates =
+                        parts.drag(target, state.coordinates, position);
+
+                    self.set(state, coordinates, shell);
+                    shell.capture_event();
+                } else if let Some(on_eyedropper) = &self.on_eyedropper
+                    && parts.eyedropper.contains(position)
+                {
+                    shell.publish(on_eyedropper.clone());
+                    shell.capture_event();
+                } else if let Some(color) = layout
+                    .children()
+                    .skip(INPUTS_OFFSET + 3)
+                    .zip(&self.recent)
+                    .find_map(|(swatch, color)| {
+                        swatch.bounds().contains(position).then_some(*color)
+                    })
+                {
+                    self.pick(state, color, shell);
+
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(target) = state.drag
+                    && let Some(position) = cursor.land().position()
+                {
+                    let coordinates =
+                        parts.drag(target, state.coordinates, position);
+
+                    self.set(state, coordinates, shell);
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                modifiers,
+                ..
+            }) => {
+                let Some(target) = cursor
+                    .position()
+                    .and_then(|position| parts.target(position))
+                else {
+                    return;
+                };
+
+                let (x, y) = match key {
+                    key::Named::ArrowLeft => (-1.0, 0.0),
+                    key::Named::ArrowRight => (1.0, 0.0),
+                    key::Named::ArrowUp => (0.0, -1.0),
+                    key::Named::ArrowDown => (0.0, 1.0),
+                    _ => return,
+                };
+
+                let step = if modifiers.shift() { 0.1 } else { 0.01 };
+                let mut coordinates = state.coordinates;
+
+                match target {
+                    Target::Plane => {
+                        coordinates.x =
+                            (coordinates.x + x * step).clamp(0.0, 1.0);
+                        coordinates.y =
+                            (coordinates.y + y * step).clamp(0.0, 1.0);
+                    }
+                    Target::Hue => {
+                        coordinates.hue = (coordinates.hue
+                            + (x - y) * step * 360.0)
+                            .rem_euclid(360.0);
+                    }
+                    Target::Alpha => {
+                        coordinates.alpha = (coordinates.alpha
+                            + (x - y) * step)
+                            .clamp(0.0, 1.0);
+                    }
+                }
+
+                self.set(state, coordinates, shell);
+                shell.capture_event();
+            }
+            _ => {}
+        }
+
+        let status = if state.drag.is_some() {
+            Status::Dragging
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_status = Some(status);
+        } else if self
+            .last_status
+            .is_some_and(|last_status| last_status != status)
+            || shell.is_event_captured()
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        match state.drag {
+            Some(Target::Plane) => return mouse::Interaction::Crosshair,
+            Some(Target::Hue | Target::Alpha) => {
+                return mouse::Interaction::Grabbing;
+            }
+            None => {}
+        }
+
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return mouse::Interaction::default();
+        };
+
+        let parts = Parts::new(layout);
+
+        match parts.target(position) {
+            Some(Target::Plane) => return mouse::Interaction::Crosshair,
+            Some(Target::Hue | Target::Alpha) => {
+                return mouse::Interaction::Grab;
+            }
+            None => {}
+        }
+
+        if parts.eyedropper.contains(position)
+            || layout
+                .children()
+                .skip(INPUTS_OFFSET + 3)
+                .any(|swatch| swatch.bounds().contains(position))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.inputs
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children().skip(INPUTS_OFFSET))
+            .map(|((input, tree), layout)| {
+                input
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let coordinates = self.coordinates(state);
+        let style = Catalog::style(
+            theme,
+            &self.class,
+            self.last_status.unwrap_or(Status::Active),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let parts = Parts::new(layout);
+        let opaque = Color {
+            a: 1.0,
+            ..coordinates.color(self.mode)
+        };
+        let hue = coordinates.hue;
+
+        // Plane
+        match self.mode {
+            Mode::Hsv => {
+                fill_strip(renderer, parts.plane, Axis::X, 1, 2, |t| {
+                    hsv_to_rgb(hue, t, 1.0)
+                });
+                fill_strip(renderer, parts.plane, Axis::Y, 1, 2, |t| {
+                    Color::BLACK.scale_alpha(t)
+                });
+            }
+            Mode::Oklch => {
+                const COLUMNS: usize = 32;
+
+                let width = parts.plane.width / COLUMNS as f32;
+
+                for column in 0..COLUMNS {
+                    let bounds = Rectangle {
+                        x: parts.plane.x + column as f32 * width,
+                        width,
+                        ..parts.plane
+                    };
+                    let x = (column as f32 + 0.5) / COLUMNS as f32;
+
+                    fill_strip(renderer, bounds, Axis::Y, 1, 8, |y| {
+                        Mode::Oklch.sample(hue, x, y)
+                    });
+                }
+            }
+        }
+
+        outline(renderer, parts.plane, style.control_border);
+
+        // Hue
+        match self.mode {
+            Mode::Hsv => {
+                fill_strip(renderer, parts.hue, Axis::X, 6, 2, |t| {
+                    hsv_to_rgb(t * 360.0, 1.0, 1.0)
+                });
+            }
+            Mode::Oklch => {
+                fill_strip(renderer, parts.hue, Axis::X, 12, 4, |t| {
+                    oklch_to_rgb(0.75, 0.12, t * 360.0)
+                });
+            }
+        }
+
+        outline(renderer, parts.hue, style.control_border);
+
+        // Alpha
+        checkerboard(renderer, parts.alpha, style.checkerboard);
+        fill_strip(renderer, parts.alpha, Axis::X, 1, 2, |t| {
+            opaque.scale_alpha(t)
+        });
+        outline(renderer, parts.alpha, style.control_border);
+
+        // Preview
+        checkerboard(renderer, parts.preview, style.checkerboard);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: parts.preview,
+                border: style.control_border,
+                ..renderer::Quad::default()
+            },
+            self.color,
+        );
+
+        // Handles
+        let center = Point::new(
+            parts.plane.x + coordinates.x * parts.plane.width,
+            parts.plane.y + coordinates.y * parts.plane.height,
+        );
+
+        for (radius, color) in
+            [(8.0, style.handle_border_color), (7.0, style.handle_color)]
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        center - Vector::new(radius, radius),
+                        Size::new(radius * 2.0, radius * 2.0),
+                    ),
+                    border: Border {
+                        radius: radius.into(),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(
+                    center - Vector::new(5.0, 5.0),
+                    Size::new(10.0, 10.0),
+                ),
+                border: Border {
+                    radius: 5.0.into(),
+                    ..Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            opaque,
+        );
+
+        for (bounds, t) in [
+            (parts.hue, coordinates.hue / 360.0),
+            (parts.alpha, coordinates.alpha),
+        ] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + t * bounds.width - 3.0,
+                        y: bounds.y - 2.0,
+                        width: 6.0,
+                        height: bounds.height + 4.0,
+                    },
+                    border: Border {
+                        radius: 3.0.into(),
+                        width: 1.0,
+                        color: style.handle_border_color,
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.handle_color,
+            );
+        }
+
+        // Eyedropper
+        if self.on_eyedropper.is_some() {
+            let bounds = parts.eyedropper;
+            let is_hovered = cursor.is_over(bounds);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        color: if is_hovered {
+                            style.accent_color
+                        } else {
+                            style.control_border.color
+                        },
+                        ..style.control_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                Background::Color(Color::TRANSPARENT),
+            );
+
+            let center = bounds.center();
+            let color = if is_hovered {
+                style.accent_color
+            } else {
+                style.text_color
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        center - Vector::new(6.0, 6.0),
+                        Size::new(12.0, 12.0),
+                    ),
+                    border: Border {
+                        radius: 6.0.into(),
+                        width: 1.5,
+                        color,
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                Background::Color(Color::TRANSPARENT),
+            );
+
+            for tick in [
+                Rectangle::new(
+                    Point::new(center.x - 0.75, center.y - 9.0),
+                    Size::new(1.5, 5.0),
+                ),
+                Rectangle::new(
+                    Point::new(center.x - 0.75, center.y + 4.0),
+                    Size::new(1.5, 5.0),
+                ),
+                Rectangle::new(
+                    Point::new(center.x - 9.0, center.y - 0.75),
+                    Size::new(5.0, 1.5),
+                ),
+                Rectangle::new(
+                    Point::new(center.x + 4.0, center.y - 0.75),
+                    Size::new(5.0, 1.5),
+                ),
+            ] {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tick,
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+        }
+
+        // Inputs
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        for ((input, (tree, field)), layout) in self
+            .inputs
+            .iter()
+            .zip(tree.children.iter().zip(Field::ALL))
+            .zip(layout.children().skip(INPUTS_OFFSET))
+        {
+            let bounds = layout.bounds();
+
+            renderer.fill_text(
+                Text {
+                    content: field.label().to_owned(),
+                    bounds: Size::new(label_width(text_size), bounds.height),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x: text::Alignment::Left,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                Point::new(parts.plane.x, bounds.center_y()),
+                style.text_color,
+                *viewport,
+            );
+
+            input.draw(tree, renderer, theme, layout, cursor, None, viewport);
+        }
+
+        // Recent swatches
+        for (swatch, color) in
+            layout.children().skip(INPUTS_OFFSET + 3).zip(&self.recent)
+        {
+            let bounds = swatch.bounds();
+
+            checkerboard(renderer, bounds, style.checkerboard);
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        color: if cursor.is_over(bounds) {
+                            style.accent_color
+                        } else {
+                            style.control_border.color
+                        },
+                        ..style.control_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                *color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<ColorPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(color_picker: ColorPicker<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(color_picker)
+    }
+}
+
+/// The color space of the plane and the hue bar of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// The plane displays the saturation and the value of a hue.
+    #[default]
+    Hsv,
+    /// The plane displays the chroma and the lightness of a hue in the
+    /// perceptually uniform OKLCH color space.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    Oklch,
+}
+
+impl Mode {
+    /// Returns the opaque [`Color`] at the given position of a plane with
+    /// the given hue.
+    fn sample(self, hue: f32, x: f32, y: f32) -> Color {
+        match self {
+            Mode::Hsv => hsv_to_rgb(hue, x, 1.0 - y),
+            Mode::Oklch => oklch_to_rgb(1.0 - y, x * MAX_CHROMA, hue),
+        }
+    }
+}
+
+/// The state of a [`ColorPicker`].
+#[derive(Debug, Default)]
+struct State {
+    color: Option<Color>,
+    mode: Mode,
+    coordinates: Coordinates,
+    drag: Option<Target>,
+    drafts: [Option<String>; 3],
+}
+
+/// The position of a [`Color`] in the plane and the bars of a
+/// [`ColorPicker`].
+///
+/// Keeping it around preserves the hue and the position in the plane when
+/// they cannot be recovered from the [`Color`]; like for grays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Coordinates {
+    hue: f32,
+    x: f32,
+    y: f32,
+    alpha: f32,
+}
+
+impl Default for Coordinates {
+    fn default() -> Self {
+        Self {
+            hue: 0.0,
+            x: 0.0,
+            y: 0.0,
+            alpha: 1.0,
+        }
+    }
+}
+
+impl Coordinates {
+    fn from_color(mode: Mode, color: Color, previous: Self) -> Self {
+        let (hue, x, y) = match mode {
+            Mode::Hsv => {
+                let (hue, saturation, value) = rgb_to_hsv(color);
+
+                (hue, (value > 0.0).then_some(saturation), 1.0 - value)
+            }
+            Mode::Oklch => {
+                let (lightness, chroma, hue) = rgb_to_oklch(color);
+
+                (
+                    hue,
+                    (lightness > 0.0).then_some((chroma / MAX_CHROMA).min(1.0)),
+                    1.0 - lightness,
+                )
+            }
+        };
+
+        Self {
+            hue: hue.unwrap_or(previous.hue),
+            x: x.unwrap_or(previous.x),
+            y: y.clamp(0.0, 1.0),
+            alpha: color.a,
+        }
+    }
+
+    fn color(self, mode: Mode) -> Color {
+        Color {
+            a: self.alpha,
+            ..mode.sample(self.hue, self.x, self.y)
+        }
+    }
+}
+
+/// An interactive part of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Plane,
+    Hue,
+    Alpha,
+}
+
+/// The bounds of the parts of a [`ColorPicker`].
+struct Parts {
+    plane: Rectangle,
+    preview: Rectangle,
+    hue: Rectangle,
+    alpha: Rectangle,
+    eyedropper: Rectangle,
+}
+
+impl Parts {
+    fn new(layout: Layout<'_>) -> Self {
+        Self {
+            plane: layout.child(0).bounds(),
+            preview: layout.child(1).bounds(),
+            hue: layout.child(2).bounds(),
+            alpha: layout.child(3).bounds(),
+            eyedropper: layout.child(4).bounds(),
+        }
+    }
+
+    fn target(&self, position: Point) -> Option<Target> {
+        if self.plane.contains(position) {
+            Some(Target::Plane)
+        } else if self.hue.expand(2.0).contains(position) {
+            Some(Target::Hue)
+        } else if self.alpha.expand(2.0).contains(position) {
+            Some(Target::Alpha)
+        } else {
+            None
+        }
+    }
+
+    fn drag(
+        &self,
+        target: Target,
+        mut coordinates: Coordinates,
+        position: Point,
+    ) -> Coordinates {
+        let along = |bounds: Rectangle| {
+            ((position.x - bounds.x) / bounds.width).clamp(0.0, 1.0)
+        };
+
+        match target {
+            Target::Plane => {
+                coordinates.x = along(self.plane);
+                coordinates.y = ((position.y - self.plane.y)
+                    / self.plane.height)
+                    .clamp(0.0, 1.0);
+            }
+            Target::Hue => {
+                coordinates.hue = along(self.hue) * 360.0;
+            }
+            Target::Alpha => {
+                coordinates.alpha = along(self.alpha);
+            }
+        }
+
+        coordinates
+    }
+}
+
+/// A text input of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Hex,
+    Rgb,
+    Hsl,
+}
+
+impl Field {
+    const ALL: [Self; 3] = [Self::Hex, Self::Rgb, Self::Hsl];
+
+    fn label(self) -> &'static str {
+        match self {
+            Field::Hex => "HEX",
+            Field::Rgb => "RGB",
+            Field::Hsl => "HSL",
+        }
+    }
+
+    fn format(self, color: Color) -> String {
+        let alpha = if color.a < 1.0 {
+            format!(", {:.2}", color.a)
+        } else {
+            String::new()
+        };
+
+        match self {
+            Field::Hex => color.to_string(),
+            Field::Rgb => {
+                let [r, g, b, _a] = color.into_rgba8();
+
+                format!("{r}, {g}, {b}{alpha}")
+            }
+            Field::Hsl => {
+                let (hue, saturation, lightness) = rgb_to_hsl(color);
+
+                format!(
+                    "{:.0}, {:.0}%, {:.0}%{alpha}",
+                    hue.unwrap_or(0.0),
+                    saturation * 100.0,
+                    lightness * 100.0
+                )
+            }
+        }
+    }
+
+    /// Returns whether the value may be the start of a longer one; in which
+    /// case it is only picked once submitted.
+    fn is_partial(self, value: &str) -> bool {
+        let value = value.trim();
+
+        self == Field::Hex
+            && matches!(value.strip_prefix('#').unwrap_or(value).len(), 3 | 4)
+    }
+
+    /// Parses the value of the input, keeping the given alpha if the value
+    /// does not specify any.
+    fn parse(self, value: &str, alpha: f32) -> Option<Color> {
+        match self {
+            Field::Hex => {
+                let value = value.trim();
+                let digits = value.strip_prefix('#').unwrap_or(value);
+
+                if !digits.is_ascii() {
+                    return None;
+                }
+
+                let color = value.parse::<Color>().ok()?;
+
+                Some(if matches!(digits.len(), 3 | 6) {
+                    Color { a: alpha, ..color }
+                } else {
+                    color
+                })
+            }
+            Field::Rgb => {
+                let components = components(value)?;
+                let [r, g, b] = [components[0], components[1], components[2]]
+                    .map(|channel| channel.clamp(0.0, 255.0) / 255.0);
+
+                Some(Color::from_rgba(
+                    r,
+                    g,
+                    b,
+                    components.get(3).map_or(alpha, |a| a.clamp(0.0, 1.0)),
+                ))
+            }
+            Field::Hsl => {
+                let components = components(value)?;
+
+                Some(Color {
+                    a: components.get(3).map_or(alpha, |a| a.clamp(0.0, 1.0)),
+                    ..hsl_to_rgb(
+                        components[0].rem_euclid(360.0),
+                        components[1].clamp(0.0, 100.0) / 100.0,
+                        components[2].clamp(0.0, 100.0) / 100.0,
+                    )
+                })
+            }
+        }
+    }
+}
+
+/// Parses three or four numeric components; like `12, 34, 56` or
+/// `hsl(12 34% 56% / 50%)`.
+///
+/// The fourth component is the alpha channel, from 0 to 1 or as a
+/// percentage.
+fn components(value: &str) -> Option<Vec<f32>> {
+    let value = value
+        .trim()
+        .trim_start_matches(|c: char| c.is_ascii_alphabetic() || c == '(')
+        .trim_end_matches(')');
+
+    let components = value
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|component| !component.is_empty())
+        .enumerate()
+        .map(|(i, component)| {
+            let number = component.trim_end_matches(['%', '°']);
+
+            let scale = if i == 3 && component.ends_with('%') {
+                100.0
+            } else {
+                1.0
+            };
+
+            number
+                .parse::<f32>()
+                .ok()
+                .filter(|component| component.is_finite())
+                .map(|component| component / scale)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    (3..=4).contains(&components.len()).then_some(components)
+}
+
+/// An event produced by the text inputs of a [`ColorPicker`].
+#[derive(Debug, Clone)]
+enum Edit {
+    Input(Field, String),
+    Submit(Field),
+}
+
+fn label_width(text_size: Pixels) -> f32 {
+    text_size.0 * 2.5
+}
+
+/// The direction of a gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    X,
+    Y,
+}
+
+/// Fills the bounds with linear gradients along the given [`Axis`].
+///
+/// The bounds are split in segments, each one sampling the given amount of
+/// stops; since a [`Gradient`] is limited to 8 of them.
+fn fill_strip<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    axis: Axis,
+    segments: usize,
+    stops: usize,
+    sample: impl Fn(f32) -> Color,
+) where
+    Renderer: crate::core::Renderer,
+{
+    let (start, end) = match axis {
+        Axis::X => (Point::new(0.0, 0.5), Point::new(1.0, 0.5)),
+        Axis::Y => (Point::new(0.5, 0.0), Point::new(0.5, 1.0)),
+    };
+
+    for segment in 0..segments {
+        let (from, to) = (
+            segment as f32 / segments as f32,
+            (segment + 1) as f32 / segments as f32,
+        );
+
+        let bounds = match axis {
+            Axis::X => Rectangle {
+                x: bounds.x + from * bounds.width,
+                width: (to - from) * bounds.width,
+                ..bounds
+            },
+            Axis::Y => Rectangle {
+                y: bounds.y + from * bounds.height,
+                height: (to - from) * bounds.height,
+                ..bounds
+            },
+        };
+
+        let gradient = Gradient::with_stops(
+            GradientType::Linear,
+            start,
+            end,
+            (0..stops).map(|stop| {
+                let offset = stop as f32 / (stops - 1) as f32;
+
+                ColorStop {
+                    offset,
+                    color: sample(from + offset * (to - from)),
+                }
+            }),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            gradient,
+        );
+    }
+}
+
+fn checkerboard<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    [even, odd]: [Color; 2],
+) where
+    Renderer: crate::core::Renderer,
+{
+    let size = (bounds.height / 2.0).min(8.0);
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            ..renderer::Quad::default()
+        },
+        even,
+    );
+
+    let columns = (bounds.width / size).ceil() as usize;
+    let rows = (bounds.height / size).ceil() as usize;
+
+    for row in 0..rows {
+        for column in (row % 2..columns).step_by(2) {
+            let x = bounds.x + column as f32 * size;
+            let y = bounds.y + row as f32 * size;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x,
+                        y,
+                        width: size.min(bounds.x + bounds.width - x),
+                        height: size.min(bounds.y + bounds.height - y),
+                    },
+                    ..renderer::Quad::default()
+                },
+                odd,
+            );
+        }
+    }
+}
+
+fn outline<Renderer>(renderer: &mut Renderer, bounds: Rectangle, border: Border)
+where
+    Renderer: crate::core::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border,
+            ..renderer::Quad::default()
+        },
+        Background::Color(Color::TRANSPARENT),
+    );
+}
+
+/// Returns the hue in degrees, if any, of the given RGB components.
+fn hue(color: Color, max: f32, delta: f32) -> Option<f32> {
+    if delta <= f32::EPSILON {
+        return None;
+    }
+
+    let sector = if max == color.r {
+        ((color.g - color.b) / delta).rem_euclid(6.0)
+    } else if max == color.g {
+        (color.b - color.r) / delta + 2.0
+    } else {
+        (color.r - color.g) / delta + 4.0
+    };
+
+    Some(sector * 60.0)
+}
+
+fn rgb_to_hsv(color: Color) -> (Option<f32>, f32, f32) {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let delta = max - min;
+
+    let saturation = if max > 0.0 { delta / max } else { 0.0 };
+
+    (hue(color, max, delta), saturation, max)
+}
+
+fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> Color {
+    let channel = |n: f32| {
+        let k = (n + hue / 60.0) % 6.0;
+
+        value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+
+    Color::from_rgb(channel(5.0), channel(3.0), channel(1.0))
+}
+
+fn rgb_to_hsl(color: Color) -> (Option<f32>, f32, f32) {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let delta = max - min;
+    let lightness = (max + min) / 2.0;
+
+    let saturation = if delta <= f32::EPSILON {
+        0.0
+    } else {
+        delta / (1.0 - (2.0 * lightness - 1.0).abs())
+    };
+
+    (hue(color, max, delta), saturation.min(1.0), lightness)
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> Color {
+    let a = saturation * lightness.min(1.0 - lightness);
+
+    let channel = |n: f32| {
+        let k = (n + hue / 30.0) % 12.0;
+
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+
+    Color::from_rgb(channel(0.0), channel(8.0), channel(4.0))
+}
+
+/// Converts an sRGB [`Color`] into its OKLCH lightness, chroma and hue in
+/// degrees.
+fn rgb_to_oklch(color: Color) -> (f32, f32, Option<f32>) {
+    let oklch = palette::to_oklch(color);
+
+    let hue = (oklch.c > 1e-4).then(|| oklch.h.to_degrees().rem_euclid(360.0));
+
+    (oklch.l.clamp(0.0, 1.0), oklch.c, hue)
+}
+
+/// Converts OKLCH lightness, chroma and hue in degrees into an opaque sRGB
+/// [`Color`], clipping it to the sRGB gamut.
+fn oklch_to_rgb(lightness: f32, chroma: f32, hue: f32) -> Color {
+    palette::from_oklch(palette::Oklch {
+        l: lightness,
+        c: chroma,
+        h: hue.to_radians(),
+        a: 1.0,
+    })
+}
+
+/// The possible status of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`ColorPicker`] can be interacted with.
+    Active,
+    /// The [`ColorPicker`] is being hovered.
+    Hovered,
+    /// The plane or a bar of the [`ColorPicker`] is being dragged.
+    Dragging,
+}
+
+/// The appearance of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the [`ColorPicker`].
+    pub background: Background,
+    /// The [`Border`] of the [`ColorPicker`].
+    pub border: Border,
+    /// The [`Color`] of the labels and the eyedropper icon.
+    pub text_color: Color,
+    /// The [`Color`] of the handles of the plane and the bars.
+    pub handle_color: Color,
+    /// The [`Color`] of the border of the handles.
+    pub handle_border_color: Color,
+    /// The colors of the checkerboard displayed behind transparent colors.
+    pub checkerboard: [Color; 2],
+    /// The [`Border`] of the plane, the bars, the preview and the swatches.
+    pub control_border: Border,
+    /// The [`Color`] highlighting hovered swatches and buttons.
+    pub accent_color: Color,
+}
+
+/// The theme catalog of a [`ColorPicker`].
+pub trait Catalog: text_input::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the text inputs of the [`ColorPicker`].
+    fn default_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        <Self as text_input::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class with the given status.
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+    ) -> Style;
+}
+
+/// A styling function for a [`ColorPicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`ColorPicker`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            radius: 4.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+            ..Border::default()
+        },
+        text_color: palette.background.base.text,
+        handle_color: Color::WHITE,
+        handle_border_color: Color::BLACK.scale_alpha(0.6),
+        checkerboard: [Color::WHITE, Color::from_rgb8(0xCC, 0xCC, 0xCC)],
+        control_border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+            ..Border::default()
+        },
+        accent_color: palette.primary.strong.color,
+    };
+
+    match status {
+        Status::Active | Status::Hovered => active,
+        Status::Dragging => Style {
+            handle_border_color: palette.primary.strong.color,
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Color, b: Color) {
+        assert_within(a, b, 1);
+    }
+
+    fn assert_within(a: Color, b: Color, tolerance: u8) {
+        let [a, b] = [a, b].map(Color::into_rgba8);
+
+        assert!(
+            a.iter().zip(b).all(|(a, b)| a.abs_diff(b) <= tolerance),
+            "{a:?} != {b:?}"
+        );
+    }
+
+    fn colors() -> impl Iterator<Item = Color> {
+        let steps = [0, 1, 64, 127, 128, 200, 254, 255];
+
+        steps.into_iter().flat_map(move |r| {
+            steps.into_iter().flat_map(move |g| {
+                steps.into_iter().map(move |b| Color::from_rgb8(r, g, b))
+            })
+        })
+    }
+
+    #[test]
+    fn hsv_round_trips() {
+        for color in colors() {
+            let (hue, saturation, value) = rgb_to_hsv(color);
+
+            assert_close(
+                hsv_to_rgb(hue.unwrap_or(0.0), saturation, value),
+                color,
+            );
+        }
+
+        assert_eq!(rgb_to_hsv(Color::from_rgb(1.0, 0.0, 0.0)).0, Some(0.0));
+        assert_eq!(rgb_to_hsv(Color::from_rgb(0.0, 0.0, 1.0)).0, Some(240.0));
+        assert_eq!(rgb_to_hsv(Color::WHITE), (None, 0.0, 1.0));
+    }
+
+    #[test]
+    fn hsl_round_trips() {
+        for color in colors() {
+            let (hue, saturation, lightness) = rgb_to_hsl(color);
+
+            assert_close(
+                hsl_to_rgb(hue.unwrap_or(0.0), saturation, lightness),
+                color,
+            );
+        }
+    }
+
+    #[test]
+    fn oklch_round_trips() {
+        for color in colors() {
+            let (lightness, chroma, hue) = rgb_to_oklch(color);
+
+            assert_close(
+                oklch_to_rgb(lightness, chroma, hue.unwrap_or(0.0)),
+                color,
+            );
+        }
+
+        let (lightness, chroma, hue) = rgb_to_oklch(Color::BLACK);
+
+        assert!(lightness.abs() < 1e-4 && chroma < 1e-4 && hue.is_none());
+    }
+
+    #[test]
+    fn fields_parse_colors() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+
+        assert_eq!(
+            Field::Hex.parse("#ff0000", 0.5),
+            Some(Color { a: 0.5, ..red })
+        );
+        assert_eq!(
+            Field::Hex.parse("f008", 1.0),
+            Some(Color {
+                a: 0x88 as f32 / 255.0,
+                ..red
+            })
+        );
+        assert_eq!(Field::Hex.parse("#ff000", 1.0), None);
+
+        assert_eq!(
+            Field::Rgb.parse("255, 0, 0", 0.25),
+            Some(Color { a: 0.25, ..red })
+        );
+        assert_eq!(
+            Field::Rgb.parse("rgb(255 0 0 / 0.5)", 1.0),
+            Some(Color { a: 0.5, ..red })
+        );
+        assert_eq!(
+            Field::Rgb.parse("rgb(255 0 0 / 50%)", 1.0),
+            Some(Color { a: 0.5, ..red })
+        );
+        assert_eq!(Field::Rgb.parse("rgb(300 0 0 / 150%)", 0.5), Some(red));
+        assert_eq!(Field::Rgb.parse("255, 0", 1.0), None);
+        assert_eq!(Field::Rgb.parse("255, red, 0", 1.0), None);
+
+        assert_close(
+            Field::Hsl.parse("hsl(0 100% 50% / 25%)", 1.0).unwrap(),
+            Color { a: 0.25, ..red },
+        );
+        assert_close(
+            Field::Hsl.parse("120°, 100%, 25%", 1.0).unwrap(),
+            Color::from_rgb8(0, 128, 0),
+        );
+    }
+
+    #[test]
+    fn fields_round_trip() {
+        for color in colors() {
+            for alpha in [1.0, 0.5] {
+                let color = Color { a: alpha, ..color };
+
+                for field in Field::ALL {
+                    let parsed = field.parse(&field.format(color), 1.0);
+
+                    // HSL is formatted with whole percentages
+                    let tolerance = if field == Field::Hsl { 3 } else { 0 };
+
+                    assert_within(
+                        parsed.expect("Parse formatted color"),
+                        color,
+                        tolerance,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -963,7 +963,7 @@ pub trait Catalog: text_input::Catalog + menu::Catalog {
 
 impl Catalog for Theme {}
 
-fn search<'a, T, A>(
+pub(crate) fn search<'a, T, A>(
     options: impl IntoIterator<Item = T> + 'a,
     option_matchers: impl IntoIterator<Item = &'a A> + 'a,
     query: &'a str,
@@ -999,7 +999,7 @@ where
     options.into_iter().map(build_matcher).collect()
 }
 
-fn build_matcher<T>(option: T) -> String
+pub(crate) fn build_matcher<T>(option: T) -> String
 where
     T: Display,
 {
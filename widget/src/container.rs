@@ -442,7 +442,7 @@ pub fn draw_background<Renderer>(
     Renderer: core::Renderer,
 {
     if style.background.is_some()
-        || style.border.width > 0.0
+        || style.border.has_width()
         || style.shadow.color.a > 0.0
     {
         renderer.fill_quad(
@@ -598,6 +598,7 @@ pub fn bordered_box(theme: &Theme) -> Style {
             width: 1.0,
             radius: 5.0.into(),
             color: palette.background.weak.color,
+            ..Border::default()
         },
         ..Style::default()
     }
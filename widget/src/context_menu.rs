@@ -0,0 +1,465 @@
+//! Context menus display a menu of actions over some element when it is
+//! right clicked.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::context_menu;
+//! use iced::widget::overlay::menu;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Copy,
+//!     Paste,
+//! }
+//!
+//! fn view(_state: &State) -> Element<'_, Message> {
+//!     context_menu(
+//!         "Right click me!",
+//!         [
+//!             menu::Item::button("Copy")
+//!                 .shortcut("Ctrl+C")
+//!                 .on_press(Message::Copy),
+//!             menu::Item::button("Paste")
+//!                 .shortcut("Ctrl+V")
+//!                 .on_press(Message::Paste),
+//!         ],
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::{self, Operation, Widget};
+use crate::core::{
+    Clipboard, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size,
+    Vector,
+};
+use crate::overlay::menu;
+
+/// An element that displays a menu of actions when right clicked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::context_menu;
+/// use iced::widget::overlay::menu;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Rename,
+///     Delete,
+/// }
+///
+/// fn view(_state: &State) -> Element<'_, Message> {
+///     context_menu(
+///         "Right click me!",
+///         [
+///             menu::Item::button("Rename").on_press(Message::Rename),
+///             menu::Item::separator(),
+///             menu::Item::button("Delete").on_press(Message::Delete),
+///         ],
+///     )
+///     .into()
+/// }
+/// ```
+pub struct ContextMenu<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: menu::Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    items: Vec<menu::Item<Message>>,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: menu::Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`ContextMenu`] for the given content with the given
+    /// menu items.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        items: impl IntoIterator<Item = menu::Item<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            items: items.into_iter().collect(),
+            font: None,
+            text_size: None,
+            class: <Theme as menu::Catalog>::default(),
+        }
+    }
+
+    /// Sets the font of the menu of the [`ContextMenu`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the text size of the menu of the [`ContextMenu`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the menu of the [`ContextMenu`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the menu of the [`ContextMenu`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    menu: menu::nested::State,
+    position: Point,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ContextMenu<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: menu::Catalog,
+    Renderer: text::Renderer,
+{
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() || self.items.is_empty() {
+            return;
+        }
+
+        let is_triggered = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                | Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(key::Named::ContextMenu),
+                    ..
+                })
+        );
+
+        if !is_triggered {
+            return;
+        }
+
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        state.menu.open();
+        state.position = position;
+
+        shell.capture_event();
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            inherited_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let menu = if state.menu.is_open() {
+            Some(overlay::Element::new(Box::new(menu::nested::Overlay {
+                origin: Rectangle::new(
+                    state.position + translation,
+                    Size::ZERO,
+                ),
+                state: &mut state.menu,
+                items: &self.items,
+                font: self.font,
+                text_size: self.text_size,
+                class: &self.class,
+            })))
+        } else {
+            None
+        };
+
+        if content.is_some() || menu.is_some() {
+            Some(
+                overlay::Group::with_children(
+                    content.into_iter().chain(menu).collect(),
+                )
+                .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<ContextMenu<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: menu::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        context_menu: ContextMenu<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(context_menu)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::Harness;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Copy,
+        Bold,
+        Paste,
+    }
+
+    fn context_menu() -> Harness<'static, Message> {
+        Harness::new(ContextMenu::new(
+            crate::Space::new().width(100).height(100),
+            [
+                menu::Item::button("Cut"),
+                menu::Item::button("Copy").on_press(Message::Copy),
+                menu::Item::separator(),
+                menu::Item::submenu(
+                    "Format",
+                    [menu::Item::button("Bold").on_press(Message::Bold)],
+                ),
+                menu::Item::button("Paste").on_press(Message::Paste),
+            ],
+        ))
+    }
+
+    fn right_click(harness: &mut Harness<'_, Message>) {
+        harness.point_at(Point::new(10.0, 10.0));
+
+        let _ = harness.update(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Right,
+        )));
+    }
+
+    #[test]
+    fn arrows_skip_disabled_items_and_separators() {
+        let mut harness = context_menu();
+
+        right_click(&mut harness);
+        assert!(harness.is_open());
+
+        assert_eq!(harness.press(key::Named::ArrowDown), vec![]);
+        assert_eq!(harness.press(key::Named::Enter), vec![Message::Copy]);
+        assert!(!harness.is_open());
+
+        right_click(&mut harness);
+
+        let _ = harness.press(key::Named::ArrowUp);
+        assert_eq!(harness.press(key::Named::Enter), vec![Message::Paste]);
+
+        right_click(&mut harness);
+
+        for _ in 0..3 {
+            let _ = harness.press(key::Named::ArrowDown);
+        }
+
+        assert_eq!(harness.press(key::Named::Enter), vec![Message::Paste]);
+    }
+
+    #[test]
+    fn arrows_open_and_close_submenus() {
+        let mut harness = context_menu();
+
+        right_click(&mut harness);
+
+        let _ = harness.press(key::Named::ArrowDown);
+        let _ = harness.press(key::Named::ArrowDown);
+        let _ = harness.press(key::Named::ArrowRight);
+
+        assert_eq!(harness.press(key::Named::Enter), vec![Message::Bold]);
+
+        right_click(&mut harness);
+
+        let _ = harness.press(key::Named::ArrowDown);
+        let _ = harness.press(key::Named::ArrowDown);
+        let _ = harness.press(key::Named::ArrowRight);
+        let _ = harness.press(key::Named::ArrowLeft);
+        let _ = harness.press(key::Named::ArrowDown);
+
+        assert_eq!(harness.press(key::Named::Enter), vec![Message::Paste]);
+    }
+
+    #[test]
+    fn escape_closes_one_menu_at_a_time() {
+        let mut harness = context_menu();
+
+        right_click(&mut harness);
+
+        let _ = harness.press(key::Named::ArrowUp);
+        let _ = harness.press(key::Named::ArrowUp);
+        let _ = harness.press(key::Named::Enter);
+        let _ = harness.press(key::Named::Escape);
+
+        assert!(harness.is_open());
+
+        let _ = harness.press(key::Named::Escape);
+
+        assert!(!harness.is_open());
+        assert_eq!(harness.press(key::Named::Enter), vec![]);
+    }
+}
@@ -0,0 +1,550 @@
+//! Date pickers let users choose a date from a calendar displayed below them.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::calendar::Date;
+//! use iced::widget::date_picker;
+//!
+//! struct State {
+//!     birthday: Option<Date>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     BirthdayChanged(Date),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     date_picker(state.birthday, Message::BirthdayChanged)
+//!         .placeholder("Birthday")
+//!         .max(Date::today())
+//!         .into()
+//! }
+//! ```
+use crate::calendar::{self, Calendar, Date, Locale, Weekday};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length,
+    Padding, Pixels, Rectangle, Shell, Size, Theme, Vector,
+};
+use crate::overlay::dropdown::Dropdown;
+use crate::picker;
+
+/// A field displaying a [`Date`], which opens a [`Calendar`] when clicked.
+///
+/// While focused, the [`Calendar`] can also be opened with `Enter`, `Space`
+/// or `ArrowDown`, and closed with `Escape`.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::calendar::{Date, Weekday};
+/// use iced::widget::date_picker;
+///
+/// struct State {
+///     due: Option<Date>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DueChanged(Date),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     date_picker(state.due, Message::DueChanged)
+///         .week_start(Weekday::Sunday)
+///         .min(Date::today())
+///         .format(|date| format!("{}/{}/{}", date.month(), date.day(), date.year()))
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    date: Option<Date>,
+    on_change: Box<dyn Fn(Date) -> Message + 'a>,
+    calendar: Calendar<'a, Date, Theme, Renderer>,
+    format: Option<Box<dyn Fn(Date) -> String + 'a>>,
+    placeholder: Option<String>,
+    width: Length,
+    padding: Padding,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: <Theme as Catalog>::Class<'a>,
+    last_status: Option<Status>,
+}
+
+impl<'a, Message, Theme, Renderer> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of a [`DatePicker`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`DatePicker`] with the given [`Date`], if any, and the
+    /// message produced when a new one is picked.
+    pub fn new(
+        date: Option<Date>,
+        on_change: impl Fn(Date) -> Message + 'a,
+    ) -> Self {
+        let mut calendar = Calendar::new(date, |date| date);
+        calendar.set_class(Theme::default_calendar());
+
+        Self {
+            date,
+            on_change: Box::new(on_change),
+            calendar,
+            format: None,
+            placeholder: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            font: None,
+            text_size: None,
+            class: <Theme as Catalog>::default(),
+            last_status: None,
+        }
+    }
+
+    /// Sets the placeholder of the [`DatePicker`], shown while no [`Date`] is
+    /// picked.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the function used to display the picked [`Date`].
+    ///
+    /// By default, dates are displayed in the ISO 8601 format (`YYYY-MM-DD`).
+    pub fn format(mut self, format: impl Fn(Date) -> String + 'a) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the first day of the week of the [`Calendar`].
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.calendar = self.calendar.week_start(week_start);
+        self
+    }
+
+    /// Sets the earliest [`Date`] that can be picked.
+    pub fn min(mut self, min: Date) -> Self {
+        self.calendar = self.calendar.min(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be picked.
+    pub fn max(mut self, max: Date) -> Self {
+        self.calendar = self.calendar.max(max);
+        self
+    }
+
+    /// Sets the function deciding which dates cannot be picked.
+    pub fn disabled(mut self, is_disabled: impl Fn(Date) -> bool + 'a) -> Self {
+        self.calendar = self.calendar.disabled(is_disabled);
+        self
+    }
+
+    /// Sets the [`Locale`] of the [`Calendar`].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.calendar = self.calendar.locale(locale);
+        self
+    }
+
+    /// Sets the width of the [`DatePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`DatePicker`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Renderer::Font`] of the [`DatePicker`] and its [`Calendar`].
+    ///
+    /// [`Renderer::Font`]: text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        let font = font.into();
+
+        self.font = Some(font);
+        self.calendar = self.calendar.font(font);
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`] and its [`Calendar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_size = Some(size);
+        self.calendar = self.calendar.text_size(size);
+        self
+    }
+
+    /// Sets the style of the [`DatePicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the [`Calendar`] of the [`DatePicker`].
+    #[must_use]
+    pub fn calendar_style(
+        mut self,
+        style: impl Fn(&Theme) -> calendar::Style + 'a,
+    ) -> Self
+    where
+        <Theme as calendar::Catalog>::Class<'a>:
+            From<calendar::StyleFn<'a, Theme>>,
+    {
+        self.calendar = self.calendar.style(style);
+        self
+    }
+
+    /// Sets the style class of the [`DatePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the [`Calendar`] of the [`DatePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn calendar_class(
+        mut self,
+        class: impl Into<<Theme as calendar::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.calendar = self.calendar.class(class);
+        self
+    }
+
+    fn label(&self) -> Option<String> {
+        self.date.map(|date| match &self.format {
+            Some(format) => format(date),
+            None => date.to_string(),
+        })
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DatePicker<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<picker::State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(picker::State::<Renderer::Paragraph>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.calendar as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.calendar as &dyn Widget<_, _, _>]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let label = self
+            .label()
+            .or_else(|| self.placeholder.clone())
+            .unwrap_or_else(|| Date::today().to_string());
+
+        picker::layout(
+            tree.state
+                .downcast_mut::<picker::State<Renderer::Paragraph>>(),
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.font,
+            self.text_size,
+            &label,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree
+            .state
+            .downcast_mut::<picker::State<Renderer::Paragraph>>();
+
+        operation.focusable(None, layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<picker::State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        if picker::update(state, event, bounds, cursor, shell) {
+            // The calendar always opens on the picked date
+            children[0] = Tree::new(&self.calendar as &dyn Widget<_, _, _>);
+            calendar::focus(&mut children[0]);
+        }
+
+        let status = picker::status(state, bounds, cursor);
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_status = Some(status);
+        } else if self
+            .last_status
+            .is_some_and(|last_status| last_status != status)
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        picker::draw(
+            renderer,
+            theme,
+            &self.class,
+            self.last_status.unwrap_or(Status::Active),
+            layout.bounds(),
+            self.padding,
+            self.font,
+            self.text_size,
+            self.label(),
+            self.placeholder.as_deref(),
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<picker::State<Renderer::Paragraph>>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(
+            Dropdown::new(
+                &mut self.calendar,
+                &mut children[0],
+                &mut state.is_open,
+                &self.on_change,
+            )
+            .overlay(layout.position() + translation, layout.bounds().height),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<DatePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(date_picker: DatePicker<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(date_picker)
+    }
+}
+
+/// The possible status of a [`DatePicker`] or a [`TimePicker`].
+///
+/// [`TimePicker`]: crate::TimePicker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The picker can be interacted with.
+    Active,
+    /// The picker is being hovered.
+    Hovered,
+    /// The picker is focused.
+    Focused,
+    /// The picker is open.
+    Opened {
+        /// Whether the picker is hovered, while open.
+        is_hovered: bool,
+    },
+}
+
+/// The appearance of the field of a [`DatePicker`] or a [`TimePicker`].
+///
+/// [`TimePicker`]: crate::TimePicker
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The text [`Color`] of the picker.
+    pub text_color: Color,
+    /// The placeholder [`Color`] of the picker.
+    pub placeholder_color: Color,
+    /// The handle [`Color`] of the picker.
+    pub handle_color: Color,
+    /// The [`Background`] of the picker.
+    pub background: Background,
+    /// The [`Border`] of the picker.
+    pub border: Border,
+}
+
+/// The theme catalog of a [`DatePicker`] or a [`TimePicker`].
+///
+/// [`TimePicker`]: crate::TimePicker
+pub trait Catalog: calendar::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the [`Calendar`] of the picker.
+    fn default_calendar<'a>() -> <Self as calendar::Catalog>::Class<'a> {
+        <Self as calendar::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class with the given status.
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+    ) -> Style;
+}
+
+/// A styling function for a [`DatePicker`] or a [`TimePicker`].
+///
+/// [`TimePicker`]: crate::TimePicker
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of the field of a picker.
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        text_color: palette.background.weak.text,
+        background: palette.background.weak.color.into(),
+        placeholder_color: palette.secondary.base.color,
+        handle_color: palette.background.weak.text,
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+            ..Border::default()
+        },
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered | Status::Focused | Status::Opened { .. } => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+    }
+}
@@ -0,0 +1,813 @@
+//! Draggables let users drag some payload onto a [`DropZone`].
+//!
+//! [`DropZone`]: crate::DropZone
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, draggable, drop_zone, row, text};
+//!
+//! struct State {
+//!     todo: Vec<String>,
+//!     done: Vec<String>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Done(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     row![
+//!         column(state.todo.iter().enumerate().map(|(i, task)| {
+//!             draggable(i, text(task)).into()
+//!         })),
+//!         drop_zone(
+//!             column(state.done.iter().map(|task| text(task).into())),
+//!             Message::Done,
+//!         ),
+//!     ]
+//!     .into()
+//! }
+//! ```
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+};
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+/// The distance the cursor must travel before a drag starts.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// An element that can be dragged onto a [`DropZone`] carrying some
+/// payload.
+///
+/// A drag can be started with the mouse or, once the [`Draggable`] is
+/// focused, by pressing `Space` or `Enter`. A keyboard drag is dropped by
+/// focusing a [`DropZone`] and pressing `Space` or `Enter` again, and
+/// `Escape` cancels any drag.
+///
+/// [`DropZone`]: crate::DropZone
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, draggable, drop_zone};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(&'static str),
+/// }
+///
+/// fn view(_state: &State) -> Element<'_, Message> {
+///     column![
+///         draggable("apple", "Drag me!"),
+///         drop_zone("Drop it here!", Message::Dropped),
+///     ]
+///     .into()
+/// }
+/// ```
+pub struct Draggable<
+    'a,
+    Payload,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    payload: Payload,
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drag_start: Option<Message>,
+    on_drag_end: Option<Message>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    Draggable<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Theme: Catalog,
+{
+    /// Creates a new [`Draggable`] carrying the given payload.
+    pub fn new(
+        payload: Payload,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            payload,
+            content: content.into(),
+            on_drag_start: None,
+            on_drag_end: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when a drag of the
+    /// [`Draggable`] starts.
+    pub fn on_drag_start(mut self, message: Message) -> Self {
+        self.on_drag_start = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when a drag of the
+    /// [`Draggable`] ends, whether it was dropped or not.
+    pub fn on_drag_end(mut self, message: Message) -> Self {
+        self.on_drag_end = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Draggable`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Draggable`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Default)]
+struct State {
+    origin: Option<Point>,
+    drag: Option<Kind>,
+    is_focused: bool,
+    /// The drag started by the [`Draggable`], kept alive until the event
+    /// after it ends.
+    handle: Option<Rc<RefCell<Drag>>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Mouse,
+    Keyboard,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Payload, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Draggable<'_, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(None, layout.bounds(), state);
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        settle(event);
+
+        if state.drag.is_none() {
+            state.handle = None;
+        }
+
+        // A keyboard drag ends once its payload has been dropped
+        if state.drag == Some(Kind::Keyboard) && !is_active() {
+            state.drag = None;
+
+            if let Some(on_drag_end) = &self.on_drag_end {
+                shell.publish(on_drag_end.clone());
+            }
+
+            shell.request_redraw();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+                if state.drag.is_none() =>
+            {
+                if let Some(origin) = state.origin
+                    && position.distance(origin) > DRAG_THRESHOLD
+                {
+                    state.handle = Some(start(self.payload.clone(), false));
+                    state.drag = Some(Kind::Mouse);
+
+                    if let Some(on_drag_start) = &self.on_drag_start {
+                        shell.publish(on_drag_start.clone());
+                    }
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if state.drag == Some(Kind::Mouse) {
+                    shell.request_redraw();
+                }
+
+                return;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.origin = cursor.position_over(layout.bounds());
+                state.is_focused = state.origin.is_some();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.origin = None;
+
+                if state.drag.take().is_some() {
+                    release();
+
+                    if let Some(on_drag_end) = &self.on_drag_end {
+                        shell.publish(on_drag_end.clone());
+                    }
+
+                    shell.request_redraw();
+                    return;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::Escape)
+                        if state.drag.is_some() =>
+                    {
+                        cancel();
+                        state.drag = None;
+                        state.origin = None;
+                        state.handle = None;
+
+                        if let Some(on_drag_end) = &self.on_drag_end {
+                            shell.publish(on_drag_end.clone());
+                        }
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                    keyboard::Key::Named(
+                        key::Named::Space | key::Named::Enter,
+                    ) if state.is_focused && !is_active() => {
+                        state.handle = Some(start(self.payload.clone(), true));
+                        state.drag = Some(Kind::Keyboard);
+
+                        if let Some(on_drag_start) = &self.on_drag_start {
+                            shell.publish(on_drag_start.clone());
+                        }
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag == Some(Kind::Mouse) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::None
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let status = if state.drag.is_some() {
+            Status::Dragged
+        } else {
+            Status::Idle
+        };
+
+        draw_background(
+            renderer,
+            &theme.style(&self.class, status),
+            layout.bounds(),
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+
+        match (state.drag, state.origin) {
+            (Some(Kind::Mouse), Some(origin)) => {
+                Some(overlay::Element::new(Box::new(Preview {
+                    content: &self.content,
+                    tree: &tree.children[0],
+                    layout,
+                    origin,
+                    translation,
+                    class: &self.class,
+                })))
+            }
+            _ => self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                viewport,
+                translation,
+            ),
+        }
+    }
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    From<Draggable<'a, Payload, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        draggable: Draggable<'a, Payload, Message, Theme, Renderer>,
+    ) -> Self {
+        Element::new(draggable)
+    }
+}
+
+/// The preview of a [`Draggable`] following the cursor.
+struct Preview<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b Tree,
+    layout: Layout<'b>,
+    origin: Point,
+    translation: Vector,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Preview<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, _bounds: Size) -> layout::Node {
+        // The preview never blocks the widgets below it
+        layout::Node::new(Size::ZERO)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let Some(position) = cursor.position() else {
+            return;
+        };
+
+        let offset = position - (self.origin + self.translation);
+        let bounds = self.layout.bounds() + self.translation + offset;
+
+        draw_background(
+            renderer,
+            &theme.style(self.class, Status::Preview),
+            bounds,
+        );
+
+        renderer.with_translation(self.translation + offset, |renderer| {
+            self.content.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                style,
+                self.layout,
+                mouse::Cursor::Unavailable,
+                &self.layout.bounds(),
+            );
+        });
+    }
+}
+
+fn draw_background<Renderer>(
+    renderer: &mut Renderer,
+    style: &Style,
+    bounds: Rectangle,
+) where
+    Renderer: renderer::Renderer,
+{
+    if style.background.is_some()
+        || style.border.has_width()
+        || style.shadow.color.a > 0.0
+    {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+                ..renderer::Quad::default()
+            },
+            style
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+    }
+}
+
+/// The payload being dragged by some [`Draggable`].
+///
+/// A drag is owned by the state of the [`Draggable`] that starts it, while
+/// any [`DropZone`](crate::DropZone) can reach it regardless of its
+/// position in the widget tree. If the [`Draggable`] is removed, so is its
+/// drag.
+struct Drag {
+    payload: Option<Box<dyn Any>>,
+    is_keyboard: bool,
+    is_released: bool,
+}
+
+thread_local! {
+    static DRAG: RefCell<Weak<RefCell<Drag>>> =
+        const { RefCell::new(Weak::new()) };
+}
+
+/// Returns the current drag, if its [`Draggable`] still exists.
+fn current() -> Option<Rc<RefCell<Drag>>> {
+    DRAG.with_borrow(Weak::upgrade)
+}
+
+fn start(payload: impl Any, is_keyboard: bool) -> Rc<RefCell<Drag>> {
+    let drag = Rc::new(RefCell::new(Drag {
+        payload: Some(Box::new(payload)),
+        is_keyboard,
+        is_released: false,
+    }));
+
+    DRAG.with_borrow_mut(|current| *current = Rc::downgrade(&drag));
+
+    drag
+}
+
+/// Marks the current mouse drag as released.
+///
+/// The drag stays available until the next event, so any
+/// [`DropZone`](crate::DropZone) can still take its payload.
+pub(crate) fn release() {
+    if let Some(drag) = current() {
+        let mut drag = drag.borrow_mut();
+
+        if !drag.is_keyboard {
+            drag.is_released = true;
+        }
+    }
+}
+
+/// Cancels the current drag, if any.
+pub(crate) fn cancel() {
+    DRAG.with_borrow_mut(|current| *current = Weak::new());
+}
+
+/// Clears a released drag once the event that released it has been
+/// processed.
+pub(crate) fn settle(event: &Event) {
+    if matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    ) {
+        return;
+    }
+
+    if current().is_some_and(|drag| drag.borrow().is_released) {
+        cancel();
+    }
+}
+
+/// Returns true if a drag is in progress.
+pub(crate) fn is_active() -> bool {
+    current().is_some_and(|drag| {
+        let drag = drag.borrow();
+
+        drag.payload.is_some() && !drag.is_released
+    })
+}
+
+/// Returns true if the payload of the current drag is a `Payload` that
+/// satisfies the given predicate.
+///
+/// A released drag is still visible, so its payload can be dropped.
+pub(crate) fn accepts<Payload: 'static>(
+    is_keyboard: Option<bool>,
+    accepts: impl FnOnce(&Payload) -> bool,
+) -> bool {
+    let Some(drag) = current() else {
+        return false;
+    };
+
+    let drag = drag.borrow();
+
+    is_keyboard.is_none_or(|is_keyboard| drag.is_keyboard == is_keyboard)
+        && drag
+            .payload
+            .as_ref()
+            .and_then(|payload| payload.downcast_ref::<Payload>())
+            .is_some_and(accepts)
+}
+
+/// Takes the payload of the current drag, if it is a `Payload`.
+pub(crate) fn take<Payload: 'static>() -> Option<Payload> {
+    let drag = current()?;
+    let mut drag = drag.borrow_mut();
+
+    if !drag.payload.as_ref()?.is::<Payload>() {
+        return None;
+    }
+
+    drag.is_released = true;
+    drag.payload.take()?.downcast().ok().map(|payload| *payload)
+}
+
+/// The possible status of a [`Draggable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Draggable`] is not being dragged.
+    Idle,
+    /// The [`Draggable`] is being dragged.
+    Dragged,
+    /// The preview of a [`Draggable`] following the cursor.
+    Preview,
+}
+
+/// The appearance of a [`Draggable`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Background`] of the [`Draggable`].
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`Draggable`].
+    pub border: Border,
+    /// The [`Shadow`] of the [`Draggable`].
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`Draggable`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Draggable`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Draggable`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Idle => Style::default(),
+        Status::Dragged => Style {
+            background: Some(
+                palette.background.weak.color.scale_alpha(0.5).into(),
+            ),
+            border: Border::default()
+                .width(1)
+                .color(palette.background.strong.color)
+                .style(crate::core::border::Style::Dashed),
+            ..Style::default()
+        },
+        Status::Preview => Style {
+            background: Some(
+                palette.background.base.color.scale_alpha(0.8).into(),
+            ),
+            border: Border::default()
+                .width(1)
+                .color(palette.primary.strong.color),
+            shadow: Shadow {
+                color: Color::BLACK.scale_alpha(0.3),
+                offset: Vector::new(0.0, 4.0),
+                blur_radius: 12.0,
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Font, Pixels, clipboard};
+
+    #[test]
+    fn removing_a_draggable_ends_its_drag() {
+        let mut draggable: Draggable<'_, &str, ()> =
+            Draggable::new("apple", crate::Space::new().width(100).height(100));
+
+        let renderer = crate::Renderer::new(Font::default(), Pixels(16.0));
+        let mut tree = Tree::new(&draggable as &dyn Widget<_, _, _>);
+        let node = draggable.layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+        );
+
+        for (event, position) in [
+            (
+                mouse::Event::ButtonPressed(mouse::Button::Left),
+                Point::new(50.0, 50.0),
+            ),
+            (
+                mouse::Event::CursorMoved {
+                    position: Point::new(80.0, 80.0),
+                },
+                Point::new(80.0, 80.0),
+            ),
+        ] {
+            let mut messages = Vec::new();
+
+            draggable.update(
+                &mut tree,
+                &Event::Mouse(event),
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &renderer,
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::with_size(Size::new(800.0, 600.0)),
+            );
+        }
+
+        assert!(is_active());
+        assert!(accepts::<&str>(Some(false), |payload| *payload == "apple"));
+
+        drop(tree);
+
+        assert!(!is_active());
+        assert_eq!(take::<&str>(), None);
+    }
+}
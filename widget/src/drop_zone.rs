@@ -0,0 +1,472 @@
+//! Drop zones receive the payload of a [`Draggable`] dropped onto them.
+//!
+//! [`Draggable`]: crate::Draggable
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, draggable, drop_zone};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Dropped(u32),
+//! }
+//!
+//! fn view(_state: &State) -> Element<'_, Message> {
+//!     column![
+//!         draggable(1, "One"),
+//!         draggable(2, "Two"),
+//!         drop_zone("Even numbers only!", Message::Dropped)
+//!             .accepts(|number: &u32| number % 2 == 0),
+//!     ]
+//!     .into()
+//! }
+//! ```
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Rectangle,
+    Shell, Size, Theme, Vector, Widget,
+};
+use crate::draggable;
+
+/// An element that accepts the payload of a [`Draggable`] dropped onto it.
+///
+/// [`Draggable`]: crate::Draggable
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, draggable, drop_zone};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(String),
+/// }
+///
+/// fn view(_state: &State) -> Element<'_, Message> {
+///     column![
+///         draggable(String::from("Hello"), "Drag me!"),
+///         drop_zone("Drop it here!", Message::Dropped),
+///     ]
+///     .into()
+/// }
+/// ```
+pub struct DropZone<
+    'a,
+    Payload,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Box<dyn Fn(Payload) -> Message + 'a>,
+    accepts: Option<Box<dyn Fn(&Payload) -> bool + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    DropZone<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: 'static,
+    Theme: Catalog,
+{
+    /// Creates a new [`DropZone`] with the given content and the message
+    /// produced when a payload is dropped onto it.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_drop: impl Fn(Payload) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: Box::new(on_drop),
+            accepts: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the predicate deciding which payloads the [`DropZone`]
+    /// accepts.
+    ///
+    /// By default, any payload of the right type is accepted.
+    pub fn accepts(mut self, accepts: impl Fn(&Payload) -> bool + 'a) -> Self {
+        self.accepts = Some(Box::new(accepts));
+        self
+    }
+
+    /// Sets the style of the [`DropZone`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DropZone`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn accepts_drag(&self, is_keyboard: Option<bool>) -> bool {
+        draggable::accepts(is_keyboard, |payload| {
+            self.accepts.as_ref().is_none_or(|accepts| accepts(payload))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_hovered: bool,
+    is_focused: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Payload, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropZone<'_, Payload, Message, Theme, Renderer>
+where
+    Payload: 'static,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(None, layout.bounds(), state);
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        draggable::settle(event);
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let is_hovered = cursor.is_over(bounds)
+                    && draggable::is_active()
+                    && self.accepts_drag(Some(false));
+
+                if is_hovered != state.is_hovered {
+                    state.is_hovered = is_hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.is_focused = cursor.is_over(bounds);
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.is_hovered = false;
+
+                if !shell.is_event_captured()
+                    && cursor.is_over(bounds)
+                    && self.accepts_drag(Some(false))
+                    && let Some(payload) = draggable::take()
+                {
+                    shell.publish((self.on_drop)(payload));
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+
+                draggable::release();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. })
+                if state.is_focused && !shell.is_event_captured() =>
+            {
+                match key.as_ref() {
+                    keyboard::Key::Named(
+                        key::Named::Space | key::Named::Enter,
+                    ) if draggable::is_active()
+                        && self.accepts_drag(Some(true)) =>
+                    {
+                        if let Some(payload) = draggable::take() {
+                            shell.publish((self.on_drop)(payload));
+                        }
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                    }
+                    keyboard::Key::Named(key::Named::Escape)
+                        if draggable::is_active() =>
+                    {
+                        draggable::cancel();
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if !draggable::is_active() || !self.accepts_drag(None) {
+            Status::Idle
+        } else if cursor.is_over(bounds) || state.is_focused {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let appearance = theme.style(&self.class, status);
+
+        if appearance.background.is_some() || appearance.border.has_width() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    ..renderer::Quad::default()
+                },
+                appearance
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    From<DropZone<'a, Payload, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Payload: 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        drop_zone: DropZone<'a, Payload, Message, Theme, Renderer>,
+    ) -> Self {
+        Element::new(drop_zone)
+    }
+}
+
+/// The possible status of a [`DropZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// No payload accepted by the [`DropZone`] is being dragged.
+    Idle,
+    /// A payload accepted by the [`DropZone`] is being dragged.
+    Active,
+    /// A payload accepted by the [`DropZone`] is being dragged over it.
+    Hovered,
+}
+
+/// The appearance of a [`DropZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Background`] of the [`DropZone`].
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`DropZone`].
+    pub border: Border,
+}
+
+/// The theme catalog of a [`DropZone`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`DropZone`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`DropZone`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Idle => Style::default(),
+        Status::Active => Style {
+            background: None,
+            border: Border::default()
+                .width(1)
+                .color(palette.primary.weak.color)
+                .style(crate::core::border::Style::Dashed),
+        },
+        Status::Hovered => Style {
+            background: Some(
+                palette.primary.weak.color.scale_alpha(0.3).into(),
+            ),
+            border: Border::default()
+                .width(2)
+                .color(palette.primary.strong.color),
+        },
+    }
+}
@@ -0,0 +1,252 @@
+//! Drive widgets in unit tests, without a runtime.
+use crate::core::clipboard;
+use crate::core::keyboard::{self, key};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer::{self, Headless};
+use crate::core::time::Instant;
+use crate::core::widget::{Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Color, Element, Event, Font, Pixels, Point, Rectangle, Shell, Size,
+    SmolStr, Vector,
+};
+use crate::{Renderer, Theme};
+
+/// An [`Element`] under test, together with its widget state.
+///
+/// Events are routed to the overlay of the [`Element`] first, if any, and
+/// then to the [`Element`] itself if the overlay did not capture them.
+pub struct Harness<'a, Message> {
+    element: Element<'a, Message, Theme, Renderer>,
+    tree: Tree,
+    renderer: Renderer,
+    viewport: Size,
+    cursor: mouse::Cursor,
+}
+
+impl<'a, Message> Harness<'a, Message> {
+    /// Creates a new [`Harness`] for the given [`Element`] with an 800x600
+    /// viewport.
+    pub fn new(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let element = element.into();
+
+        Self {
+            tree: Tree::new(&element),
+            element,
+            renderer: Renderer::new(Font::default(), Pixels(16.0)),
+            viewport: Size::new(800.0, 600.0),
+            cursor: mouse::Cursor::Unavailable,
+        }
+    }
+
+    /// Replaces the [`Element`] under test, keeping the widget state of
+    /// the previous one where it matches, like a new `view` would.
+    pub fn rebuild(
+        &mut self,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) {
+        self.element = element.into();
+        self.tree.diff(&self.element);
+    }
+
+    /// Returns the [`Renderer`] of the [`Harness`].
+    pub fn renderer(&self) -> &Renderer {
+        &self.renderer
+    }
+
+    /// Lays out the [`Element`] under test in the viewport.
+    pub fn layout(&mut self) -> layout::Node {
+        self.element.as_widget_mut().layout(
+            &mut self.tree,
+            &self.renderer,
+            &layout::Limits::new(Size::ZERO, self.viewport),
+        )
+    }
+
+    /// Returns true if the [`Element`] under test has an open overlay.
+    pub fn is_open(&mut self) -> bool {
+        let node = self.layout();
+
+        self.element
+            .as_widget_mut()
+            .overlay(
+                &mut self.tree,
+                Layout::new(&node),
+                &self.renderer,
+                &Rectangle::with_size(self.viewport),
+                Vector::ZERO,
+            )
+            .is_some()
+    }
+
+    /// Points the mouse cursor at the given position.
+    ///
+    /// This does _not_ produce mouse movement events!
+    pub fn point_at(&mut self, position: impl Into<Point>) {
+        self.cursor = mouse::Cursor::Available(position.into());
+    }
+
+    /// Processes the given [`Event`], returning the published messages.
+    pub fn update(&mut self, event: Event) -> Vec<Message> {
+        let node = self.layout();
+        let viewport = Rectangle::with_size(self.viewport);
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        if let Some(mut overlay) = self.element.as_widget_mut().overlay(
+            &mut self.tree,
+            Layout::new(&node),
+            &self.renderer,
+            &viewport,
+            Vector::ZERO,
+        ) {
+            let node = overlay
+                .as_overlay_mut()
+                .layout(&self.renderer, self.viewport);
+
+            overlay.as_overlay_mut().update(
+                &event,
+                Layout::new(&node),
+                self.cursor,
+                &self.renderer,
+                &mut clipboard::Null,
+                &mut shell,
+            );
+        }
+
+        if !shell.is_event_captured() {
+            self.element.as_widget_mut().update(
+                &mut self.tree,
+                &event,
+                Layout::new(&node),
+                self.cursor,
+                &self.renderer,
+                &mut clipboard::Null,
+                &mut shell,
+                &viewport,
+            );
+        }
+
+        messages
+    }
+
+    /// Clicks the left mouse button at the given position.
+    pub fn click(&mut self, position: impl Into<Point>) -> Vec<Message> {
+        self.point_at(position);
+
+        let mut messages = self.update(Event::Mouse(
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+        ));
+
+        messages.extend(self.update(Event::Mouse(
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        )));
+
+        messages
+    }
+
+    /// Drags the mouse cursor with the left button pressed from one
+    /// position to another.
+    pub fn drag(
+        &mut self,
+        from: impl Into<Point>,
+        to: impl Into<Point>,
+    ) -> Vec<Message> {
+        let to = to.into();
+
+        self.point_at(from);
+
+        let mut messages = self.update(Event::Mouse(
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+        ));
+
+        self.point_at(to);
+
+        messages.extend(
+            self.update(Event::Mouse(mouse::Event::CursorMoved {
+                position: to,
+            })),
+        );
+
+        messages.extend(self.update(Event::Mouse(
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        )));
+
+        messages
+    }
+
+    /// Presses the given key, without releasing it.
+    pub fn press(&mut self, key: impl Into<keyboard::Key>) -> Vec<Message> {
+        let key = key.into();
+
+        let text = match &key {
+            keyboard::Key::Character(c) => Some(c.clone()),
+            _ => None,
+        };
+
+        self.update(Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            modified_key: key,
+            physical_key: key::Physical::Unidentified(
+                key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text,
+        }))
+    }
+
+    /// Presses the key of every character of the given text, in order.
+    pub fn type_text(&mut self, text: &str) -> Vec<Message> {
+        text.chars()
+            .flat_map(|c| {
+                self.press(keyboard::Key::Character(SmolStr::new(
+                    c.to_string(),
+                )))
+            })
+            .collect()
+    }
+
+    /// Requests a redraw of the [`Element`] under test at the given time.
+    pub fn redraw(&mut self, now: Instant) -> Vec<Message> {
+        self.update(Event::Window(window::Event::RedrawRequested(now)))
+    }
+
+    /// Applies the given [`Operation`] to the [`Element`] under test.
+    pub fn operate(&mut self, operation: &mut dyn Operation) {
+        let node = self.layout();
+
+        self.element.as_widget_mut().operate(
+            &mut self.tree,
+            Layout::new(&node),
+            &self.renderer,
+            operation,
+        );
+    }
+
+    /// Draws the [`Element`] under test, returning the RGBA pixels of the
+    /// viewport.
+    pub fn screenshot(&mut self) -> Vec<u8> {
+        let node = self.layout();
+
+        self.element.as_widget().draw(
+            &self.tree,
+            &mut self.renderer,
+            &Theme::Light,
+            &renderer::Style::default(),
+            Layout::new(&node),
+            self.cursor,
+            &Rectangle::with_size(self.viewport),
+        );
+
+        self.renderer.screenshot(
+            Size::new(self.viewport.width as u32, self.viewport.height as u32),
+            1.0,
+            Color::TRANSPARENT,
+        )
+    }
+}
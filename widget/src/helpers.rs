@@ -1,28 +1,44 @@
 //! Helper functions to create pure widgets.
 use crate::button::{self, Button};
+use crate::calendar::{self, Calendar, Date};
 use crate::checkbox::{self, Checkbox};
+use crate::collapsible::{self, Accordion, Collapsible};
+use crate::color_picker::{self, ColorPicker};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
+use crate::context_menu::ContextMenu;
 use crate::core;
 use crate::core::{theme, Point};
 use crate::core::widget::operation::{self, Operation};
 use crate::core::window;
 use crate::core::{Element, Length, Size, Widget};
+use crate::date_picker::{self, DatePicker};
+use crate::draggable::{self, Draggable};
+use crate::drop_zone::{self, DropZone};
 use crate::float::{self, Float};
 use crate::keyed;
+use crate::menu_bar::{self, MenuBar};
+use crate::modal::{self, Modal};
 use crate::overlay;
+use crate::overlay::menu;
 use crate::pane_grid::{self, PaneGrid};
-use crate::pick_list::{self, PickList};
+use crate::pick_list::{self, MultiPickList, PickList};
 use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
+use crate::range_slider::{self, RangeSlider};
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::tabs::{self, Tabs};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::time_picker::{Time, TimePicker};
+use crate::toast::{self, Toast};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
+use crate::tree_view::{self, TreeView};
 use crate::vertical_slider::{self, VerticalSlider};
+use crate::virtual_list::VirtualList;
 use crate::{
     Column, Grid, MouseArea, Pin, Responsive, Row, Sensor, Space, Stack, Themer,
 };
@@ -30,7 +46,7 @@ use crate::{
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
 use crate::core::gradient::GradientType;
-pub use crate::table::table;
+pub use crate::table::{table, virtual_table};
 
 /// Creates a [`Column`] with the given children.
 ///
@@ -126,6 +142,375 @@ macro_rules! grid {
     );
 }
 
+/// Creates a new [`Calendar`] with the selected [`Date`], if any, and the
+/// message produced when a date is selected.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::calendar;
+/// use iced::widget::calendar::Date;
+///
+/// struct State {
+///     date: Option<Date>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DatePicked(Date),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     calendar(state.date, Message::DatePicked).into()
+/// }
+/// ```
+pub fn calendar<'a, Message, Theme, Renderer>(
+    selected: Option<Date>,
+    on_select: impl Fn(Date) -> Message + 'a,
+) -> Calendar<'a, Message, Theme, Renderer>
+where
+    Theme: calendar::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    Calendar::new(selected, on_select)
+}
+
+/// Creates a new [`ColorPicker`] with the given [`Color`] and the message
+/// produced when it changes.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::Color; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::Color;
+/// use iced::widget::color_picker;
+///
+/// struct State {
+///     color: Color,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ColorChanged(Color),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     color_picker(state.color, Message::ColorChanged).into()
+/// }
+/// ```
+///
+/// [`Color`]: core::Color
+pub fn color_picker<'a, Message, Theme, Renderer>(
+    color: core::Color,
+    on_change: impl Fn(core::Color) -> Message + 'a,
+) -> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: color_picker::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    ColorPicker::new(color, on_change)
+}
+
+/// Creates a new [`ContextMenu`] for the provided content, which displays
+/// the given menu items when right clicked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::context_menu;
+/// use iced::widget::overlay::menu;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+///     Paste,
+/// }
+///
+/// fn view(_state: &State) -> Element<'_, Message> {
+///     context_menu(
+///         "Right click me!",
+///         [
+///             menu::Item::button("Copy").on_press(Message::Copy),
+///             menu::Item::button("Paste").on_press(Message::Paste),
+///         ],
+///     )
+///     .into()
+/// }
+/// ```
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = menu::Item<Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: menu::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    ContextMenu::new(content, items)
+}
+
+/// Creates a new [`DatePicker`] with the picked [`Date`], if any, and the
+/// message produced when a new one is picked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::calendar::Date;
+/// use iced::widget::date_picker;
+///
+/// struct State {
+///     date: Option<Date>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DateChanged(Date),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     date_picker(state.date, Message::DateChanged)
+///         .placeholder("Pick a date...")
+///         .into()
+/// }
+/// ```
+pub fn date_picker<'a, Message, Theme, Renderer>(
+    date: Option<Date>,
+    on_change: impl Fn(Date) -> Message + 'a,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    DatePicker::new(date, on_change)
+}
+
+/// Creates a new [`Draggable`] carrying the given payload.
+///
+/// The payload can be dropped onto any [`DropZone`] accepting it.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, draggable, drop_zone};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(usize),
+/// }
+///
+/// fn view(_state: &State) -> Element<'_, Message> {
+///     column![
+///         draggable(0, "First"),
+///         draggable(1, "Second"),
+///         drop_zone("Drop here!", Message::Dropped),
+///     ]
+///     .into()
+/// }
+/// ```
+pub fn draggable<'a, Payload, Message, Theme, Renderer>(
+    payload: Payload,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Draggable<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Theme: draggable::Catalog + 'a,
+{
+    Draggable::new(payload, content)
+}
+
+/// Creates a new [`DropZone`] with the given content and the message
+/// produced when a [`Draggable`] payload is dropped onto it.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, draggable, drop_zone};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Trashed(String),
+/// }
+///
+/// fn view(_state: &State) -> Element<'_, Message> {
+///     column![
+///         draggable(String::from("notes.txt"), "notes.txt"),
+///         drop_zone("Trash", Message::Trashed)
+///             .accepts(|file: &String| file.ends_with(".txt")),
+///     ]
+///     .into()
+/// }
+/// ```
+pub fn drop_zone<'a, Payload, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_drop: impl Fn(Payload) -> Message + 'a,
+) -> DropZone<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: 'static,
+    Theme: drop_zone::Catalog + 'a,
+{
+    DropZone::new(content, on_drop)
+}
+
+/// Creates a new [`MenuBar`] with the given top-level menus.
+///
+/// Every top-level menu should be a [`menu::Item::submenu`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::menu_bar;
+/// use iced::widget::overlay::menu::Item;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     New,
+///     Quit,
+/// }
+///
+/// fn view(_state: &State) -> Element<'_, Message> {
+///     menu_bar([Item::submenu(
+///         "&File",
+///         [
+///             Item::button("&New").on_press(Message::New),
+///             Item::separator(),
+///             Item::button("&Quit").on_press(Message::Quit),
+///         ],
+///     )])
+///     .into()
+/// }
+/// ```
+pub fn menu_bar<'a, Message, Theme, Renderer>(
+    menus: impl IntoIterator<Item = menu::Item<Message>>,
+) -> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: menu_bar::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    MenuBar::new(menus)
+}
+
+/// Creates new [`Tabs`] with the given tabs, the index of the selected one,
+/// and its contents.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::tabs::Tab;
+/// use iced::widget::{tabs, text};
+///
+/// struct State {
+///     active: usize,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(usize),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     tabs(
+///         [Tab::new("Inbox"), Tab::new("Archive")],
+///         state.active,
+///         text!("Folder {}", state.active),
+///     )
+///     .on_select(Message::Select)
+///     .into()
+/// }
+/// ```
+pub fn tabs<'a, Message, Theme, Renderer>(
+    tabs: impl IntoIterator<Item = tabs::Tab<Renderer::Font>>,
+    selected: usize,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: tabs::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    Tabs::new(tabs, selected, content)
+}
+
+/// Creates a new [`TimePicker`] with the picked [`Time`], if any, and the
+/// message produced when a new one is picked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::time_picker;
+/// use iced::widget::time_picker::Time;
+///
+/// struct State {
+///     time: Option<Time>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TimeChanged(Time),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     time_picker(state.time, Message::TimeChanged)
+///         .placeholder("Pick a time...")
+///         .into()
+/// }
+/// ```
+pub fn time_picker<'a, Message, Theme, Renderer>(
+    time: Option<Time>,
+    on_change: impl Fn(Time) -> Message + 'a,
+) -> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    TimePicker::new(time, on_change)
+}
+
+/// Creates a new [`TreeView`] with the given root nodes.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::tree_view::Node;
+/// use iced::widget::tree_view;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(Vec<usize>),
+/// }
+///
+/// fn view(selected: &[usize]) -> Element<'_, Message> {
+///     tree_view([
+///         Node::new(0, "Fruits")
+///             .children([Node::new(1, "Apple"), Node::new(2, "Orange")]),
+///         Node::new(3, "Vegetables").children([Node::new(4, "Carrot")]),
+///     ])
+///     .selected(selected.iter().copied())
+///     .on_select(Message::Select)
+///     .into()
+/// }
+/// ```
+pub fn tree_view<'a, Key, Message, Theme, Renderer>(
+    nodes: impl IntoIterator<Item = tree_view::Node<Key, Renderer::Font>>,
+) -> TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + Eq + std::hash::Hash + 'static,
+    Theme: tree_view::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    TreeView::new(nodes)
+}
+
 /// Creates a new [`Text`] widget with the provided content.
 ///
 /// [`Text`]: core::widget::Text
@@ -517,6 +902,37 @@ where
     keyed::Column::with_children(children)
 }
 
+/// Creates a new [`keyed::Row`] from an iterator of elements.
+///
+/// Keyed rows distribute content horizontally while keeping continuity.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{keyed_row, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     keyed_row((0..=100).map(|i| {
+///         (i, text!("Item {i}").into())
+///     })).into()
+/// }
+/// ```
+pub fn keyed_row<'a, Key, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+) -> keyed::Row<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: core::Renderer,
+{
+    keyed::Row::with_children(children)
+}
+
 /// Creates a new [`Row`] from an iterator.
 ///
 /// Rows distribute their children horizontally.
@@ -1119,6 +1535,66 @@ where
     Tooltip::new(content, tooltip, position)
 }
 
+/// Creates a new [`Toast`] with the given title.
+///
+/// Toasts are displayed on top of some content by a [`toast::Manager`];
+/// see [`toasts`].
+pub fn toast<'a, Message>(
+    title: impl core::text::IntoFragment<'a>,
+) -> Toast<'a, Message> {
+    Toast::new(title)
+}
+
+/// Creates a new [`toast::Manager`] that displays the given toasts on top
+/// of the provided content.
+///
+/// Toasts display short-lived notifications on top of some content.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{text, toast, toasts};
+///
+/// struct State {
+///    notifications: Vec<String>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dismiss(usize),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     toasts(
+///         text("My app"),
+///         state.notifications.iter().map(|notification| toast(notification.as_str())),
+///         Message::Dismiss,
+///     )
+///     .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::Dismiss(index) => {
+///             state.notifications.remove(index);
+///         }
+///     }
+/// }
+/// ```
+pub fn toasts<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    toasts: impl IntoIterator<Item = Toast<'a, Message>>,
+    on_dismiss: impl Fn(usize) -> Message + 'a,
+) -> toast::Manager<'a, Message, Theme, Renderer>
+where
+    Theme: toast::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    toast::Manager::new(content, toasts, on_dismiss)
+}
+
 /// Creates a new [`Text`] widget with the provided content.
 ///
 /// # Example
@@ -1288,6 +1764,70 @@ where
     Checkbox::new(is_checked)
 }
 
+/// Creates a new [`Collapsible`] with the given header and content.
+///
+/// Collapsibles show or hide their content when their header is toggled.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{collapsible, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     collapsible(text("Details"), text("Some very interesting details")).into()
+/// }
+/// ```
+pub fn collapsible<'a, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Collapsible<'a, Message, Theme, Renderer>
+where
+    Theme: collapsible::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    Collapsible::new(header, content)
+}
+
+/// Creates a new [`Accordion`] with the given [`Collapsible`] sections.
+///
+/// Accordions expand, at most, one of their sections at a time.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{accordion, collapsible, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     accordion([
+///         collapsible(text("Appearance"), text("...")),
+///         collapsible(text("Shortcuts"), text("...")),
+///     ])
+///     .into()
+/// }
+/// ```
+pub fn accordion<'a, Message, Theme, Renderer>(
+    sections: impl IntoIterator<Item = Collapsible<'a, Message, Theme, Renderer>>,
+) -> Accordion<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: collapsible::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    Accordion::new(sections)
+}
+
 /// Creates a new [`Radio`].
 ///
 /// Radio buttons let users choose a single option from a bunch of options.
@@ -1589,6 +2129,55 @@ where
     VerticalSlider::new(range, value, on_change)
 }
 
+/// Creates a new [`RangeSlider`].
+///
+/// Range sliders let users select a span of values by moving two indicators.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::range_slider;
+///
+/// struct State {
+///    low: f32,
+///    high: f32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RangeChanged(f32, f32),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     range_slider(0.0..=100.0, (state.low, state.high), Message::RangeChanged)
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::RangeChanged(low, high) => {
+///             state.low = low;
+///             state.high = high;
+///         }
+///     }
+/// }
+/// ```
+pub fn range_slider<'a, T, Message, Theme, Renderer>(
+    range: std::ops::RangeInclusive<T>,
+    values: (T, T),
+    on_change: impl Fn(T, T) -> Message + 'a,
+) -> RangeSlider<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Theme: range_slider::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    RangeSlider::new(range, values, on_change)
+}
+
 /// Creates a new [`PickList`].
 ///
 /// Pick lists display a dropdown list of selectable options.
@@ -1669,6 +2258,62 @@ where
     PickList::new(options, selected, on_selected)
 }
 
+/// Creates a new [`MultiPickList`].
+///
+/// Multi pick lists display a dropdown list of options where many of them
+/// can be selected at once.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::multi_pick_list;
+///
+/// struct State {
+///    languages: Vec<&'static str>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LanguagesChanged(Vec<&'static str>),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     multi_pick_list(
+///         ["Rust", "Haskell", "OCaml", "Zig"],
+///         state.languages.as_slice(),
+///         Message::LanguagesChanged,
+///     )
+///     .placeholder("Select your languages...")
+///     .searchable("Search...")
+///     .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::LanguagesChanged(languages) => {
+///             state.languages = languages;
+///         }
+///     }
+/// }
+/// ```
+pub fn multi_pick_list<'a, T, L, V, Message, Theme, Renderer>(
+    options: L,
+    selected: V,
+    on_change: impl Fn(Vec<T>) -> Message + 'a,
+) -> MultiPickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<[T]> + 'a,
+    Message: Clone,
+    Theme: pick_list::Catalog + overlay::menu::Catalog,
+    Renderer: core::text::Renderer,
+{
+    MultiPickList::new(options, selected, on_change)
+}
+
 /// Creates a new [`ComboBox`].
 ///
 /// Combo boxes display a dropdown list of searchable and selectable options.
@@ -1809,7 +2454,9 @@ where
 /// ```
 /// <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
 #[cfg(feature = "image")]
-pub fn image<Handle>(handle: impl Into<Handle>) -> crate::Image<Handle> {
+pub fn image<'a, Message, Handle>(
+    handle: impl Into<Handle>,
+) -> crate::Image<'a, Message, Handle> {
     crate::Image::new(handle.into())
 }
 
@@ -2020,6 +2667,51 @@ where
     MouseArea::new(widget)
 }
 
+/// Creates a new [`Modal`] that displays the given dialog, if any, on top
+/// of the provided base content.
+///
+/// Modals block any interaction with the base content until dismissed.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{button, container, modal, text};
+///
+/// struct State {
+///    show_about: bool,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ShowAbout,
+///     HideAbout,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     modal(
+///         button("About").on_press(Message::ShowAbout),
+///         state.show_about.then(|| {
+///             container(text("Made with iced")).padding(20)
+///         }),
+///         Message::HideAbout,
+///     )
+///     .into()
+/// }
+/// ```
+pub fn modal<'a, Message, Theme, Renderer>(
+    base: impl Into<Element<'a, Message, Theme, Renderer>>,
+    dialog: Option<impl Into<Element<'a, Message, Theme, Renderer>>>,
+    on_dismiss: Message,
+) -> Modal<'a, Message, Theme, Renderer>
+where
+    Theme: modal::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    Modal::new(base, dialog, on_dismiss)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, Theme, Renderer>(
     theme: Option<Theme>,
@@ -2109,3 +2801,18 @@ where
 {
     Responsive::new(f)
 }
+
+/// Creates a new [`VirtualList`] with the given amount of rows and a
+/// closure that produces the row with the given index.
+///
+/// Only the rows that are visible are built, which makes it suitable for
+/// displaying huge amounts of items inside a [`Scrollable`].
+pub fn virtual_list<'a, Message, Theme, Renderer>(
+    count: usize,
+    view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    VirtualList::new(count, view)
+}
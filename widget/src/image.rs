@@ -16,7 +16,9 @@
 //! }
 //! ```
 //! <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
+pub mod animated;
 pub mod viewer;
+pub use animated::Animated;
 pub use viewer::Viewer;
 
 use crate::core::border;
@@ -25,18 +27,25 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::Tree;
+use crate::core::widget::tree;
+use crate::core::window;
 use crate::core::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Rotation, Size,
-    Vector, Widget,
+    Clipboard, Color, ContentFit, Element, Event, Layout, Length, Point,
+    Rectangle, Rotation, Shell, Size, Vector, Widget,
 };
 
-pub use image::{FilterMethod, Handle};
+pub use image::{FilterMethod, Frame, Frames, Handle, Status};
 
 /// Creates a new [`Viewer`] with the given image `Handle`.
 pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
     Viewer::new(handle)
 }
 
+/// Creates a new [`Animated`] image with the given [`Frames`].
+pub fn animated<Message>(frames: impl Into<Frames>) -> Animated<Message> {
+    Animated::new(frames)
+}
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
 /// # Example
@@ -55,7 +64,7 @@ pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
 /// }
 /// ```
 /// <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
-pub struct Image<Handle = image::Handle> {
+pub struct Image<'a, Message, Handle = image::Handle> {
     handle: Handle,
     width: Length,
     height: Length,
@@ -67,9 +76,11 @@ pub struct Image<Handle = image::Handle> {
     opacity: f32,
     scale: f32,
     expand: bool,
+    placeholder: Option<Placeholder<Handle>>,
+    on_status: Option<Box<dyn Fn(Status) -> Message + 'a>>,
 }
 
-impl<Handle> Image<Handle> {
+impl<'a, Message, Handle> Image<'a, Message, Handle> {
     /// Creates a new [`Image`] with the given path.
     pub fn new(handle: impl Into<Handle>) -> Self {
         Image {
@@ -84,6 +95,8 @@ impl<Handle> Image<Handle> {
             opacity: 1.0,
             scale: 1.0,
             expand: false,
+            placeholder: None,
+            on_status: None,
         }
     }
 
@@ -179,6 +192,94 @@ impl<Handle> Image<Handle> {
         self.border_radius = border_radius.into();
         self
     }
+
+    /// Sets the [`Placeholder`] of the [`Image`], drawn inside its bounds
+    /// while the image is still being loaded.
+    ///
+    /// Since the [`Image`] cannot be measured until it is loaded, you may
+    /// want to give it an explicit [`width`](Self::width) and
+    /// [`height`](Self::height) when using a placeholder.
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<Placeholder<Handle>>,
+    ) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the message that should be produced when the loading
+    /// [`Status`] of the [`Image`] changes.
+    pub fn on_status(
+        mut self,
+        on_status: impl Fn(Status) -> Message + 'a,
+    ) -> Self {
+        self.on_status = Some(Box::new(on_status));
+        self
+    }
+}
+
+/// The content drawn by an [`Image`] while it is being loaded.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Placeholder<Handle = image::Handle> {
+    /// A solid [`Color`].
+    Color(Color),
+    /// Another image; usually a small, low-resolution preview that is
+    /// cheap to decode.
+    ///
+    /// The preview is stretched with linear filtering, which blurs it.
+    Image(Handle),
+}
+
+impl<Handle> From<Color> for Placeholder<Handle> {
+    fn from(color: Color) -> Self {
+        Self::Color(color)
+    }
+}
+
+/// Draws the [`Placeholder`] of an [`Image`].
+pub fn draw_placeholder<Renderer, Handle>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    placeholder: &Placeholder<Handle>,
+    border_radius: border::Radius,
+    content_fit: ContentFit,
+    rotation: Rotation,
+    opacity: f32,
+) where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone,
+{
+    match placeholder {
+        Placeholder::Color(color) => {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border: border::rounded(border_radius),
+                    ..renderer::Quad::default()
+                },
+                color.scale_alpha(opacity),
+            );
+        }
+        Placeholder::Image(handle) => {
+            draw(
+                renderer,
+                layout,
+                handle,
+                None,
+                border_radius,
+                content_fit,
+                FilterMethod::Linear,
+                rotation,
+                opacity,
+                1.0,
+            );
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    status: Option<Status>,
 }
 
 /// Computes the layout of an [`Image`].
@@ -350,11 +451,19 @@ pub fn draw<Renderer, Handle>(
 }
 
 impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer>
-    for Image<Handle>
+    for Image<'_, Message, Handle>
 where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -381,6 +490,36 @@ where
         )
     }
 
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Some(on_status) = &self.on_status else {
+            return;
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            let status = renderer.image_status(&self.handle);
+
+            if state
+                .status
+                .as_ref()
+                .is_none_or(|last| !last.is_same_stage(&status))
+            {
+                state.status = Some(status.clone());
+                shell.publish(on_status(status));
+            }
+        }
+    }
+
     fn draw(
         &self,
         _tree: &Tree,
@@ -391,6 +530,22 @@ where
         _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
+        if let Some(placeholder) = &self.placeholder
+            && renderer.image_status(&self.handle).is_loading()
+        {
+            draw_placeholder(
+                renderer,
+                layout,
+                placeholder,
+                self.border_radius,
+                self.content_fit,
+                self.rotation,
+                self.opacity,
+            );
+
+            return;
+        }
+
         draw(
             renderer,
             layout,
@@ -406,13 +561,16 @@ where
     }
 }
 
-impl<'a, Message, Theme, Renderer, Handle> From<Image<Handle>>
+impl<'a, Message, Theme, Renderer, Handle> From<Image<'a, Message, Handle>>
     for Element<'a, Message, Theme, Renderer>
 where
+    Message: 'a,
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + 'a,
 {
-    fn from(image: Image<Handle>) -> Element<'a, Message, Theme, Renderer> {
+    fn from(
+        image: Image<'a, Message, Handle>,
+    ) -> Element<'a, Message, Theme, Renderer> {
         Element::new(image)
     }
 }
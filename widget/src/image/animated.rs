@@ -0,0 +1,456 @@
+//! Play animated images, like GIFs, APNGs, or animated WebPs.
+use crate::core::border;
+use crate::core::image::{self, FilterMethod, Frames};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Event, Layout, Length, Rectangle, Rotation,
+    Shell, Size, Widget,
+};
+
+#[cfg(feature = "animated-image")]
+pub use crate::graphics::image::load_frames as load;
+
+/// The minimum delay between frames, used to avoid busy looping on
+/// frames without any delay.
+const MIN_DELAY: Duration = Duration::from_millis(10);
+
+/// A frame that plays animated [`Frames`].
+///
+/// Animated [`Frames`] can be obtained with the [`load`] function, which
+/// decodes all the frames of a GIF, APNG, or animated WebP.
+///
+/// Every frame is allocated in the image cache of the renderer the first
+/// time it is shown, and kept there while the [`Animated`] image is
+/// displayed, no matter the cache budget. Therefore, frames are only
+/// uploaded once.
+pub struct Animated<Message> {
+    frames: Frames,
+    width: Length,
+    height: Length,
+    border_radius: border::Radius,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    rotation: Rotation,
+    opacity: f32,
+    is_playing: bool,
+    is_looping: bool,
+    on_finish: Option<Message>,
+}
+
+impl<Message> Animated<Message> {
+    /// Creates a new [`Animated`] image with the given [`Frames`].
+    pub fn new(frames: impl Into<Frames>) -> Self {
+        Self {
+            frames: frames.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            border_radius: border::Radius::default(),
+            content_fit: ContentFit::default(),
+            filter_method: FilterMethod::default(),
+            rotation: Rotation::default(),
+            opacity: 1.0,
+            is_playing: true,
+            is_looping: true,
+            on_finish: None,
+        }
+    }
+
+    /// Sets the width of the [`Animated`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Animated`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Animated`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Animated`].
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Applies the given [`Rotation`] to the [`Animated`].
+    pub fn rotation(mut self, rotation: impl Into<Rotation>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Animated`].
+    ///
+    /// It should be in the [0.0, 1.0] range—`0.0` meaning completely transparent,
+    /// and `1.0` meaning completely opaque.
+    pub fn opacity(mut self, opacity: impl Into<f32>) -> Self {
+        self.opacity = opacity.into();
+        self
+    }
+
+    /// Sets the [`border::Radius`] of the [`Animated`].
+    pub fn border_radius(
+        mut self,
+        border_radius: impl Into<border::Radius>,
+    ) -> Self {
+        self.border_radius = border_radius.into();
+        self
+    }
+
+    /// Sets whether the [`Animated`] is playing.
+    ///
+    /// A paused animation keeps showing its current frame. Resuming a
+    /// finished animation will play it again from the start.
+    ///
+    /// Defaults to `true`.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
+    /// Sets whether the [`Animated`] should start over once it reaches
+    /// its last frame.
+    ///
+    /// Defaults to `true`.
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+
+    /// Sets the message that should be produced when a non-looping
+    /// [`Animated`] reaches its last frame.
+    pub fn on_finish(mut self, message: Message) -> Self {
+        self.on_finish = Some(message);
+        self
+    }
+}
+
+/// The local state of an [`Animated`] image.
+#[derive(Debug, Clone)]
+struct State {
+    frames: image::Id,
+    current: usize,
+    next_frame: Option<Instant>,
+    was_playing: bool,
+    is_finished: bool,
+    allocations: Vec<Option<image::Allocation>>,
+}
+
+impl State {
+    fn new(frames: &Frames) -> Self {
+        Self {
+            frames: frames.id(),
+            current: 0,
+            next_frame: None,
+            was_playing: false,
+            is_finished: false,
+            allocations: vec![None; frames.len()],
+        }
+    }
+
+    /// Allocates the current frame, keeping it in the image cache of the
+    /// renderer for as long as the [`State`] lives.
+    fn allocate<Renderer>(&mut self, frames: &Frames, renderer: &Renderer)
+    where
+        Renderer: image::Renderer<Handle = image::Handle>,
+    {
+        let (Some(frame), Some(allocation @ None)) = (
+            frames.get(self.current),
+            self.allocations.get_mut(self.current),
+        ) else {
+            return;
+        };
+
+        *allocation = renderer.load_image(&frame.handle).ok();
+    }
+}
+
+fn delay(frames: &Frames, index: usize) -> Duration {
+    frames
+        .get(index)
+        .map(|frame| frame.delay)
+        .unwrap_or_default()
+        .max(MIN_DELAY)
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Animated<Message>
+where
+    Message: Clone,
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(&self.frames))
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Some(frame) = self.frames.get(0) else {
+            return layout::Node::new(limits.resolve(
+                self.width,
+                self.height,
+                Size::ZERO,
+            ));
+        };
+
+        super::layout(
+            renderer,
+            limits,
+            &frame.handle,
+            self.width,
+            self.height,
+            None,
+            self.content_fit,
+            self.rotation,
+            false,
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.frames != self.frames.id() {
+            *state = State::new(&self.frames);
+            shell.request_redraw();
+        }
+
+        state.allocate(&self.frames, renderer);
+
+        let was_playing = state.was_playing;
+        state.was_playing = self.is_playing;
+
+        if !self.is_playing || self.frames.len() < 2 {
+            state.next_frame = None;
+            return;
+        }
+
+        if state.is_finished {
+            if was_playing {
+                return;
+            }
+
+            state.current = 0;
+            state.is_finished = false;
+            shell.request_redraw();
+        }
+
+        let mut next_frame = *state
+            .next_frame
+            .get_or_insert_with(|| *now + delay(&self.frames, state.current));
+
+        // Skip whole loops after long pauses (e.g. a hidden window)
+        if now.saturating_duration_since(next_frame) > self.frames.duration() {
+            next_frame = *now;
+        }
+
+        while *now >= next_frame {
+            if state.current + 1 < self.frames.len() {
+                state.current += 1;
+            } else if self.is_looping {
+                state.current = 0;
+            } else {
+                state.next_frame = None;
+                state.is_finished = true;
+
+                if let Some(on_finish) = &self.on_finish {
+                    shell.publish(on_finish.clone());
+                }
+
+                return;
+            }
+
+            next_frame += delay(&self.frames, state.current);
+        }
+
+        state.allocate(&self.frames, renderer);
+        state.next_frame = Some(next_frame);
+        shell.request_redraw_at(next_frame);
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let current = if state.frames == self.frames.id() {
+            state.current
+        } else {
+            0
+        };
+
+        let Some(frame) = self.frames.get(current) else {
+            return;
+        };
+
+        super::draw(
+            renderer,
+            layout,
+            &frame.handle,
+            None,
+            self.border_radius,
+            self.content_fit,
+            self.filter_method,
+            self.rotation,
+            self.opacity,
+            1.0,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Animated<Message>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: image::Renderer<Handle = image::Handle> + 'a,
+{
+    fn from(
+        animated: Animated<Message>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(animated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::Harness;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Finished,
+    }
+
+    /// The colors of each frame, in RGBA.
+    const COLORS: [[u8; 4]; 3] =
+        [[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255]];
+
+    fn frames() -> Frames {
+        Frames::new(COLORS.map(|color| image::Frame {
+            handle: image::Handle::from_rgba(10, 10, color.repeat(100)),
+            delay: Duration::from_millis(100),
+        }))
+    }
+
+    /// Returns the index of the frame that is drawn, if any.
+    fn frame(harness: &mut Harness<'_, Message>) -> Option<usize> {
+        let pixels = harness.screenshot();
+        let center = &pixels[(5 * 800 + 5) * 4..][..4];
+
+        COLORS.iter().position(|color| color == center)
+    }
+
+    fn after(start: Instant, millis: u64) -> Instant {
+        start + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn frames_advance_when_redrawn() {
+        let mut harness = Harness::new(Animated::new(frames()));
+        let start = Instant::now();
+
+        for (millis, expected) in
+            [(0, 0), (50, 0), (100, 1), (250, 2), (300, 0), (400, 1)]
+        {
+            assert!(harness.redraw(after(start, millis)).is_empty());
+            assert_eq!(frame(&mut harness), Some(expected), "{millis}ms");
+        }
+    }
+
+    #[test]
+    fn non_looping_animations_stop_on_their_last_frame() {
+        let mut harness = Harness::new(
+            Animated::new(frames())
+                .looping(false)
+                .on_finish(Message::Finished),
+        );
+        let start = Instant::now();
+
+        assert!(harness.redraw(start).is_empty());
+        assert!(harness.redraw(after(start, 200)).is_empty());
+        assert_eq!(frame(&mut harness), Some(2));
+
+        assert_eq!(harness.redraw(after(start, 300)), [Message::Finished]);
+        assert_eq!(frame(&mut harness), Some(2));
+
+        for millis in [400, 1_000, 10_000] {
+            assert!(harness.redraw(after(start, millis)).is_empty());
+            assert_eq!(frame(&mut harness), Some(2), "{millis}ms");
+        }
+    }
+
+    #[test]
+    fn paused_animations_keep_their_current_frame() {
+        let frames = frames();
+        let mut harness = Harness::new(Animated::new(frames.clone()));
+        let start = Instant::now();
+
+        let _ = harness.redraw(start);
+        let _ = harness.redraw(after(start, 100));
+        assert_eq!(frame(&mut harness), Some(1));
+
+        harness.rebuild(Animated::new(frames.clone()).playing(false));
+
+        for millis in [200, 500, 10_000] {
+            let _ = harness.redraw(after(start, millis));
+            assert_eq!(frame(&mut harness), Some(1), "{millis}ms");
+        }
+
+        // Resumed with the whole delay of the current frame
+        harness.rebuild(Animated::new(frames).playing(true));
+
+        let _ = harness.redraw(after(start, 10_050));
+        assert_eq!(frame(&mut harness), Some(1));
+
+        let _ = harness.redraw(after(start, 10_150));
+        assert_eq!(frame(&mut harness), Some(2));
+    }
+}
@@ -38,8 +38,12 @@
 //! to help them keep continuity, you need to make sure the hint stays the same
 //! for the same items in your user interface between `view` calls.
 pub mod column;
+pub mod row;
+
+mod reorder;
 
 pub use column::Column;
+pub use row::Row;
 
 /// Creates a keyed [`Column`] with the given children.
 ///
@@ -73,3 +77,36 @@ macro_rules! keyed_column {
         $crate::keyed::Column::with_children(vec![$(($key, $crate::core::Element::from($x))),+])
     );
 }
+
+/// Creates a keyed [`Row`] with the given children.
+///
+/// Keyed rows distribute content horizontally while keeping continuity.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::keyed_row;
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     keyed_row![
+///         (0, "Item 0"),
+///         (1, "Item 1"),
+///         (2, "Item 2"),
+///     ].into()
+/// }
+/// ```
+#[macro_export]
+macro_rules! keyed_row {
+    () => (
+        $crate::keyed::Row::new()
+    );
+    ($(($key:expr, $x:expr)),+ $(,)?) => (
+        $crate::keyed::Row::with_children(vec![$(($key, $crate::core::Element::from($x))),+])
+    );
+}
@@ -1,5 +1,6 @@
 //! Keyed columns distribute content vertically while keeping continuity.
 use crate::core::layout;
+use crate::core::layout::flex::Axis;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
@@ -9,6 +10,7 @@ use crate::core::{
     Alignment, Clipboard, Element, Event, Layout, Length, Padding, Pixels,
     Rectangle, Shell, Size, Vector, Widget,
 };
+use crate::keyed::reorder;
 
 /// A container that distributes its contents vertically while keeping continuity.
 ///
@@ -46,6 +48,7 @@ pub struct Column<
     align_items: Alignment,
     keys: Vec<Key>,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
 }
 
 impl<'a, Key, Message, Theme, Renderer>
@@ -79,6 +82,7 @@ where
             align_items: Alignment::Start,
             keys,
             children,
+            on_reorder: None,
         }
     }
 
@@ -141,6 +145,27 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the user reorders the
+    /// children of the [`Column`], enabling reordering.
+    ///
+    /// A drag handle is displayed next to every child. Children can be dragged
+    /// by their handle or moved with `Alt+Up` and `Alt+Down` once their handle,
+    /// or any widget inside of them, is focused.
+    ///
+    /// The message receives the index of the moved child and the index it
+    /// must be moved to; which can be applied with [`Vec::remove`] followed by
+    /// [`Vec::insert`].
+    ///
+    /// Enable [`Scrollable::drag_scroll`](crate::Scrollable::drag_scroll) on
+    /// any parent scrollable to scroll while a child is dragged to its edges.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
     /// Adds an element to the [`Column`].
     pub fn push(
         mut self,
@@ -199,6 +224,7 @@ where
     Key: Copy + PartialEq,
 {
     keys: Vec<Key>,
+    reorder: reorder::State<Key>,
 }
 
 impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -214,6 +240,7 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State {
             keys: self.keys.clone(),
+            reorder: reorder::State::new(self.keys.len()),
         })
     }
 
@@ -241,6 +268,7 @@ where
 
         if state.keys != self.keys {
             state.keys.clone_from(&self.keys);
+            state.reorder.reset(self.keys.len());
         }
     }
 
@@ -268,7 +296,11 @@ where
             &limits,
             self.width,
             self.height,
-            self.padding,
+            if self.on_reorder.is_some() {
+                reorder::padding(&Axis::Vertical, self.padding)
+            } else {
+                self.padding
+            },
             self.spacing,
             self.align_items,
             &mut self.children,
@@ -308,6 +340,36 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
+        if let Some(on_reorder) = &self.on_reorder {
+            let Tree {
+                state, children, ..
+            } = tree;
+
+            state.downcast_mut::<State<Key>>().reorder.update(
+                &Axis::Vertical,
+                &self.keys,
+                event,
+                layout,
+                self.padding,
+                self.spacing,
+                cursor,
+                || {
+                    reorder::focused_child(
+                        &mut self.children,
+                        children,
+                        layout,
+                        renderer,
+                    )
+                },
+                on_reorder,
+                shell,
+            );
+
+            if shell.is_event_captured() {
+                return;
+            }
+        }
+
         for ((child, tree), layout) in self
             .children
             .iter_mut()
@@ -329,6 +391,21 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self.on_reorder.is_some()
+            && let Some(interaction) = tree
+                .state
+                .downcast_ref::<State<Key>>()
+                .reorder
+                .mouse_interaction(
+                    &Axis::Vertical,
+                    layout,
+                    self.padding,
+                    cursor,
+                )
+        {
+            return interaction;
+        }
+
         self.children
             .iter()
             .zip(&tree.children)
@@ -352,16 +429,37 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        for ((child, state), layout) in self
-            .children
-            .iter()
-            .zip(&tree.children)
-            .zip(layout.children())
-        {
-            child
-                .as_widget()
-                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        if self.on_reorder.is_none() {
+            for ((child, state), layout) in self
+                .children
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget().draw(
+                    state, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+
+            return;
         }
+
+        let state = tree.state.downcast_ref::<State<Key>>();
+
+        reorder::draw(
+            &state.reorder,
+            &Axis::Vertical,
+            &self.keys,
+            &self.children,
+            &tree.children,
+            renderer,
+            theme,
+            style,
+            layout,
+            self.padding,
+            cursor,
+            viewport,
+        );
     }
 
     fn overlay<'b>(
@@ -0,0 +1,544 @@
+//! Let users reorder the children of keyed widgets by dragging them.
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::flex::Axis;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::Instant;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::{Id, Tree};
+use crate::core::window;
+use crate::core::{
+    Animation, Color, Element, Event, Layout, Padding, Point, Rectangle, Shell,
+    Size, Vector,
+};
+
+/// The size of the drag handle of every child, in the cross axis.
+const HANDLE_SIZE: f32 = 16.0;
+
+/// The reordering state of a keyed widget.
+#[derive(Debug)]
+pub struct State<Key> {
+    drag: Option<Drag>,
+    focused: Option<Key>,
+    offsets: Vec<Animation<f32>>,
+    now: Instant,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: Point,
+    position: Point,
+    target: usize,
+}
+
+impl<Key> State<Key>
+where
+    Key: Copy + PartialEq,
+{
+    /// Creates the reordering [`State`] of the given amount of children.
+    pub fn new(children: usize) -> Self {
+        Self {
+            drag: None,
+            focused: None,
+            offsets: (0..children)
+                .map(|_| Animation::new(0.0).quick())
+                .collect(),
+            now: Instant::now(),
+        }
+    }
+
+    /// Resets the [`State`] after the children have changed.
+    pub fn reset(&mut self, children: usize) {
+        *self = Self {
+            focused: self.focused,
+            ..Self::new(children)
+        };
+    }
+
+    /// Returns the translation of the child at the given index.
+    pub fn translation(&self, axis: &Axis, index: usize) -> Vector {
+        match self.drag {
+            Some(drag) if drag.index == index => {
+                pack(axis, main(axis, drag.position - drag.origin))
+            }
+            _ => self.offsets.get(index).map_or(Vector::ZERO, |offset| {
+                pack(axis, offset.interpolate_with(|offset| offset, self.now))
+            }),
+        }
+    }
+
+    /// Returns the index of the child being dragged, if any.
+    pub fn dragged(&self) -> Option<usize> {
+        self.drag.map(|drag| drag.index)
+    }
+
+    /// Returns true if the child with the given key has been focused to be
+    /// reordered.
+    pub fn is_focused(&self, key: &Key) -> bool {
+        self.focused.as_ref() == Some(key)
+    }
+
+    /// Processes an [`Event`], producing a reordering message if needed.
+    ///
+    /// The `layout` must be the one of the whole widget, with the `padding`
+    /// it was given by the user.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update<Message>(
+        &mut self,
+        axis: &Axis,
+        keys: &[Key],
+        event: &Event,
+        layout: Layout<'_>,
+        padding: Padding,
+        spacing: f32,
+        cursor: mouse::Cursor,
+        focused_child: impl FnOnce() -> Option<usize>,
+        on_reorder: &dyn Fn(usize, usize) -> Message,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                let index = layout.children().position(|child| {
+                    handle_bounds(
+                        axis,
+                        layout.bounds(),
+                        padding,
+                        child.bounds(),
+                    )
+                    .contains(position)
+                });
+
+                let Some(index) = index else {
+                    self.focused = None;
+                    return;
+                };
+
+                self.focused = keys.get(index).copied();
+                self.drag = Some(Drag {
+                    index,
+                    origin: position,
+                    position,
+                    target: index,
+                });
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Window(window::Event::RedrawRequested(_)) => {
+                if let Event::Window(window::Event::RedrawRequested(now)) =
+                    event
+                {
+                    self.now = *now;
+
+                    if self
+                        .offsets
+                        .iter()
+                        .any(|offset| offset.is_animating(self.now))
+                    {
+                        shell.request_redraw();
+                    }
+                }
+
+                let Some(mut drag) = self.drag else {
+                    return;
+                };
+
+                // The cursor may leave the widget while dragging
+                let Some(position) = cursor.land().position() else {
+                    return;
+                };
+
+                let bounds = layout.bounds();
+                let children: Vec<Rectangle> =
+                    layout.children().map(|child| child.bounds()).collect();
+
+                // Keep the dragged child inside the widget
+                let dragged = children[drag.index];
+                let min = main(axis, bounds.position() - dragged.position());
+                let max = min + main_size(axis, bounds.size())
+                    - main_size(axis, dragged.size());
+
+                let delta =
+                    main(axis, position - drag.origin).min(max).max(min);
+
+                drag.position = drag.origin + pack(axis, delta);
+
+                let dragged_center = main(axis, center(dragged)) + delta;
+
+                let target = children
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, child)| {
+                        *index != drag.index
+                            && main(axis, center(**child)) < dragged_center
+                    })
+                    .count();
+
+                if target != drag.target {
+                    drag.target = target;
+
+                    self.displace(
+                        drag.index,
+                        target,
+                        main_size(axis, dragged.size()) + spacing,
+                    );
+                }
+
+                self.drag = Some(drag);
+
+                if matches!(event, Event::Mouse(_)) {
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(drag) = self.drag.take() else {
+                    return;
+                };
+
+                if drag.target != drag.index {
+                    shell.publish(on_reorder(drag.index, drag.target));
+                }
+
+                // The children will be laid out in their new order
+                self.offsets = (0..self.offsets.len())
+                    .map(|_| Animation::new(0.0).quick())
+                    .collect();
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => match key.as_ref() {
+                keyboard::Key::Named(key::Named::Escape)
+                    if self.drag.is_some() =>
+                {
+                    self.drag = None;
+                    self.displace(0, 0, 0.0);
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+                keyboard::Key::Named(named) if modifiers.alt() => {
+                    let forward = match (axis, named) {
+                        (Axis::Vertical, key::Named::ArrowUp)
+                        | (Axis::Horizontal, key::Named::ArrowLeft) => false,
+                        (Axis::Vertical, key::Named::ArrowDown)
+                        | (Axis::Horizontal, key::Named::ArrowRight) => true,
+                        _ => return,
+                    };
+
+                    let Some(index) = self
+                        .focused
+                        .and_then(|focused| {
+                            keys.iter().position(|key| *key == focused)
+                        })
+                        .or_else(focused_child)
+                    else {
+                        return;
+                    };
+
+                    let target = if forward {
+                        Some(index + 1).filter(|target| *target < keys.len())
+                    } else {
+                        index.checked_sub(1)
+                    };
+
+                    if let Some(target) = target {
+                        shell.publish(on_reorder(index, target));
+                    }
+
+                    shell.capture_event();
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Returns the [`mouse::Interaction`] of the drag handles, if any.
+    pub fn mouse_interaction(
+        &self,
+        axis: &Axis,
+        layout: Layout<'_>,
+        padding: Padding,
+        cursor: mouse::Cursor,
+    ) -> Option<mouse::Interaction> {
+        if self.drag.is_some() {
+            return Some(mouse::Interaction::Grabbing);
+        }
+
+        let position = cursor.position()?;
+
+        layout
+            .children()
+            .any(|child| {
+                handle_bounds(axis, layout.bounds(), padding, child.bounds())
+                    .contains(position)
+            })
+            .then_some(mouse::Interaction::Grab)
+    }
+
+    /// Animates the children between `index` and `target` to make room for
+    /// the dragged child.
+    fn displace(&mut self, index: usize, target: usize, size: f32) {
+        let now = Instant::now();
+
+        for (i, offset) in self.offsets.iter_mut().enumerate() {
+            let value = if index < target && i > index && i <= target {
+                -size
+            } else if target < index && i >= target && i < index {
+                size
+            } else {
+                0.0
+            };
+
+            if offset.value() != value {
+                offset.go_mut(value, now);
+            }
+        }
+    }
+}
+
+/// Returns the [`Padding`] of a keyed widget with room for the drag handles.
+pub fn padding(axis: &Axis, padding: Padding) -> Padding {
+    match axis {
+        Axis::Vertical => Padding {
+            left: padding.left + HANDLE_SIZE,
+            ..padding
+        },
+        Axis::Horizontal => Padding {
+            top: padding.top + HANDLE_SIZE,
+            ..padding
+        },
+    }
+}
+
+/// Draws the children of a keyed widget with their drag handles, displaced
+/// by any ongoing reordering.
+///
+/// The dragged child is drawn last, on top of its siblings.
+#[allow(clippy::too_many_arguments)]
+pub fn draw<Key, Message, Theme, Renderer>(
+    state: &State<Key>,
+    axis: &Axis,
+    keys: &[Key],
+    children: &[Element<'_, Message, Theme, Renderer>],
+    trees: &[Tree],
+    renderer: &mut Renderer,
+    theme: &Theme,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    padding: Padding,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+) where
+    Key: Copy + PartialEq,
+    Renderer: crate::core::Renderer,
+{
+    let bounds = layout.bounds();
+    let dragged = state.dragged();
+
+    let draw_child = |renderer: &mut Renderer,
+                      index: usize,
+                      child: &Element<'_, Message, Theme, Renderer>,
+                      tree: &Tree,
+                      layout: Layout<'_>,
+                      cursor: mouse::Cursor| {
+        let color = if dragged == Some(index)
+            || keys.get(index).is_some_and(|key| state.is_focused(key))
+        {
+            style.text_color
+        } else {
+            style.text_color.scale_alpha(0.4)
+        };
+
+        renderer.with_translation(state.translation(axis, index), |renderer| {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+
+            draw_handle(
+                renderer,
+                axis,
+                bounds,
+                padding,
+                layout.bounds(),
+                color,
+            );
+        });
+    };
+
+    for (index, ((child, tree), layout)) in children
+        .iter()
+        .zip(trees)
+        .zip(layout.children())
+        .enumerate()
+    {
+        if dragged == Some(index) {
+            continue;
+        }
+
+        draw_child(renderer, index, child, tree, layout, cursor);
+    }
+
+    if let Some(index) = dragged
+        && let Some(((child, tree), layout)) =
+            children.iter().zip(trees).zip(layout.children()).nth(index)
+    {
+        renderer.with_layer(*viewport, |renderer| {
+            draw_child(
+                renderer,
+                index,
+                child,
+                tree,
+                layout,
+                mouse::Cursor::Unavailable,
+            );
+        });
+    }
+}
+
+fn draw_handle<Renderer>(
+    renderer: &mut Renderer,
+    axis: &Axis,
+    bounds: Rectangle,
+    padding: Padding,
+    child: Rectangle,
+    color: Color,
+) where
+    Renderer: renderer::Renderer,
+{
+    const DOT: f32 = 2.0;
+    const GAP: f32 = 4.0;
+
+    let handle = handle_bounds(axis, bounds, padding, child);
+    let center = handle.center();
+
+    let (columns, rows) = match axis {
+        Axis::Vertical => (2, 3),
+        Axis::Horizontal => (3, 2),
+    };
+
+    for column in 0..columns {
+        for row in 0..rows {
+            let x =
+                center.x + (column as f32 - (columns - 1) as f32 / 2.0) * GAP;
+            let y = center.y + (row as f32 - (rows - 1) as f32 / 2.0) * GAP;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - DOT / 2.0,
+                        y: y - DOT / 2.0,
+                        width: DOT,
+                        height: DOT,
+                    },
+                    border: crate::core::border::rounded(DOT / 2.0),
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+}
+
+/// Returns the index of the child containing a focused widget, if any.
+pub fn focused_child<Message, Theme, Renderer>(
+    children: &mut [Element<'_, Message, Theme, Renderer>],
+    trees: &mut [Tree],
+    layout: Layout<'_>,
+    renderer: &Renderer,
+) -> Option<usize>
+where
+    Renderer: crate::core::Renderer,
+{
+    struct IsFocused(bool);
+
+    impl Operation for IsFocused {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn focusable(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn operation::Focusable,
+        ) {
+            self.0 |= state.is_focused();
+        }
+    }
+
+    children
+        .iter_mut()
+        .zip(trees)
+        .zip(layout.children())
+        .position(|((child, tree), layout)| {
+            let mut operation = IsFocused(false);
+
+            child.as_widget_mut().operate(
+                tree,
+                layout,
+                renderer,
+                &mut operation,
+            );
+
+            operation.0
+        })
+}
+
+fn handle_bounds(
+    axis: &Axis,
+    bounds: Rectangle,
+    padding: Padding,
+    child: Rectangle,
+) -> Rectangle {
+    match axis {
+        Axis::Vertical => Rectangle {
+            x: bounds.x + padding.left,
+            width: HANDLE_SIZE,
+            ..child
+        },
+        Axis::Horizontal => Rectangle {
+            y: bounds.y + padding.top,
+            height: HANDLE_SIZE,
+            ..child
+        },
+    }
+}
+
+fn main(axis: &Axis, vector: Vector) -> f32 {
+    match axis {
+        Axis::Vertical => vector.y,
+        Axis::Horizontal => vector.x,
+    }
+}
+
+fn main_size(axis: &Axis, size: Size) -> f32 {
+    match axis {
+        Axis::Vertical => size.height,
+        Axis::Horizontal => size.width,
+    }
+}
+
+fn pack(axis: &Axis, value: f32) -> Vector {
+    match axis {
+        Axis::Vertical => Vector::new(0.0, value),
+        Axis::Horizontal => Vector::new(value, 0.0),
+    }
+}
+
+fn center(bounds: Rectangle) -> Vector {
+    bounds.center() - Point::ORIGIN
+}
@@ -12,23 +12,34 @@ pub use core::widget::Id;
 mod action;
 mod column;
 mod mouse_area;
+mod picker;
 mod pin;
 mod responsive;
 mod stack;
 mod themer;
 
 pub mod button;
+pub mod calendar;
 pub mod checkbox;
+pub mod collapsible;
+pub mod color_picker;
 pub mod combo_box;
 pub mod container;
+pub mod context_menu;
+pub mod date_picker;
+pub mod draggable;
+pub mod drop_zone;
 pub mod float;
 pub mod grid;
 pub mod keyed;
+pub mod menu_bar;
+pub mod modal;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
@@ -36,17 +47,25 @@ pub mod sensor;
 pub mod slider;
 pub mod space;
 pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
+pub mod time_picker;
+pub mod toast;
 pub mod toggler;
 pub mod tooltip;
+pub mod tree_view;
 pub mod vertical_slider;
+pub mod virtual_list;
 
 mod helpers;
 
 pub use helpers::*;
 
+#[cfg(test)]
+mod harness;
+
 #[cfg(feature = "lazy")]
 mod lazy;
 
@@ -56,22 +75,42 @@ pub use crate::lazy::helpers::*;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use calendar::Calendar;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use collapsible::{Accordion, Collapsible};
+#[doc(no_inline)]
+pub use color_picker::ColorPicker;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_zone::DropZone;
+#[doc(no_inline)]
 pub use float::Float;
 #[doc(no_inline)]
 pub use grid::Grid;
 #[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
+pub use pick_list::MultiPickList;
+#[doc(no_inline)]
 pub use pick_list::PickList;
 #[doc(no_inline)]
 pub use pin::Pin;
@@ -80,6 +119,8 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
 pub use responsive::Responsive;
 #[doc(no_inline)]
 pub use row::Row;
@@ -96,6 +137,8 @@ pub use space::Space;
 #[doc(no_inline)]
 pub use stack::Stack;
 #[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
@@ -104,11 +147,17 @@ pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use themer::Themer;
 #[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
 
 #[cfg(feature = "wgpu")]
 pub mod shader;
@@ -0,0 +1,737 @@
+//! Menu bars display a row of menus, like the ones found at the top of
+//! desktop applications.
+//!
+//! Every top-level menu is a submenu [`Item`]. An ampersand (`&`) in its
+//! title marks its mnemonic, which opens the menu when typed together with
+//! the `Alt` key.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::keyboard; }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::keyboard::Modifiers;
+//! use iced::widget::menu_bar;
+//! use iced::widget::overlay::menu::{Item, Shortcut};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Open,
+//!     Save,
+//!     ToggleWrap,
+//! }
+//!
+//! fn view(_state: &State) -> Element<'_, Message> {
+//!     menu_bar([
+//!         Item::submenu(
+//!             "&File",
+//!             [
+//!                 Item::button("&Open...")
+//!                     .accelerator(Shortcut::character(Modifiers::CTRL, 'o'))
+//!                     .on_press(Message::Open),
+//!                 Item::button("&Save")
+//!                     .accelerator(Shortcut::character(Modifiers::CTRL, 's'))
+//!                     .on_press(Message::Save),
+//!             ],
+//!         ),
+//!         Item::submenu(
+//!             "&View",
+//!             [Item::checkbox("&Word Wrap", true)
+//!                 .on_press(Message::ToggleWrap)],
+//!         ),
+//!     ])
+//!     .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::overlay::menu::nested::ITEM_PADDING;
+use crate::overlay::menu::{self, Item};
+
+/// A horizontal bar of menus.
+///
+/// Top-level button items are displayed as plain buttons in the bar.
+///
+/// The accelerators of all the items of a [`MenuBar`] are triggered
+/// whenever their [`Shortcut`] is pressed and no other widget captures it,
+/// even while all of its menus are closed.
+///
+/// [`Shortcut`]: menu::Shortcut
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::menu_bar;
+/// use iced::widget::overlay::menu::Item;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Quit,
+///     SelectTheme(usize),
+/// }
+///
+/// fn view(_state: &State) -> Element<'_, Message> {
+///     menu_bar([
+///         Item::submenu(
+///             "&File",
+///             [Item::button("&Quit").on_press(Message::Quit)],
+///         ),
+///         Item::submenu(
+///             "&View",
+///             [Item::submenu(
+///                 "&Theme",
+///                 [
+///                     Item::radio("&Light", true)
+///                         .on_press(Message::SelectTheme(0)),
+///                     Item::radio("&Dark", false)
+///                         .on_press(Message::SelectTheme(1)),
+///                 ],
+///             )],
+///         ),
+///     ])
+///     .into()
+/// }
+/// ```
+pub struct MenuBar<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    menus: Vec<Item<Message>>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 2.0,
+        right: 4.0,
+        bottom: 2.0,
+        left: 4.0,
+    };
+
+    /// Creates a new [`MenuBar`] with the given top-level menus.
+    pub fn new(menus: impl IntoIterator<Item = Item<Message>>) -> Self {
+        Self {
+            menus: menus.into_iter().collect(),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 0.0,
+            font: None,
+            text_size: None,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+        }
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the horizontal spacing between the menus of the [`MenuBar`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`] and its menus.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`] and its menus.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the menus of the [`MenuBar`].
+    #[must_use]
+    pub fn menu_style(
+        mut self,
+        style: impl Fn(&Theme) -> menu::Style + 'a,
+    ) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MenuBar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the menus of the [`MenuBar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(
+        mut self,
+        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    /// Opens the menu at the given index or, if it is a button, produces
+    /// its message.
+    fn open(
+        &self,
+        state: &mut State,
+        index: usize,
+        highlight_first: bool,
+        shell: &mut Shell<'_, Message>,
+    ) where
+        Message: Clone,
+    {
+        let Some(item) = self.menus.get(index) else {
+            return;
+        };
+
+        if let Some(on_press) = item.on_press_message() {
+            shell.publish(on_press.clone());
+            state.menu.close();
+        } else if item.is_enabled() {
+            state.open = Some(index);
+
+            if highlight_first {
+                state.menu.open_with_first(item.items());
+            } else {
+                state.menu.open();
+            }
+        } else {
+            return;
+        }
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    /// Returns the index of the closest menu to the given one in the given
+    /// direction that can be opened.
+    fn neighbor(&self, index: usize, forward: bool) -> Option<usize> {
+        let count = self.menus.len();
+
+        (1..count)
+            .map(|distance| {
+                if forward {
+                    (index + distance) % count
+                } else {
+                    (index + count - distance) % count
+                }
+            })
+            .find(|&index| !self.menus[index].items().is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    open: Option<usize>,
+    hovered: Option<usize>,
+    menu: menu::nested::State,
+    modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    fn opened(&self) -> Option<usize> {
+        self.open.filter(|_| self.menu.is_open())
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height =
+            text::LineHeight::default().to_absolute(size).0 + ITEM_PADDING.y();
+
+        let mut x = self.padding.left;
+
+        let titles = self
+            .menus
+            .iter()
+            .map(|item| {
+                let width = Renderer::Paragraph::with_text(Text {
+                    content: item.label(),
+                    bounds: Size::INFINITE,
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x: text::Alignment::Default,
+                    align_y: alignment::Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                })
+                .min_width()
+                    + ITEM_PADDING.x();
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(x, self.padding.top));
+
+                x += width + self.spacing;
+                node
+            })
+            .collect();
+
+        let intrinsic = Size::new(
+            x - self.spacing.min(x) + self.padding.right,
+            height + self.padding.y(),
+        );
+
+        layout::Node::with_children(
+            limits.resolve(self.width, Length::Shrink, intrinsic),
+            titles,
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let title_at = |cursor: mouse::Cursor| {
+            layout
+                .children()
+                .position(|title| cursor.is_over(title.bounds()))
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let hovered = title_at(cursor);
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+
+                if let Some(open) = state.opened()
+                    && let Some(hovered) = hovered
+                    && hovered != open
+                    && !self.menus[hovered].items().is_empty()
+                {
+                    self.open(state, hovered, false, shell);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(index) = title_at(cursor) else {
+                    return;
+                };
+
+                if state.opened() == Some(index) {
+                    state.menu.close();
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                } else {
+                    self.open(state, index, false, shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                if modifiers.alt() != state.modifiers.alt() {
+                    shell.request_redraw();
+                }
+
+                state.modifiers = *modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) if !shell.is_event_captured() => {
+                if let Some(on_press) =
+                    menu::nested::find_accelerator(&self.menus, key, *modifiers)
+                {
+                    shell.publish(on_press.clone());
+
+                    if state.menu.is_open() {
+                        state.menu.close();
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                    }
+
+                    shell.capture_event();
+                    return;
+                }
+
+                match key.as_ref() {
+                    keyboard::Key::Character(c) if modifiers.alt() => {
+                        let Some(index) = self.menus.iter().position(|item| {
+                            item.is_enabled() && item.has_mnemonic(c)
+                        }) else {
+                            return;
+                        };
+
+                        self.open(state, index, true, shell);
+                    }
+                    keyboard::Key::Named(
+                        named
+                        @ (key::Named::ArrowLeft | key::Named::ArrowRight),
+                    ) => {
+                        let Some(index) = state.opened().and_then(|open| {
+                            self.neighbor(open, named == key::Named::ArrowRight)
+                        }) else {
+                            return;
+                        };
+
+                        self.open(state, index, true, shell);
+                    }
+                    _ => return,
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = <Theme as Catalog>::style(theme, &self.class);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let opened = state.opened();
+        let show_mnemonics = opened.is_some() || state.modifiers.alt();
+
+        for (index, (item, title)) in
+            self.menus.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = title.bounds();
+
+            let is_selected = item.is_enabled()
+                && (opened == Some(index)
+                    || opened.is_none() && cursor.is_over(bounds));
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            radius: style.border.radius,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.selected_background,
+                );
+            }
+
+            let text_color = if !item.is_enabled() {
+                style.disabled_text_color
+            } else if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            let position =
+                Point::new(bounds.x + ITEM_PADDING.left, bounds.center_y());
+
+            renderer.fill_text(
+                Text {
+                    content: item.label().to_owned(),
+                    bounds: bounds.shrink(ITEM_PADDING).size(),
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x: text::Alignment::Left,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                position,
+                text_color,
+                bounds,
+            );
+
+            if show_mnemonics && let Some(mnemonic) = item.mnemonic() {
+                menu::nested::draw_mnemonic(
+                    renderer,
+                    item.label(),
+                    mnemonic,
+                    position,
+                    font,
+                    size,
+                    text_color,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        let index = state.opened()?;
+        let title = layout.children().nth(index)?;
+
+        Some(overlay::Element::new(Box::new(menu::nested::Overlay {
+            state: &mut state.menu,
+            items: self.menus[index].items(),
+            origin: title.bounds() + translation,
+            font: self.font,
+            text_size: self.text_size,
+            class: &self.menu_class,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(menu_bar)
+    }
+}
+
+/// The appearance of a [`MenuBar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the bar.
+    pub background: Background,
+    /// The [`Border`] of the bar.
+    pub border: Border,
+    /// The text [`Color`] of the titles of the menus.
+    pub text_color: Color,
+    /// The text [`Color`] of the title of a disabled menu.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of the title of a hovered or open menu.
+    pub selected_background: Background,
+    /// The text [`Color`] of the title of a hovered or open menu.
+    pub selected_text_color: Color,
+}
+
+/// The theme catalog of a [`MenuBar`].
+pub trait Catalog: menu::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the menus of the [`MenuBar`].
+    fn default_menu<'a>() -> <Self as menu::Catalog>::Class<'a> {
+        <Self as menu::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`MenuBar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`MenuBar`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weakest.color.into(),
+        border: Border {
+            radius: 2.0.into(),
+            ..Border::default()
+        },
+        text_color: palette.background.weakest.text,
+        disabled_text_color: palette.background.strong.color,
+        selected_background: palette.background.weak.color.into(),
+        selected_text_color: palette.background.weak.text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Font, clipboard};
+    use crate::overlay::menu::Shortcut;
+
+    fn save(is_captured: bool) -> Vec<&'static str> {
+        let mut menu_bar: MenuBar<'_, &'static str> =
+            MenuBar::new([Item::submenu(
+                "&File",
+                [Item::button("&Save")
+                    .accelerator(Shortcut::character(
+                        keyboard::Modifiers::CTRL,
+                        's',
+                    ))
+                    .on_press("save")],
+            )]);
+
+        let renderer = crate::Renderer::new(Font::default(), Pixels(16.0));
+        let mut tree = Tree::new(&menu_bar as &dyn Widget<_, _, _>);
+        let node = menu_bar.layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        if is_captured {
+            shell.capture_event();
+        }
+
+        menu_bar.update(
+            &mut tree,
+            &Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character("s".into()),
+                modified_key: keyboard::Key::Character("s".into()),
+                physical_key: key::Physical::Unidentified(
+                    key::NativeCode::Unidentified,
+                ),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::CTRL,
+                text: None,
+            }),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::new(800.0, 600.0)),
+        );
+
+        messages
+    }
+
+    #[test]
+    fn accelerators_publish_their_messages() {
+        assert_eq!(save(false), vec!["save"]);
+    }
+
+    #[test]
+    fn accelerators_skip_captured_events() {
+        assert_eq!(save(true), Vec::<&str>::new());
+    }
+}
@@ -0,0 +1,683 @@
+//! Modals display a dialog on top of some content, blocking any
+//! interaction with it until dismissed.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::{button, column, container, modal, text};
+//!
+//! struct State {
+//!    is_confirming: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Delete,
+//!     Confirm,
+//!     Cancel,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let dialog = state.is_confirming.then(|| {
+//!         container(column![
+//!             text("Are you sure?"),
+//!             button("Delete").on_press(Message::Confirm),
+//!         ])
+//!         .padding(20)
+//!         .style(container::rounded_box)
+//!     });
+//!
+//!     modal(
+//!         button("Delete everything").on_press(Message::Delete),
+//!         dialog,
+//!         Message::Cancel,
+//!     )
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Delete => {
+//!             state.is_confirming = true;
+//!         }
+//!         Message::Confirm => {
+//!             state.is_confirming = false;
+//!             // ...
+//!         }
+//!         Message::Cancel => {
+//!             state.is_confirming = false;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::operation::Focusable;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Operation};
+use crate::core::{
+    Alignment, Background, Clipboard, Color, Element, Event, Layout, Length,
+    Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+/// A widget that displays a dialog on top of some base content.
+///
+/// While the dialog is shown, the base content does not receive any
+/// input and focus traversal is trapped inside the dialog. Pressing
+/// `Escape` or clicking the backdrop produces the `on_dismiss` message.
+///
+/// The dialog is optional, so that the [`Modal`] can stay in the widget
+/// tree while hidden. This way, the base content keeps its state and the
+/// widget that was focused before the dialog was shown gets its focus back
+/// once the dialog is gone.
+///
+/// Modals can be stacked by using a [`Modal`] as the base of another one.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{center, container, modal, text};
+///
+/// struct State {
+///    show_help: bool,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CloseHelp,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     let help = state.show_help.then(|| {
+///         container(text("Press Escape to close this dialog"))
+///             .padding(20)
+///             .style(container::rounded_box)
+///     });
+///
+///     modal(center(text("My app")), help, Message::CloseHelp).into()
+/// }
+/// ```
+pub struct Modal<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+{
+    base: Element<'a, Message, Theme, Renderer>,
+    dialog: Option<Element<'a, Message, Theme, Renderer>>,
+    on_dismiss: Message,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Modal<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Modal`] that displays the given dialog, if any,
+    /// on top of the given base content.
+    ///
+    /// The `on_dismiss` message is produced when the user presses `Escape`
+    /// or clicks outside of the dialog.
+    pub fn new(
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        dialog: Option<impl Into<Element<'a, Message, Theme, Renderer>>>,
+        on_dismiss: Message,
+    ) -> Self {
+        Self {
+            base: base.into(),
+            dialog: dialog.map(Into::into),
+            on_dismiss,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the style of the [`Modal`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Modal`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_open: bool,
+    restore: Option<usize>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Modal<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.base)
+            .chain(&self.dialog)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        match &self.dialog {
+            Some(dialog) => {
+                tree.diff_children(&[&self.base, dialog]);
+            }
+            None => {
+                tree.diff_children(std::slice::from_ref(&self.base));
+            }
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.base.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.base.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let base = self.base.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+
+        let size = base.size();
+
+        let Some(dialog) = &mut self.dialog else {
+            return layout::Node::with_children(size, vec![base]);
+        };
+
+        let dialog = dialog
+            .as_widget_mut()
+            .layout(
+                &mut tree.children[1],
+                renderer,
+                &layout::Limits::new(Size::ZERO, size),
+            )
+            .align(Alignment::Center, Alignment::Center, size);
+
+        layout::Node::with_children(size, vec![base, dialog])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut children = layout.children();
+        let base = children.next().unwrap();
+
+        match (&mut self.dialog, children.next()) {
+            (Some(dialog), Some(layout)) => {
+                dialog.as_widget_mut().operate(
+                    &mut tree.children[1],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            }
+            _ => {
+                self.base.as_widget_mut().operate(
+                    &mut tree.children[0],
+                    base,
+                    renderer,
+                    operation,
+                );
+            }
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut children = layout.children();
+        let base = children.next().unwrap();
+        let dialog_layout = children.next();
+
+        if state.is_open != self.dialog.is_some() {
+            state.is_open = self.dialog.is_some();
+
+            let (base_tree, dialog_tree) = tree.children.split_at_mut(1);
+
+            if let (Some(dialog), Some(layout)) =
+                (&mut self.dialog, dialog_layout)
+            {
+                let mut unfocus = FocusAt::new(None);
+
+                self.base.as_widget_mut().operate(
+                    &mut base_tree[0],
+                    base,
+                    renderer,
+                    &mut unfocus,
+                );
+
+                state.restore = unfocus.focused;
+
+                dialog.as_widget_mut().operate(
+                    &mut dialog_tree[0],
+                    layout,
+                    renderer,
+                    &mut FocusAt::new(Some(0)),
+                );
+            } else {
+                self.base.as_widget_mut().operate(
+                    &mut base_tree[0],
+                    base,
+                    renderer,
+                    &mut FocusAt::new(state.restore.take()),
+                );
+            }
+
+            shell.request_redraw();
+        }
+
+        let (Some(dialog), Some(dialog_layout)) =
+            (&mut self.dialog, dialog_layout)
+        else {
+            self.base.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                base,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            return;
+        };
+
+        dialog.as_widget_mut().update(
+            &mut tree.children[1],
+            event,
+            dialog_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let Event::Window(_) = event {
+            self.base.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                base,
+                mouse::Cursor::Unavailable,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                shell.publish(self.on_dismiss.clone());
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(bounds)
+                    && !cursor.is_over(dialog_layout.bounds()) =>
+            {
+                shell.publish(self.on_dismiss.clone());
+                shell.capture_event();
+            }
+            Event::Mouse(_) | Event::Touch(_) if cursor.is_over(bounds) => {
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let base = children.next().unwrap();
+
+        let (Some(dialog), Some(dialog_layout)) =
+            (&self.dialog, children.next())
+        else {
+            self.base.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                base,
+                cursor,
+                viewport,
+            );
+
+            return;
+        };
+
+        self.base.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            base,
+            mouse::Cursor::Unavailable,
+            viewport,
+        );
+
+        let bounds = layout.bounds();
+        let appearance = theme.style(&self.class);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                appearance.backdrop,
+            );
+
+            dialog.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                dialog_layout,
+                cursor,
+                &bounds,
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let base = children.next().unwrap();
+
+        match (&self.dialog, children.next()) {
+            (Some(dialog), Some(layout)) => {
+                dialog.as_widget().mouse_interaction(
+                    &tree.children[1],
+                    layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            }
+            _ => self.base.as_widget().mouse_interaction(
+                &tree.children[0],
+                base,
+                cursor,
+                viewport,
+                renderer,
+            ),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = layout.children();
+        let base = children.next().unwrap();
+
+        match (&mut self.dialog, children.next()) {
+            (Some(dialog), Some(layout)) => dialog.as_widget_mut().overlay(
+                &mut tree.children[1],
+                layout,
+                renderer,
+                viewport,
+                translation,
+            ),
+            _ => self.base.as_widget_mut().overlay(
+                &mut tree.children[0],
+                base,
+                renderer,
+                viewport,
+                translation,
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Modal<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(
+        modal: Modal<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(modal)
+    }
+}
+
+/// Focuses the focusable widget at the `target` index, unfocusing every
+/// other one, while recording the index of the widget that was focused.
+struct FocusAt {
+    target: Option<usize>,
+    current: usize,
+    focused: Option<usize>,
+}
+
+impl FocusAt {
+    fn new(target: Option<usize>) -> Self {
+        Self {
+            target,
+            current: 0,
+            focused: None,
+        }
+    }
+}
+
+impl Operation for FocusAt {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn focusable(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        state: &mut dyn Focusable,
+    ) {
+        if state.is_focused() {
+            self.focused = Some(self.current);
+        }
+
+        if self.target == Some(self.current) {
+            state.focus();
+        } else {
+            state.unfocus();
+        }
+
+        self.current += 1;
+    }
+}
+
+/// The appearance of a [`Modal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] drawn between the base content and the dialog.
+    pub backdrop: Background,
+}
+
+/// The theme catalog of a [`Modal`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Modal`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Modal`].
+pub fn default(_theme: &Theme) -> Style {
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.8).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Point;
+    use crate::harness::Harness;
+    use crate::{Column, TextInput};
+
+    fn view(is_open: bool) -> Modal<'static, ()> {
+        let input = || TextInput::new("", "").on_input(|_| ());
+
+        Modal::new(
+            Column::new().push(input()).push(input()).push(input()),
+            is_open.then(|| Column::new().push(input()).push(input())),
+            (),
+        )
+    }
+
+    /// Records which focusable widgets are focused, in order.
+    struct Focused(Vec<bool>);
+
+    impl Operation for Focused {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn focusable(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            self.0.push(state.is_focused());
+        }
+    }
+
+    /// Shows the given [`Modal`], returning the focus of the widgets that
+    /// operations reach.
+    fn show<'a>(
+        harness: &mut Harness<'a, ()>,
+        modal: Modal<'a, ()>,
+    ) -> Vec<bool> {
+        harness.rebuild(modal);
+
+        let _ = harness.update(Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::ORIGIN,
+        }));
+
+        let mut focused = Focused(Vec::new());
+        harness.operate(&mut focused);
+
+        focused.0
+    }
+
+    #[test]
+    fn closing_restores_the_focused_widget() {
+        let mut harness = Harness::new(view(false));
+        harness.operate(&mut FocusAt::new(Some(1)));
+
+        assert_eq!(show(&mut harness, view(true)), vec![true, false]);
+        assert_eq!(show(&mut harness, view(false)), vec![false, true, false]);
+    }
+
+    #[test]
+    fn closing_without_focus_focuses_nothing() {
+        let mut harness = Harness::new(view(false));
+
+        assert_eq!(show(&mut harness, view(false)), vec![false, false, false]);
+        assert_eq!(show(&mut harness, view(true)), vec![true, false]);
+        assert_eq!(show(&mut harness, view(false)), vec![false, false, false]);
+    }
+}
@@ -1,2 +1,3 @@
 //! Display interactive elements on top of other widgets.
+pub mod dropdown;
 pub mod menu;
@@ -0,0 +1,186 @@
+//! Display a widget below another one until it produces a value.
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{Clipboard, Event, Layout, Point, Shell, Size, Widget};
+
+/// A widget displayed below a target, or above it if there is more space.
+///
+/// The [`Dropdown`] closes once its content produces a value, which is then
+/// mapped into a message; or when `Escape` is pressed.
+#[allow(missing_debug_implementations)]
+pub struct Dropdown<'a, 'b, T, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    content: &'b mut (dyn Widget<T, Theme, Renderer> + 'a),
+    tree: &'b mut Tree,
+    is_open: &'b mut bool,
+    on_select: &'b dyn Fn(T) -> Message,
+    position: Point,
+    target_height: f32,
+}
+
+impl<'a, 'b, T, Message, Theme, Renderer>
+    Dropdown<'a, 'b, T, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`Dropdown`] with the given content and its [`Tree`].
+    ///
+    /// The `is_open` flag is reset once the [`Dropdown`] closes.
+    pub fn new(
+        content: &'b mut (dyn Widget<T, Theme, Renderer> + 'a),
+        tree: &'b mut Tree,
+        is_open: &'b mut bool,
+        on_select: &'b dyn Fn(T) -> Message,
+    ) -> Self {
+        Self {
+            content,
+            tree,
+            is_open,
+            on_select,
+            position: Point::ORIGIN,
+            target_height: 0.0,
+        }
+    }
+
+    /// Turns the [`Dropdown`] into an overlay [`Element`] at the given target
+    /// position.
+    ///
+    /// The `target_height` will be used to display the [`Dropdown`] either
+    /// on top or below the target.
+    ///
+    /// [`Element`]: overlay::Element
+    pub fn overlay(
+        mut self,
+        position: Point,
+        target_height: f32,
+    ) -> overlay::Element<'b, Message, Theme, Renderer>
+    where
+        T: 'b,
+        Message: 'b,
+        Theme: 'b,
+        Renderer: 'b,
+        'a: 'b,
+    {
+        self.position = position;
+        self.target_height = target_height;
+
+        overlay::Element::new(Box::new(self))
+    }
+}
+
+impl<T, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Dropdown<'_, '_, T, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let space_below =
+            bounds.height - (self.position.y + self.target_height);
+        let space_above = self.position.y;
+
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(bounds.width, space_below.max(space_above)),
+        );
+
+        let node = self.content.layout(self.tree, renderer, &limits);
+        let size = node.size();
+
+        let x = self.position.x.min(bounds.width - size.width).max(0.0);
+
+        node.move_to(if space_below > space_above {
+            Point::new(x, self.position.y + self.target_height)
+        } else {
+            Point::new(x, self.position.y - size.height)
+        })
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.operate(self.tree, layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key::Named::Escape),
+            ..
+        }) = event
+        {
+            *self.is_open = false;
+
+            shell.capture_event();
+            shell.request_redraw();
+            return;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut values = Vec::new();
+        let mut local_shell = Shell::new(&mut values);
+
+        self.content.update(
+            self.tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            &bounds,
+        );
+
+        if !local_shell.is_empty() {
+            *self.is_open = false;
+        }
+
+        shell.merge(local_shell, self.on_select);
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.mouse_interaction(
+            self.tree,
+            layout,
+            cursor,
+            &layout.bounds(),
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+
+        self.content
+            .draw(self.tree, renderer, theme, style, layout, cursor, &bounds);
+    }
+}
@@ -1,4 +1,12 @@
-//! Build and show dropdown menus.
+//! Build and show dropdown menus and nested menus of actions.
+//!
+//! A [`Menu`] lists the selectable options of some dropdown, while an
+//! [`Item`] is an entry of the nested menus of a context menu or a menu
+//! bar.
+pub(crate) mod nested;
+
+pub use nested::{Item, Shortcut};
+
 use crate::core::alignment;
 use crate::core::border::{self, Border};
 use crate::core::layout::{self, Layout};
@@ -11,7 +19,7 @@ use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Event, Length, Padding, Pixels, Point,
-    Rectangle, Size, Theme, Vector,
+    Rectangle, Shadow, Size, Theme, Vector,
 };
 use crate::core::{Element, Shell, Widget};
 use crate::scrollable::{self, Scrollable};
@@ -34,6 +42,8 @@ pub struct Menu<
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    is_checked: Option<Box<dyn Fn(&T) -> bool + 'a>>,
+    search: Option<Search<'a>>,
     width: f32,
     padding: Padding,
     text_size: Option<Pixels>,
@@ -68,6 +78,8 @@ where
             hovered_option,
             on_selected: Box::new(on_selected),
             on_option_hovered,
+            is_checked: None,
+            search: None,
             width: 0.0,
             padding: Padding::ZERO,
             text_size: None,
@@ -117,6 +129,23 @@ where
         self
     }
 
+    /// Displays a checkmark next to the options of the [`Menu`] for which
+    /// the given closure returns `true`.
+    pub fn checkmarks(mut self, is_checked: impl Fn(&T) -> bool + 'a) -> Self {
+        self.is_checked = Some(Box::new(is_checked));
+        self
+    }
+
+    /// Displays the given search query on top of the options of the
+    /// [`Menu`]; or the placeholder, if the query is empty.
+    ///
+    /// The [`Menu`] does not filter its options. It only displays the
+    /// query, which must be handled by its owner.
+    pub fn search(mut self, query: &'a str, placeholder: &'a str) -> Self {
+        self.search = Some(Search { query, placeholder });
+        self
+    }
+
     /// Turns the [`Menu`] into an overlay [`Element`] at the given target
     /// position.
     ///
@@ -161,6 +190,12 @@ impl Default for State {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+struct Search<'a> {
+    query: &'a str,
+    placeholder: &'a str,
+}
+
 struct Overlay<'a, 'b, Message, Theme, Renderer>
 where
     Theme: Catalog,
@@ -170,8 +205,14 @@ where
     viewport: Rectangle,
     tree: &'a mut Tree,
     list: Scrollable<'a, Message, Theme, Renderer>,
+    search: Option<Search<'a>>,
     width: f32,
     target_height: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -198,6 +239,8 @@ where
             hovered_option,
             on_selected,
             on_option_hovered,
+            is_checked,
+            search,
             width,
             padding,
             font,
@@ -212,6 +255,7 @@ where
             hovered_option,
             on_selected,
             on_option_hovered,
+            is_checked,
             font,
             text_size,
             text_line_height,
@@ -228,13 +272,53 @@ where
             viewport,
             tree: &mut state.tree,
             list,
+            search,
             width,
             target_height,
+            padding,
+            text_size,
+            text_line_height,
+            text_shaping,
+            font,
             class,
         }
     }
 }
 
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn search_height(&self, renderer: &Renderer) -> f32 {
+        if self.search.is_none() {
+            return 0.0;
+        }
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.text_line_height.to_absolute(text_size))
+            + self.padding.y()
+    }
+
+    fn split<'c>(
+        &self,
+        layout: Layout<'c>,
+    ) -> (Option<Layout<'c>>, Layout<'c>) {
+        if self.search.is_some() {
+            let mut children = layout.children();
+
+            let search = children.next();
+            let list = children.next().unwrap_or(layout);
+
+            (search, list)
+        } else {
+            (None, layout)
+        }
+    }
+}
+
 impl<Message, Theme, Renderer> crate::core::Overlay<Message, Theme, Renderer>
     for Overlay<'_, '_, Message, Theme, Renderer>
 where
@@ -245,6 +329,7 @@ where
         let space_below =
             bounds.height - (self.position.y + self.target_height);
         let space_above = self.position.y;
+        let search_height = self.search_height(renderer);
 
         let limits = layout::Limits::new(
             Size::ZERO,
@@ -254,12 +339,27 @@ where
                     space_below
                 } else {
                     space_above
-                },
+                } - search_height,
             ),
         )
         .width(self.width);
 
-        let node = self.list.layout(self.tree, renderer, &limits);
+        let list = self.list.layout(self.tree, renderer, &limits);
+
+        let node = if self.search.is_some() {
+            let size = list.size();
+
+            layout::Node::with_children(
+                Size::new(size.width, size.height + search_height),
+                vec![
+                    layout::Node::new(Size::new(size.width, search_height)),
+                    list.move_to(Point::new(0.0, search_height)),
+                ],
+            )
+        } else {
+            list
+        };
+
         let size = node.size();
 
         node.move_to(if space_below > space_above {
@@ -278,12 +378,21 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) {
+        let (search, layout) = self.split(layout);
         let bounds = layout.bounds();
 
         self.list.update(
             self.tree, event, layout, cursor, renderer, clipboard, shell,
             &bounds,
         );
+
+        if let Some(search) = search
+            && let Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            && cursor.is_over(search.bounds())
+        {
+            shell.capture_event();
+        }
     }
 
     fn mouse_interaction(
@@ -292,6 +401,8 @@ where
         cursor: mouse::Cursor,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        let (_search, layout) = self.split(layout);
+
         self.list.mouse_interaction(
             self.tree,
             layout,
@@ -317,11 +428,67 @@ where
             renderer::Quad {
                 bounds,
                 border: style.border,
+                shadow: style.shadow,
                 ..renderer::Quad::default()
             },
             style.background,
         );
 
+        let (search_layout, layout) = self.split(layout);
+
+        if let (Some(search), Some(search_layout)) =
+            (self.search, search_layout)
+        {
+            let search_bounds = search_layout.bounds();
+
+            let text_size =
+                self.text_size.unwrap_or_else(|| renderer.default_size());
+
+            let (content, color) = if search.query.is_empty() {
+                (search.placeholder, style.text_color.scale_alpha(0.5))
+            } else {
+                (search.query, style.text_color)
+            };
+
+            renderer.fill_text(
+                Text {
+                    content: content.to_owned(),
+                    bounds: Size::new(
+                        search_bounds.width - self.padding.x(),
+                        search_bounds.height,
+                    ),
+                    size: text_size,
+                    line_height: self.text_line_height,
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    align_x: text::Alignment::Default,
+                    align_y: alignment::Vertical::Center,
+                    shaping: self.text_shaping,
+                    wrapping: text::Wrapping::None,
+                },
+                Point::new(
+                    search_bounds.x + self.padding.left,
+                    search_bounds.center_y(),
+                ),
+                color,
+                search_bounds,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: search_bounds.y + search_bounds.height
+                            - style.border.width.max(1.0),
+                        height: style.border.width.max(1.0),
+                        ..search_bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.border.color,
+            );
+        }
+
+        let bounds = layout.bounds();
+
         self.list.draw(
             self.tree, renderer, theme, defaults, layout, cursor, &bounds,
         );
@@ -337,6 +504,7 @@ where
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    is_checked: Option<Box<dyn Fn(&T) -> bool + 'a>>,
     padding: Padding,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
@@ -527,6 +695,12 @@ where
 
         let visible_options = &self.options[start..end.min(self.options.len())];
 
+        let checkmark_width = if self.is_checked.is_some() {
+            text_size.0 * 1.5
+        } else {
+            0.0
+        };
+
         for (i, option) in visible_options.iter().enumerate() {
             let i = start + i;
             let is_selected = *self.hovered_option == Some(i);
@@ -553,6 +727,36 @@ where
                 );
             }
 
+            let text_color = if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            if let Some(is_checked) = &self.is_checked
+                && is_checked(option)
+            {
+                renderer.fill_text(
+                    Text {
+                        content: Renderer::CHECKMARK_ICON.to_string(),
+                        bounds: Size::new(text_size.0, bounds.height),
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font: Renderer::ICON_FONT,
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                    },
+                    Point::new(
+                        bounds.x + self.padding.left + text_size.0 / 2.0,
+                        bounds.center_y(),
+                    ),
+                    text_color,
+                    *viewport,
+                );
+            }
+
             renderer.fill_text(
                 Text {
                     content: option.to_string(),
@@ -565,12 +769,11 @@ where
                     shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
                 },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
-                if is_selected {
-                    style.selected_text_color
-                } else {
-                    style.text_color
-                },
+                Point::new(
+                    bounds.x + self.padding.left + checkmark_width,
+                    bounds.center_y(),
+                ),
+                text_color,
                 *viewport,
             );
         }
@@ -592,22 +795,30 @@ where
     }
 }
 
-/// The appearance of a [`Menu`].
+/// The appearance of a [`Menu`] or of some nested menus.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the menu.
     pub background: Background,
     /// The [`Border`] of the menu.
     pub border: Border,
+    /// The [`Shadow`] of the menu.
+    pub shadow: Shadow,
     /// The text [`Color`] of the menu.
     pub text_color: Color,
     /// The text [`Color`] of a selected option in the menu.
     pub selected_text_color: Color,
     /// The background [`Color`] of a selected option in the menu.
     pub selected_background: Background,
+    /// The text [`Color`] of a disabled [`Item`] of the menu.
+    pub disabled_text_color: Color,
+    /// The text [`Color`] of the shortcuts of the items of the menu.
+    pub shortcut_text_color: Color,
+    /// The [`Color`] of the separators of the menu.
+    pub separator: Color,
 }
 
-/// The theme catalog of a [`Menu`].
+/// The theme catalog of a [`Menu`] or of some nested menus.
 pub trait Catalog: scrollable::Catalog {
     /// The item class of the [`Catalog`].
     type Class<'a>;
@@ -624,7 +835,7 @@ pub trait Catalog: scrollable::Catalog {
     fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
 }
 
-/// A styling function for a [`Menu`].
+/// A styling function for a [`Menu`] or for some nested menus.
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
 
 impl Catalog for Theme {
@@ -649,9 +860,14 @@ pub fn default(theme: &Theme) -> Style {
             width: 1.0,
             radius: 0.0.into(),
             color: palette.background.strong.color,
+            ..Border::default()
         },
+        shadow: Shadow::default(),
         text_color: palette.background.weak.text,
         selected_text_color: palette.primary.strong.text,
         selected_background: palette.primary.strong.color.into(),
+        disabled_text_color: palette.background.strong.color,
+        shortcut_text_color: palette.background.strongest.color,
+        separator: palette.background.strong.color,
     }
 }
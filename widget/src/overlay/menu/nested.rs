@@ -0,0 +1,1083 @@
+//! Nested menus of actions, like context menus and menu bars.
+//!
+//! A nested menu is a list of [`Item`]s. An [`Item`] can be a button that
+//! produces a message, a submenu that nests more items, or a separator.
+//!
+//! An ampersand (`&`) in the label of an [`Item`] marks the next
+//! character as its mnemonic, which activates the [`Item`] when typed while
+//! its menu is open. Use `&&` for a literal ampersand.
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::{
+    Border, Clipboard, Color, Event, Layout, Padding, Pixels, Point, Rectangle,
+    Shell, Size, SmolStr,
+};
+use crate::overlay::menu::Catalog;
+
+use std::fmt;
+
+/// The space between the border of a menu and its items.
+const MENU_PADDING: f32 = 4.0;
+
+/// The padding of a single item of a menu.
+pub(crate) const ITEM_PADDING: Padding = Padding {
+    top: 4.0,
+    right: 10.0,
+    bottom: 4.0,
+    left: 10.0,
+};
+
+/// The height of a separator.
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// The space between the label of an item and its shortcut.
+const GAP: f32 = 24.0;
+
+/// The minimum width of a menu.
+const MIN_WIDTH: f32 = 120.0;
+
+/// An entry of a menu.
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    label: String,
+    mnemonic: Option<usize>,
+    shortcut: Option<String>,
+    accelerator: Option<Shortcut>,
+    mark: Option<Mark>,
+    kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Button(Option<Message>),
+    Submenu(Vec<Item<Message>>),
+    Separator,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    Check(bool),
+    Radio(bool),
+}
+
+impl<Message> Item<Message> {
+    fn new(label: impl Into<String>, kind: Kind<Message>) -> Self {
+        let (label, mnemonic) = parse_mnemonic(&label.into());
+
+        Self {
+            label,
+            mnemonic,
+            shortcut: None,
+            accelerator: None,
+            mark: None,
+            kind,
+        }
+    }
+
+    /// Creates a new button [`Item`] with the given label.
+    ///
+    /// The [`Item`] is disabled until [`Item::on_press`] is called.
+    pub fn button(label: impl Into<String>) -> Self {
+        Self::new(label, Kind::Button(None))
+    }
+
+    /// Creates a new button [`Item`] with the given label that displays
+    /// a checkmark when checked.
+    pub fn checkbox(label: impl Into<String>, is_checked: bool) -> Self {
+        Self {
+            mark: Some(Mark::Check(is_checked)),
+            ..Self::button(label)
+        }
+    }
+
+    /// Creates a new button [`Item`] with the given label that displays
+    /// a radio indicator, filled when selected.
+    pub fn radio(label: impl Into<String>, is_selected: bool) -> Self {
+        Self {
+            mark: Some(Mark::Radio(is_selected)),
+            ..Self::button(label)
+        }
+    }
+
+    /// Creates a new [`Item`] with the given label that opens a nested menu
+    /// with the given items.
+    pub fn submenu(
+        label: impl Into<String>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self::new(label, Kind::Submenu(items.into_iter().collect()))
+    }
+
+    /// Creates a new separator [`Item`].
+    pub fn separator() -> Self {
+        Self::new("", Kind::Separator)
+    }
+
+    /// Sets the message that will be produced when a button [`Item`] is
+    /// pressed.
+    pub fn on_press(mut self, message: Message) -> Self {
+        if let Kind::Button(on_press) = &mut self.kind {
+            *on_press = Some(message);
+        }
+
+        self
+    }
+
+    /// Sets the message that will be produced when a button [`Item`] is
+    /// pressed, if `Some`.
+    ///
+    /// If `None`, the [`Item`] will be disabled.
+    pub fn on_press_maybe(mut self, message: Option<Message>) -> Self {
+        if let Kind::Button(on_press) = &mut self.kind {
+            *on_press = message;
+        }
+
+        self
+    }
+
+    /// Sets the label of the keyboard shortcut of the [`Item`], which is
+    /// displayed next to its label.
+    ///
+    /// The label is merely descriptive; use [`Item::accelerator`] to
+    /// have the shortcut handled for you.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Sets the [`Shortcut`] that triggers the [`Item`], which is also
+    /// displayed next to its label unless [`Item::shortcut`] is set.
+    ///
+    /// Accelerators are handled by a [`MenuBar`], even while all of its
+    /// menus are closed.
+    ///
+    /// [`MenuBar`]: crate::MenuBar
+    pub fn accelerator(mut self, shortcut: Shortcut) -> Self {
+        if self.shortcut.is_none() {
+            self.shortcut = Some(shortcut.to_string());
+        }
+
+        self.accelerator = Some(shortcut);
+        self
+    }
+
+    /// Returns the label of the [`Item`], without its mnemonic marker.
+    pub(crate) fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the byte index of the mnemonic in the label of the [`Item`].
+    pub(crate) fn mnemonic(&self) -> Option<usize> {
+        self.mnemonic
+    }
+
+    /// Returns true if the mnemonic of the [`Item`] is the given text,
+    /// ignoring case.
+    pub(crate) fn has_mnemonic(&self, text: &str) -> bool {
+        self.mnemonic
+            .and_then(|index| self.label[index..].chars().next())
+            .is_some_and(|mnemonic| {
+                mnemonic
+                    .to_lowercase()
+                    .eq(text.chars().flat_map(char::to_lowercase))
+            })
+    }
+
+    /// Returns the nested items of a submenu [`Item`].
+    pub(crate) fn items(&self) -> &[Item<Message>] {
+        match &self.kind {
+            Kind::Submenu(items) => items,
+            Kind::Button(_) | Kind::Separator => &[],
+        }
+    }
+
+    /// Returns the message produced by an enabled button [`Item`].
+    pub(crate) fn on_press_message(&self) -> Option<&Message> {
+        match &self.kind {
+            Kind::Button(on_press) => on_press.as_ref(),
+            Kind::Submenu(_) | Kind::Separator => None,
+        }
+    }
+
+    /// Returns true if the [`Item`] can be highlighted and activated.
+    pub(crate) fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Button(on_press) => on_press.is_some(),
+            Kind::Submenu(items) => !items.is_empty(),
+            Kind::Separator => false,
+        }
+    }
+
+    fn is_submenu(&self) -> bool {
+        matches!(self.kind, Kind::Submenu(_))
+    }
+}
+
+/// Finds the message of the enabled item, nested anywhere in the given
+/// items, whose accelerator matches the given key and modifiers.
+pub(crate) fn find_accelerator<'a, Message>(
+    items: &'a [Item<Message>],
+    key: &keyboard::Key,
+    modifiers: keyboard::Modifiers,
+) -> Option<&'a Message> {
+    items.iter().find_map(|item| match &item.kind {
+        Kind::Button(Some(on_press))
+            if item
+                .accelerator
+                .as_ref()
+                .is_some_and(|shortcut| shortcut.matches(key, modifiers)) =>
+        {
+            Some(on_press)
+        }
+        Kind::Submenu(items) => find_accelerator(items, key, modifiers),
+        Kind::Button(_) | Kind::Separator => None,
+    })
+}
+
+/// Removes the mnemonic markers of a label, returning the byte index of
+/// its mnemonic, if any.
+fn parse_mnemonic(label: &str) -> (String, Option<usize>) {
+    let mut parsed = String::with_capacity(label.len());
+    let mut mnemonic = None;
+    let mut chars = label.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '&' {
+            parsed.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('&') => parsed.push('&'),
+            Some(c) => {
+                if mnemonic.is_none() {
+                    mnemonic = Some(parsed.len());
+                }
+
+                parsed.push(c);
+            }
+            None => {}
+        }
+    }
+
+    (parsed, mnemonic)
+}
+
+/// A keyboard shortcut, like `Ctrl+S`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The [`keyboard::Modifiers`] that must be pressed.
+    pub modifiers: keyboard::Modifiers,
+    /// The [`keyboard::Key`] that must be pressed.
+    pub key: keyboard::Key,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] with the given modifiers and key.
+    pub fn new(
+        modifiers: keyboard::Modifiers,
+        key: impl Into<keyboard::Key>,
+    ) -> Self {
+        Self {
+            modifiers,
+            key: key.into(),
+        }
+    }
+
+    /// Creates a new [`Shortcut`] with the given modifiers and character.
+    pub fn character(modifiers: keyboard::Modifiers, character: char) -> Self {
+        Self::new(
+            modifiers,
+            keyboard::Key::Character(SmolStr::from(
+                character.to_lowercase().collect::<String>(),
+            )),
+        )
+    }
+
+    /// Returns true if the [`Shortcut`] is triggered by the given key and
+    /// modifiers.
+    ///
+    /// Characters are compared ignoring case.
+    pub fn matches(
+        &self,
+        key: &keyboard::Key,
+        modifiers: keyboard::Modifiers,
+    ) -> bool {
+        self.modifiers == modifiers
+            && match (&self.key, key) {
+                (keyboard::Key::Character(a), keyboard::Key::Character(b)) => {
+                    a.to_lowercase() == b.to_lowercase()
+                }
+                (a, b) => a == b,
+            }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifiers = [
+            (keyboard::Modifiers::CTRL, "Ctrl"),
+            (keyboard::Modifiers::ALT, "Alt"),
+            (keyboard::Modifiers::SHIFT, "Shift"),
+            (
+                keyboard::Modifiers::LOGO,
+                if cfg!(target_os = "macos") {
+                    "Cmd"
+                } else {
+                    "Super"
+                },
+            ),
+        ];
+
+        for (modifier, name) in modifiers {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        match &self.key {
+            keyboard::Key::Named(named) => write!(f, "{named:?}"),
+            keyboard::Key::Character(c) => write!(f, "{}", c.to_uppercase()),
+            keyboard::Key::Unidentified => write!(f, "?"),
+        }
+    }
+}
+
+/// The open menus of some widget.
+///
+/// Every entry of the stack is an open menu, together with its
+/// highlighted item. Every menu after the first one is the submenu of the
+/// highlighted item of the previous menu.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct State {
+    stack: Vec<Option<usize>>,
+}
+
+impl State {
+    pub fn is_open(&self) -> bool {
+        !self.stack.is_empty()
+    }
+
+    pub fn open(&mut self) {
+        self.stack = vec![None];
+    }
+
+    pub fn open_with_first<Message>(&mut self, items: &[Item<Message>]) {
+        self.stack = vec![items.iter().position(Item::is_enabled)];
+    }
+
+    pub fn close(&mut self) {
+        self.stack.clear();
+    }
+}
+
+/// The overlay of some open menus.
+pub(crate) struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub state: &'b mut State,
+    pub items: &'b [Item<Message>],
+    /// The absolute bounds the root menu is attached to.
+    pub origin: Rectangle,
+    pub font: Option<Renderer::Font>,
+    pub text_size: Option<Pixels>,
+    pub class: &'b <Theme as Catalog>::Class<'a>,
+}
+
+impl<'b, Message, Theme, Renderer> Overlay<'_, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the items of the open menu at the given level.
+    fn menu(&self, level: usize) -> &'b [Item<Message>] {
+        let mut items = self.items;
+
+        for &index in &self.state.stack[..level] {
+            match index.and_then(|index| items.get(index)) {
+                Some(Item {
+                    kind: Kind::Submenu(children),
+                    ..
+                }) => {
+                    items = children;
+                }
+                _ => return &[],
+            }
+        }
+
+        items
+    }
+
+    /// Returns the level and the index of the item under the cursor.
+    fn item_at(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<(usize, usize)> {
+        let position = cursor.position()?;
+
+        layout
+            .children()
+            .enumerate()
+            .rev()
+            .find(|(_, menu)| menu.bounds().contains(position))
+            .and_then(|(level, menu)| {
+                menu.children()
+                    .position(|item| item.bounds().contains(position))
+                    .map(|index| (level, index))
+            })
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> bool {
+        layout.children().any(|menu| cursor.is_over(menu.bounds()))
+    }
+
+    /// Highlights the given item, opening its submenu if it has one.
+    fn highlight(
+        &mut self,
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(item) = self.menu(level).get(index) else {
+            return;
+        };
+
+        let highlighted = item.is_enabled().then_some(index);
+
+        if self.state.stack[level] == highlighted
+            && (self.state.stack.len() > level + 1 || !item.is_submenu())
+        {
+            return;
+        }
+
+        self.state.stack.truncate(level + 1);
+        self.state.stack[level] = highlighted;
+
+        if highlighted.is_some() && item.is_submenu() {
+            self.state.stack.push(None);
+        }
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    /// Activates the given item, producing its message or opening its
+    /// submenu.
+    fn activate(
+        &mut self,
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) where
+        Message: Clone,
+    {
+        let Some(item) = self.menu(level).get(index) else {
+            return;
+        };
+
+        match &item.kind {
+            Kind::Button(Some(on_press)) => {
+                shell.publish(on_press.clone());
+
+                self.state.close();
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+            Kind::Submenu(items) => {
+                self.highlight(level, index, shell);
+
+                if let Some(first) = self.state.stack.last_mut() {
+                    *first = items.iter().position(Item::is_enabled);
+                }
+            }
+            Kind::Button(None) | Kind::Separator => {}
+        }
+    }
+
+    /// Moves the highlighted item of the deepest open menu.
+    fn step(&mut self, forward: bool, shell: &mut Shell<'_, Message>) {
+        let level = self.state.stack.len() - 1;
+        let items = self.menu(level);
+        let count = items.len();
+
+        let mut index = self.state.stack[level];
+
+        for _ in 0..count {
+            let next = match index {
+                None if forward => 0,
+                None => count - 1,
+                Some(index) if forward => (index + 1) % count,
+                Some(index) => (index + count - 1) % count,
+            };
+
+            index = Some(next);
+
+            if items[next].is_enabled() {
+                self.state.stack.truncate(level + 1);
+                self.state.stack[level] = index;
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                return;
+            }
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let item_height =
+            text::LineHeight::default().to_absolute(size).0 + ITEM_PADDING.y();
+
+        let measure = |content: &str| {
+            Renderer::Paragraph::with_text(Text {
+                content,
+                bounds: Size::INFINITE,
+                size,
+                line_height: text::LineHeight::default(),
+                font,
+                align_x: text::Alignment::Default,
+                align_y: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            })
+            .min_width()
+        };
+
+        let mut anchor = self.origin;
+        let mut menus = Vec::with_capacity(self.state.stack.len());
+
+        for level in 0..self.state.stack.len() {
+            let items = self.menu(level);
+
+            let label = items
+                .iter()
+                .map(|item| measure(&item.label))
+                .fold(0.0, f32::max);
+
+            let shortcut = items
+                .iter()
+                .filter_map(|item| item.shortcut.as_deref())
+                .map(measure)
+                .fold(0.0, f32::max);
+
+            let has_submenu = items.iter().any(Item::is_submenu);
+
+            let item_width = (ITEM_PADDING.x()
+                + mark_width(items, size)
+                + label
+                + if shortcut > 0.0 { GAP + shortcut } else { 0.0 }
+                + if has_submenu { GAP / 2.0 + size.0 } else { 0.0 })
+            .max(MIN_WIDTH);
+
+            let mut y = MENU_PADDING;
+
+            let nodes: Vec<_> = items
+                .iter()
+                .map(|item| {
+                    let height = if let Kind::Separator = item.kind {
+                        SEPARATOR_HEIGHT
+                    } else {
+                        item_height
+                    };
+
+                    let node = layout::Node::new(Size::new(item_width, height))
+                        .move_to(Point::new(MENU_PADDING, y));
+
+                    y += height;
+                    node
+                })
+                .collect();
+
+            let menu_size =
+                Size::new(item_width + MENU_PADDING * 2.0, y + MENU_PADDING);
+
+            let (x, y) = if level == 0 {
+                let x = if anchor.x + menu_size.width > bounds.width {
+                    anchor.x + anchor.width - menu_size.width
+                } else {
+                    anchor.x
+                };
+
+                let y = if anchor.y + anchor.height + menu_size.height
+                    > bounds.height
+                {
+                    anchor.y - menu_size.height
+                } else {
+                    anchor.y + anchor.height
+                };
+
+                (x, y)
+            } else {
+                let x = if anchor.x + anchor.width + menu_size.width
+                    > bounds.width
+                {
+                    anchor.x - menu_size.width
+                } else {
+                    anchor.x + anchor.width
+                };
+
+                (x, anchor.y - MENU_PADDING)
+            };
+
+            let position = Point::new(
+                x.min(bounds.width - menu_size.width).max(0.0),
+                y.min(bounds.height - menu_size.height).max(0.0),
+            );
+
+            if let Some(item) =
+                self.state.stack[level].and_then(|i| nodes.get(i))
+            {
+                anchor = Rectangle {
+                    x: position.x,
+                    y: position.y + item.bounds().y,
+                    width: menu_size.width,
+                    height: item.bounds().height,
+                };
+            }
+
+            menus.push(
+                layout::Node::with_children(menu_size, nodes).move_to(position),
+            );
+        }
+
+        layout::Node::with_children(bounds, menus)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if !self.state.is_open() {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((level, index)) = self.item_at(layout, cursor) {
+                    self.highlight(level, index, shell);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                if let Some((level, index)) = self.item_at(layout, cursor) {
+                    self.activate(level, index, shell);
+                    shell.capture_event();
+                } else if self.is_over(layout, cursor) {
+                    shell.capture_event();
+                } else if cursor.is_over(self.origin) {
+                    self.state.close();
+
+                    shell.capture_event();
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                } else {
+                    self.state.close();
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => {
+                let level = self.state.stack.len() - 1;
+                let highlighted = self.state.stack[level];
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowDown) => {
+                        self.step(true, shell);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowUp) => {
+                        self.step(false, shell);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowRight)
+                        if let Some(index) = highlighted
+                            && self
+                                .menu(level)
+                                .get(index)
+                                .is_some_and(Item::is_submenu) =>
+                    {
+                        self.activate(level, index, shell);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowLeft)
+                        if level > 0 =>
+                    {
+                        let _ = self.state.stack.pop();
+
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                    }
+                    keyboard::Key::Named(
+                        key::Named::Enter | key::Named::Space,
+                    ) => {
+                        if let Some(index) = highlighted {
+                            self.activate(level, index, shell);
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        if level > 0 {
+                            let _ = self.state.stack.pop();
+                        } else {
+                            self.state.close();
+                        }
+
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                    }
+                    keyboard::Key::Character(c)
+                        if !modifiers.control()
+                            && !modifiers.alt()
+                            && !modifiers.logo() =>
+                    {
+                        let Some(index) =
+                            self.menu(level).iter().position(|item| {
+                                item.is_enabled() && item.has_mnemonic(c)
+                            })
+                        else {
+                            return;
+                        };
+
+                        self.activate(level, index, shell);
+                    }
+                    _ => return,
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_over(layout, cursor) {
+            mouse::Interaction::Idle
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = <Theme as Catalog>::style(theme, self.class);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        for (level, menu) in layout.children().enumerate() {
+            let bounds = menu.bounds();
+            let items = self.menu(level);
+            let mark_width = mark_width(items, size);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            for ((index, item), layout) in
+                items.iter().enumerate().zip(menu.children())
+            {
+                let bounds = layout.bounds();
+
+                if let Kind::Separator = item.kind {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                y: bounds.center_y().floor(),
+                                height: 1.0,
+                                ..bounds
+                            },
+                            snap: true,
+                            ..renderer::Quad::default()
+                        },
+                        style.separator,
+                    );
+
+                    continue;
+                }
+
+                let is_selected = self.state.stack[level] == Some(index);
+
+                if is_selected {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: Border {
+                                radius: style.border.radius,
+                                ..Border::default()
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.selected_background,
+                    );
+                }
+
+                let text_color = if !item.is_enabled() {
+                    style.disabled_text_color
+                } else if is_selected {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                };
+
+                let text = |content: String, align_x| Text {
+                    content,
+                    bounds: bounds.shrink(ITEM_PADDING).size(),
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                };
+
+                if let Some(mark) = item.mark {
+                    draw_mark(
+                        renderer,
+                        mark,
+                        Rectangle {
+                            x: bounds.x + ITEM_PADDING.left,
+                            width: mark_width,
+                            ..bounds
+                        },
+                        size,
+                        text_color,
+                    );
+                }
+
+                let label = Point::new(
+                    bounds.x + ITEM_PADDING.left + mark_width,
+                    bounds.center_y(),
+                );
+
+                renderer.fill_text(
+                    text(item.label.clone(), text::Alignment::Left),
+                    label,
+                    text_color,
+                    bounds,
+                );
+
+                if let Some(mnemonic) = item.mnemonic {
+                    draw_mnemonic(
+                        renderer,
+                        &item.label,
+                        mnemonic,
+                        label,
+                        font,
+                        size,
+                        text_color,
+                    );
+                }
+
+                let right = bounds.x + bounds.width - ITEM_PADDING.right;
+
+                if let Some(shortcut) = &item.shortcut {
+                    renderer.fill_text(
+                        text(shortcut.clone(), text::Alignment::Right),
+                        Point::new(right, bounds.center_y()),
+                        if item.is_enabled() && !is_selected {
+                            style.shortcut_text_color
+                        } else {
+                            text_color
+                        },
+                        bounds,
+                    );
+                }
+
+                if item.is_submenu() {
+                    renderer.fill_text(
+                        Text {
+                            font: Renderer::ICON_FONT,
+                            shaping: text::Shaping::Basic,
+                            ..text(
+                                Renderer::SCROLL_RIGHT_ICON.to_string(),
+                                text::Alignment::Right,
+                            )
+                        },
+                        Point::new(right, bounds.center_y()),
+                        text_color,
+                        bounds,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns the width of the column of check and radio marks of the
+/// given items.
+fn mark_width<Message>(items: &[Item<Message>], size: Pixels) -> f32 {
+    if items.iter().any(|item| item.mark.is_some()) {
+        size.0 + ITEM_PADDING.left / 2.0
+    } else {
+        0.0
+    }
+}
+
+/// Draws a check or radio mark, centered vertically in the given bounds.
+fn draw_mark<Renderer>(
+    renderer: &mut Renderer,
+    mark: Mark,
+    bounds: Rectangle,
+    size: Pixels,
+    color: Color,
+) where
+    Renderer: text::Renderer,
+{
+    let center = Point::new(bounds.x + size.0 / 2.0, bounds.center_y());
+
+    match mark {
+        Mark::Check(is_checked) => {
+            if is_checked {
+                renderer.fill_text(
+                    Text {
+                        content: Renderer::CHECKMARK_ICON.to_string(),
+                        bounds: Size::new(size.0, bounds.height),
+                        size,
+                        line_height: text::LineHeight::default(),
+                        font: Renderer::ICON_FONT,
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                    },
+                    center,
+                    color,
+                    bounds,
+                );
+            }
+        }
+        Mark::Radio(is_selected) => {
+            let radius = size.0 / 3.0;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x - radius,
+                        y: center.y - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    },
+                    border: Border {
+                        radius: radius.into(),
+                        width: 1.0,
+                        color,
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+
+            if is_selected {
+                let radius = radius / 2.0;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center.x - radius,
+                            y: center.y - radius,
+                            width: radius * 2.0,
+                            height: radius * 2.0,
+                        },
+                        border: Border {
+                            radius: radius.into(),
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+        }
+    }
+}
+
+/// Underlines the mnemonic at the given byte index of a label drawn
+/// left-aligned and vertically centered at the given position.
+pub(crate) fn draw_mnemonic<Renderer>(
+    renderer: &mut Renderer,
+    label: &str,
+    mnemonic: usize,
+    position: Point,
+    font: Renderer::Font,
+    size: Pixels,
+    color: Color,
+) where
+    Renderer: text::Renderer,
+{
+    let end = label[mnemonic..]
+        .chars()
+        .next()
+        .map_or(mnemonic, |c| mnemonic + c.len_utf8());
+
+    let measure = |content: &str| {
+        Renderer::Paragraph::with_text(Text {
+            content,
+            bounds: Size::INFINITE,
+            size,
+            line_height: text::LineHeight::default(),
+            font,
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        })
+        .min_width()
+    };
+
+    let start_x = measure(&label[..mnemonic]);
+    let end_x = measure(&label[..end]);
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: position.x + start_x,
+                y: (position.y + size.0 / 2.0).round(),
+                width: end_x - start_x,
+                height: 1.0,
+            },
+            snap: true,
+            ..renderer::Quad::default()
+        },
+        color,
+    );
+}
@@ -1329,6 +1329,7 @@ pub fn default(theme: &Theme) -> Style {
                 width: 2.0,
                 color: palette.primary.strong.color,
                 radius: 0.0.into(),
+                ..Border::default()
             },
         },
         hovered_split: Line {
@@ -60,8 +60,14 @@
 //!     }
 //! }
 //! ```
+pub mod multi;
+
+pub use multi::MultiPickList;
+
+use crate::combo_box;
 use crate::core::alignment;
 use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
@@ -162,6 +168,8 @@ pub struct PickList<
     on_close: Option<Message>,
     options: L,
     placeholder: Option<String>,
+    search: Option<String>,
+    filtered: Vec<T>,
     selected: Option<V>,
     width: Length,
     padding: Padding,
@@ -199,6 +207,8 @@ where
             on_close: None,
             options,
             placeholder: None,
+            search: None,
+            filtered: Vec::new(),
             selected,
             width: Length::Shrink,
             padding: crate::button::DEFAULT_PADDING,
@@ -220,6 +230,16 @@ where
         self
     }
 
+    /// Makes the [`PickList`] searchable.
+    ///
+    /// While open, typing filters the options of the [`Menu`]. The given
+    /// placeholder is displayed on top of the options until the user
+    /// starts typing.
+    pub fn searchable(mut self, placeholder: impl Into<String>) -> Self {
+        self.search = Some(placeholder.into());
+        self
+    }
+
     /// Sets the width of the [`PickList`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -368,6 +388,7 @@ where
         let options = self.options.borrow();
 
         state.options.resize_with(options.len(), Default::default);
+        state.matchers.clear();
 
         let option_text = Text {
             content: "",
@@ -392,8 +413,14 @@ where
                 content: &label,
                 ..option_text
             });
+
+            if self.search.is_some() {
+                state.matchers.push(combo_box::build_matcher(label));
+            }
         }
 
+        state.filter();
+
         if let Some(placeholder) = &self.placeholder {
             let _ = state.placeholder.update(Text {
                 content: placeholder,
@@ -470,6 +497,9 @@ where
                         .iter()
                         .position(|option| Some(option) == selected);
 
+                    state.query.clear();
+                    state.filter();
+
                     if let Some(on_open) = &self.on_open {
                         shell.publish(on_open.clone());
                     }
@@ -519,6 +549,46 @@ where
                     shell.capture_event();
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                text,
+                modifiers,
+                ..
+            }) if state.is_open => match key {
+                keyboard::Key::Named(key::Named::Escape) => {
+                    state.is_open = false;
+
+                    if let Some(on_close) = &self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+
+                    shell.capture_event();
+                }
+                keyboard::Key::Named(key::Named::Enter) => {
+                    if let Some(option) = state
+                        .hovered_option
+                        .and_then(|index| state.filtered.get(index))
+                        .and_then(|index| self.options.borrow().get(*index))
+                    {
+                        state.is_open = false;
+
+                        shell.publish((self.on_select)(option.clone()));
+                    }
+
+                    shell.capture_event();
+                }
+                _ if self.search.is_some()
+                    && state.type_query(key, text.as_deref(), *modifiers) =>
+                {
+                    state.hovered_option =
+                        (!state.filtered.is_empty()).then_some(0);
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+                _ => {}
+            },
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 state.keyboard_modifiers = *modifiers;
             }
@@ -596,69 +666,15 @@ where
             style.background,
         );
 
-        let handle = match &self.handle {
-            Handle::Arrow { size } => Some((
-                Renderer::ICON_FONT,
-                Renderer::ARROW_DOWN_ICON,
-                *size,
-                text::LineHeight::default(),
-                text::Shaping::Basic,
-            )),
-            Handle::Static(Icon {
-                font,
-                code_point,
-                size,
-                line_height,
-                shaping,
-            }) => Some((*font, *code_point, *size, *line_height, *shaping)),
-            Handle::Dynamic { open, closed } => {
-                if state.is_open {
-                    Some((
-                        open.font,
-                        open.code_point,
-                        open.size,
-                        open.line_height,
-                        open.shaping,
-                    ))
-                } else {
-                    Some((
-                        closed.font,
-                        closed.code_point,
-                        closed.size,
-                        closed.line_height,
-                        closed.shaping,
-                    ))
-                }
-            }
-            Handle::None => None,
-        };
-
-        if let Some((font, code_point, size, line_height, shaping)) = handle {
-            let size = size.unwrap_or_else(|| renderer.default_size());
-
-            renderer.fill_text(
-                Text {
-                    content: code_point.to_string(),
-                    size,
-                    line_height,
-                    font,
-                    bounds: Size::new(
-                        bounds.width,
-                        f32::from(line_height.to_absolute(size)),
-                    ),
-                    align_x: text::Alignment::Right,
-                    align_y: alignment::Vertical::Center,
-                    shaping,
-                    wrapping: text::Wrapping::default(),
-                },
-                Point::new(
-                    bounds.x + bounds.width - self.padding.right,
-                    bounds.center_y(),
-                ),
-                style.handle_color,
-                *viewport,
-            );
-        }
+        draw_handle(
+            renderer,
+            &self.handle,
+            state.is_open,
+            bounds,
+            self.padding,
+            style.handle_color,
+            viewport,
+        );
 
         let label = selected.map(ToString::to_string);
 
@@ -708,9 +724,23 @@ where
 
             let on_select = &self.on_select;
 
+            let options = if state.query.is_empty() {
+                self.options.borrow()
+            } else {
+                let options = self.options.borrow();
+
+                self.filtered = state
+                    .filtered
+                    .iter()
+                    .filter_map(|index| options.get(*index).cloned())
+                    .collect();
+
+                &self.filtered
+            };
+
             let mut menu = Menu::new(
                 &mut state.menu,
-                self.options.borrow(),
+                options,
                 &mut state.hovered_option,
                 |option| {
                     state.is_open = false;
@@ -729,6 +759,10 @@ where
                 menu = menu.text_size(text_size);
             }
 
+            if let Some(placeholder) = &self.search {
+                menu = menu.search(&state.query, placeholder);
+            }
+
             Some(menu.overlay(
                 layout.position() + translation,
                 *viewport,
@@ -767,6 +801,9 @@ struct State<P: text::Paragraph> {
     hovered_option: Option<usize>,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
+    query: String,
+    matchers: Vec<String>,
+    filtered: Vec<usize>,
 }
 
 impl<P: text::Paragraph> State<P> {
@@ -779,8 +816,33 @@ impl<P: text::Paragraph> State<P> {
             hovered_option: Option::default(),
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
+            query: String::new(),
+            matchers: Vec::new(),
+            filtered: Vec::new(),
         }
     }
+
+    /// Updates the search query with the given key press, returning
+    /// whether it changed.
+    fn type_query(
+        &mut self,
+        key: &keyboard::Key,
+        text: Option<&str>,
+        modifiers: keyboard::Modifiers,
+    ) -> bool {
+        let changed = type_query(&mut self.query, key, text, modifiers);
+
+        if changed {
+            self.filter();
+        }
+
+        changed
+    }
+
+    /// Filters the options that match the current search query.
+    fn filter(&mut self) {
+        self.filtered = filter(self.options.len(), &self.matchers, &self.query);
+    }
 }
 
 impl<P: text::Paragraph> Default for State<P> {
@@ -913,6 +975,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             radius: 2.0.into(),
             width: 1.0,
             color: palette.background.strong.color,
+            ..Border::default()
         },
     };
 
@@ -927,3 +990,234 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+fn type_query(
+    query: &mut String,
+    key: &keyboard::Key,
+    text: Option<&str>,
+    modifiers: keyboard::Modifiers,
+) -> bool {
+    match key {
+        keyboard::Key::Named(key::Named::Backspace) => query.pop().is_some(),
+        _ if modifiers.command() => false,
+        _ => match text {
+            Some(text) if !text.chars().any(char::is_control) => {
+                query.push_str(text);
+                true
+            }
+            _ => false,
+        },
+    }
+}
+
+fn filter(len: usize, matchers: &[String], query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        (0..len).collect()
+    } else {
+        combo_box::search(0..len, matchers, query).collect()
+    }
+}
+
+fn draw_handle<Renderer>(
+    renderer: &mut Renderer,
+    handle: &Handle<Renderer::Font>,
+    is_open: bool,
+    bounds: Rectangle,
+    padding: Padding,
+    color: Color,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let handle = match handle {
+        Handle::Arrow { size } => Some((
+            Renderer::ICON_FONT,
+            Renderer::ARROW_DOWN_ICON,
+            *size,
+            text::LineHeight::default(),
+            text::Shaping::Basic,
+        )),
+        Handle::Static(Icon {
+            font,
+            code_point,
+            size,
+            line_height,
+            shaping,
+        }) => Some((*font, *code_point, *size, *line_height, *shaping)),
+        Handle::Dynamic { open, closed } => {
+            if is_open {
+                Some((
+                    open.font,
+                    open.code_point,
+                    open.size,
+                    open.line_height,
+                    open.shaping,
+                ))
+            } else {
+                Some((
+                    closed.font,
+                    closed.code_point,
+                    closed.size,
+                    closed.line_height,
+                    closed.shaping,
+                ))
+            }
+        }
+        Handle::None => None,
+    };
+
+    if let Some((font, code_point, size, line_height, shaping)) = handle {
+        let size = size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_text(
+            Text {
+                content: code_point.to_string(),
+                size,
+                line_height,
+                font,
+                bounds: Size::new(
+                    bounds.width,
+                    f32::from(line_height.to_absolute(size)),
+                ),
+                align_x: text::Alignment::Right,
+                align_y: alignment::Vertical::Center,
+                shaping,
+                wrapping: text::Wrapping::default(),
+            },
+            Point::new(
+                bounds.x + bounds.width - padding.right,
+                bounds.center_y(),
+            ),
+            color,
+            *viewport,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::Harness;
+
+    const FRUITS: &[&str] = &["Apple", "Banana", "Blueberry", "Cherry"];
+
+    /// A point over the [`PickList`] itself, which toggles its menu.
+    const TOGGLE: Point = Point::new(5.0, 5.0);
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Select(&'static str),
+        Open,
+        Close,
+    }
+
+    type Fruits = PickList<
+        'static,
+        &'static str,
+        &'static [&'static str],
+        &'static str,
+        Message,
+    >;
+
+    fn harness(pick_list: Fruits) -> Harness<'static, Message> {
+        Harness::new(pick_list.on_open(Message::Open).on_close(Message::Close))
+    }
+
+    fn searchable() -> Harness<'static, Message> {
+        harness(
+            PickList::new(FRUITS, None, Message::Select)
+                .searchable("Search..."),
+        )
+    }
+
+    #[test]
+    fn typing_filters_the_options() {
+        let mut harness = searchable();
+
+        assert_eq!(harness.click(TOGGLE), vec![Message::Open]);
+        assert!(harness.is_open());
+
+        assert!(harness.type_text("b").is_empty());
+        assert_eq!(
+            harness.press(key::Named::Enter),
+            vec![Message::Select("Banana")]
+        );
+
+        let _ = harness.click(TOGGLE);
+        let _ = harness.type_text("blue");
+
+        for _ in 0..3 {
+            let _ = harness.press(key::Named::Backspace);
+        }
+
+        assert_eq!(
+            harness.press(key::Named::Enter),
+            vec![Message::Select("Banana")]
+        );
+
+        let _ = harness.click(TOGGLE);
+        let _ = harness.type_text("bx");
+
+        assert!(harness.press(key::Named::Enter).is_empty());
+        assert!(harness.is_open());
+    }
+
+    #[test]
+    fn enter_selects_the_first_match() {
+        let mut harness = searchable();
+
+        let _ = harness.click(TOGGLE);
+        let _ = harness.type_text("blue");
+
+        assert_eq!(
+            harness.press(key::Named::Enter),
+            vec![Message::Select("Blueberry")]
+        );
+        assert!(!harness.is_open());
+    }
+
+    #[test]
+    fn reopening_clears_the_query() {
+        let mut harness = searchable();
+
+        let _ = harness.click(TOGGLE);
+        let _ = harness.type_text("cher");
+
+        assert_eq!(harness.press(key::Named::Escape), vec![Message::Close]);
+        assert!(!harness.is_open());
+
+        assert_eq!(harness.click(TOGGLE), vec![Message::Open]);
+        let _ = harness.type_text("b");
+
+        assert_eq!(
+            harness.press(key::Named::Enter),
+            vec![Message::Select("Banana")]
+        );
+    }
+
+    #[test]
+    fn escape_and_enter_work_without_search() {
+        let mut harness =
+            harness(PickList::new(FRUITS, Some("Banana"), Message::Select));
+
+        // Keys are ignored while closed
+        assert!(harness.press(key::Named::Enter).is_empty());
+        assert!(harness.press(key::Named::Escape).is_empty());
+        assert!(!harness.is_open());
+
+        let _ = harness.click(TOGGLE);
+
+        // Typing does not filter, and the selected option is hovered
+        // when opened
+        assert!(harness.type_text("ch").is_empty());
+        assert_eq!(
+            harness.press(key::Named::Enter),
+            vec![Message::Select("Banana")]
+        );
+
+        let _ = harness.click(TOGGLE);
+
+        assert_eq!(harness.press(key::Named::Escape), vec![Message::Close]);
+        assert!(!harness.is_open());
+    }
+}
@@ -0,0 +1,1023 @@
+//! Multi pick lists display a dropdown list of options where many of them
+//! can be selected at once.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::multi_pick_list;
+//!
+//! struct State {
+//!    tags: Vec<String>,
+//!    selected: Vec<String>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     TagsChanged(Vec<String>),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     multi_pick_list(
+//!         state.tags.as_slice(),
+//!         state.selected.as_slice(),
+//!         Message::TagsChanged,
+//!     )
+//!     .placeholder("Filter by tag...")
+//!     .select_all("Select all")
+//!     .searchable("Type to filter...")
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::TagsChanged(selected) => {
+//!             state.selected = selected;
+//!         }
+//!     }
+//! }
+//! ```
+use super::{
+    Catalog, Handle, Status, Style, StyleFn, draw_handle, filter, type_query,
+};
+use crate::combo_box;
+use crate::core::alignment;
+use crate::core::border;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::paragraph;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::overlay::menu::{self, Menu};
+
+use std::borrow::Borrow;
+use std::f32;
+use std::fmt;
+
+/// A widget for selecting multiple values from a list of options.
+///
+/// The selected values are displayed as chips, which can be removed
+/// individually. The options of its [`Menu`] display a checkmark when
+/// selected and, optionally, can be filtered by typing.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::multi_pick_list;
+///
+/// struct State {
+///    toppings: Vec<Topping>,
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Topping {
+///     Cheese,
+///     Mushrooms,
+///     Olives,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ToppingsChanged(Vec<Topping>),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     multi_pick_list(
+///         [Topping::Cheese, Topping::Mushrooms, Topping::Olives],
+///         state.toppings.as_slice(),
+///         Message::ToppingsChanged,
+///     )
+///     .placeholder("Choose your toppings...")
+///     .into()
+/// }
+///
+/// impl std::fmt::Display for Topping {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str(match self {
+///             Self::Cheese => "Cheese",
+///             Self::Mushrooms => "Mushrooms",
+///             Self::Olives => "Olives",
+///         })
+///     }
+/// }
+/// ```
+pub struct MultiPickList<
+    'a,
+    T,
+    L,
+    V,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    T: ToString + PartialEq + Clone,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<[T]> + 'a,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    on_change: Box<dyn Fn(Vec<T>) -> Message + 'a>,
+    on_open: Option<Message>,
+    on_close: Option<Message>,
+    options: L,
+    selected: V,
+    placeholder: Option<String>,
+    select_all: Option<String>,
+    search: Option<String>,
+    entries: Vec<Entry<T>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    handle: Handle<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+    last_status: Option<Status>,
+    menu_height: Length,
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer>
+    MultiPickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<[T]> + 'a,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`MultiPickList`] with the given list of options, the
+    /// current selected values, and the message to produce when the
+    /// selection changes.
+    ///
+    /// The `on_change` closure receives the whole new selection.
+    pub fn new(
+        options: L,
+        selected: V,
+        on_change: impl Fn(Vec<T>) -> Message + 'a,
+    ) -> Self {
+        Self {
+            on_change: Box::new(on_change),
+            on_open: None,
+            on_close: None,
+            options,
+            selected,
+            placeholder: None,
+            select_all: None,
+            search: None,
+            entries: Vec::new(),
+            width: Length::Shrink,
+            padding: crate::button::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::default(),
+            font: None,
+            handle: Handle::default(),
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+            last_status: None,
+            menu_height: Length::Shrink,
+        }
+    }
+
+    /// Sets the placeholder of the [`MultiPickList`], displayed when no
+    /// values are selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Adds an option with the given label on top of the [`Menu`] that
+    /// selects all the options; or deselects them, if they are all
+    /// selected already.
+    ///
+    /// If the [`MultiPickList`] is being searched, only the matching
+    /// options are affected.
+    pub fn select_all(mut self, label: impl Into<String>) -> Self {
+        self.select_all = Some(label.into());
+        self
+    }
+
+    /// Makes the [`MultiPickList`] searchable.
+    ///
+    /// While open, typing filters the options of the [`Menu`]. The given
+    /// placeholder is displayed on top of the options until the user
+    /// starts typing.
+    pub fn searchable(mut self, placeholder: impl Into<String>) -> Self {
+        self.search = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`MultiPickList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Menu`].
+    pub fn menu_height(mut self, menu_height: impl Into<Length>) -> Self {
+        self.menu_height = menu_height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`MultiPickList`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MultiPickList`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`MultiPickList`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`MultiPickList`].
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the [`MultiPickList`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Handle`] of the [`MultiPickList`].
+    pub fn handle(mut self, handle: Handle<Renderer::Font>) -> Self {
+        self.handle = handle;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`MultiPickList`]
+    /// is opened.
+    pub fn on_open(mut self, on_open: Message) -> Self {
+        self.on_open = Some(on_open);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`MultiPickList`]
+    /// is closed.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Sets the style of the [`MultiPickList`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the [`Menu`].
+    #[must_use]
+    pub fn menu_style(
+        mut self,
+        style: impl Fn(&Theme) -> menu::Style + 'a,
+    ) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MultiPickList`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the [`Menu`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(
+        mut self,
+        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    fn entry(&self, filtered: &[usize], index: usize) -> Option<Entry<T>> {
+        match (&self.select_all, index) {
+            (Some(label), 0) => Some(Entry::All(label.clone())),
+            (Some(_), index) => self.option(filtered, index - 1),
+            (None, index) => self.option(filtered, index),
+        }
+    }
+
+    fn option(&self, filtered: &[usize], index: usize) -> Option<Entry<T>> {
+        filtered
+            .get(index)
+            .and_then(|index| self.options.borrow().get(*index))
+            .cloned()
+            .map(Entry::Option)
+    }
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MultiPickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'a,
+    L: Borrow<[T]>,
+    V: Borrow<[T]>,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height =
+            f32::from(self.text_line_height.to_absolute(text_size));
+        let options = self.options.borrow();
+        let selected = self.selected.borrow();
+
+        state.options.resize_with(options.len(), Default::default);
+        state.chips.resize_with(selected.len(), Chip::default);
+        state.matchers.clear();
+
+        let option_text = Text {
+            content: "",
+            bounds: Size::new(f32::INFINITY, line_height),
+            size: text_size,
+            line_height: self.text_line_height,
+            font,
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::default(),
+        };
+
+        for (option, paragraph) in options.iter().zip(state.options.iter_mut())
+        {
+            let label = option.to_string();
+
+            let _ = paragraph.update(Text {
+                content: &label,
+                ..option_text
+            });
+
+            if self.search.is_some() {
+                state.matchers.push(combo_box::build_matcher(label));
+            }
+        }
+
+        for (value, chip) in selected.iter().zip(state.chips.iter_mut()) {
+            let _ = chip.label.update(Text {
+                content: &value.to_string(),
+                ..option_text
+            });
+        }
+
+        if let Some(placeholder) = &self.placeholder {
+            let _ = state.placeholder.update(Text {
+                content: placeholder,
+                ..option_text
+            });
+        }
+
+        state.filter();
+
+        let chip_width = |chip: &Chip<Renderer::Paragraph>| {
+            chip.label.min_width() + CHIP_PADDING * 1.5 + line_height
+        };
+
+        let max_width = match self.width {
+            Length::Shrink => {
+                let labels_width =
+                    state.options.iter().fold(0.0, |width, paragraph| {
+                        f32::max(width, paragraph.min_width())
+                    });
+
+                let chips_width = state
+                    .chips
+                    .iter()
+                    .map(|chip| chip_width(chip) + CHIP_SPACING)
+                    .sum::<f32>();
+
+                labels_width.max(chips_width).max(
+                    self.placeholder
+                        .as_ref()
+                        .map(|_| state.placeholder.min_width())
+                        .unwrap_or(0.0),
+                )
+            }
+            _ => 0.0,
+        };
+
+        let handle_width = text_size.0 + self.padding.left;
+        let limits = limits.width(self.width).shrink(self.padding);
+
+        let width = limits
+            .resolve(
+                self.width,
+                Length::Shrink,
+                Size::new(max_width + handle_width, line_height),
+            )
+            .width;
+
+        let available = (width - handle_width).max(0.0);
+        let mut position = Point::ORIGIN;
+
+        for chip in &mut state.chips {
+            let width = chip_width(chip).min(available);
+
+            if position.x > 0.0 && position.x + width > available {
+                position =
+                    Point::new(0.0, position.y + line_height + CHIP_SPACING);
+            }
+
+            chip.bounds = Rectangle {
+                x: self.padding.left + position.x,
+                y: self.padding.top + position.y,
+                width,
+                height: line_height,
+            };
+
+            position.x += width + CHIP_SPACING;
+        }
+
+        let size = limits
+            .resolve(
+                self.width,
+                Length::Shrink,
+                Size::new(width, position.y + line_height),
+            )
+            .expand(self.padding);
+
+        layout::Node::new(size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let removed = state.chips.iter().position(|chip| {
+                    cursor.is_over(
+                        chip.close_bounds() + Vector::new(bounds.x, bounds.y),
+                    )
+                });
+
+                if let Some(removed) = removed {
+                    let selected = self.selected.borrow();
+
+                    shell.publish((self.on_change)(
+                        selected[..removed]
+                            .iter()
+                            .chain(&selected[removed + 1..])
+                            .cloned()
+                            .collect(),
+                    ));
+
+                    shell.capture_event();
+                } else if state.is_open {
+                    // Event wasn't processed by overlay, so cursor was clicked either outside its
+                    // bounds or on the field, either way we close the overlay.
+                    state.is_open = false;
+
+                    if let Some(on_close) = &self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+
+                    shell.capture_event();
+                } else if cursor.is_over(bounds) {
+                    state.is_open = true;
+                    state.hovered_option = None;
+
+                    state.query.clear();
+                    state.filter();
+
+                    if let Some(on_open) = &self.on_open {
+                        shell.publish(on_open.clone());
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                text,
+                modifiers,
+                ..
+            }) if state.is_open => match key {
+                keyboard::Key::Named(key::Named::Escape) => {
+                    state.is_open = false;
+
+                    if let Some(on_close) = &self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+
+                    shell.capture_event();
+                }
+                keyboard::Key::Named(key::Named::Enter) => {
+                    if let Some(entry) = state
+                        .hovered_option
+                        .and_then(|index| self.entry(&state.filtered, index))
+                    {
+                        shell.publish((self.on_change)(toggle(
+                            &entry,
+                            self.options.borrow(),
+                            self.selected.borrow(),
+                            &state.filtered,
+                        )));
+                    }
+
+                    shell.capture_event();
+                }
+                _ if self.search.is_some()
+                    && state.type_query(key, text.as_deref(), *modifiers) =>
+                {
+                    state.hovered_option = (!state.filtered.is_empty())
+                        .then_some(usize::from(self.select_all.is_some()));
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+                _ => {}
+            },
+            _ => {}
+        };
+
+        let status = {
+            let is_hovered = cursor.is_over(bounds);
+
+            if state.is_open {
+                Status::Opened { is_hovered }
+            } else if is_hovered {
+                Status::Hovered
+            } else {
+                Status::Active
+            }
+        };
+
+        let hovered_chip = state.chips.iter().position(|chip| {
+            cursor
+                .is_over(chip.close_bounds() + Vector::new(bounds.x, bounds.y))
+        });
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_status = Some(status);
+            state.hovered_chip = hovered_chip;
+        } else if self
+            .last_status
+            .is_some_and(|last_status| last_status != status)
+            || state.hovered_chip != hovered_chip
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let selected = self.selected.borrow();
+
+        let bounds = layout.bounds();
+
+        let style = Catalog::style(
+            theme,
+            &self.class,
+            self.last_status.unwrap_or(Status::Active),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        draw_handle(
+            renderer,
+            &self.handle,
+            state.is_open,
+            bounds,
+            self.padding,
+            style.handle_color,
+            viewport,
+        );
+
+        if state.chips.is_empty() {
+            if let Some(placeholder) = &self.placeholder {
+                renderer.fill_text(
+                    Text {
+                        content: placeholder.clone(),
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font,
+                        bounds: Size::new(
+                            bounds.width - self.padding.x(),
+                            f32::from(
+                                self.text_line_height.to_absolute(text_size),
+                            ),
+                        ),
+                        align_x: text::Alignment::Default,
+                        align_y: alignment::Vertical::Center,
+                        shaping: self.text_shaping,
+                        wrapping: text::Wrapping::default(),
+                    },
+                    Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                    style.placeholder_color,
+                    *viewport,
+                );
+            }
+
+            return;
+        }
+
+        for (value, chip) in selected.iter().zip(&state.chips) {
+            let chip_bounds = chip.bounds + Vector::new(bounds.x, bounds.y);
+            let close_bounds =
+                chip.close_bounds() + Vector::new(bounds.x, bounds.y);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: chip_bounds,
+                    border: border::rounded(chip_bounds.height / 2.0),
+                    ..renderer::Quad::default()
+                },
+                style.text_color.scale_alpha(0.1),
+            );
+
+            if let Some(clip_bounds) = chip_bounds.intersection(viewport) {
+                renderer.fill_text(
+                    Text {
+                        content: value.to_string(),
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font,
+                        bounds: Size::new(f32::INFINITY, chip_bounds.height),
+                        align_x: text::Alignment::Default,
+                        align_y: alignment::Vertical::Center,
+                        shaping: self.text_shaping,
+                        wrapping: text::Wrapping::None,
+                    },
+                    Point::new(
+                        chip_bounds.x + CHIP_PADDING,
+                        chip_bounds.center_y(),
+                    ),
+                    style.text_color,
+                    Rectangle {
+                        width: (close_bounds.x - clip_bounds.x).max(0.0),
+                        ..clip_bounds
+                    },
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: String::from("×"),
+                    size: text_size,
+                    line_height: self.text_line_height,
+                    font,
+                    bounds: close_bounds.size(),
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                close_bounds.center(),
+                if cursor.is_over(close_bounds) {
+                    style.text_color
+                } else {
+                    style.handle_color
+                },
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        if !state.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let options = self.options.borrow();
+        let selected = self.selected.borrow();
+        let filtered = &state.filtered;
+        let on_change = &self.on_change;
+
+        self.entries = self
+            .select_all
+            .iter()
+            .map(|label| Entry::All(label.clone()))
+            .chain(
+                filtered
+                    .iter()
+                    .filter_map(|index| options.get(*index).cloned())
+                    .map(Entry::Option),
+            )
+            .collect();
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            &self.entries,
+            &mut state.hovered_option,
+            move |entry| on_change(toggle(&entry, options, selected, filtered)),
+            None,
+            &self.menu_class,
+        )
+        .width(bounds.width)
+        .padding(self.padding)
+        .font(font)
+        .text_shaping(self.text_shaping)
+        .checkmarks(move |entry| {
+            is_checked(entry, options, selected, filtered)
+        });
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        if let Some(placeholder) = &self.search {
+            menu = menu.search(&state.query, placeholder);
+        }
+
+        Some(menu.overlay(
+            layout.position() + translation,
+            *viewport,
+            bounds.height,
+            self.menu_height,
+        ))
+    }
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer>
+    From<MultiPickList<'a, T, L, V, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'a,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<[T]> + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        pick_list: MultiPickList<'a, T, L, V, Message, Theme, Renderer>,
+    ) -> Self {
+        Self::new(pick_list)
+    }
+}
+
+const CHIP_PADDING: f32 = 6.0;
+const CHIP_SPACING: f32 = 4.0;
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    menu: menu::State,
+    is_open: bool,
+    hovered_option: Option<usize>,
+    hovered_chip: Option<usize>,
+    options: Vec<paragraph::Plain<P>>,
+    chips: Vec<Chip<P>>,
+    placeholder: paragraph::Plain<P>,
+    query: String,
+    matchers: Vec<String>,
+    filtered: Vec<usize>,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn new() -> Self {
+        Self {
+            menu: menu::State::default(),
+            is_open: false,
+            hovered_option: None,
+            hovered_chip: None,
+            options: Vec::new(),
+            chips: Vec::new(),
+            placeholder: paragraph::Plain::default(),
+            query: String::new(),
+            matchers: Vec::new(),
+            filtered: Vec::new(),
+        }
+    }
+
+    fn type_query(
+        &mut self,
+        key: &keyboard::Key,
+        text: Option<&str>,
+        modifiers: keyboard::Modifiers,
+    ) -> bool {
+        let changed = type_query(&mut self.query, key, text, modifiers);
+
+        if changed {
+            self.filter();
+        }
+
+        changed
+    }
+
+    fn filter(&mut self) {
+        self.filtered = filter(self.options.len(), &self.matchers, &self.query);
+    }
+}
+
+#[derive(Debug, Default)]
+struct Chip<P: text::Paragraph> {
+    label: paragraph::Plain<P>,
+    bounds: Rectangle,
+}
+
+impl<P: text::Paragraph> Chip<P> {
+    fn close_bounds(&self) -> Rectangle {
+        let size = self.bounds.height;
+
+        Rectangle {
+            x: self.bounds.x + self.bounds.width - CHIP_PADDING / 2.0 - size,
+            y: self.bounds.y,
+            width: size,
+            height: size,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Entry<T> {
+    All(String),
+    Option(T),
+}
+
+impl<T: ToString> fmt::Display for Entry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Entry::All(label) => f.write_str(label),
+            Entry::Option(option) => f.write_str(&option.to_string()),
+        }
+    }
+}
+
+fn is_checked<T: PartialEq>(
+    entry: &Entry<T>,
+    options: &[T],
+    selected: &[T],
+    filtered: &[usize],
+) -> bool {
+    match entry {
+        Entry::All(_) => {
+            !filtered.is_empty()
+                && filtered
+                    .iter()
+                    .filter_map(|index| options.get(*index))
+                    .all(|option| selected.contains(option))
+        }
+        Entry::Option(option) => selected.contains(option),
+    }
+}
+
+fn toggle<T: PartialEq + Clone>(
+    entry: &Entry<T>,
+    options: &[T],
+    selected: &[T],
+    filtered: &[usize],
+) -> Vec<T> {
+    match entry {
+        Entry::All(_) => {
+            let visible =
+                filtered.iter().filter_map(|index| options.get(*index));
+
+            if is_checked(entry, options, selected, filtered) {
+                let visible: Vec<_> = visible.collect();
+
+                selected
+                    .iter()
+                    .filter(|value| !visible.contains(value))
+                    .cloned()
+                    .collect()
+            } else {
+                selected
+                    .iter()
+                    .chain(visible.filter(|option| !selected.contains(option)))
+                    .cloned()
+                    .collect()
+            }
+        }
+        Entry::Option(option) => {
+            if selected.contains(option) {
+                selected
+                    .iter()
+                    .filter(|value| *value != option)
+                    .cloned()
+                    .collect()
+            } else {
+                selected
+                    .iter()
+                    .chain(std::iter::once(option))
+                    .cloned()
+                    .collect()
+            }
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Share the field of the [`DatePicker`] and the [`TimePicker`].
+//!
+//! [`DatePicker`]: crate::DatePicker
+//! [`TimePicker`]: crate::TimePicker
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::paragraph;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::operation;
+use crate::core::{
+    Event, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
+};
+use crate::date_picker::{Catalog, Status};
+
+/// The state of the field of a picker.
+#[derive(Debug, Default)]
+pub struct State<P: text::Paragraph> {
+    pub is_open: bool,
+    is_focused: bool,
+    label: paragraph::Plain<P>,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// Computes the layout of the field of a picker.
+#[allow(clippy::too_many_arguments)]
+pub fn layout<Renderer>(
+    state: &mut State<Renderer::Paragraph>,
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    width: Length,
+    padding: Padding,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    label: &str,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let font = font.unwrap_or_else(|| renderer.default_font());
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+    let line_height = text::LineHeight::default();
+
+    let _ = state.label.update(Text {
+        content: label,
+        bounds: Size::new(
+            f32::INFINITY,
+            line_height.to_absolute(text_size).into(),
+        ),
+        size: text_size,
+        line_height,
+        font,
+        align_x: text::Alignment::Default,
+        align_y: alignment::Vertical::Center,
+        shaping: text::Shaping::Basic,
+        wrapping: text::Wrapping::None,
+    });
+
+    let intrinsic = Size::new(
+        state.label.min_width() + text_size.0 + padding.left,
+        f32::from(line_height.to_absolute(text_size)),
+    );
+
+    let size = limits
+        .width(width)
+        .shrink(padding)
+        .resolve(width, Length::Shrink, intrinsic)
+        .expand(padding);
+
+    layout::Node::new(size)
+}
+
+/// Processes an [`Event`] on the field of a picker.
+///
+/// Returns true if the picker has been opened; in which case the state of its
+/// content should be recreated.
+pub fn update<Message, P: text::Paragraph>(
+    state: &mut State<P>,
+    event: &Event,
+    bounds: Rectangle,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+) -> bool {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            state.is_focused = cursor.is_over(bounds);
+
+            if state.is_open {
+                // The event was not captured by the overlay, so it was
+                // pressed outside of it
+                state.is_open = false;
+
+                shell.capture_event();
+                shell.request_redraw();
+            } else if state.is_focused {
+                state.is_open = true;
+
+                shell.capture_event();
+                shell.request_redraw();
+
+                return true;
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key:
+                keyboard::Key::Named(
+                    key::Named::Enter
+                    | key::Named::Space
+                    | key::Named::ArrowDown,
+                ),
+            ..
+        }) if state.is_focused && !state.is_open => {
+            state.is_open = true;
+
+            shell.capture_event();
+            shell.request_redraw();
+
+            return true;
+        }
+        _ => {}
+    }
+
+    false
+}
+
+/// Returns the [`Status`] of the field of a picker.
+pub fn status<P: text::Paragraph>(
+    state: &State<P>,
+    bounds: Rectangle,
+    cursor: mouse::Cursor,
+) -> Status {
+    let is_hovered = cursor.is_over(bounds);
+
+    if state.is_open {
+        Status::Opened { is_hovered }
+    } else if is_hovered {
+        Status::Hovered
+    } else if state.is_focused {
+        Status::Focused
+    } else {
+        Status::Active
+    }
+}
+
+/// Draws the field of a picker.
+#[allow(clippy::too_many_arguments)]
+pub fn draw<Theme, Renderer>(
+    renderer: &mut Renderer,
+    theme: &Theme,
+    class: &<Theme as Catalog>::Class<'_>,
+    status: Status,
+    bounds: Rectangle,
+    padding: Padding,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    label: Option<String>,
+    placeholder: Option<&str>,
+    viewport: &Rectangle,
+) where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    let style = Catalog::style(theme, class, status);
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: style.border,
+            ..renderer::Quad::default()
+        },
+        style.background,
+    );
+
+    let font = font.unwrap_or_else(|| renderer.default_font());
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+    let line_height = text::LineHeight::default();
+
+    let text = |content: String, font, align_x| Text {
+        content,
+        bounds: Size::new(
+            bounds.width - padding.x(),
+            f32::from(line_height.to_absolute(text_size)),
+        ),
+        size: text_size,
+        line_height,
+        font,
+        align_x,
+        align_y: alignment::Vertical::Center,
+        shaping: text::Shaping::Basic,
+        wrapping: text::Wrapping::None,
+    };
+
+    renderer.fill_text(
+        text(
+            Renderer::ARROW_DOWN_ICON.to_string(),
+            Renderer::ICON_FONT,
+            text::Alignment::Right,
+        ),
+        Point::new(bounds.x + bounds.width - padding.right, bounds.center_y()),
+        style.handle_color,
+        *viewport,
+    );
+
+    let (label, color) = match (label, placeholder) {
+        (Some(label), _) => (label, style.text_color),
+        (None, Some(placeholder)) => {
+            (placeholder.to_owned(), style.placeholder_color)
+        }
+        (None, None) => return,
+    };
+
+    renderer.fill_text(
+        text(label, font, text::Alignment::Default),
+        Point::new(bounds.x + padding.left, bounds.center_y()),
+        color,
+        *viewport,
+    );
+}
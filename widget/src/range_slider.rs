@@ -0,0 +1,1177 @@
+//! Range sliders let users select a span of values by moving two indicators.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::range_slider;
+//!
+//! struct State {
+//!    low: u8,
+//!    high: u8,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     RangeChanged(u8, u8),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     range_slider(0..=100, (state.low, state.high), Message::RangeChanged)
+//!         .step(10)
+//!         .step_ticks()
+//!         .labels(true)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::RangeChanged(low, high) => {
+//!             state.low = low;
+//!             state.high = high;
+//!         }
+//!     }
+//! }
+//! ```
+use std::fmt;
+use std::ops::RangeInclusive;
+
+pub use crate::slider::{
+    Catalog, Handle, HandleShape, Status, Style, StyleFn, default,
+};
+
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::paragraph;
+use crate::core::text::{self, Text};
+use crate::core::theme::palette;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Length, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::slider;
+
+/// An horizontal bar and two handles that select a span of values from a
+/// range of values.
+///
+/// The handles of a [`RangeSlider`] cannot cross each other; the start of
+/// the span is always less than or equal to its end.
+///
+/// Like a [`Slider`], the range of numeric values is generic and its step
+/// size defaults to 1 unit.
+///
+/// [`Slider`]: crate::Slider
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::range_slider;
+///
+/// struct State {
+///    low: f32,
+///    high: f32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RangeChanged(f32, f32),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     range_slider(0.0..=1.0, (state.low, state.high), Message::RangeChanged)
+///         .step(0.05)
+///         .ticks([0.0, 0.25, 0.5, 0.75, 1.0])
+///         .labels(true)
+///         .format(|value| format!("{:.0}%", value * 100.0))
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::RangeChanged(low, high) => {
+///             state.low = low;
+///             state.high = high;
+///         }
+///     }
+/// }
+/// ```
+pub struct RangeSlider<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    range: RangeInclusive<T>,
+    step: T,
+    shift_step: Option<T>,
+    values: (T, T),
+    on_change: Box<dyn Fn(T, T) -> Message + 'a>,
+    on_release: Option<Message>,
+    ticks: Ticks<T>,
+    labels: bool,
+    tooltips: bool,
+    format: Option<Box<dyn Fn(T) -> String + 'a>>,
+    width: Length,
+    height: f32,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: Theme::Class<'a>,
+    status: Option<Status>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    RangeSlider<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default height of a [`RangeSlider`], without labels.
+    pub const DEFAULT_HEIGHT: f32 = 16.0;
+
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current start and end values of the [`RangeSlider`]
+    ///   * a function that will be called when any of the handles of the
+    ///     [`RangeSlider`] is dragged. It receives the new start and end
+    ///     values and must produce a `Message`.
+    pub fn new<F>(
+        range: RangeInclusive<T>,
+        values: (T, T),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn(T, T) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (start, end) = (clamp(values.0), clamp(values.1));
+
+        let values = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        RangeSlider {
+            range,
+            step: T::from(1),
+            shift_step: None,
+            values,
+            on_change: Box::new(on_change),
+            on_release: None,
+            ticks: Ticks::None,
+            labels: false,
+            tooltips: true,
+            format: None,
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            font: None,
+            text_size: None,
+            class: Theme::default(),
+            status: None,
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when the mouse is released from any of its handles.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the rail area of the [`RangeSlider`].
+    ///
+    /// If labels are enabled, they are laid out below this area.
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the optional "shift" step for the [`RangeSlider`].
+    ///
+    /// If set, this value is used as the step while the shift key is pressed.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Places tick marks at the given values of the [`RangeSlider`].
+    ///
+    /// Values outside of the range of the [`RangeSlider`] are ignored.
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = T>) -> Self {
+        self.ticks = Ticks::Values(ticks.into_iter().collect());
+        self
+    }
+
+    /// Places tick marks at every step of the [`RangeSlider`].
+    ///
+    /// No ticks are shown if the range contains more than 500 steps.
+    pub fn step_ticks(mut self) -> Self {
+        self.ticks = Ticks::Steps;
+        self
+    }
+
+    /// Sets whether the tick marks of the [`RangeSlider`] are labeled
+    /// with their value.
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets whether the value of a handle is shown in a tooltip
+    /// while it is being dragged.
+    ///
+    /// Tooltips are enabled by default.
+    pub fn tooltips(mut self, tooltips: bool) -> Self {
+        self.tooltips = tooltips;
+        self
+    }
+
+    /// Sets the function used to format the values of the [`RangeSlider`]
+    /// in its labels and tooltips.
+    ///
+    /// By default, values are formatted with their [`Display`] implementation.
+    ///
+    /// [`Display`]: fmt::Display
+    pub fn format(mut self, format: impl Fn(T) -> String + 'a) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the [`Renderer::Font`] of the labels and tooltips of the
+    /// [`RangeSlider`].
+    ///
+    /// [`Renderer::Font`]: text::Renderer
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the text size of the labels and tooltips of the [`RangeSlider`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`RangeSlider`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<T, Message, Theme, Renderer> RangeSlider<'_, T, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + fmt::Display,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn label(&self, value: T) -> String {
+        match &self.format {
+            Some(format) => format(value),
+            None => value.to_string(),
+        }
+    }
+
+    fn fraction(&self, value: T) -> f32 {
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        if start >= end {
+            0.0
+        } else {
+            ((value.into() - start) / (end - start)) as f32
+        }
+    }
+
+    /// Returns the value under the given cursor position, rounded to the
+    /// given step.
+    fn locate(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        step: T,
+    ) -> Option<T> {
+        if cursor_position.x <= bounds.x {
+            Some(*self.range.start())
+        } else if cursor_position.x >= bounds.x + bounds.width {
+            Some(*self.range.end())
+        } else {
+            let percent = f64::from(cursor_position.x - bounds.x)
+                / f64::from(bounds.width);
+
+            slider::value_at(percent, &self.range, step)
+        }
+    }
+
+    /// Returns the handle closest to the given cursor position.
+    fn nearest(&self, bounds: Rectangle, cursor_position: Point) -> Thumb {
+        let start_x = bounds.x + self.fraction(self.values.0) * bounds.width;
+        let end_x = bounds.x + self.fraction(self.values.1) * bounds.width;
+
+        let to_start = (cursor_position.x - start_x).abs();
+        let to_end = (cursor_position.x - end_x).abs();
+
+        if to_start < to_end
+            || (to_start == to_end && cursor_position.x < start_x)
+        {
+            Thumb::Start
+        } else {
+            Thumb::End
+        }
+    }
+
+    /// Moves the given handle towards the given value, without crossing the
+    /// other handle.
+    ///
+    /// Returns the handle that was moved, which may differ from the given
+    /// one if both handles coincide and they can be `separated`.
+    fn change(
+        &mut self,
+        thumb: Thumb,
+        value: T,
+        separate: bool,
+        shell: &mut Shell<'_, Message>,
+    ) -> Thumb {
+        let (start, end) = self.values;
+        let value_f64 = value.into();
+
+        // Coinciding handles are separated in the direction of the change,
+        // so they never get stuck together.
+        let thumb = if separate && start.into() == end.into() {
+            if value_f64 < start.into() {
+                Thumb::Start
+            } else if value_f64 > end.into() {
+                Thumb::End
+            } else {
+                thumb
+            }
+        } else {
+            thumb
+        };
+
+        let values = match thumb {
+            Thumb::Start if value_f64 > end.into() => (end, end),
+            Thumb::Start => (value, end),
+            Thumb::End if value_f64 < start.into() => (start, start),
+            Thumb::End => (start, value),
+        };
+
+        if (values.0.into() - start.into()).abs() > f64::EPSILON
+            || (values.1.into() - end.into()).abs() > f64::EPSILON
+        {
+            shell.publish((self.on_change)(values.0, values.1));
+
+            self.values = values;
+        }
+
+        thumb
+    }
+
+    /// Moves the handle closest to the given cursor position one step
+    /// forward or backward.
+    fn nudge(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        step: T,
+        forward: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let thumb = if self.values.0.into() == self.values.1.into() {
+            if forward { Thumb::End } else { Thumb::Start }
+        } else {
+            self.nearest(bounds, cursor_position)
+        };
+
+        let value = match thumb {
+            Thumb::Start => self.values.0,
+            Thumb::End => self.values.1,
+        };
+
+        let value = if forward {
+            slider::increment(value, step, &self.range)
+        } else {
+            slider::decrement(value, step, &self.range)
+        };
+
+        if let Some(value) = value {
+            let _ = self.change(thumb, value, true, shell);
+        }
+    }
+
+    fn tick_values(&self) -> Vec<T> {
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        match &self.ticks {
+            Ticks::None => Vec::new(),
+            Ticks::Steps => {
+                let step = self.step.into();
+
+                if step <= 0.0 || start > end {
+                    return Vec::new();
+                }
+
+                let steps = ((end - start) / step).floor() as usize;
+
+                if steps >= MAX_STEP_TICKS {
+                    return Vec::new();
+                }
+
+                (0..=steps)
+                    .filter_map(|i| T::from_f64(start + step * i as f64))
+                    .collect()
+            }
+            Ticks::Values(values) => values
+                .iter()
+                .copied()
+                .filter(|value| (start..=end).contains(&(*value).into()))
+                .collect(),
+        }
+    }
+
+    fn text<'b>(
+        &self,
+        content: &'b str,
+        renderer: &Renderer,
+    ) -> Text<&'b str, Renderer::Font> {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = text::LineHeight::default();
+
+        Text {
+            content,
+            bounds: Size::new(
+                f32::INFINITY,
+                line_height.to_absolute(size).into(),
+            ),
+            size,
+            line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RangeSlider<'_, T, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + fmt::Display,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let ticks = self.tick_values();
+        let mut height = self.height;
+
+        state.ticks = ticks.iter().map(|tick| self.fraction(*tick)).collect();
+
+        if self.labels && !ticks.is_empty() {
+            state
+                .labels
+                .resize_with(ticks.len(), paragraph::Plain::default);
+
+            let mut line_height = 0.0f32;
+
+            for (tick, label) in ticks.iter().zip(&mut state.labels) {
+                let content = self.label(*tick);
+                let _ = label.update(self.text(&content, renderer));
+
+                line_height = line_height.max(label.min_bounds().height);
+            }
+
+            height += LABEL_SPACING + line_height;
+        } else {
+            state.labels.clear();
+        }
+
+        layout::atomic(limits, self.width, height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        let step =
+            slider::step(self.step, self.shift_step, state.keyboard_modifiers);
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
+                    let thumb = self.nearest(bounds, cursor_position);
+
+                    state.dragging = Some(
+                        match self.locate(bounds, cursor_position, step) {
+                            Some(value) => {
+                                self.change(thumb, value, true, shell)
+                            }
+                            None => thumb,
+                        },
+                    );
+
+                    // Handles that only meet while dragging stay in place
+                    state.is_separating =
+                        self.values.0.into() == self.values.1.into();
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.dragging.is_some() =>
+            {
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                state.dragging = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(thumb) = state.dragging {
+                    if let Some(value) =
+                        cursor.land().position().and_then(|position| {
+                            self.locate(bounds, position, step)
+                        })
+                    {
+                        let thumb = self.change(
+                            thumb,
+                            value,
+                            state.is_separating,
+                            shell,
+                        );
+
+                        state.dragging = Some(thumb);
+                        state.is_separating &=
+                            self.values.0.into() == self.values.1.into();
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if state.keyboard_modifiers.control() =>
+            {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
+                    let delta = match delta {
+                        mouse::ScrollDelta::Lines { x: _, y } => y,
+                        mouse::ScrollDelta::Pixels { x: _, y } => y,
+                    };
+
+                    self.nudge(
+                        bounds,
+                        cursor_position,
+                        step,
+                        *delta >= 0.0,
+                        shell,
+                    );
+
+                    shell.capture_event();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
+                    match key {
+                        Key::Named(key::Named::ArrowUp) => {
+                            self.nudge(
+                                bounds,
+                                cursor_position,
+                                step,
+                                true,
+                                shell,
+                            );
+                            shell.capture_event();
+                        }
+                        Key::Named(key::Named::ArrowDown) => {
+                            self.nudge(
+                                bounds,
+                                cursor_position,
+                                step,
+                                false,
+                                shell,
+                            );
+                            shell.capture_event();
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = *modifiers;
+            }
+            _ => {}
+        }
+
+        let current_status = if state.dragging.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.status = Some(current_status);
+        } else if self.status.is_some_and(|status| status != current_status) {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        let style =
+            theme.style(&self.class, self.status.unwrap_or(Status::Active));
+
+        let (handle_width, handle_height, handle_border_radius) =
+            handle(&style, self.height);
+
+        let rail_y = bounds.y + self.height / 2.0;
+        let track = bounds.width - handle_width;
+
+        let start = bounds.x
+            + track * self.fraction(self.values.0)
+            + handle_width / 2.0;
+        let end = bounds.x
+            + track * self.fraction(self.values.1)
+            + handle_width / 2.0;
+
+        for (x, width, background) in [
+            (bounds.x, start - bounds.x, style.rail.backgrounds.1),
+            (start, end - start, style.rail.backgrounds.0),
+            (end, bounds.x + bounds.width - end, style.rail.backgrounds.1),
+        ] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x,
+                        y: rail_y - style.rail.width / 2.0,
+                        width,
+                        height: style.rail.width,
+                    },
+                    border: style.rail.border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let ticks = state
+            .ticks
+            .iter()
+            .map(|fraction| bounds.x + handle_width / 2.0 + track * fraction);
+
+        let is_sparse = state
+            .ticks
+            .windows(2)
+            .all(|pair| (pair[1] - pair[0]).abs() * track >= MIN_TICK_SPACING);
+
+        if is_sparse {
+            for x in ticks.clone() {
+                let background = if x >= start && x <= end {
+                    style.rail.backgrounds.0
+                } else {
+                    style.rail.backgrounds.1
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: x - TICK_WIDTH / 2.0,
+                            y: rail_y + style.rail.width / 2.0 + TICK_SPACING,
+                            width: TICK_WIDTH,
+                            height: TICK_LENGTH,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+        }
+
+        // Labels that would overlap the previous one are skipped
+        let mut labeled_until = f32::NEG_INFINITY;
+
+        for (x, label) in ticks.zip(&state.labels) {
+            let size = label.min_bounds();
+
+            let label_x = (x - size.width / 2.0)
+                .min(bounds.x + bounds.width - size.width)
+                .max(bounds.x);
+
+            if label_x < labeled_until {
+                continue;
+            }
+
+            renderer.fill_paragraph(
+                label.raw(),
+                Point::new(label_x, bounds.y + self.height + LABEL_SPACING),
+                defaults.text_color,
+                *viewport,
+            );
+
+            labeled_until = label_x + size.width + LABEL_SPACING * 2.0;
+        }
+
+        let handles = if state.dragging == Some(Thumb::Start) {
+            [end, start]
+        } else {
+            [start, end]
+        };
+
+        for center in handles {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center - handle_width / 2.0,
+                        y: rail_y - handle_height / 2.0,
+                        width: handle_width,
+                        height: handle_height,
+                    },
+                    border: Border {
+                        radius: handle_border_radius,
+                        width: style.handle.border_width,
+                        color: style.handle.border_color,
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.handle.background,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if !self.tooltips {
+            return None;
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let value = match state.dragging? {
+            Thumb::Start => self.values.0,
+            Thumb::End => self.values.1,
+        };
+
+        let content = self.label(value);
+        let _ = state.tooltip.update(self.text(&content, renderer));
+
+        Some(overlay::Element::new(Box::new(Tooltip {
+            paragraph: &state.tooltip,
+            rail: Rectangle {
+                height: self.height,
+                ..layout.bounds() + translation
+            },
+            fraction: self.fraction(value),
+            class: &self.class,
+        })))
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<RangeSlider<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + fmt::Display + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        range_slider: RangeSlider<'a, T, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(range_slider)
+    }
+}
+
+const LABEL_SPACING: f32 = 4.0;
+const TICK_SPACING: f32 = 2.0;
+const TICK_WIDTH: f32 = 1.0;
+const TICK_LENGTH: f32 = 4.0;
+const MIN_TICK_SPACING: f32 = 4.0;
+const MAX_STEP_TICKS: usize = 500;
+const TOOLTIP_PADDING: f32 = 4.0;
+const TOOLTIP_GAP: f32 = 4.0;
+
+#[derive(Debug)]
+enum Ticks<T> {
+    None,
+    Steps,
+    Values(Vec<T>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Thumb {
+    Start,
+    End,
+}
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    dragging: Option<Thumb>,
+    is_separating: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+    ticks: Vec<f32>,
+    labels: Vec<paragraph::Plain<P>>,
+    tooltip: paragraph::Plain<P>,
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self {
+            dragging: None,
+            is_separating: false,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            ticks: Vec::new(),
+            labels: Vec::new(),
+            tooltip: paragraph::Plain::default(),
+        }
+    }
+}
+
+/// Returns the width, height and border radius of the handles of a slider
+/// with the given [`Style`] and height.
+fn handle(style: &Style, height: f32) -> (f32, f32, border::Radius) {
+    match style.handle.shape {
+        HandleShape::Circle { radius } => {
+            (radius * 2.0, radius * 2.0, radius.into())
+        }
+        HandleShape::Rectangle {
+            width,
+            border_radius,
+        } => (f32::from(width), height, border_radius),
+    }
+}
+
+/// The value of a dragged handle, shown above it.
+struct Tooltip<'a, 'b, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    paragraph: &'b paragraph::Plain<Renderer::Paragraph>,
+    rail: Rectangle,
+    fraction: f32,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Theme, Renderer> Tooltip<'_, '_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the horizontal center of the dragged handle, given its width.
+    fn center(&self, handle_width: f32) -> f32 {
+        self.rail.x
+            + handle_width / 2.0
+            + (self.rail.width - handle_width) * self.fraction
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Tooltip<'_, '_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = self
+            .paragraph
+            .min_bounds()
+            .expand(Size::new(TOOLTIP_PADDING * 2.0, TOOLTIP_PADDING * 2.0));
+
+        // The exact width of the handle depends on the theme, which is only
+        // known when drawing; the height of the rail is a close estimate.
+        let x = (self.center(self.rail.height) - size.width / 2.0)
+            .min(bounds.width - size.width)
+            .max(0.0);
+
+        let above = self.rail.y - TOOLTIP_GAP - size.height;
+
+        let y = if above >= 0.0 {
+            above
+        } else {
+            self.rail.y + self.rail.height + TOOLTIP_GAP
+        };
+
+        layout::Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class, Status::Dragged);
+        let (handle_width, _, _) = handle(&style, self.rail.height);
+
+        let bounds = layout.bounds()
+            + Vector::new(
+                self.center(handle_width) - self.center(self.rail.height),
+                0.0,
+            );
+
+        let text_color = match style.handle.background {
+            Background::Color(color) => palette::readable(color, Color::WHITE),
+            Background::Gradient(_) => Color::WHITE,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: border::rounded(TOOLTIP_PADDING),
+                ..renderer::Quad::default()
+            },
+            style.handle.background,
+        );
+
+        renderer.fill_paragraph(
+            self.paragraph.raw(),
+            Point::new(bounds.x + TOOLTIP_PADDING, bounds.y + TOOLTIP_PADDING),
+            text_color,
+            bounds,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::Harness;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Change(i32, i32),
+    }
+
+    fn range_slider(values: (i32, i32)) -> Harness<'static, Message> {
+        Harness::new(
+            RangeSlider::new(0..=100, values, Message::Change).width(100),
+        )
+    }
+
+    fn drag(
+        harness: &mut Harness<'_, Message>,
+        from: f32,
+        to: &[f32],
+    ) -> Vec<Message> {
+        harness.point_at(Point::new(from, 8.0));
+
+        let mut messages = harness.update(Event::Mouse(
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+        ));
+
+        for &x in to {
+            let position = Point::new(x, 8.0);
+
+            harness.point_at(position);
+            messages.extend(
+                harness.update(Event::Mouse(mouse::Event::CursorMoved {
+                    position,
+                })),
+            );
+        }
+
+        messages.extend(harness.update(Event::Mouse(
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        )));
+
+        messages
+    }
+
+    fn assert_ordered(messages: &[Message]) {
+        assert!(
+            messages
+                .iter()
+                .all(|Message::Change(start, end)| start <= end),
+            "{messages:?}"
+        );
+    }
+
+    #[test]
+    fn start_stops_at_end() {
+        let mut harness = range_slider((20, 60));
+
+        let messages = drag(&mut harness, 20.0, &[40.0, 80.0, 100.0, 90.0]);
+
+        assert_ordered(&messages);
+        assert_eq!(messages.last(), Some(&Message::Change(60, 60)));
+    }
+
+    #[test]
+    fn end_stops_at_start() {
+        let mut harness = range_slider((20, 60));
+
+        let messages = drag(&mut harness, 60.0, &[30.0, 10.0, 0.0]);
+
+        assert_ordered(&messages);
+        assert_eq!(messages.last(), Some(&Message::Change(20, 20)));
+    }
+
+    #[test]
+    fn coinciding_thumbs_move_apart() {
+        let mut harness = range_slider((50, 50));
+
+        let messages = drag(&mut harness, 50.0, &[30.0, 70.0]);
+
+        assert_ordered(&messages);
+        assert_eq!(
+            messages,
+            vec![Message::Change(30, 50), Message::Change(50, 50)]
+        );
+
+        let messages = drag(&mut harness, 50.0, &[70.0]);
+
+        assert_eq!(messages, vec![Message::Change(50, 70)]);
+    }
+
+    #[test]
+    fn arrows_never_cross_thumbs() {
+        let mut harness = range_slider((49, 50));
+
+        harness.point_at(Point::new(49.0, 8.0));
+
+        let messages: Vec<_> = (0..3)
+            .flat_map(|_| harness.press(key::Named::ArrowUp))
+            .collect();
+
+        assert_ordered(&messages);
+        assert_eq!(
+            messages,
+            vec![
+                Message::Change(50, 50),
+                Message::Change(50, 51),
+                Message::Change(51, 51)
+            ]
+        );
+    }
+}
@@ -78,6 +78,7 @@ pub struct Scrollable<
     height: Length,
     direction: Direction,
     auto_scroll: bool,
+    drag_scroll: bool,
     content: Element<'a, Message, Theme, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
     class: Theme::Class<'a>,
@@ -107,6 +108,7 @@ where
             height: Length::Shrink,
             direction: direction.into(),
             auto_scroll: false,
+            drag_scroll: false,
             content: content.into(),
             on_scroll: None,
             class: Theme::default(),
@@ -238,6 +240,19 @@ where
         self
     }
 
+    /// Sets whether the [`Scrollable`] should scroll while its content is
+    /// dragging something close to its edges.
+    ///
+    /// This is useful to reorder the children of a keyed column or row that
+    /// does not fit in the [`Scrollable`]. Any other drag captured by the
+    /// content, like moving a slider, will scroll as well.
+    ///
+    /// By default, it is disabled.
+    pub fn drag_scroll(mut self, drag_scroll: bool) -> Self {
+        self.drag_scroll = drag_scroll;
+        self
+    }
+
     /// Sets the style of this [`Scrollable`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -582,6 +597,8 @@ where
     ) {
         const AUTOSCROLL_DEADZONE: f32 = 20.0;
         const AUTOSCROLL_SMOOTHNESS: f32 = 1.5;
+        const DRAG_SCROLL_EDGE: f32 = 30.0;
+        const DRAG_SCROLL_SPEED: f32 = 10.0;
 
         let state = tree.state.downcast_mut::<State>();
         let bounds = layout.bounds();
@@ -827,6 +844,112 @@ where
                 }
             };
 
+            // Scroll while the content is dragging something close to the edges
+            match (event, state.interaction) {
+                (
+                    Event::Mouse(mouse::Event::ButtonPressed(
+                        mouse::Button::Left,
+                    )),
+                    Interaction::None,
+                ) if self.drag_scroll
+                    && shell.is_event_captured()
+                    && cursor_over_scrollable.is_some() =>
+                {
+                    state.interaction = Interaction::ContentDragging {
+                        velocity: Vector::ZERO,
+                        last_frame: None,
+                    };
+                }
+                (
+                    Event::Mouse(mouse::Event::CursorMoved { .. }),
+                    Interaction::ContentDragging { last_frame, .. },
+                ) if shell.is_event_captured() => {
+                    let velocity = cursor.land().position().map_or(
+                        Vector::ZERO,
+                        |position| {
+                            let speed =
+                                |position: f32, start: f32, end: f32| {
+                                    let distance = if position
+                                        < start + DRAG_SCROLL_EDGE
+                                    {
+                                        position - start - DRAG_SCROLL_EDGE
+                                    } else if position > end - DRAG_SCROLL_EDGE
+                                    {
+                                        position - end + DRAG_SCROLL_EDGE
+                                    } else {
+                                        0.0
+                                    };
+
+                                    distance.clamp(
+                                        -DRAG_SCROLL_EDGE * 3.0,
+                                        DRAG_SCROLL_EDGE * 3.0,
+                                    ) * DRAG_SCROLL_SPEED
+                                };
+
+                            self.direction.align(Vector::new(
+                                speed(
+                                    position.x,
+                                    bounds.x,
+                                    bounds.x + bounds.width,
+                                ),
+                                speed(
+                                    position.y,
+                                    bounds.y,
+                                    bounds.y + bounds.height,
+                                ),
+                            ))
+                        },
+                    );
+
+                    if velocity == Vector::ZERO {
+                        state.interaction = Interaction::ContentDragging {
+                            velocity,
+                            last_frame: None,
+                        };
+                    } else {
+                        state.interaction = Interaction::ContentDragging {
+                            velocity,
+                            last_frame,
+                        };
+
+                        shell.request_redraw();
+                    }
+                }
+                (
+                    Event::Window(window::Event::RedrawRequested(now)),
+                    Interaction::ContentDragging {
+                        velocity,
+                        last_frame,
+                    },
+                ) if velocity != Vector::ZERO => {
+                    if let Some(last_frame) = last_frame {
+                        let time_delta = *now - last_frame;
+
+                        state.scroll(
+                            velocity * time_delta.as_secs_f32(),
+                            bounds,
+                            content_bounds,
+                        );
+
+                        let _ = notify_scroll(
+                            state,
+                            &self.on_scroll,
+                            bounds,
+                            content_bounds,
+                            shell,
+                        );
+                    }
+
+                    state.interaction = Interaction::ContentDragging {
+                        velocity,
+                        last_frame: Some(*now),
+                    };
+
+                    shell.request_redraw();
+                }
+                _ => {}
+            }
+
             if matches!(
                 event,
                 Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
@@ -1213,7 +1336,7 @@ where
                         && scrollbar.bounds.height > 0.0
                         && (style.background.is_some()
                             || (style.border.color != Color::TRANSPARENT
-                                && style.border.width > 0.0))
+                                && style.border.has_width()))
                     {
                         renderer.fill_quad(
                             renderer::Quad {
@@ -1233,7 +1356,7 @@ where
                         && (style.scroller.color != Color::TRANSPARENT
                             || (style.scroller.border.color
                                 != Color::TRANSPARENT
-                                && style.scroller.border.width > 0.0))
+                                && style.scroller.border.has_width()))
                     {
                         renderer.fill_quad(
                             renderer::Quad {
@@ -1663,6 +1786,10 @@ enum Interaction {
         current: Point,
         last_frame: Option<Instant>,
     },
+    ContentDragging {
+        velocity: Vector,
+        last_frame: Option<Instant>,
+    },
 }
 
 impl Default for State {
@@ -2424,3 +2551,76 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Font, clipboard};
+    use crate::{Column, Slider, Space};
+
+    #[derive(Debug, Clone, Copy)]
+    enum Message {
+        Slide,
+        Scroll(Viewport),
+    }
+
+    fn drag_slider_near_edge(drag_scroll: bool) -> f32 {
+        let mut scrollable: Scrollable<'_, Message> = Scrollable::new(
+            Column::new()
+                .push(Space::new().height(80))
+                .push(Slider::new(0.0..=1.0, 0.5, |_| Message::Slide))
+                .push(Space::new().height(1000)),
+        )
+        .height(100)
+        .drag_scroll(drag_scroll)
+        .on_scroll(Message::Scroll);
+
+        let renderer = crate::Renderer::new(Font::default(), Pixels(16.0));
+        let mut tree = Tree::new(&scrollable as &dyn Widget<_, _, _>);
+        let node = scrollable.layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let now = Instant::now();
+        let mut messages = Vec::new();
+
+        for event in [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(60.0, 90.0),
+            }),
+            Event::Window(window::Event::RedrawRequested(now)),
+            Event::Window(window::Event::RedrawRequested(
+                now + Duration::from_secs(1),
+            )),
+        ] {
+            scrollable.update(
+                &mut tree,
+                &event,
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(60.0, 90.0)),
+                &renderer,
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::with_size(Size::new(200.0, 100.0)),
+            );
+        }
+
+        messages
+            .iter()
+            .rev()
+            .find_map(|message| match message {
+                Message::Scroll(viewport) => Some(viewport.absolute_offset().y),
+                Message::Slide => None,
+            })
+            .unwrap_or(0.0)
+    }
+
+    #[test]
+    fn captured_drags_only_scroll_when_enabled() {
+        assert_eq!(drag_slider_near_edge(false), 0.0);
+        assert!(drag_slider_near_edge(true) > 0.0);
+    }
+}
@@ -256,6 +256,8 @@ where
 
         let mut update = || {
             let current_value = self.value;
+            let step =
+                step(self.step, self.shift_step, state.keyboard_modifiers);
 
             let locate = |cursor_position: Point| -> Option<T> {
                 let bounds = layout.bounds();
@@ -265,61 +267,15 @@ where
                 } else if cursor_position.x >= bounds.x + bounds.width {
                     Some(*self.range.end())
                 } else {
-                    let step = if state.keyboard_modifiers.shift() {
-                        self.shift_step.unwrap_or(self.step)
-                    } else {
-                        self.step
-                    }
-                    .into();
-
-                    let start = (*self.range.start()).into();
-                    let end = (*self.range.end()).into();
-
                     let percent = f64::from(cursor_position.x - bounds.x)
                         / f64::from(bounds.width);
 
-                    let steps = (percent * (end - start) / step).round();
-                    let value = steps * step + start;
-
-                    T::from_f64(value.min(end))
+                    value_at(percent, &self.range, step)
                 }
             };
 
-            let increment = |value: T| -> Option<T> {
-                let step = if state.keyboard_modifiers.shift() {
-                    self.shift_step.unwrap_or(self.step)
-                } else {
-                    self.step
-                }
-                .into();
-
-                let steps = (value.into() / step).round();
-                let new_value = step * (steps + 1.0);
-
-                if new_value > (*self.range.end()).into() {
-                    return Some(*self.range.end());
-                }
-
-                T::from_f64(new_value)
-            };
-
-            let decrement = |value: T| -> Option<T> {
-                let step = if state.keyboard_modifiers.shift() {
-                    self.shift_step.unwrap_or(self.step)
-                } else {
-                    self.step
-                }
-                .into();
-
-                let steps = (value.into() / step).round();
-                let new_value = step * (steps - 1.0);
-
-                if new_value < (*self.range.start()).into() {
-                    return Some(*self.range.start());
-                }
-
-                T::from_f64(new_value)
-            };
+            let increment = |value: T| increment(value, step, &self.range);
+            let decrement = |value: T| decrement(value, step, &self.range);
 
             let change = |new_value: T| {
                 if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
@@ -515,6 +471,7 @@ where
                     radius: handle_border_radius,
                     width: style.handle.border_width,
                     color: style.handle.border_color,
+                    ..Border::default()
                 },
                 ..renderer::Quad::default()
             },
@@ -557,6 +514,79 @@ where
     }
 }
 
+/// Returns the step of a slider for the given keyboard modifiers.
+pub(crate) fn step<T: Copy>(
+    step: T,
+    shift_step: Option<T>,
+    modifiers: keyboard::Modifiers,
+) -> T {
+    if modifiers.shift() {
+        shift_step.unwrap_or(step)
+    } else {
+        step
+    }
+}
+
+/// Returns the value found at the given percentage of the range of a slider,
+/// rounded to the closest step.
+pub(crate) fn value_at<T>(
+    percent: f64,
+    range: &RangeInclusive<T>,
+    step: T,
+) -> Option<T>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    let step = step.into();
+    let start = (*range.start()).into();
+    let end = (*range.end()).into();
+
+    let steps = (percent * (end - start) / step).round();
+    let value = steps * step + start;
+
+    T::from_f64(value.min(end))
+}
+
+/// Moves the value of a slider one step forward, without leaving its range.
+pub(crate) fn increment<T>(
+    value: T,
+    step: T,
+    range: &RangeInclusive<T>,
+) -> Option<T>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    let step = step.into();
+    let steps = (value.into() / step).round();
+    let new_value = step * (steps + 1.0);
+
+    if new_value > (*range.end()).into() {
+        return Some(*range.end());
+    }
+
+    T::from_f64(new_value)
+}
+
+/// Moves the value of a slider one step backward, without leaving its range.
+pub(crate) fn decrement<T>(
+    value: T,
+    step: T,
+    range: &RangeInclusive<T>,
+) -> Option<T>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    let step = step.into();
+    let steps = (value.into() / step).round();
+    let new_value = step * (steps - 1.0);
+
+    if new_value < (*range.start()).into() {
+        return Some(*range.start());
+    }
+
+    T::from_f64(new_value)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_dragging: bool,
@@ -680,6 +710,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
                 radius: 2.0.into(),
                 width: 0.0,
                 color: Color::TRANSPARENT,
+                ..Border::default()
             },
         },
         handle: Handle {
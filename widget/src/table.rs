@@ -1,15 +1,32 @@
 //! Display tables.
+//!
+//! A [`Table`] can be made interactive: its columns can be sorted by
+//! clicking their headers and resized by dragging their dividers, while its
+//! rows can be selected with the mouse and the keyboard.
+//!
+//! A virtual [`Table`], created with [`virtual_table`], only builds the rows
+//! that are visible; so it can display huge amounts of data.
 use crate::core;
 use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::text;
 use crate::core::widget;
 use crate::core::{
-    Alignment, Background, Element, Layout, Length, Pixels, Rectangle, Size,
-    Widget,
+    Alignment, Background, Element, Event, Layout, Length, Pixels, Point,
+    Rectangle, Size, Vector, Widget,
 };
+use crate::virtual_list;
+
+use std::ops::Range;
+
+/// The width of the area around a column divider that can be dragged to
+/// resize the column.
+const HANDLE_WIDTH: f32 = 8.0;
 
 /// Creates a new [`Table`] with the given columns and rows.
 ///
@@ -22,11 +39,32 @@ pub fn table<'a, 'b, T, Message, Theme, Renderer>(
 where
     T: Clone,
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     Table::new(columns, rows)
 }
 
+/// Creates a new virtual [`Table`] with the given columns and amount of
+/// rows, produced by the `row` function.
+///
+/// Only the rows that are visible are built, which makes it suitable for
+/// displaying huge amounts of data inside a [`Scrollable`].
+///
+/// [`Scrollable`]: crate::Scrollable
+pub fn virtual_table<'a, T, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = Column<'a, 'a, T, Message, Theme, Renderer>>,
+    count: usize,
+    row: impl Fn(usize) -> T + 'a,
+) -> Table<'a, Message, Theme, Renderer>
+where
+    T: Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    Table::virtualized(columns, count, row)
+}
+
 /// Creates a new [`Column`] with the given header and view function.
 ///
 /// The view function will be called for each row in a [`Table`] and it must
@@ -45,6 +83,27 @@ where
         width: Length::Shrink,
         align_x: alignment::Horizontal::Left,
         align_y: alignment::Vertical::Top,
+        sort: None,
+        on_sort: None,
+    }
+}
+
+/// The order of a sorted [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// From the smallest to the largest value.
+    Ascending,
+    /// From the largest to the smallest value.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite [`Order`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
     }
 }
 
@@ -53,32 +112,54 @@ pub struct Table<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
     Theme: Catalog,
 {
-    columns: Vec<Column_>,
+    columns: Vec<Column_<'a, Message>>,
     cells: Vec<Element<'a, Message, Theme, Renderer>>,
+    rows: Option<Rows<'a, Message, Theme, Renderer>>,
+    row_height: Option<f32>,
     width: Length,
     height: Length,
     padding_x: f32,
     padding_y: f32,
     separator_x: f32,
     separator_y: f32,
+    selected: Vec<usize>,
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    is_multi_select: bool,
+    on_column_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    is_header_sticky: bool,
     class: Theme::Class<'a>,
 }
 
-struct Column_ {
+/// The rows of a virtual [`Table`], which are built on demand.
+struct Rows<'a, Message, Theme, Renderer> {
+    count: usize,
+    view: Box<dyn Fn(usize) -> Vec<Element<'a, Message, Theme, Renderer>> + 'a>,
+}
+
+/// The function of a [`Column`] that produces its cells.
+type View<'a, 'b, T, Message, Theme, Renderer> =
+    Box<dyn Fn(T) -> Element<'a, Message, Theme, Renderer> + 'b>;
+
+struct Column_<'a, Message> {
     width: Length,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    sort: Option<Order>,
+    on_sort: Option<Box<dyn Fn(Order) -> Message + 'a>>,
 }
 
 impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
 where
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     /// Creates a new [`Table`] with the given columns and rows.
     ///
     /// Columns can be created using the [`column()`] function, while rows can be any
     /// iterator over some data type `T`.
+    ///
+    /// Every cell is built eagerly. Consider [`Table::virtualized`] for
+    /// huge amounts of rows.
     pub fn new<'b, T>(
         columns: impl IntoIterator<
             Item = Column<'a, 'b, T, Message, Theme, Renderer>,
@@ -88,17 +169,73 @@ where
     where
         T: Clone,
     {
-        let columns = columns.into_iter();
         let rows = rows.into_iter();
+        let (mut table, views) = Self::with_columns(columns);
 
-        let mut width = Length::Shrink;
-        let mut height = Length::Shrink;
+        table.cells.reserve(views.len() * rows.size_hint().0);
 
-        let mut cells = Vec::with_capacity(
-            columns.size_hint().0 * (1 + rows.size_hint().0),
-        );
+        for row in rows {
+            for view in &views {
+                let cell = view(row.clone());
+                let size_hint = cell.as_widget().size_hint();
+
+                table.height = table.height.enclose(size_hint.height);
+                table.cells.push(cell);
+            }
+        }
+
+        table
+    }
+
+    /// Creates a new virtual [`Table`] with the given columns and amount of
+    /// rows, produced by the `row` function.
+    ///
+    /// Only the rows that intersect the visible area are built and laid
+    /// out, and every data row has the same height—see
+    /// [`Table::row_height`].
+    ///
+    /// The width of [`Length::Shrink`] columns is measured from the visible
+    /// rows only; consider other widths for columns whose contents vary a
+    /// lot.
+    pub fn virtualized<T>(
+        columns: impl IntoIterator<
+            Item = Column<'a, 'a, T, Message, Theme, Renderer>,
+        >,
+        count: usize,
+        row: impl Fn(usize) -> T + 'a,
+    ) -> Self
+    where
+        T: Clone + 'a,
+        Message: 'a,
+        Theme: 'a,
+        Renderer: 'a,
+    {
+        let (mut table, views) = Self::with_columns(columns);
+
+        table.rows = Some(Rows {
+            count,
+            view: Box::new(move |index| {
+                let row = row(index);
+
+                views.iter().map(|view| view(row.clone())).collect()
+            }),
+        });
+
+        table
+    }
+
+    /// Creates a new [`Table`] with the headers of the given columns and no
+    /// rows, returning the views of the columns.
+    fn with_columns<'b, T>(
+        columns: impl IntoIterator<
+            Item = Column<'a, 'b, T, Message, Theme, Renderer>,
+        >,
+    ) -> (Self, Vec<View<'a, 'b, T, Message, Theme, Renderer>>) {
+        let mut width = Length::Shrink;
+        let mut cells = Vec::new();
 
         let (mut columns, views): (Vec<_>, Vec<_>) = columns
+            .into_iter()
             .map(|column| {
                 width = width.enclose(column.width);
 
@@ -109,40 +246,41 @@ where
                         width: column.width,
                         align_x: column.align_x,
                         align_y: column.align_y,
+                        sort: column.sort,
+                        on_sort: column.on_sort,
                     },
                     column.view,
                 )
             })
             .collect();
 
-        for row in rows {
-            for view in &views {
-                let cell = view(row.clone());
-                let size_hint = cell.as_widget().size_hint();
-
-                height = height.enclose(size_hint.height);
-
-                cells.push(cell);
-            }
-        }
-
         if width == Length::Shrink
             && let Some(first) = columns.first_mut()
+            && !matches!(first.width, Length::Fixed(_))
         {
             first.width = Length::Fill;
         }
 
-        Self {
+        let table = Self {
             columns,
             cells,
+            rows: None,
+            row_height: None,
             width,
-            height,
+            height: Length::Shrink,
             padding_x: 10.0,
             padding_y: 5.0,
             separator_x: 1.0,
             separator_y: 1.0,
+            selected: Vec::new(),
+            on_select: None,
+            is_multi_select: false,
+            on_column_resize: None,
+            is_header_sticky: false,
             class: Theme::default(),
-        }
+        };
+
+        (table, views)
     }
 
     /// Sets the width of the [`Table`].
@@ -151,6 +289,16 @@ where
         self
     }
 
+    /// Sets the height of the contents of every data row of the [`Table`].
+    ///
+    /// By default, the rows of a [`Table`] fit their contents, while the
+    /// rows of a virtual [`Table`] are as tall as a line of text of the
+    /// default size.
+    pub fn row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = Some(height.into().0);
+        self
+    }
+
     /// Sets the padding of the cells of the [`Table`].
     pub fn padding(self, padding: impl Into<Pixels>) -> Self {
         let padding = padding.into();
@@ -188,18 +336,290 @@ where
         self.separator_y = separator.into().0;
         self
     }
+
+    /// Sets the indices of the selected rows of the [`Table`].
+    pub fn selected(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        self.selected = rows.into_iter().collect();
+        self
+    }
+
+    /// Sets the message that should be produced when the selected rows of
+    /// the [`Table`] change.
+    ///
+    /// The closure receives the indices of the new selected rows. Rows can
+    /// be selected by clicking them or, once the [`Table`] has been clicked,
+    /// with the arrow keys, `Home`, and `End`.
+    ///
+    /// If this method is not called, rows cannot be selected.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Vec<usize>) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets whether multiple rows of the [`Table`] can be selected at once.
+    ///
+    /// When enabled, rows can be toggled with `Ctrl` (or `Cmd` on macOS) and
+    /// ranges can be selected with `Shift`.
+    ///
+    /// Defaults to `false`.
+    pub fn multi_select(mut self, is_multi_select: bool) -> Self {
+        self.is_multi_select = is_multi_select;
+        self
+    }
+
+    /// Sets the message that should be produced when a column of the
+    /// [`Table`] is resized by dragging its divider in the header.
+    ///
+    /// The closure receives the index of the column and its new width,
+    /// which should be applied using [`Column::width`].
+    ///
+    /// If this method is not called, columns cannot be resized.
+    pub fn on_column_resize(
+        mut self,
+        on_column_resize: impl Fn(usize, f32) -> Message + 'a,
+    ) -> Self {
+        self.on_column_resize = Some(Box::new(on_column_resize));
+        self
+    }
+
+    /// Sets whether the header of the [`Table`] should stick to the top
+    /// of the visible area while the rest of the [`Table`] is scrolled.
+    ///
+    /// Defaults to `false`.
+    pub fn sticky_header(mut self, is_header_sticky: bool) -> Self {
+        self.is_header_sticky = is_header_sticky;
+        self
+    }
+
+    /// Returns the horizontal start and the width of the given column,
+    /// including its padding.
+    fn column_bounds(&self, metrics: &Metrics, column: usize) -> (f32, f32) {
+        let spacing = self.padding_x * 2.0 + self.separator_x;
+
+        let x = metrics.columns[..column]
+            .iter()
+            .map(|width| width + spacing)
+            .sum();
+
+        (x, metrics.columns[column] + self.padding_x * 2.0)
+    }
+
+    /// Returns the vertical start and the height of the given row,
+    /// including its padding.
+    ///
+    /// The header is the row `0`, followed by the data rows.
+    fn row_bounds(&self, metrics: &Metrics, row: usize) -> (f32, f32) {
+        match metrics.row_height {
+            Some(height) if row > 0 => {
+                let spacing = self.padding_y * 2.0 + self.separator_y;
+                let header = metrics.rows[0] + spacing;
+
+                (
+                    header + (row - 1) as f32 * (height + spacing),
+                    height + self.padding_y * 2.0,
+                )
+            }
+            _ => (
+                metrics.offsets[row],
+                metrics.rows[row] + self.padding_y * 2.0,
+            ),
+        }
+    }
+
+    fn column_at(&self, metrics: &Metrics, x: f32) -> Option<usize> {
+        (0..metrics.columns.len()).find(|&column| {
+            let (start, width) = self.column_bounds(metrics, column);
+
+            x >= start && x < start + width + self.separator_x
+        })
+    }
+
+    fn row_at(&self, metrics: &Metrics, y: f32) -> Option<usize> {
+        let row = match metrics.row_height {
+            Some(height) => {
+                let spacing = self.padding_y * 2.0 + self.separator_y;
+                let header = metrics.rows.first()? + spacing;
+
+                if y < header {
+                    0
+                } else {
+                    1 + ((y - header) / (height + spacing)) as usize
+                }
+            }
+            None => metrics
+                .offsets
+                .partition_point(|offset| *offset <= y)
+                .checked_sub(1)?,
+        };
+
+        if row > metrics.row_count {
+            return None;
+        }
+
+        let (start, height) = self.row_bounds(metrics, row);
+
+        (y >= start && y < start + height + self.separator_y).then_some(row)
+    }
+
+    /// Returns the rows that intersect the `viewport`, starting with the
+    /// header.
+    fn visible_rows(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Range<usize> {
+        let Some(visible) = bounds.intersection(viewport) else {
+            return 0..0;
+        };
+
+        let start = self.row_at(metrics, visible.y - bounds.y).unwrap_or(0);
+        let end = self
+            .row_at(metrics, visible.y + visible.height - bounds.y)
+            .map_or(metrics.row_count + 1, |row| row + 1);
+
+        start..end
+    }
+
+    /// Returns the column whose divider is at the given horizontal
+    /// position, if any.
+    fn divider_at(&self, metrics: &Metrics, x: f32) -> Option<usize> {
+        (0..metrics.columns.len()).find(|&column| {
+            let (start, width) = self.column_bounds(metrics, column);
+            let divider = start + width + self.separator_x / 2.0;
+
+            (x - divider).abs() <= HANDLE_WIDTH / 2.0
+        })
+    }
+
+    /// Returns the bounds of the header of the [`Table`], which may be
+    /// offset to stick to the top of the `viewport`.
+    fn header_bounds(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Rectangle {
+        let height = if metrics.rows.is_empty() {
+            0.0
+        } else {
+            self.row_bounds(metrics, 0).1
+        };
+
+        let offset = if self.is_header_sticky {
+            (viewport.y - bounds.y)
+                .clamp(0.0, (bounds.height - height).max(0.0))
+        } else {
+            0.0
+        };
+
+        Rectangle {
+            y: bounds.y + offset,
+            height,
+            ..bounds
+        }
+    }
+
+    /// Returns the index of the data row under the cursor, if any.
+    fn hovered_row(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        header: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        if self.on_select.is_none() || cursor.is_over(header) {
+            return None;
+        }
+
+        let position = cursor.position_in(bounds)?;
+
+        self.row_at(metrics, position.y)
+            .and_then(|row| row.checked_sub(1))
+    }
+
+    /// Returns the new selection of the [`Table`] after clicking the
+    /// given data row.
+    fn click(&self, state: &mut State, row: usize) -> Vec<usize> {
+        let modifiers = state.keyboard_modifiers;
+
+        state.focused_row = Some(row);
+
+        if self.is_multi_select && modifiers.shift() {
+            let anchor = *state.anchor.get_or_insert(row);
+
+            return (anchor.min(row)..=anchor.max(row)).collect();
+        }
+
+        state.anchor = Some(row);
+
+        if self.is_multi_select && modifiers.command() {
+            let mut selected = self.selected.clone();
+
+            if let Some(index) = selected.iter().position(|&i| i == row) {
+                let _ = selected.remove(index);
+            } else {
+                selected.push(row);
+            }
+
+            return selected;
+        }
+
+        vec![row]
+    }
+
+    /// Returns the indicator width reserved in the header of sortable
+    /// columns.
+    fn indicator(&self, renderer: &Renderer, column: usize) -> f32 {
+        if self.columns[column].on_sort.is_some() {
+            renderer.default_size().0
+        } else {
+            0.0
+        }
+    }
 }
 
 struct Metrics {
     columns: Vec<f32>,
+    /// The heights of the rows that are laid out, starting with the header.
     rows: Vec<f32>,
+    /// The vertical start of the rows that are laid out.
+    offsets: Vec<f32>,
+    /// The index of the first data row that is laid out.
+    first_row: usize,
+    /// The total amount of data rows.
+    row_count: usize,
+    /// The height of every data row, if fixed.
+    row_height: Option<f32>,
+}
+
+struct State {
+    metrics: Metrics,
+    range: Option<Range<usize>>,
+    built: Range<usize>,
+    resizing: Option<Resizing>,
+    hovered_row: Option<usize>,
+    focused_row: Option<usize>,
+    anchor: Option<usize>,
+    is_focused: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Table<'a, Message, Theme, Renderer>
 where
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     fn size(&self) -> Size<Length> {
         Size {
@@ -209,13 +629,27 @@ where
     }
 
     fn tag(&self) -> widget::tree::Tag {
-        widget::tree::Tag::of::<Metrics>()
+        widget::tree::Tag::of::<State>()
     }
 
     fn state(&self) -> widget::tree::State {
-        widget::tree::State::new(Metrics {
-            columns: Vec::new(),
-            rows: Vec::new(),
+        widget::tree::State::new(State {
+            metrics: Metrics {
+                columns: Vec::new(),
+                rows: Vec::new(),
+                offsets: Vec::new(),
+                first_row: 0,
+                row_count: 0,
+                row_height: None,
+            },
+            range: None,
+            built: 0..0,
+            resizing: None,
+            hovered_row: None,
+            focused_row: None,
+            anchor: None,
+            is_focused: false,
+            keyboard_modifiers: keyboard::Modifiers::default(),
         })
     }
 
@@ -227,7 +661,17 @@ where
     }
 
     fn diff(&self, tree: &mut widget::Tree) {
-        tree.diff_children(&self.cells);
+        if self.rows.is_none() {
+            tree.diff_children(&self.cells);
+            return;
+        }
+
+        // The rows of a virtual table are diffed once they are built
+        let headers = self.columns.len();
+        let rows = tree.children.split_off(headers.min(tree.children.len()));
+
+        tree.diff_children(&self.cells[..headers]);
+        tree.children.extend(rows);
     }
 
     fn layout(
@@ -236,28 +680,103 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let metrics = tree.state.downcast_mut::<Metrics>();
         let columns = self.columns.len();
-        let rows = self.cells.len() / columns;
+        let indicators: Vec<_> = (0..columns)
+            .map(|column| self.indicator(renderer, column))
+            .collect();
 
         let limits = limits.width(self.width).height(self.height);
         let available = limits.max();
         let table_fluid = self.width.fluid();
 
+        let spacing_x = self.padding_x * 2.0 + self.separator_x;
+        let spacing_y = self.padding_y * 2.0 + self.separator_y;
+
+        let row_height = match self.rows {
+            Some(_) => Some(self.row_height.unwrap_or_else(|| {
+                text::LineHeight::default()
+                    .to_absolute(renderer.default_size())
+                    .0
+            })),
+            None => self.row_height,
+        };
+
+        let (first_row, row_count) = if let Some(rows) = &self.rows {
+            let state = tree.state.downcast_mut::<State>();
+            let height = row_height.unwrap_or_default() + spacing_y;
+
+            let range = state
+                .range
+                .get_or_insert_with(|| {
+                    let visible =
+                        virtual_list::initial_height(&limits) / height.max(1.0);
+
+                    0..visible.ceil() as usize + 1
+                })
+                .clone();
+
+            let range = range.start.min(rows.count)..range.end.min(rows.count);
+            let built = std::mem::replace(&mut state.built, range.clone());
+
+            let mut old =
+                tree.children.split_off(columns.min(tree.children.len()));
+            let is_reusable = old.len() == built.len() * columns;
+
+            self.cells.truncate(columns);
+
+            for index in range.clone() {
+                for (column, cell) in (rows.view)(index).into_iter().enumerate()
+                {
+                    let child = if is_reusable && built.contains(&index) {
+                        let mut child = std::mem::replace(
+                            &mut old[(index - built.start) * columns + column],
+                            widget::Tree::empty(),
+                        );
+
+                        child.diff(cell.as_widget());
+                        child
+                    } else {
+                        widget::Tree::new(cell.as_widget())
+                    };
+
+                    tree.children.push(child);
+                    self.cells.push(cell);
+                }
+            }
+
+            (range.start, rows.count)
+        } else {
+            (0, (self.cells.len() / columns).saturating_sub(1))
+        };
+
+        let metrics = &mut tree.state.downcast_mut::<State>().metrics;
+        let rows = self.cells.len() / columns;
+
         let mut cells = Vec::with_capacity(self.cells.len());
         cells.resize(self.cells.len(), layout::Node::default());
 
         metrics.columns = vec![0.0; self.columns.len()];
-        metrics.rows = vec![0.0; rows];
+        metrics.rows = (0..rows)
+            .map(|row| {
+                if row > 0 {
+                    row_height.unwrap_or_default()
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        metrics.first_row = first_row;
+        metrics.row_count = row_count;
+        metrics.row_height = row_height;
+
+        // The fixed height of the given row, if any
+        let fixed = |row: usize| if row > 0 { row_height } else { None };
 
         let mut column_factors = vec![0; self.columns.len()];
         let mut total_row_factors = 0;
         let mut total_fluid_height = 0.0;
         let mut row_factor = 0;
 
-        let spacing_x = self.padding_x * 2.0 + self.separator_x;
-        let spacing_y = self.padding_y * 2.0 + self.separator_y;
-
         // FIRST PASS
         // Lay out non-fluid cells
         let mut x = self.padding_x;
@@ -288,7 +807,11 @@ where
             }
 
             let width_factor = width.fill_factor();
-            let height_factor = size.height.fill_factor();
+            let height_factor = if fixed(row).is_some() {
+                0
+            } else {
+                size.height.fill_factor()
+            };
 
             if width_factor != 0 || height_factor != 0 || size.width.is_fill() {
                 column_factors[column] =
@@ -301,15 +824,26 @@ where
 
             let limits = layout::Limits::new(
                 Size::ZERO,
-                Size::new(available.width - x, available.height - y),
+                Size::new(
+                    available.width - x,
+                    fixed(row).unwrap_or(available.height - y),
+                ),
             )
             .width(width);
 
             let layout = cell.as_widget_mut().layout(state, renderer, &limits);
-            let size = limits.resolve(width, Length::Shrink, layout.size());
+            let mut size = limits.resolve(width, Length::Shrink, layout.size());
+
+            if row == 0 && width == Length::Shrink {
+                size.width += indicators[column];
+            }
 
             metrics.columns[column] = metrics.columns[column].max(size.width);
-            metrics.rows[row] = metrics.rows[row].max(size.height);
+
+            if fixed(row).is_none() {
+                metrics.rows[row] = metrics.rows[row].max(size.height);
+            }
+
             cells[i] = layout;
 
             x += size.width + spacing_x;
@@ -352,7 +886,11 @@ where
 
             let width = self.columns[column].width;
             let width_factor = width.fill_factor();
-            let height_factor = size.height.fill_factor();
+            let height_factor = if fixed(row).is_some() {
+                0
+            } else {
+                size.height.fill_factor()
+            };
 
             if column == 0 {
                 x = self.padding_x;
@@ -364,7 +902,7 @@ where
 
             if width_factor == 0
                 && size.width.fill_factor() == 0
-                && size.height.fill_factor() == 0
+                && height_factor == 0
             {
                 continue;
             }
@@ -379,7 +917,9 @@ where
                 width_unit * width_factor as f32
             };
 
-            let max_height = if height_factor == 0 {
+            let max_height = if let Some(height) = fixed(row) {
+                height
+            } else if height_factor == 0 {
                 if size.height.is_fill() {
                     metrics.rows[row]
                 } else {
@@ -396,7 +936,7 @@ where
             .width(width);
 
             let layout = cell.as_widget_mut().layout(state, renderer, &limits);
-            let size = limits.resolve(
+            let mut size = limits.resolve(
                 if let Length::Fixed(_) = width {
                     width
                 } else {
@@ -406,13 +946,32 @@ where
                 layout.size(),
             );
 
+            if row == 0 && width == Length::Shrink {
+                size.width += indicators[column];
+            }
+
             metrics.columns[column] = metrics.columns[column].max(size.width);
-            metrics.rows[row] = metrics.rows[row].max(size.height);
+
+            if fixed(row).is_none() {
+                metrics.rows[row] = metrics.rows[row].max(size.height);
+            }
+
             cells[i] = layout;
 
             x += size.width + spacing_x;
         }
 
+        metrics.offsets = metrics
+            .rows
+            .iter()
+            .scan(0.0, |y, height| {
+                let offset = *y;
+                *y += height + spacing_y;
+
+                Some(offset)
+            })
+            .collect();
+
         // THIRD PASS
         // Position each cell
         let mut x = self.padding_x;
@@ -423,39 +982,37 @@ where
             let column = i % columns;
 
             if column == 0 {
-                x = self.padding_x;
+                let index = if row > 0 { first_row + row } else { 0 };
 
-                if row > 0 {
-                    y += metrics.rows[row - 1] + spacing_y;
-                }
+                x = self.padding_x;
+                y = self.row_bounds(metrics, index).0 + self.padding_y;
             }
 
             let Column_ {
                 align_x, align_y, ..
             } = &self.columns[column];
 
+            let indicator = if row == 0 { indicators[column] } else { 0.0 };
+
             cell.move_to_mut((x, y));
             cell.align_mut(
                 Alignment::from(*align_x),
                 Alignment::from(*align_y),
-                Size::new(metrics.columns[column], metrics.rows[row]),
+                Size::new(
+                    metrics.columns[column] - indicator,
+                    metrics.rows[row],
+                ),
             );
 
             x += metrics.columns[column] + spacing_x;
         }
 
+        let (y, height) = self.row_bounds(metrics, row_count);
+
         let intrinsic = limits.resolve(
             self.width,
             self.height,
-            Size::new(
-                x - spacing_x + self.padding_x,
-                y + metrics
-                    .rows
-                    .last()
-                    .copied()
-                    .map(|height| height + self.padding_y)
-                    .unwrap_or_default(),
-            ),
+            Size::new(x - spacing_x + self.padding_x, y + height),
         );
 
         layout::Node::with_children(intrinsic, cells)
@@ -464,7 +1021,7 @@ where
     fn update(
         &mut self,
         tree: &mut widget::Tree,
-        event: &core::Event,
+        event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         renderer: &Renderer,
@@ -472,17 +1029,195 @@ where
         shell: &mut core::Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        for ((cell, tree), layout) in self
+        let bounds = layout.bounds();
+        let columns = self.columns.len();
+
+        let header = self.header_bounds(
+            &tree.state.downcast_ref::<State>().metrics,
+            bounds,
+            viewport,
+        );
+        let offset = Vector::new(0.0, bounds.y - header.y);
+
+        for (i, ((cell, tree), layout)) in self
             .cells
             .iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
+            .enumerate()
         {
+            let cursor = if i < columns {
+                cursor + offset
+            } else if cursor.is_over(header) {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            };
+
             cell.as_widget_mut().update(
                 tree, event, layout, cursor, renderer, clipboard, shell,
                 viewport,
             );
         }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        let hovered_row =
+            self.hovered_row(&state.metrics, bounds, header, cursor);
+
+        if state.hovered_row != hovered_row {
+            state.hovered_row = hovered_row;
+            shell.request_redraw();
+        }
+
+        if let Some(rows) = &self.rows {
+            let visible = self.visible_rows(&state.metrics, bounds, viewport);
+            let needed = visible.start.saturating_sub(1)
+                ..visible.end.saturating_sub(1).min(rows.count);
+
+            let is_stale = state.range.as_ref().is_none_or(|range| {
+                range.end > rows.count
+                    || !needed.is_empty()
+                        && (needed.start < range.start
+                            || needed.end > range.end)
+            });
+
+            if is_stale {
+                let margin = viewport.height / 2.0;
+                let expanded = Rectangle {
+                    y: viewport.y - margin,
+                    height: viewport.height + margin * 2.0,
+                    ..*viewport
+                };
+
+                let visible =
+                    self.visible_rows(&state.metrics, bounds, &expanded);
+
+                state.range = Some(
+                    visible.start.saturating_sub(1)
+                        ..visible.end.saturating_sub(1).min(rows.count),
+                );
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+                    return;
+                };
+
+                let x = position.x - bounds.x;
+
+                if cursor.is_over(header) {
+                    if self.on_column_resize.is_some()
+                        && let Some(column) = self.divider_at(&state.metrics, x)
+                    {
+                        state.resizing = Some(Resizing {
+                            column,
+                            origin: position.x,
+                            width: state.metrics.columns[column],
+                        });
+
+                        shell.capture_event();
+                    } else if let Some(column) =
+                        self.column_at(&state.metrics, x)
+                        && let Some(on_sort) = &self.columns[column].on_sort
+                    {
+                        let order = self.columns[column]
+                            .sort
+                            .map_or(Order::Ascending, Order::reverse);
+
+                        shell.publish(on_sort(order));
+                        shell.capture_event();
+                    }
+
+                    return;
+                }
+
+                state.is_focused = self.on_select.is_some();
+
+                if let Some(on_select) = &self.on_select
+                    && let Some(row) = hovered_row
+                {
+                    let selected = self.click(state, row);
+
+                    shell.publish(on_select(selected));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(resizing) = state.resizing
+                    && let Some(on_column_resize) = &self.on_column_resize
+                {
+                    let width = (resizing.width + position.x - resizing.origin)
+                        .max(0.0);
+
+                    shell.publish(on_column_resize(resizing.column, width));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.resizing.take().is_some() =>
+            {
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.is_focused => {
+                let Some(on_select) = &self.on_select else {
+                    return;
+                };
+
+                let Some(last) = state.metrics.row_count.checked_sub(1) else {
+                    return;
+                };
+
+                let current =
+                    state.focused_row.or(self.selected.first().copied());
+
+                let row = match key {
+                    key::Named::ArrowUp => {
+                        current.map_or(0, |row| row.saturating_sub(1))
+                    }
+                    key::Named::ArrowDown => {
+                        current.map_or(0, |row| (row + 1).min(last))
+                    }
+                    key::Named::Home => 0,
+                    key::Named::End => last,
+                    _ => return,
+                };
+
+                let selected = if self.is_multi_select
+                    && state.keyboard_modifiers.shift()
+                {
+                    let anchor = *state.anchor.get_or_insert(row);
+
+                    (anchor.min(row)..=anchor.max(row)).collect()
+                } else {
+                    state.anchor = Some(row);
+
+                    vec![row]
+                };
+
+                state.focused_row = Some(row);
+
+                shell.publish(on_select(selected));
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = *modifiers;
+            }
+            _ => {}
+        }
     }
 
     fn draw(
@@ -495,17 +1230,71 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        for ((cell, state), layout) in
-            self.cells.iter().zip(&tree.children).zip(layout.children())
-        {
-            cell.as_widget()
-                .draw(state, renderer, theme, style, layout, cursor, viewport);
-        }
-
         let bounds = layout.bounds();
-        let metrics = tree.state.downcast_ref::<Metrics>();
+        let state = tree.state.downcast_ref::<State>();
+        let metrics = &state.metrics;
+        let columns = self.columns.len();
+        let header = self.header_bounds(metrics, bounds, viewport);
+        let offset = Vector::new(0.0, bounds.y - header.y);
+        let is_floating = offset.y != 0.0;
+        let defaults = style;
         let style = theme.style(&self.class);
 
+        if self.on_select.is_some() {
+            let hovered = self.hovered_row(metrics, bounds, header, cursor);
+
+            for (row, background) in hovered
+                .map(|row| (row, style.hovered_row))
+                .into_iter()
+                .chain(
+                    self.selected.iter().map(|&row| (row, style.selected_row)),
+                )
+            {
+                if row >= metrics.row_count {
+                    continue;
+                }
+
+                let (y, height) = self.row_bounds(metrics, row + 1);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: bounds.y + y,
+                            height,
+                            ..bounds
+                        },
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+        }
+
+        let body_cursor = if cursor.is_over(header) {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        for ((cell, state), layout) in self
+            .cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .skip(columns)
+        {
+            cell.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                defaults,
+                layout,
+                body_cursor,
+                viewport,
+            );
+        }
+
         if self.separator_x > 0.0 {
             let mut x = self.padding_x;
 
@@ -533,17 +1322,16 @@ where
         }
 
         if self.separator_y > 0.0 {
-            let mut y = self.padding_y;
+            let visible = self.visible_rows(metrics, bounds, viewport);
 
-            for height in &metrics.rows[..metrics.rows.len().saturating_sub(1)]
-            {
-                y += height + self.padding_y;
+            for row in visible.start..visible.end.min(metrics.row_count) {
+                let (y, height) = self.row_bounds(metrics, row);
 
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds: Rectangle {
                             x: bounds.x,
-                            y: bounds.y + y,
+                            y: bounds.y + y + height,
                             width: bounds.width,
                             height: self.separator_y,
                         },
@@ -552,9 +1340,128 @@ where
                     },
                     style.separator_y,
                 );
+            }
+        }
+
+        let draw_header = |renderer: &mut Renderer| {
+            if is_floating {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            height: header.height + self.separator_y,
+                            ..bounds
+                        },
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    style.header,
+                );
+
+                for column in 0..columns.saturating_sub(1) {
+                    let (x, width) = self.column_bounds(metrics, column);
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x + x + width,
+                                y: bounds.y,
+                                width: self.separator_x,
+                                height: header.height,
+                            },
+                            snap: true,
+                            ..renderer::Quad::default()
+                        },
+                        style.separator_x,
+                    );
+                }
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: bounds.y + header.height,
+                            height: self.separator_y,
+                            ..bounds
+                        },
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    style.separator_y,
+                );
+            }
+
+            for (column, ((cell, state), layout)) in self
+                .cells
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .take(columns)
+                .enumerate()
+            {
+                cell.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    defaults,
+                    layout,
+                    cursor + offset,
+                    &(*viewport + offset),
+                );
+
+                let Some(order) = self.columns[column].sort else {
+                    continue;
+                };
 
-                y += self.separator_y + self.padding_y;
+                let indicator = self.indicator(renderer, column);
+
+                if indicator == 0.0 {
+                    continue;
+                }
+
+                let (x, width) = self.column_bounds(metrics, column);
+
+                renderer.fill_text(
+                    core::Text {
+                        content: match order {
+                            Order::Ascending => Renderer::SCROLL_UP_ICON,
+                            Order::Descending => Renderer::SCROLL_DOWN_ICON,
+                        }
+                        .to_string(),
+                        bounds: Size::new(indicator, metrics.rows[0]),
+                        size: Pixels(indicator * 0.75),
+                        line_height: text::LineHeight::default(),
+                        font: Renderer::ICON_FONT,
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                    },
+                    Point::new(
+                        bounds.x + x + width - self.padding_x - indicator / 2.0,
+                        bounds.y + self.padding_y + metrics.rows[0] / 2.0,
+                    ),
+                    defaults.text_color,
+                    bounds,
+                );
             }
+        };
+
+        if is_floating {
+            let clip_bounds = Rectangle {
+                height: header.height + self.separator_y,
+                ..header
+            };
+
+            renderer.with_layer(
+                clip_bounds.intersection(viewport).unwrap_or(clip_bounds),
+                |renderer| {
+                    renderer.with_translation(
+                        Vector::new(0.0, -offset.y),
+                        draw_header,
+                    );
+                },
+            );
+        } else {
+            draw_header(renderer);
         }
     }
 
@@ -566,16 +1473,56 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.cells
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let columns = self.columns.len();
+        let header = self.header_bounds(&state.metrics, bounds, viewport);
+        let offset = Vector::new(0.0, bounds.y - header.y);
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let interaction = self
+            .cells
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
-            .map(|((cell, tree), layout)| {
+            .enumerate()
+            .map(|(i, ((cell, tree), layout))| {
+                let cursor = if i < columns {
+                    cursor + offset
+                } else if cursor.is_over(header) {
+                    mouse::Cursor::Unavailable
+                } else {
+                    cursor
+                };
+
                 cell.as_widget()
                     .mouse_interaction(tree, layout, cursor, viewport, renderer)
             })
             .max()
-            .unwrap_or_default()
+            .unwrap_or_default();
+
+        if let Some(position) = cursor.position_over(header) {
+            let x = position.x - bounds.x;
+
+            if self.on_column_resize.is_some()
+                && self.divider_at(&state.metrics, x).is_some()
+            {
+                return mouse::Interaction::ResizingHorizontally;
+            }
+
+            if interaction == mouse::Interaction::None
+                && self.column_at(&state.metrics, x).is_some_and(|column| {
+                    self.columns[column].on_sort.is_some()
+                })
+            {
+                return mouse::Interaction::Pointer;
+            }
+        }
+
+        interaction
     }
 
     fn operate(
@@ -620,7 +1567,7 @@ impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(table: Table<'a, Message, Theme, Renderer>) -> Self {
         Element::new(table)
@@ -641,6 +1588,8 @@ pub struct Column<
     width: Length,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    sort: Option<Order>,
+    on_sort: Option<Box<dyn Fn(Order) -> Message + 'a>>,
 }
 
 impl<'a, 'b, T, Message, Theme, Renderer>
@@ -669,6 +1618,24 @@ impl<'a, 'b, T, Message, Theme, Renderer>
         self.align_y = alignment.into();
         self
     }
+
+    /// Sets the current sort [`Order`] of the [`Column`], displayed as an
+    /// indicator in its header.
+    pub fn sort(mut self, order: impl Into<Option<Order>>) -> Self {
+        self.sort = order.into();
+        self
+    }
+
+    /// Sets the message that should be produced when the header of the
+    /// [`Column`] is clicked.
+    ///
+    /// The closure receives the [`Order`] the [`Column`] should be sorted
+    /// by next: the reverse of its current [`Order`] or
+    /// [`Order::Ascending`] if it is not sorted.
+    pub fn on_sort(mut self, on_sort: impl Fn(Order) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
 }
 
 /// The appearance of a [`Table`].
@@ -678,6 +1645,13 @@ pub struct Style {
     pub separator_x: Background,
     /// The background color of the vertical line separator between cells.
     pub separator_y: Background,
+    /// The [`Background`] of the header while it sticks to the top of the
+    /// visible area.
+    pub header: Background,
+    /// The [`Background`] of a hovered row of a selectable [`Table`].
+    pub hovered_row: Background,
+    /// The [`Background`] of a selected row.
+    pub selected_row: Background,
 }
 
 /// The theme catalog of a [`Table`].
@@ -721,5 +1695,128 @@ pub fn default(theme: &crate::Theme) -> Style {
     Style {
         separator_x: separator,
         separator_y: separator,
+        header: palette.background.base.color.into(),
+        hovered_row: palette.background.weakest.color.into(),
+        selected_row: palette.primary.weak.color.scale_alpha(0.5).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Font;
+
+    type Element =
+        core::Element<'static, Vec<usize>, crate::Theme, crate::Renderer>;
+
+    fn virtual_table(count: usize) -> Element {
+        let cell = || crate::Space::new().width(20).height(10);
+
+        super::virtual_table([column(cell(), move |_| cell())], count, |i| i)
+            .row_height(10)
+            .padding(0)
+            .separator(0)
+            .on_select(|rows| rows)
+            .into()
+    }
+
+    fn layout(table: &mut Element, tree: &mut widget::Tree) -> layout::Node {
+        let renderer = crate::Renderer::new(Font::default(), Pixels(16.0));
+
+        table.as_widget_mut().layout(
+            tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, f32::INFINITY)),
+        )
+    }
+
+    fn update(
+        table: &mut Element,
+        tree: &mut widget::Tree,
+        node: &layout::Node,
+        event: Event,
+        cursor: mouse::Cursor,
+        viewport: Rectangle,
+    ) -> (Vec<Vec<usize>>, bool) {
+        let renderer = crate::Renderer::new(Font::default(), Pixels(16.0));
+        let mut messages = Vec::new();
+        let mut shell = core::Shell::new(&mut messages);
+
+        table.as_widget_mut().update(
+            tree,
+            &event,
+            Layout::new(node),
+            cursor,
+            &renderer,
+            &mut core::clipboard::Null,
+            &mut shell,
+            &viewport,
+        );
+
+        let is_layout_invalid = shell.is_layout_invalid();
+
+        (messages, is_layout_invalid)
+    }
+
+    #[test]
+    fn virtual_tables_build_the_initial_rows() {
+        let mut table = virtual_table(10_000);
+        let mut tree = widget::Tree::new(table.as_widget());
+        let node = layout(&mut table, &mut tree);
+
+        // The header and the rows filling the initial height
+        assert_eq!(node.children().len(), 1 + 109);
+        assert_eq!(node.size().height, 10.0 + 10_000.0 * 10.0);
+        assert_eq!(node.children()[109].bounds().y, 10.0 + 108.0 * 10.0);
+    }
+
+    #[test]
+    fn virtual_tables_build_the_visible_rows() {
+        let mut table = virtual_table(10_000);
+        let mut tree = widget::Tree::new(table.as_widget());
+        let node = layout(&mut table, &mut tree);
+
+        let viewport =
+            Rectangle::new(Point::new(0.0, 50_000.0), Size::new(100.0, 500.0));
+
+        let (_, is_layout_invalid) = update(
+            &mut table,
+            &mut tree,
+            &node,
+            Event::Mouse(mouse::Event::CursorLeft),
+            mouse::Cursor::Unavailable,
+            viewport,
+        );
+
+        assert!(is_layout_invalid);
+
+        let node = layout(&mut table, &mut tree);
+
+        // The visible rows and half a viewport around them
+        assert_eq!(node.children().len(), 1 + 101);
+        assert_eq!(node.children()[1].bounds().y, 10.0 + 4_974.0 * 10.0);
+        assert_eq!(node.size().height, 10.0 + 10_000.0 * 10.0);
+
+        let (_, is_layout_invalid) = update(
+            &mut table,
+            &mut tree,
+            &node,
+            Event::Mouse(mouse::Event::CursorLeft),
+            mouse::Cursor::Unavailable,
+            viewport,
+        );
+
+        assert!(!is_layout_invalid);
+
+        let (messages, _) = update(
+            &mut table,
+            &mut tree,
+            &node,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            mouse::Cursor::Available(Point::new(10.0, 50_005.0)),
+            viewport,
+        );
+
+        assert_eq!(messages, vec![vec![4_999]]);
     }
 }
@@ -0,0 +1,1279 @@
+//! Tabs display a bar of labels to switch between different views.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::tabs::Tab;
+//! use iced::widget::{tabs, text};
+//!
+//! struct State {
+//!     documents: Vec<String>,
+//!     active: usize,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Select(usize),
+//!     Close(usize),
+//!     Reorder(usize, usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     tabs(
+//!         state.documents.iter().map(|name| Tab::new(name.as_str())),
+//!         state.active,
+//!         text(&state.documents[state.active]),
+//!     )
+//!     .on_select(Message::Select)
+//!     .on_close(Message::Close)
+//!     .on_reorder(Message::Reorder)
+//!     .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Theme, Vector,
+};
+use crate::overlay::menu;
+
+/// The padding of a single tab.
+const TAB_PADDING: Padding = Padding {
+    top: 6.0,
+    right: 12.0,
+    bottom: 6.0,
+    left: 12.0,
+};
+
+/// The space between the icon, the label, and the close button of a tab.
+const SPACING: f32 = 6.0;
+
+/// The distance the cursor must travel before a tab starts being dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The amount of pixels scrolled per line of a mouse wheel.
+const SCROLL_LINE: f32 = 40.0;
+
+/// A bar of tabs on top of the contents of the selected one.
+///
+/// Only the contents of the selected tab are part of the widget tree.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::tabs::Tab;
+/// use iced::widget::{tabs, text};
+///
+/// struct State {
+///     active: usize,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(usize),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     tabs(
+///         [Tab::new("General"), Tab::new("Advanced")],
+///         state.active,
+///         text!("Settings page {}", state.active),
+///     )
+///     .on_select(Message::Select)
+///     .into()
+/// }
+/// ```
+pub struct Tabs<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tabs: Vec<Tab<Renderer::Font>>,
+    selected: usize,
+    content: Element<'a, Message, Theme, Renderer>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+    overflow: Vec<menu::Item<Message>>,
+}
+
+impl<'a, Message, Theme, Renderer> Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Tabs`] with the given tabs, the index of the
+    /// selected one, and its contents.
+    pub fn new(
+        tabs: impl IntoIterator<Item = Tab<Renderer::Font>>,
+        selected: usize,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            tabs: tabs.into_iter().collect(),
+            selected,
+            content: content.into(),
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            font: None,
+            text_size: None,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+            overflow: Vec::new(),
+        }
+    }
+
+    /// Sets the message produced when a tab is selected, either by
+    /// clicking it or with `Ctrl+Tab` and `Ctrl+Shift+Tab`.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message produced when the close button of a tab is
+    /// pressed, or when a tab is middle clicked.
+    ///
+    /// Close buttons are only displayed if this is set.
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message produced when a tab is dragged to a new position.
+    ///
+    /// The message receives the current index of the tab and the index it
+    /// should be moved to.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the font of the labels of the [`Tabs`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Tabs`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the menu listing the tabs that do not fit.
+    #[must_use]
+    pub fn menu_style(
+        mut self,
+        style: impl Fn(&Theme) -> menu::Style + 'a,
+    ) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tabs`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the menu listing the tabs that do not fit.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(
+        mut self,
+        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    fn is_closable(&self, index: usize) -> bool {
+        self.on_close.is_some()
+            && self.tabs.get(index).is_some_and(|tab| tab.is_closable)
+    }
+}
+
+/// A tab of some [`Tabs`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab<Font = crate::core::Font> {
+    label: String,
+    icon: Option<Icon<Font>>,
+    is_closable: bool,
+}
+
+impl<Font> Tab<Font> {
+    /// Creates a new [`Tab`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            is_closable: true,
+        }
+    }
+
+    /// Sets the [`Icon`] displayed before the label of the [`Tab`].
+    pub fn icon(mut self, font: impl Into<Font>, code_point: char) -> Self {
+        self.icon = Some(Icon {
+            font: font.into(),
+            code_point,
+        });
+        self
+    }
+
+    /// Sets whether the [`Tab`] displays a close button.
+    ///
+    /// Tabs are closable by default, as long as [`Tabs::on_close`] is set.
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+}
+
+/// The icon of a [`Tab`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Icon<Font> {
+    /// The font that will be used to display the `code_point`.
+    pub font: Font,
+    /// The unicode code point that will be used as the icon.
+    pub code_point: char,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    offset: f32,
+    revealed: Option<usize>,
+    hovered: Option<Hover>,
+    drag: Option<Drag>,
+    menu: menu::nested::State,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Hover {
+    Tab(usize),
+    Close(usize),
+    Overflow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    delta: f32,
+    is_dragging: bool,
+}
+
+/// The geometry of the bar of some [`Tabs`].
+struct Bar {
+    bounds: Rectangle,
+    /// The visible region of the tabs, excluding the overflow button.
+    tabs: Rectangle,
+    overflow: Option<Rectangle>,
+    total_width: f32,
+}
+
+impl Bar {
+    fn new(bar: Layout<'_>) -> Self {
+        let bounds = bar.bounds();
+
+        let total_width = bar
+            .children()
+            .last()
+            .map(|tab| tab.bounds().x + tab.bounds().width - bounds.x)
+            .unwrap_or_default();
+
+        if total_width > bounds.width {
+            let button = Rectangle {
+                x: bounds.x + bounds.width - bounds.height,
+                width: bounds.height,
+                ..bounds
+            };
+
+            Self {
+                bounds,
+                tabs: Rectangle {
+                    width: bounds.width - button.width,
+                    ..bounds
+                },
+                overflow: Some(button),
+                total_width,
+            }
+        } else {
+            Self {
+                bounds,
+                tabs: bounds,
+                overflow: None,
+                total_width,
+            }
+        }
+    }
+
+    fn max_offset(&self) -> f32 {
+        (self.total_width - self.tabs.width).max(0.0)
+    }
+
+    /// Returns the index and the visible bounds of the tab under the cursor.
+    fn tab_at(
+        &self,
+        bar: Layout<'_>,
+        offset: f32,
+        cursor: mouse::Cursor,
+    ) -> Option<(usize, Rectangle)> {
+        let position = cursor.position_over(self.tabs)?;
+
+        bar.children()
+            .map(|tab| tab.bounds() - Vector::new(offset, 0.0))
+            .enumerate()
+            .find(|(_, bounds)| bounds.contains(position))
+    }
+}
+
+/// Returns the bounds of the close button of a tab with the given bounds.
+fn close_bounds(tab: Rectangle, size: Pixels) -> Rectangle {
+    Rectangle {
+        x: tab.x + tab.width - TAB_PADDING.right - size.0,
+        y: tab.center_y() - size.0 / 2.0,
+        width: size.0,
+        height: size.0,
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabs<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height =
+            text::LineHeight::default().to_absolute(size).0 + TAB_PADDING.y();
+
+        let limits = limits.width(self.width).height(self.height);
+
+        let mut x = 0.0;
+
+        let tabs: Vec<_> = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(index, tab)| {
+                let label = Renderer::Paragraph::with_text(Text {
+                    content: tab.label.as_str(),
+                    bounds: Size::INFINITE,
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x: text::Alignment::Default,
+                    align_y: alignment::Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                })
+                .min_width();
+
+                let width = TAB_PADDING.x()
+                    + label
+                    + if tab.icon.is_some() {
+                        size.0 + SPACING
+                    } else {
+                        0.0
+                    }
+                    + if self.is_closable(index) {
+                        size.0 + SPACING
+                    } else {
+                        0.0
+                    };
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(x, 0.0));
+
+                x += width;
+                node
+            })
+            .collect();
+
+        let content = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(0.0, height)),
+        );
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(
+                x.max(content.size().width),
+                height + content.size().height,
+            ),
+        );
+
+        let bar =
+            layout::Node::with_children(Size::new(size.width, height), tabs);
+
+        let state = tree.state.downcast_mut::<State>();
+        let geometry = Bar::new(Layout::new(&bar));
+
+        if state.revealed != Some(self.selected)
+            && let Some(tab) = bar.children().get(self.selected)
+        {
+            let tab = tab.bounds();
+
+            if tab.x < state.offset {
+                state.offset = tab.x;
+            } else if tab.x + tab.width > state.offset + geometry.tabs.width {
+                state.offset = tab.x + tab.width - geometry.tabs.width;
+            }
+
+            state.revealed = Some(self.selected);
+        }
+
+        state.offset = state.offset.clamp(0.0, geometry.max_offset());
+
+        layout::Node::with_children(
+            size,
+            vec![bar, content.move_to(Point::new(0.0, height))],
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout.children().nth(1).unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let bar_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bar = Bar::new(bar_layout);
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let hover = |offset: f32| {
+            if bar.overflow.is_some_and(|button| cursor.is_over(button)) {
+                return Some(Hover::Overflow);
+            }
+
+            let (index, bounds) = bar.tab_at(bar_layout, offset, cursor)?;
+
+            if self.is_closable(index)
+                && cursor.is_over(close_bounds(bounds, size))
+            {
+                Some(Hover::Close(index))
+            } else {
+                Some(Hover::Tab(index))
+            }
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let hovered = hover(state.offset);
+
+                if let Some(drag) = &mut state.drag
+                    && let Some(position) = cursor.position()
+                {
+                    drag.delta = position.x - drag.origin;
+
+                    if drag.delta.abs() > DRAG_THRESHOLD {
+                        drag.is_dragging = true;
+                    }
+
+                    if drag.is_dragging {
+                        shell.request_redraw();
+                    }
+                }
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                match hover(state.offset) {
+                    Some(Hover::Overflow) => {
+                        state.menu.open();
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                    }
+                    Some(Hover::Close(index)) => {
+                        if let Some(on_close) = &self.on_close {
+                            shell.publish(on_close(index));
+                        }
+                    }
+                    Some(Hover::Tab(index)) => {
+                        if index != self.selected
+                            && let Some(on_select) = &self.on_select
+                        {
+                            shell.publish(on_select(index));
+                        }
+
+                        if self.on_reorder.is_some()
+                            && let Some(position) = cursor.position()
+                        {
+                            state.drag = Some(Drag {
+                                index,
+                                origin: position.x,
+                                delta: 0.0,
+                                is_dragging: false,
+                            });
+                        }
+                    }
+                    None => return,
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => {
+                if let Some(Hover::Tab(index) | Hover::Close(index)) =
+                    hover(state.offset)
+                    && self.is_closable(index)
+                    && let Some(on_close) = &self.on_close
+                {
+                    shell.publish(on_close(index));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(drag) = state.drag.take() else {
+                    return;
+                };
+
+                if !drag.is_dragging {
+                    return;
+                }
+
+                let center = bar_layout
+                    .children()
+                    .nth(drag.index)
+                    .map(|tab| tab.bounds().center_x() + drag.delta)
+                    .unwrap_or_default();
+
+                let target = bar_layout
+                    .children()
+                    .enumerate()
+                    .filter(|(index, _)| *index != drag.index)
+                    .filter(|(_, tab)| tab.bounds().center_x() < center)
+                    .count();
+
+                if target != drag.index
+                    && let Some(on_reorder) = &self.on_reorder
+                {
+                    shell.publish(on_reorder(drag.index, target));
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if bar.overflow.is_none() || !cursor.is_over(bar.tabs) {
+                    return;
+                }
+
+                let delta = match *delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (if x.abs() > y.abs() { x } else { y }) * SCROLL_LINE
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x.abs() > y.abs() { x } else { y }
+                    }
+                };
+
+                let offset =
+                    (state.offset - delta).clamp(0.0, bar.max_offset());
+
+                if offset != state.offset {
+                    state.offset = offset;
+                    state.hovered = hover(state.offset);
+
+                    shell.request_redraw();
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            }) if modifiers.control() && !self.tabs.is_empty() => {
+                let Some(on_select) = &self.on_select else {
+                    return;
+                };
+
+                let count = self.tabs.len();
+
+                let index = if modifiers.shift() {
+                    (self.selected + count - 1) % count
+                } else {
+                    (self.selected + 1) % count
+                };
+
+                shell.publish(on_select(index));
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let mut children = layout.children();
+        let bar_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if cursor.is_over(bar_layout.bounds()) {
+            return match state.hovered {
+                Some(Hover::Close(_) | Hover::Overflow) => {
+                    mouse::Interaction::Pointer
+                }
+                Some(Hover::Tab(_)) | None => mouse::Interaction::None,
+            };
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let mut children = layout.children();
+        let bar_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let bar = Bar::new(bar_layout);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let active =
+            <Theme as Catalog>::style(theme, &self.class, Status::Active);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bar.bounds,
+                ..renderer::Quad::default()
+            },
+            active.bar,
+        );
+
+        let dragged = state
+            .drag
+            .filter(|drag| drag.is_dragging)
+            .map(|drag| drag.index);
+
+        let draw_tab = |renderer: &mut Renderer, index: usize, bounds| {
+            let tab = &self.tabs[index];
+
+            let status = if index == self.selected || dragged == Some(index) {
+                Status::Selected
+            } else if matches!(
+                state.hovered,
+                Some(Hover::Tab(i) | Hover::Close(i)) if i == index
+            ) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let style = <Theme as Catalog>::style(theme, &self.class, status);
+
+            self.draw_tab(
+                renderer,
+                tab,
+                index,
+                bounds,
+                &style,
+                font,
+                size,
+                matches!(state.hovered, Some(Hover::Close(i)) if i == index),
+            );
+        };
+
+        renderer.with_layer(bar.tabs, |renderer| {
+            for (index, tab) in bar_layout.children().enumerate() {
+                if dragged == Some(index) {
+                    continue;
+                }
+
+                draw_tab(
+                    renderer,
+                    index,
+                    tab.bounds() - Vector::new(state.offset, 0.0),
+                );
+            }
+
+            if let Some(drag) = state.drag.filter(|drag| drag.is_dragging)
+                && let Some(tab) = bar_layout.children().nth(drag.index)
+            {
+                draw_tab(
+                    renderer,
+                    drag.index,
+                    tab.bounds() - Vector::new(state.offset - drag.delta, 0.0),
+                );
+            }
+        });
+
+        if let Some(button) = bar.overflow {
+            let style = <Theme as Catalog>::style(
+                theme,
+                &self.class,
+                if state.menu.is_open() {
+                    Status::Selected
+                } else if state.hovered == Some(Hover::Overflow) {
+                    Status::Hovered
+                } else {
+                    Status::Active
+                },
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: button,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            renderer.fill_text(
+                Text {
+                    content: Renderer::ARROW_DOWN_ICON.to_string(),
+                    bounds: button.size(),
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font: Renderer::ICON_FONT,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                button.center(),
+                style.text_color,
+                button,
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = layout.children();
+        let bar_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let state = tree.state.downcast_mut::<State>();
+        let bar = Bar::new(bar_layout);
+
+        let menu = match bar.overflow {
+            Some(button) if state.menu.is_open() => {
+                self.overflow = self
+                    .tabs
+                    .iter()
+                    .enumerate()
+                    .map(|(index, tab)| {
+                        menu::Item::radio(
+                            tab.label.replace('&', "&&"),
+                            index == self.selected,
+                        )
+                        .on_press_maybe(
+                            self.on_select
+                                .as_ref()
+                                .map(|on_select| on_select(index)),
+                        )
+                    })
+                    .collect();
+
+                Some(overlay::Element::new(Box::new(menu::nested::Overlay {
+                    state: &mut state.menu,
+                    items: &self.overflow,
+                    origin: button + translation,
+                    font: self.font,
+                    text_size: self.text_size,
+                    class: &self.menu_class,
+                })))
+            }
+            _ => None,
+        };
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            content_layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        if content.is_some() || menu.is_some() {
+            Some(
+                overlay::Group::with_children(
+                    content.into_iter().chain(menu).collect(),
+                )
+                .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Tabs<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn draw_tab(
+        &self,
+        renderer: &mut Renderer,
+        tab: &Tab<Renderer::Font>,
+        index: usize,
+        bounds: Rectangle,
+        style: &Style,
+        font: Renderer::Font,
+        size: Pixels,
+        is_close_hovered: bool,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        if let Some(indicator) = style.indicator {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - 2.0,
+                        height: 2.0,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                indicator,
+            );
+        }
+
+        let text = |content: String, font, x| {
+            (
+                Text {
+                    content,
+                    bounds: Size::new(f32::INFINITY, bounds.height),
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x: text::Alignment::Left,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                Point::new(x, bounds.center_y()),
+            )
+        };
+
+        let mut x = bounds.x + TAB_PADDING.left;
+
+        if let Some(icon) = tab.icon {
+            let (text, position) =
+                text(icon.code_point.to_string(), icon.font, x);
+
+            renderer.fill_text(text, position, style.text_color, bounds);
+
+            x += size.0 + SPACING;
+        }
+
+        let (label, position) = text(tab.label.clone(), font, x);
+
+        renderer.fill_text(label, position, style.text_color, bounds);
+
+        if self.is_closable(index) {
+            let close = close_bounds(bounds, size);
+
+            if is_close_hovered {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: close,
+                        border: border::rounded(2),
+                        ..renderer::Quad::default()
+                    },
+                    style.text_color.scale_alpha(0.15),
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: String::from("×"),
+                    bounds: close.size(),
+                    size,
+                    line_height: text::LineHeight::Absolute(size),
+                    font,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                close.center(),
+                style.text_color,
+                bounds,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tabs<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tabs: Tabs<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tabs)
+    }
+}
+
+/// The possible status of a tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The tab is not selected.
+    Active,
+    /// The tab is not selected and it is being hovered.
+    Hovered,
+    /// The tab is selected or being dragged.
+    Selected,
+}
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the bar of the tabs.
+    pub bar: Background,
+    /// The [`Background`] of the tab.
+    pub background: Background,
+    /// The text [`Color`] of the tab.
+    pub text_color: Color,
+    /// The [`Border`] of the tab.
+    pub border: Border,
+    /// The [`Color`] of the line drawn at the bottom of the tab, if any.
+    pub indicator: Option<Color>,
+}
+
+/// The theme catalog of some [`Tabs`].
+pub trait Catalog: menu::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the menu listing the tabs that do not fit.
+    fn default_menu<'a>() -> <Self as menu::Catalog>::Class<'a> {
+        <Self as menu::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class with the given status.
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+    ) -> Style;
+}
+
+/// A styling function for some [`Tabs`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+    ) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of some [`Tabs`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        bar: palette.background.weakest.color.into(),
+        background: Color::TRANSPARENT.into(),
+        text_color: palette.background.weakest.text.scale_alpha(0.7),
+        border: border::rounded(border::top(4)),
+        indicator: None,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            background: palette.background.weak.color.into(),
+            text_color: palette.background.weak.text,
+            ..active
+        },
+        Status::Selected => Style {
+            background: palette.background.base.color.into(),
+            text_color: palette.background.base.text,
+            indicator: Some(palette.primary.strong.color),
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::Harness;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Select(usize),
+        Close(usize),
+        Reorder(usize, usize),
+    }
+
+    fn tabs() -> Harness<'static, Message> {
+        Harness::new(
+            Tabs::new(
+                [
+                    Tab::new("One"),
+                    Tab::new("Two").closable(false),
+                    Tab::new("Three"),
+                ],
+                0,
+                crate::Space::new(),
+            )
+            .on_select(Message::Select)
+            .on_close(Message::Close)
+            .on_reorder(Message::Reorder),
+        )
+    }
+
+    fn tab(harness: &mut Harness<'_, Message>, index: usize) -> Rectangle {
+        harness.layout().children()[0].children()[index].bounds()
+    }
+
+    #[test]
+    fn close_buttons_close_their_tab() {
+        let mut harness = tabs();
+        let close = close_bounds(tab(&mut harness, 2), Pixels(16.0)).center();
+
+        assert_eq!(harness.click(close), vec![Message::Close(2)]);
+    }
+
+    #[test]
+    fn middle_clicks_close_closable_tabs() {
+        let mut harness = tabs();
+        let middle =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle));
+
+        let first = tab(&mut harness, 0).center();
+        harness.point_at(first);
+        assert_eq!(harness.update(middle.clone()), vec![Message::Close(0)]);
+
+        let second = tab(&mut harness, 1).center();
+        harness.point_at(second);
+        assert_eq!(harness.update(middle), vec![]);
+    }
+
+    #[test]
+    fn dragging_a_tab_reorders_it() {
+        let mut harness = tabs();
+        let first = tab(&mut harness, 0).center();
+        let last = tab(&mut harness, 2);
+
+        assert_eq!(
+            harness.drag(first, Point::new(last.x + last.width, first.y)),
+            vec![Message::Reorder(0, 2)]
+        );
+
+        let second = tab(&mut harness, 1).center();
+
+        assert_eq!(
+            harness.drag(second, Point::new(0.0, second.y)),
+            vec![Message::Select(1), Message::Reorder(1, 0)]
+        );
+    }
+
+    #[test]
+    fn short_drags_only_select() {
+        let mut harness = tabs();
+        let second = tab(&mut harness, 1).center();
+
+        assert_eq!(
+            harness.drag(second, second + Vector::new(DRAG_THRESHOLD, 0.0)),
+            vec![Message::Select(1)]
+        );
+    }
+}
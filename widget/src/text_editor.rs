@@ -31,6 +31,10 @@
 //!     }
 //! }
 //! ```
+mod gutter;
+mod history;
+mod search;
+
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::input_method;
@@ -59,6 +63,10 @@ use std::ops::DerefMut;
 use std::ops::Range;
 use std::sync::Arc;
 
+use history::History;
+
+pub use gutter::{Icon, Marker};
+pub use search::{Match, Query};
 pub use text::editor::{Action, Edit, Line, LineEnding, Motion};
 
 /// A multi-line text input.
@@ -117,6 +125,10 @@ pub struct TextEditor<
     max_height: f32,
     padding: Padding,
     wrapping: Wrapping,
+    matches: &'a [Match],
+    line_numbers: bool,
+    markers: Option<&'a [Marker<Renderer::Font>]>,
+    highlight_current_line: bool,
     class: Theme::Class<'a>,
     key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
@@ -149,6 +161,10 @@ where
             max_height: f32::INFINITY,
             padding: Padding::new(5.0),
             wrapping: Wrapping::default(),
+            matches: &[],
+            line_numbers: false,
+            markers: None,
+            highlight_current_line: false,
             class: <Theme as Catalog>::default(),
             key_binding: None,
             on_edit: None,
@@ -296,6 +312,10 @@ where
             max_height: self.max_height,
             padding: self.padding,
             wrapping: self.wrapping,
+            matches: self.matches,
+            line_numbers: self.line_numbers,
+            markers: self.markers,
+            highlight_current_line: self.highlight_current_line,
             class: self.class,
             key_binding: self.key_binding,
             on_edit: self.on_edit,
@@ -305,6 +325,42 @@ where
         }
     }
 
+    /// Highlights the given search matches in the [`TextEditor`].
+    ///
+    /// The matches are drawn in their own layer, independently of the
+    /// syntax [`Highlighter`]. You can obtain them with [`Content::search`].
+    pub fn matches(mut self, matches: &'a [Match]) -> Self {
+        self.matches = matches;
+        self
+    }
+
+    /// Sets whether the [`TextEditor`] should display line numbers in
+    /// its gutter.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the [`Marker`]s displayed in the gutter of the [`TextEditor`].
+    ///
+    /// Space for markers is reserved in the gutter as long as this method
+    /// is called, even if there are no markers; so the text does not move
+    /// when markers come and go.
+    pub fn markers(mut self, markers: &'a [Marker<Renderer::Font>]) -> Self {
+        self.markers = Some(markers);
+        self
+    }
+
+    /// Sets whether the [`TextEditor`] should highlight the line containing
+    /// the cursor.
+    pub fn highlight_current_line(
+        mut self,
+        highlight_current_line: bool,
+    ) -> Self {
+        self.highlight_current_line = highlight_current_line;
+        self
+    }
+
     /// Sets the closure to produce key bindings on key presses.
     ///
     /// See [`Binding`] for the list of available bindings.
@@ -334,6 +390,13 @@ where
         self
     }
 
+    fn text_padding(&self, state: &State<Highlighter>) -> Padding {
+        Padding {
+            left: self.padding.left + state.gutter.width(),
+            ..self.padding
+        }
+    }
+
     fn input_method<'b>(
         &self,
         state: &'b State<Highlighter>,
@@ -351,7 +414,7 @@ where
         let bounds = layout.bounds();
         let internal = self.content.0.borrow_mut();
 
-        let text_bounds = bounds.shrink(self.padding);
+        let text_bounds = bounds.shrink(self.text_padding(state));
         let translation = text_bounds.position() - Point::ORIGIN;
 
         let cursor = match internal.editor.cursor() {
@@ -388,6 +451,7 @@ where
     R: text::Renderer,
 {
     editor: R::Editor,
+    history: History,
     is_dirty: bool,
 }
 
@@ -404,18 +468,220 @@ where
     pub fn with_text(text: &str) -> Self {
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
+            history: History::default(),
             is_dirty: true,
         }))
     }
 
     /// Performs an [`Action`] on the [`Content`].
+    ///
+    /// Edits are recorded in the history of the [`Content`], which can be
+    /// traversed with [`Action::Undo`] and [`Action::Redo`]. Consecutive
+    /// typing is grouped into words.
     pub fn perform(&mut self, action: Action) {
         let internal = self.0.get_mut();
 
-        internal.editor.perform(action);
+        match action {
+            Action::Edit(edit) => {
+                internal.history.edit(&mut internal.editor, edit);
+            }
+            Action::Undo => {
+                internal.history.undo(&mut internal.editor);
+            }
+            Action::Redo => {
+                internal.history.redo(&mut internal.editor);
+            }
+            Action::Scroll { .. } => {
+                internal.editor.perform(action);
+            }
+            _ => {
+                internal.history.seal();
+                internal.editor.perform(action);
+            }
+        }
+
         internal.is_dirty = true;
     }
 
+    /// Returns true if the [`Content`] has changes that can be undone.
+    pub fn can_undo(&self) -> bool {
+        self.0.borrow().history.can_undo()
+    }
+
+    /// Returns true if the [`Content`] has undone changes that can be redone.
+    pub fn can_redo(&self) -> bool {
+        self.0.borrow().history.can_redo()
+    }
+
+    /// Returns the maximum amount of changes the [`Content`] can undo,
+    /// if bounded.
+    pub fn history_limit(&self) -> Option<usize> {
+        self.0.borrow().history.limit()
+    }
+
+    /// Sets the maximum amount of changes the [`Content`] can undo.
+    ///
+    /// By default, the history of a [`Content`] is unbounded.
+    pub fn set_history_limit(&mut self, limit: impl Into<Option<usize>>) {
+        self.0.get_mut().history.set_limit(limit.into());
+    }
+
+    /// Clears the undo and redo history of the [`Content`].
+    pub fn clear_history(&mut self) {
+        self.0.get_mut().history.clear();
+    }
+
+    /// Returns all the matches of the given [`Query`] in the [`Content`],
+    /// in order.
+    pub fn search(&self, query: &Query) -> Vec<Match> {
+        self.lines()
+            .enumerate()
+            .flat_map(|(i, line)| {
+                query.find(&line.text).into_iter().map(move |range| Match {
+                    start: (i, range.start),
+                    end: (i, range.end),
+                })
+            })
+            .collect()
+    }
+
+    /// Selects the next match of the given [`Query`] after the current
+    /// selection, wrapping around the end of the [`Content`].
+    ///
+    /// Returns the selected [`Match`], if any.
+    pub fn select_next(&mut self, query: &Query) -> Option<Match> {
+        let matches = self.search(query);
+        let (_, end) = self.selection_range();
+
+        let next = matches
+            .iter()
+            .find(|candidate| candidate.start >= end)
+            .or_else(|| matches.first())
+            .copied()?;
+
+        self.select(next);
+
+        Some(next)
+    }
+
+    /// Selects the previous match of the given [`Query`] before the current
+    /// selection, wrapping around the start of the [`Content`].
+    ///
+    /// Returns the selected [`Match`], if any.
+    pub fn select_previous(&mut self, query: &Query) -> Option<Match> {
+        let matches = self.search(query);
+        let (start, _) = self.selection_range();
+
+        let previous = matches
+            .iter()
+            .rev()
+            .find(|candidate| candidate.end <= start)
+            .or_else(|| matches.last())
+            .copied()?;
+
+        self.select(previous);
+
+        Some(previous)
+    }
+
+    /// Replaces the currently selected match of the given [`Query`] with
+    /// the `replacement`, and then selects the next match.
+    ///
+    /// If the current selection is not a match, the next match is selected
+    /// without replacing anything.
+    ///
+    /// Returns the selected [`Match`], if any.
+    pub fn replace(
+        &mut self,
+        query: &Query,
+        replacement: &str,
+    ) -> Option<Match> {
+        let (start, end) = self.selection_range();
+        let is_selected = self.search(query).contains(&Match { start, end });
+
+        if is_selected {
+            let line = self.line(start.0)?.text.into_owned();
+            let replacement =
+                query.replacement(&line, start.1..end.1, replacement);
+
+            let internal = self.0.get_mut();
+
+            internal.history.transact(&mut internal.editor, |editor| {
+                editor
+                    .perform(Action::Edit(Edit::Paste(Arc::new(replacement))));
+            });
+
+            internal.is_dirty = true;
+        }
+
+        self.select_next(query)
+    }
+
+    /// Replaces all the matches of the given [`Query`] with the
+    /// `replacement` as a single undoable edit.
+    ///
+    /// Returns the amount of replaced matches.
+    pub fn replace_all(&mut self, query: &Query, replacement: &str) -> usize {
+        let replacements: Vec<_> = self
+            .lines()
+            .enumerate()
+            .flat_map(|(i, line)| {
+                query
+                    .find(&line.text)
+                    .into_iter()
+                    .map(|range| {
+                        (
+                            Match {
+                                start: (i, range.start),
+                                end: (i, range.end),
+                            },
+                            query.replacement(&line.text, range, replacement),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        if replacements.is_empty() {
+            return 0;
+        }
+
+        let internal = self.0.get_mut();
+
+        internal.history.transact(&mut internal.editor, |editor| {
+            for (found, replacement) in replacements.iter().rev() {
+                editor.set_cursor_position(found.end, Some(found.start));
+                editor.perform(Action::Edit(Edit::Paste(Arc::new(
+                    replacement.clone(),
+                ))));
+            }
+        });
+
+        internal.is_dirty = true;
+
+        replacements.len()
+    }
+
+    fn select(&mut self, selection: Match) {
+        let internal = self.0.get_mut();
+
+        internal.history.seal();
+        internal
+            .editor
+            .set_cursor_position(selection.end, Some(selection.start));
+        internal.is_dirty = true;
+    }
+
+    /// Returns the start and end positions of the current selection, or
+    /// the cursor position twice if nothing is selected.
+    fn selection_range(&self) -> ((usize, usize), (usize, usize)) {
+        let internal = self.0.borrow();
+        let cursor = internal.editor.cursor_position();
+        let anchor = internal.editor.selection_anchor().unwrap_or(cursor);
+
+        (cursor.min(anchor), cursor.max(anchor))
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -516,7 +782,10 @@ pub struct State<Highlighter: text::Highlighter> {
     preedit: Option<input_method::Preedit>,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
+    column_origin: Option<Point>,
+    keyboard_modifiers: keyboard::Modifiers,
     partial_scroll: f32,
+    gutter: gutter::Metrics,
     last_theme: RefCell<Option<String>>,
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
@@ -591,7 +860,10 @@ where
             preedit: None,
             last_click: None,
             drag_click: None,
+            column_origin: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
             partial_scroll: 0.0,
+            gutter: gutter::Metrics::default(),
             last_theme: RefCell::default(),
             highlighter: RefCell::new(Highlighter::new(
                 &self.highlighter_settings,
@@ -639,10 +911,23 @@ where
             .min_height(self.min_height)
             .max_height(self.max_height);
 
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        state.gutter = gutter::Metrics::measure::<Renderer>(
+            internal.editor.line_count(),
+            self.line_numbers,
+            self.markers.is_some(),
+            font,
+            text_size,
+            self.line_height,
+        );
+
         internal.editor.update(
-            limits.shrink(self.padding).max(),
-            self.font.unwrap_or_else(|| renderer.default_font()),
-            self.text_size.unwrap_or_else(|| renderer.default_size()),
+            limits.shrink(self.text_padding(state)).max(),
+            font,
+            text_size,
             self.line_height,
             self.wrapping,
             state.highlighter.borrow_mut().deref_mut(),
@@ -717,6 +1002,9 @@ where
                     );
                 }
             }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = *modifiers;
+            }
             _ => {}
         }
 
@@ -724,13 +1012,18 @@ where
             event,
             state,
             layout.bounds(),
-            self.padding,
+            self.text_padding(state),
             cursor,
             self.key_binding.as_deref(),
         ) {
             match update {
                 Update::Click(click) => {
+                    let is_column = state.keyboard_modifiers.alt();
+
                     let action = match click.kind() {
+                        mouse::click::Kind::Single if is_column => {
+                            Action::AddCursor(click.position())
+                        }
                         mouse::click::Kind::Single => {
                             Action::Click(click.position())
                         }
@@ -741,15 +1034,24 @@ where
                     state.focus = Some(Focus::now());
                     state.last_click = Some(click);
                     state.drag_click = Some(click.kind());
+                    state.column_origin = is_column.then_some(click.position());
 
                     shell.publish(on_edit(action));
                     shell.capture_event();
                 }
                 Update::Drag(position) => {
-                    shell.publish(on_edit(Action::Drag(position)));
+                    let action = match state.column_origin {
+                        Some(from) => {
+                            Action::SelectColumn { from, to: position }
+                        }
+                        None => Action::Drag(position),
+                    };
+
+                    shell.publish(on_edit(action));
                 }
                 Update::Release => {
                     state.drag_click = None;
+                    state.column_origin = None;
                 }
                 Update::Scroll(lines) => {
                     let bounds = self.content.0.borrow().editor.bounds();
@@ -808,6 +1110,7 @@ where
                             Binding::Unfocus => {
                                 state.focus = None;
                                 state.drag_click = None;
+                                state.column_origin = None;
                             }
                             Binding::Copy => {
                                 if let Some(selection) = content.selection() {
@@ -851,6 +1154,9 @@ where
                             Binding::SelectAll => {
                                 publish(Action::SelectAll);
                             }
+                            Binding::SelectNextOccurrence => {
+                                publish(Action::SelectNextOccurrence);
+                            }
                             Binding::Insert(c) => {
                                 publish(Action::Edit(Edit::Insert(c)));
                             }
@@ -863,6 +1169,12 @@ where
                             Binding::Delete => {
                                 publish(Action::Edit(Edit::Delete));
                             }
+                            Binding::Undo => {
+                                publish(Action::Undo);
+                            }
+                            Binding::Redo => {
+                                publish(Action::Redo);
+                            }
                             Binding::Sequence(sequence) => {
                                 for binding in sequence {
                                     apply_binding(
@@ -974,7 +1286,79 @@ where
             style.background,
         );
 
-        let text_bounds = bounds.shrink(self.padding);
+        let text_bounds = bounds.shrink(self.text_padding(state));
+        let translation = text_bounds.position() - Point::ORIGIN;
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let visible_lines = internal.editor.visible_lines();
+        let (current_line, _) = internal.editor.cursor_position();
+
+        gutter::draw(
+            renderer,
+            state.gutter,
+            bounds,
+            text_bounds,
+            &visible_lines,
+            current_line,
+            self.line_numbers,
+            self.markers.unwrap_or_default(),
+            font,
+            text_size,
+            self.line_height,
+            &style,
+        );
+
+        if self.highlight_current_line
+            && matches!(internal.editor.cursor(), Cursor::Caret(_))
+            && let Some((_, line)) = visible_lines
+                .iter()
+                .find(|(index, _)| *index == current_line)
+        {
+            let gutter_width = state.gutter.width();
+
+            let highlight = Rectangle {
+                x: bounds.x + gutter_width,
+                y: text_bounds.y + line.y,
+                width: bounds.width - gutter_width,
+                height: line.height,
+            };
+
+            let clip_bounds = Rectangle {
+                y: text_bounds.y,
+                height: text_bounds.height,
+                ..bounds
+            };
+
+            if let Some(highlight) = clip_bounds.intersection(&highlight) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: highlight,
+                        ..renderer::Quad::default()
+                    },
+                    style.current_line,
+                );
+            }
+        }
+
+        for region in self
+            .matches
+            .iter()
+            .flat_map(|found| {
+                internal.editor.range_bounds(found.start, found.end)
+            })
+            .filter_map(|region| {
+                text_bounds.intersection(&(region + translation))
+            })
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: region,
+                    ..renderer::Quad::default()
+                },
+                style.search_match,
+            );
+        }
 
         if internal.editor.is_empty() {
             if let Some(placeholder) = self.placeholder.clone() {
@@ -982,9 +1366,7 @@ where
                     Text {
                         content: placeholder.into_owned(),
                         bounds: text_bounds.size(),
-                        size: self
-                            .text_size
-                            .unwrap_or_else(|| renderer.default_size()),
+                        size: text_size,
                         line_height: self.line_height,
                         font,
                         align_x: text::Alignment::Default,
@@ -1006,50 +1388,45 @@ where
             );
         }
 
-        let translation = text_bounds.position() - Point::ORIGIN;
-
         if let Some(focus) = state.focus.as_ref() {
-            match internal.editor.cursor() {
-                Cursor::Caret(position) if focus.is_cursor_visible() => {
-                    let cursor =
-                        Rectangle::new(
+            for cursor in internal.editor.cursors() {
+                match cursor {
+                    Cursor::Caret(position) if focus.is_cursor_visible() => {
+                        let cursor = Rectangle::new(
                             position + translation,
                             Size::new(
                                 1.0,
-                                self.line_height
-                                    .to_absolute(self.text_size.unwrap_or_else(
-                                        || renderer.default_size(),
-                                    ))
-                                    .into(),
+                                self.line_height.to_absolute(text_size).into(),
                             ),
                         );
 
-                    if let Some(clipped_cursor) =
-                        text_bounds.intersection(&cursor)
-                    {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: clipped_cursor,
-                                ..renderer::Quad::default()
-                            },
-                            style.value,
-                        );
+                        if let Some(clipped_cursor) =
+                            text_bounds.intersection(&cursor)
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: clipped_cursor,
+                                    ..renderer::Quad::default()
+                                },
+                                style.value,
+                            );
+                        }
                     }
-                }
-                Cursor::Selection(ranges) => {
-                    for range in ranges.into_iter().filter_map(|range| {
-                        text_bounds.intersection(&(range + translation))
-                    }) {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: range,
-                                ..renderer::Quad::default()
-                            },
-                            style.selection,
-                        );
+                    Cursor::Selection(ranges) => {
+                        for range in ranges.into_iter().filter_map(|range| {
+                            text_bounds.intersection(&(range + translation))
+                        }) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: range,
+                                    ..renderer::Quad::default()
+                                },
+                                style.selection,
+                            );
+                        }
                     }
+                    Cursor::Caret(_) => {}
                 }
-                Cursor::Caret(_) => {}
             }
         }
     }
@@ -1125,6 +1502,9 @@ pub enum Binding<Message> {
     SelectLine,
     /// Select the entire buffer.
     SelectAll,
+    /// Select the word at the current cursor or, if there is a selection
+    /// already, add a new selection for its next occurrence.
+    SelectNextOccurrence,
     /// Insert the given character.
     Insert(char),
     /// Break the current line.
@@ -1133,6 +1513,10 @@ pub enum Binding<Message> {
     Backspace,
     /// Delete the next character.
     Delete,
+    /// Undo the last change.
+    Undo,
+    /// Redo the last undone change.
+    Redo,
     /// A sequence of bindings to execute.
     Sequence(Vec<Self>),
     /// Produce the given message.
@@ -1194,6 +1578,21 @@ impl<Message> Binding<Message> {
             keyboard::Key::Character("a") if modifiers.command() => {
                 Some(Self::SelectAll)
             }
+            keyboard::Key::Character("d") if modifiers.command() => {
+                Some(Self::SelectNextOccurrence)
+            }
+            keyboard::Key::Character("z" | "Z") if modifiers.command() => {
+                Some(if modifiers.shift() {
+                    Self::Redo
+                } else {
+                    Self::Undo
+                })
+            }
+            keyboard::Key::Character("y")
+                if modifiers.command() && !cfg!(target_os = "macos") =>
+            {
+                Some(Self::Redo)
+            }
             _ => {
                 if let Some(text) = text {
                     let c = text.chars().find(|c| !c.is_control())?;
@@ -1406,6 +1805,16 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Color`] of the highlighted search matches of the text input.
+    pub search_match: Color,
+    /// The [`Background`] of the gutter of the text input.
+    pub gutter: Background,
+    /// The [`Color`] of the line numbers of the text input.
+    pub line_number: Color,
+    /// The [`Color`] of the line number of the current line of the text input.
+    pub current_line_number: Color,
+    /// The [`Color`] of the highlight of the current line of the text input.
+    pub current_line: Color,
 }
 
 /// The theme catalog of a [`TextEditor`].
@@ -1445,10 +1854,16 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             radius: 2.0.into(),
             width: 1.0,
             color: palette.background.strong.color,
+            ..Border::default()
         },
         placeholder: palette.secondary.base.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        search_match: palette.warning.weak.color,
+        gutter: Background::Color(palette.background.weakest.color),
+        line_number: palette.background.strong.color,
+        current_line_number: palette.background.base.text,
+        current_line: palette.background.weaker.color,
     };
 
     match status {
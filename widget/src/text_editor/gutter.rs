@@ -0,0 +1,257 @@
+use crate::core::alignment;
+use crate::core::border;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Paragraph as _, Text};
+use crate::core::{Color, Pixels, Point, Rectangle, Size};
+
+use super::Style;
+
+/// A marker displayed in the gutter of a [`TextEditor`], next to a line.
+///
+/// Markers can be used to flag lines with breakpoints, diagnostics,
+/// bookmarks, etc.
+///
+/// [`TextEditor`]: super::TextEditor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Marker<Font = crate::core::Font> {
+    /// The index of the line of the [`Marker`].
+    pub line: usize,
+    /// The [`Color`] of the [`Marker`].
+    pub color: Color,
+    /// The [`Icon`] of the [`Marker`]; a dot is drawn if `None`.
+    pub icon: Option<Icon<Font>>,
+}
+
+impl<Font> Marker<Font> {
+    /// Creates a new [`Marker`] for the given line, drawn as a dot with
+    /// the given [`Color`].
+    pub fn new(line: usize, color: impl Into<Color>) -> Self {
+        Self {
+            line,
+            color: color.into(),
+            icon: None,
+        }
+    }
+
+    /// Sets the [`Icon`] of the [`Marker`].
+    pub fn icon(mut self, font: impl Into<Font>, code_point: char) -> Self {
+        self.icon = Some(Icon {
+            font: font.into(),
+            code_point,
+        });
+        self
+    }
+}
+
+/// The icon of a [`Marker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Icon<Font> {
+    /// The font that will be used to display the `code_point`.
+    pub font: Font,
+    /// The unicode code point that will be used as the icon.
+    pub code_point: char,
+}
+
+/// The measured columns of the gutter of a [`TextEditor`].
+///
+/// [`TextEditor`]: super::TextEditor
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Metrics {
+    markers: f32,
+    numbers: f32,
+    spacing: f32,
+}
+
+impl Metrics {
+    /// Measures the gutter needed to display the line numbers and markers
+    /// of an editor with the given amount of lines.
+    pub fn measure<Renderer: text::Renderer>(
+        line_count: usize,
+        has_line_numbers: bool,
+        has_markers: bool,
+        font: Renderer::Font,
+        size: Pixels,
+        line_height: LineHeight,
+    ) -> Self {
+        if !has_line_numbers && !has_markers {
+            return Self::default();
+        }
+
+        let digit = Renderer::Paragraph::with_text(Text {
+            content: "0",
+            bounds: Size::INFINITE,
+            size,
+            line_height,
+            font,
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        })
+        .min_width();
+
+        let digits = line_count.max(1).ilog10() as usize + 1;
+
+        Self {
+            markers: if has_markers {
+                line_height.to_absolute(size).0
+            } else {
+                0.0
+            },
+            numbers: if has_line_numbers {
+                digit * digits.max(2) as f32
+            } else {
+                0.0
+            },
+            spacing: digit,
+        }
+    }
+
+    /// Returns the total width of the gutter.
+    pub fn width(self) -> f32 {
+        if self.markers == 0.0 && self.numbers == 0.0 {
+            0.0
+        } else {
+            self.markers + self.numbers + self.spacing * 2.0
+        }
+    }
+}
+
+/// Draws the gutter of a [`TextEditor`] with the given [`Metrics`].
+///
+/// The `lines` are the visible lines of the editor, relative to the
+/// `text_bounds`.
+///
+/// [`TextEditor`]: super::TextEditor
+pub fn draw<Renderer: text::Renderer>(
+    renderer: &mut Renderer,
+    metrics: Metrics,
+    bounds: Rectangle,
+    text_bounds: Rectangle,
+    lines: &[(usize, Rectangle)],
+    current_line: usize,
+    has_line_numbers: bool,
+    markers: &[Marker<Renderer::Font>],
+    font: Renderer::Font,
+    size: Pixels,
+    line_height: LineHeight,
+    style: &Style,
+) {
+    let width = metrics.width();
+
+    if width == 0.0 {
+        return;
+    }
+
+    let border = style.border.side_widths();
+
+    let gutter = Rectangle {
+        x: bounds.x + border.left,
+        y: bounds.y + border.top,
+        width,
+        height: bounds.height - border.top - border.bottom,
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: gutter,
+            border: border::rounded(
+                style.border.radius.top_right(0).bottom_right(0),
+            ),
+            ..renderer::Quad::default()
+        },
+        style.gutter,
+    );
+
+    let clip_bounds = Rectangle {
+        y: text_bounds.y,
+        height: text_bounds.height,
+        ..gutter
+    };
+
+    let line_height = line_height.to_absolute(size).0;
+
+    for &(index, line) in lines {
+        let y = text_bounds.y + line.y;
+
+        if has_line_numbers {
+            renderer.fill_text(
+                Text {
+                    content: (index + 1).to_string(),
+                    bounds: Size::new(metrics.numbers, line_height),
+                    size,
+                    line_height: LineHeight::Absolute(Pixels(line_height)),
+                    font,
+                    align_x: text::Alignment::Right,
+                    align_y: alignment::Vertical::Top,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                Point::new(
+                    gutter.x
+                        + metrics.spacing
+                        + metrics.markers
+                        + metrics.numbers,
+                    y,
+                ),
+                if index == current_line {
+                    style.current_line_number
+                } else {
+                    style.line_number
+                },
+                clip_bounds,
+            );
+        }
+
+        for marker in markers.iter().filter(|marker| marker.line == index) {
+            let center = Point::new(
+                gutter.x + metrics.spacing + metrics.markers / 2.0,
+                y + line_height / 2.0,
+            );
+
+            match marker.icon {
+                Some(icon) => {
+                    renderer.fill_text(
+                        Text {
+                            content: icon.code_point.to_string(),
+                            bounds: Size::new(metrics.markers, line_height),
+                            size,
+                            line_height: LineHeight::Absolute(Pixels(
+                                line_height,
+                            )),
+                            font: icon.font,
+                            align_x: text::Alignment::Center,
+                            align_y: alignment::Vertical::Center,
+                            shaping: text::Shaping::Advanced,
+                            wrapping: text::Wrapping::None,
+                        },
+                        center,
+                        marker.color,
+                        clip_bounds,
+                    );
+                }
+                None => {
+                    let radius = size.0 / 4.0;
+
+                    let dot = Rectangle {
+                        x: center.x - radius,
+                        y: center.y - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    };
+
+                    if clip_bounds.contains(center) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: dot,
+                                border: border::rounded(radius),
+                                ..renderer::Quad::default()
+                            },
+                            marker.color,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,358 @@
+use crate::core::text::editor::{Action, Change, Edit, Editor};
+
+use std::collections::VecDeque;
+
+/// The undo and redo stacks of a text editor.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Entry>,
+    redo: Vec<Entry>,
+    limit: Option<usize>,
+    group: Option<Group>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    change: Change,
+    before: Selection,
+    after: Selection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Selection {
+    cursor: (usize, usize),
+    anchor: Option<(usize, usize)>,
+}
+
+impl Selection {
+    fn of(editor: &impl Editor) -> Self {
+        Self {
+            cursor: editor.cursor_position(),
+            anchor: editor.selection_anchor(),
+        }
+    }
+
+    fn restore(self, editor: &mut impl Editor) {
+        editor.set_cursor_position(self.cursor, self.anchor);
+    }
+}
+
+/// The kind of edits that may be coalesced into a single [`Entry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Group {
+    Typing { last: char },
+    Backspace,
+    Delete,
+}
+
+impl Group {
+    fn of(edit: &Edit) -> Option<Self> {
+        match edit {
+            Edit::Insert(c) => Some(Self::Typing { last: *c }),
+            Edit::Backspace => Some(Self::Backspace),
+            Edit::Delete => Some(Self::Delete),
+            Edit::Paste(_) | Edit::Enter | Edit::Indent | Edit::Unindent => {
+                None
+            }
+        }
+    }
+
+    /// Returns true if an edit of the `next` group can be merged into an
+    /// [`Entry`] of this group.
+    ///
+    /// Typing is split into word-sized groups; a new group starts
+    /// whenever a word starts after some whitespace.
+    fn accepts(self, next: Self) -> bool {
+        match (self, next) {
+            (Self::Typing { last }, Self::Typing { last: next }) => {
+                !last.is_whitespace() || next.is_whitespace()
+            }
+            (Self::Backspace, Self::Backspace)
+            | (Self::Delete, Self::Delete) => true,
+            _ => false,
+        }
+    }
+}
+
+impl History {
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    pub fn set_limit(&mut self, limit: Option<usize>) {
+        self.limit = limit;
+        self.truncate();
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.group = None;
+    }
+
+    /// Stops coalescing edits into the last [`Entry`].
+    pub fn seal(&mut self) {
+        self.group = None;
+    }
+
+    /// Performs the given [`Edit`] on the editor, recording its changes.
+    pub fn edit(&mut self, editor: &mut impl Editor, edit: Edit) {
+        let group = Group::of(&edit);
+
+        self.record(editor, group, |editor| {
+            editor.perform(Action::Edit(edit));
+        });
+    }
+
+    /// Runs the given closure on the editor, recording all of its changes
+    /// as a single entry.
+    pub fn transact<E: Editor>(
+        &mut self,
+        editor: &mut E,
+        f: impl FnOnce(&mut E),
+    ) {
+        self.record(editor, None, f);
+    }
+
+    fn record<E: Editor>(
+        &mut self,
+        editor: &mut E,
+        group: Option<Group>,
+        f: impl FnOnce(&mut E),
+    ) {
+        let has_selection = editor.selection_anchor().is_some();
+        let before = Selection::of(editor);
+
+        editor.start_change();
+        f(editor);
+
+        let Some(change) = editor.finish_change() else {
+            return;
+        };
+
+        let after = Selection::of(editor);
+        self.redo.clear();
+
+        let is_coalesced = !has_selection
+            && group.is_some_and(|group| {
+                self.group.is_some_and(|last| last.accepts(group))
+            });
+
+        match self.undo.back_mut() {
+            Some(entry) if is_coalesced => {
+                entry.change.extend(change);
+                entry.after = after;
+            }
+            _ => {
+                self.undo.push_back(Entry {
+                    change,
+                    before,
+                    after,
+                });
+
+                self.truncate();
+            }
+        }
+
+        self.group = group;
+    }
+
+    pub fn undo(&mut self, editor: &mut impl Editor) {
+        self.group = None;
+
+        let Some(entry) = self.undo.pop_back() else {
+            return;
+        };
+
+        editor.apply_change(&entry.change.reversed());
+        entry.before.restore(editor);
+
+        self.redo.push(entry);
+    }
+
+    pub fn redo(&mut self, editor: &mut impl Editor) {
+        self.group = None;
+
+        let Some(entry) = self.redo.pop() else {
+            return;
+        };
+
+        editor.apply_change(&entry.change);
+        entry.after.restore(editor);
+
+        self.undo.push_back(entry);
+    }
+
+    fn truncate(&mut self) {
+        if let Some(limit) = self.limit {
+            while self.undo.len() > limit {
+                let _ = self.undo.pop_front();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::editor::Motion;
+
+    use std::sync::Arc;
+
+    type Content = crate::text_editor::Content<crate::Renderer>;
+
+    fn write(content: &mut Content, text: &str) {
+        for c in text.chars() {
+            content.perform(Action::Edit(Edit::Insert(c)));
+        }
+    }
+
+    fn undo(content: &mut Content) -> String {
+        content.perform(Action::Undo);
+        content.text()
+    }
+
+    fn redo(content: &mut Content) -> String {
+        content.perform(Action::Redo);
+        content.text()
+    }
+
+    #[test]
+    fn typing_is_grouped_into_words() {
+        let mut content = Content::new();
+        write(&mut content, "hello  big world");
+
+        assert_eq!(undo(&mut content), "hello  big ");
+        assert_eq!(undo(&mut content), "hello  ");
+        assert_eq!(undo(&mut content), "");
+        assert!(!content.can_undo());
+
+        assert_eq!(redo(&mut content), "hello  ");
+        assert_eq!(redo(&mut content), "hello  big ");
+        assert_eq!(redo(&mut content), "hello  big world");
+        assert!(!content.can_redo());
+    }
+
+    #[test]
+    fn deletions_are_grouped_by_kind() {
+        let mut content = Content::with_text("abcdef");
+        content.perform(Action::Move(Motion::DocumentEnd));
+
+        content.perform(Action::Edit(Edit::Backspace));
+        content.perform(Action::Edit(Edit::Backspace));
+        content.perform(Action::Move(Motion::DocumentStart));
+        content.perform(Action::Edit(Edit::Delete));
+        content.perform(Action::Edit(Edit::Delete));
+
+        assert_eq!(content.text(), "cd");
+        assert_eq!(undo(&mut content), "abcd");
+        assert_eq!(content.cursor_position(), (0, 0));
+        assert_eq!(undo(&mut content), "abcdef");
+        assert_eq!(content.cursor_position(), (0, 6));
+    }
+
+    #[test]
+    fn moving_the_cursor_starts_a_new_entry() {
+        let mut content = Content::new();
+        write(&mut content, "ab");
+
+        content.perform(Action::Move(Motion::Left));
+        write(&mut content, "c");
+
+        assert_eq!(content.text(), "acb");
+        assert_eq!(undo(&mut content), "ab");
+        assert_eq!(content.cursor_position(), (0, 1));
+        assert_eq!(undo(&mut content), "");
+    }
+
+    #[test]
+    fn other_edits_are_never_grouped() {
+        let mut content = Content::new();
+        write(&mut content, "ab");
+
+        content.perform(Action::Edit(Edit::Paste(Arc::new("cd".to_owned()))));
+        content.perform(Action::Edit(Edit::Enter));
+        write(&mut content, "ef");
+
+        assert_eq!(content.text(), "abcd\nef");
+        assert_eq!(undo(&mut content), "abcd\n");
+        assert_eq!(undo(&mut content), "abcd");
+        assert_eq!(undo(&mut content), "ab");
+    }
+
+    #[test]
+    fn undo_restores_the_selection() {
+        let mut content = Content::with_text("hello");
+        content.perform(Action::SelectAll);
+        write(&mut content, "bye");
+
+        assert_eq!(content.text(), "bye");
+        assert_eq!(content.selection(), None);
+
+        assert_eq!(undo(&mut content), "hello");
+        assert_eq!(content.selection().as_deref(), Some("hello"));
+
+        assert_eq!(redo(&mut content), "bye");
+        assert_eq!(content.selection(), None);
+        assert_eq!(content.cursor_position(), (0, 3));
+    }
+
+    #[test]
+    fn new_changes_discard_redo() {
+        let mut content = Content::new();
+        write(&mut content, "one two");
+
+        assert_eq!(undo(&mut content), "one ");
+        assert!(content.can_redo());
+
+        write(&mut content, "three");
+
+        assert!(!content.can_redo());
+        assert_eq!(redo(&mut content), "one three");
+        assert_eq!(undo(&mut content), "one ");
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut content = Content::new();
+        content.set_history_limit(2);
+        write(&mut content, "a b c d");
+
+        assert_eq!(undo(&mut content), "a b c ");
+        assert_eq!(undo(&mut content), "a b ");
+        assert!(!content.can_undo());
+        assert_eq!(undo(&mut content), "a b ");
+
+        content.set_history_limit(None);
+        content.clear_history();
+
+        assert!(!content.can_redo());
+    }
+
+    #[test]
+    fn group_boundaries() {
+        let typing = |last| Group::Typing { last };
+
+        assert!(typing('a').accepts(typing('b')));
+        assert!(typing('a').accepts(typing(' ')));
+        assert!(typing(' ').accepts(typing(' ')));
+        assert!(!typing(' ').accepts(typing('b')));
+        assert!(!typing('\t').accepts(typing('b')));
+
+        assert!(Group::Backspace.accepts(Group::Backspace));
+        assert!(!Group::Backspace.accepts(Group::Delete));
+        assert!(!Group::Delete.accepts(typing('a')));
+        assert!(!typing('a').accepts(Group::Backspace));
+
+        assert_eq!(Group::of(&Edit::Enter), None);
+        assert_eq!(Group::of(&Edit::Indent), None);
+    }
+}
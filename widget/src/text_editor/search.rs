@@ -0,0 +1,268 @@
+use std::ops::Range;
+
+/// A search query for the [`Content`] of a [`TextEditor`].
+///
+/// Matches never span multiple lines.
+///
+/// [`Content`]: super::Content
+/// [`TextEditor`]: super::TextEditor
+#[derive(Debug, Clone)]
+pub struct Query {
+    kind: Kind,
+    is_case_sensitive: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Kind {
+    Literal(String),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl Query {
+    /// Creates a new [`Query`] that matches the given text literally.
+    ///
+    /// The [`Query`] is case sensitive by default.
+    pub fn literal(text: impl Into<String>) -> Self {
+        Self {
+            kind: Kind::Literal(text.into()),
+            is_case_sensitive: true,
+        }
+    }
+
+    /// Creates a new [`Query`] that matches the given regular expression.
+    ///
+    /// Flags, like `(?i)` for case insensitive matching, can be used as
+    /// part of the pattern.
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            kind: Kind::Regex(regex::Regex::new(pattern)?),
+            is_case_sensitive: true,
+        })
+    }
+
+    /// Sets whether a literal [`Query`] is case sensitive.
+    ///
+    /// Regular expressions ignore this setting.
+    pub fn case_sensitive(mut self, is_case_sensitive: bool) -> Self {
+        self.is_case_sensitive = is_case_sensitive;
+        self
+    }
+
+    /// Returns the byte ranges of the non-overlapping matches of the
+    /// [`Query`] in the given line.
+    pub(super) fn find(&self, line: &str) -> Vec<Range<usize>> {
+        match &self.kind {
+            Kind::Literal(text) => {
+                if text.is_empty() {
+                    return Vec::new();
+                }
+
+                if self.is_case_sensitive {
+                    return line
+                        .match_indices(text.as_str())
+                        .map(|(start, text)| start..start + text.len())
+                        .collect();
+                }
+
+                let mut matches = Vec::new();
+                let mut start = 0;
+
+                while start < line.len() {
+                    if let Some(end) = prefix_ignore_case(&line[start..], text)
+                    {
+                        matches.push(start..start + end);
+                        start += end;
+                    } else {
+                        start += line[start..]
+                            .chars()
+                            .next()
+                            .map_or(1, char::len_utf8);
+                    }
+                }
+
+                matches
+            }
+            #[cfg(feature = "regex")]
+            Kind::Regex(regex) => regex
+                .find_iter(line)
+                .map(|found| found.range())
+                .filter(|range| !range.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Returns the text that should replace the given match in the line.
+    ///
+    /// Regular expressions expand capture groups (e.g. `$1`) in the
+    /// `replacement`.
+    pub(super) fn replacement(
+        &self,
+        _line: &str,
+        _range: Range<usize>,
+        replacement: &str,
+    ) -> String {
+        match &self.kind {
+            Kind::Literal(_) => replacement.to_owned(),
+            #[cfg(feature = "regex")]
+            Kind::Regex(regex) => {
+                let mut expanded = String::new();
+
+                if let Some(captures) = regex.captures_at(_line, _range.start) {
+                    captures.expand(replacement, &mut expanded);
+                }
+
+                expanded
+            }
+        }
+    }
+}
+
+/// Returns the byte length of the prefix of `haystack` that matches
+/// `needle` ignoring case, if any.
+fn prefix_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    let mut haystack_chars = haystack.char_indices();
+    let mut length = 0;
+
+    for expected in needle.chars() {
+        let (index, c) = haystack_chars.next()?;
+
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+
+        length = index + c.len_utf8();
+    }
+
+    Some(length)
+}
+
+/// A match of a [`Query`] in the [`Content`] of a [`TextEditor`].
+///
+/// [`Content`]: super::Content
+/// [`TextEditor`]: super::TextEditor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Match {
+    /// The start position of the match; line and column, respectively.
+    pub start: (usize, usize),
+    /// The end position of the match; line and column, respectively.
+    pub end: (usize, usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Content = crate::text_editor::Content<crate::Renderer>;
+
+    #[test]
+    fn literal_matches_are_case_sensitive_by_default() {
+        let query = Query::literal("Hello");
+
+        assert_eq!(query.find("Hello hello HELLO Hello"), vec![0..5, 18..23]);
+        assert_eq!(query.find("help"), Vec::<Range<usize>>::new());
+        assert!(Query::literal("").find("anything").is_empty());
+    }
+
+    #[test]
+    fn case_insensitive_matches_fold_case() {
+        let query = Query::literal("straße").case_sensitive(false);
+
+        assert_eq!(
+            query.find("STRAßE Straße straße"),
+            vec![0..7, 8..15, 16..23]
+        );
+
+        let query = Query::literal("ΣΟΦΙΑ").case_sensitive(false);
+
+        assert_eq!(query.find("σοφια"), vec![0..10]);
+    }
+
+    #[test]
+    fn case_insensitive_ranges_use_the_bytes_of_the_line() {
+        // `İ` lowercases to two characters, but case is folded one
+        // character at a time; so matches cover whole characters of
+        // the line.
+        let query = Query::literal("i̇x").case_sensitive(false);
+
+        assert_eq!(query.find("İx"), Vec::<Range<usize>>::new());
+
+        let query = Query::literal("İx").case_sensitive(false);
+        let line = "aİx İX";
+
+        assert_eq!(query.find(line), vec![1..4, 5..8]);
+        assert_eq!(&line[1..4], "İx");
+        assert_eq!(&line[5..8], "İX");
+    }
+
+    #[test]
+    fn matches_use_byte_columns_on_multibyte_text() {
+        let content = Content::with_text("ñandú ñu\n😀 ñu");
+        let matches = content.search(&Query::literal("ñu"));
+
+        assert_eq!(
+            matches,
+            vec![
+                Match {
+                    start: (0, 8),
+                    end: (0, 11),
+                },
+                Match {
+                    start: (1, 5),
+                    end: (1, 8),
+                },
+            ]
+        );
+
+        assert_eq!(&"ñandú ñu"[8..11], "ñu");
+        assert_eq!(&"😀 ñu"[5..8], "ñu");
+    }
+
+    #[test]
+    fn replace_all_keeps_earlier_matches_in_place() {
+        let mut content = Content::with_text("ü a ü a\nüü");
+
+        let replaced = content.replace_all(&Query::literal("ü"), "long");
+
+        assert_eq!(replaced, 4);
+        assert_eq!(content.text(), "long a long a\nlonglong");
+
+        let replaced = content
+            .replace_all(&Query::literal("LONG").case_sensitive(false), "ß");
+
+        assert_eq!(replaced, 4);
+        assert_eq!(content.text(), "ß a ß a\nßß");
+
+        content.perform(crate::text_editor::Action::Undo);
+
+        assert_eq!(content.text(), "long a long a\nlonglong");
+    }
+
+    #[test]
+    fn replace_selects_the_next_match() {
+        let mut content = Content::with_text("one two one");
+        let query = Query::literal("one");
+
+        let first = content.select_next(&query);
+
+        assert_eq!(
+            first,
+            Some(Match {
+                start: (0, 0),
+                end: (0, 3)
+            })
+        );
+
+        let next = content.replace(&query, "uno");
+
+        assert_eq!(content.text(), "uno two one");
+        assert_eq!(
+            next,
+            Some(Match {
+                start: (0, 8),
+                end: (0, 11)
+            })
+        );
+    }
+}
@@ -34,11 +34,15 @@ mod editor;
 mod value;
 
 pub mod cursor;
+pub mod mask;
+pub mod numeric;
 
 pub use cursor::Cursor;
+pub use mask::Mask;
+pub use numeric::Numeric;
 pub use value::Value;
 
-use editor::Editor;
+use editor::{Edit, Editor};
 
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
@@ -116,6 +120,11 @@ pub struct TextInput<
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    filter: Option<Box<dyn Fn(char) -> bool + 'a>>,
+    validator: Option<Box<dyn Fn(&str) -> bool + 'a>>,
+    max_length: Option<usize>,
+    mask: Option<Mask>,
+    numeric: Option<Numeric>,
     icon: Option<Icon<Renderer::Font>>,
     class: Theme::Class<'a>,
     last_status: Option<Status>,
@@ -147,6 +156,11 @@ where
             on_input: None,
             on_paste: None,
             on_submit: None,
+            filter: None,
+            validator: None,
+            max_length: None,
+            mask: None,
+            numeric: None,
             icon: None,
             class: Theme::default(),
             last_status: None,
@@ -223,6 +237,43 @@ where
         self
     }
 
+    /// Sets the filter of the characters that can be typed or pasted into
+    /// the [`TextInput`].
+    ///
+    /// Characters rejected by the filter are never inserted.
+    pub fn filter(mut self, filter: impl Fn(char) -> bool + 'a) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the validator of the value of the [`TextInput`].
+    ///
+    /// The validator is called with the new value before any edit is
+    /// applied. Edits producing an invalid value are discarded, leaving
+    /// the current value and cursor untouched.
+    pub fn validate(mut self, validator: impl Fn(&str) -> bool + 'a) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Sets the maximum amount of characters of the [`TextInput`].
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the [`Mask`] of the [`TextInput`].
+    pub fn mask(mut self, mask: Mask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Sets the [`Numeric`] mode of the [`TextInput`].
+    pub fn numeric(mut self, numeric: Numeric) -> Self {
+        self.numeric = Some(numeric);
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -290,6 +341,100 @@ where
         self
     }
 
+    /// Replaces the current [`Value`] of the [`TextInput`].
+    pub(crate) fn set_value(&mut self, value: &str) {
+        self.value = Value::new(value);
+    }
+
+    /// Performs the given [`Edit`] on the current value with the given
+    /// [`Cursor`], returning the new value and [`Cursor`] if the result
+    /// satisfies the constraints of the [`TextInput`].
+    fn edit(&self, mut cursor: Cursor, edit: Edit) -> Option<(Value, Cursor)> {
+        let edit = match edit {
+            Edit::Insert(c)
+                if self.filter.as_ref().is_some_and(|filter| !filter(c)) =>
+            {
+                return None;
+            }
+            Edit::Paste(content) => {
+                let mut content = match &self.filter {
+                    Some(filter) => Value::new(
+                        &content
+                            .graphemes()
+                            .filter(|grapheme| grapheme.chars().all(filter))
+                            .collect::<String>(),
+                    ),
+                    None => content,
+                };
+
+                if let Some(max_length) = self.max_length {
+                    let selected = cursor
+                        .selection(&self.value)
+                        .map_or(0, |(start, end)| end - start);
+
+                    content = content.until(
+                        (max_length + selected)
+                            .saturating_sub(self.value.len()),
+                    );
+                }
+
+                Edit::Paste(content)
+            }
+            edit => edit,
+        };
+
+        let mut value = self.value.clone();
+        Editor::new(&mut value, &mut cursor).perform(edit);
+
+        if let Some(mask) = &self.mask {
+            let (masked, position) = mask.apply(&value, cursor.end(&value), self.value.len());
+
+            value = masked;
+            cursor.move_to(position);
+        }
+
+        if self.max_length.is_some_and(|max_length| {
+            value.len() > max_length && value.len() > self.value.len()
+        }) {
+            return None;
+        }
+
+        self.check(value, cursor)
+    }
+
+    /// Steps the numeric value of the [`TextInput`] the given amount of
+    /// times, keeping the [`Cursor`] at the same distance from the end.
+    fn step(&self, cursor: Cursor, steps: f64) -> Option<(Value, Cursor)> {
+        let numeric = self.numeric.as_ref()?;
+        let value =
+            Value::new(&numeric.step_by(&self.value.to_string(), steps));
+
+        let distance = self.value.len() - cursor.end(&self.value);
+
+        let mut cursor = Cursor::default();
+        cursor.move_to(value.len().saturating_sub(distance));
+
+        self.check(value, cursor)
+    }
+
+    fn check(&self, value: Value, cursor: Cursor) -> Option<(Value, Cursor)> {
+        let text = value.to_string();
+
+        if self
+            .numeric
+            .as_ref()
+            .is_some_and(|numeric| !numeric.accepts(&text))
+            || self
+                .validator
+                .as_ref()
+                .is_some_and(|validator| !validator(&text))
+        {
+            return None;
+        }
+
+        Some((value, cursor))
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -940,13 +1085,19 @@ where
                                 );
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
+                            shell.capture_event();
 
-                            let message = (on_input)(editor.contents());
+                            let Some((value, cursor)) =
+                                self.edit(state.cursor, Edit::Delete)
+                            else {
+                                return;
+                            };
+
+                            self.value = value;
+                            state.cursor = cursor;
+
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
-                            shell.capture_event();
 
                             focus.updated_at = Instant::now();
                             update_cache(state, &self.value);
@@ -974,17 +1125,24 @@ where
                                 }
                             };
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.paste(content.clone());
+                            shell.capture_event();
+
+                            let Some((value, cursor)) = self.edit(
+                                state.cursor,
+                                Edit::Paste(content.clone()),
+                            ) else {
+                                return;
+                            };
+
+                            self.value = value;
+                            state.cursor = cursor;
 
                             let message = if let Some(paste) = &self.on_paste {
-                                (paste)(editor.contents())
+                                (paste)(self.value.to_string())
                             } else {
-                                (on_input)(editor.contents())
+                                (on_input)(self.value.to_string())
                             };
                             shell.publish(message);
-                            shell.capture_event();
 
                             state.is_pasting = Some(content);
                             focus.updated_at = Instant::now();
@@ -1020,14 +1178,19 @@ where
                         if let Some(c) =
                             text.chars().next().filter(|c| !c.is_control())
                         {
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
+                            shell.capture_event();
+
+                            let Some((value, cursor)) =
+                                self.edit(state.cursor, Edit::Insert(c))
+                            else {
+                                return;
+                            };
 
-                            editor.insert(c);
+                            self.value = value;
+                            state.cursor = cursor;
 
-                            let message = (on_input)(editor.contents());
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
-                            shell.capture_event();
 
                             focus.updated_at = Instant::now();
                             update_cache(state, &self.value);
@@ -1067,13 +1230,19 @@ where
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.backspace();
+                            shell.capture_event();
+
+                            let Some((value, cursor)) =
+                                self.edit(state.cursor, Edit::Backspace)
+                            else {
+                                return;
+                            };
+
+                            self.value = value;
+                            state.cursor = cursor;
 
-                            let message = (on_input)(editor.contents());
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
-                            shell.capture_event();
 
                             focus.updated_at = Instant::now();
                             update_cache(state, &self.value);
@@ -1098,13 +1267,19 @@ where
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
+                            shell.capture_event();
 
-                            let message = (on_input)(editor.contents());
+                            let Some((value, cursor)) =
+                                self.edit(state.cursor, Edit::Delete)
+                            else {
+                                return;
+                            };
+
+                            self.value = value;
+                            state.cursor = cursor;
+
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
-                            shell.capture_event();
 
                             focus.updated_at = Instant::now();
                             update_cache(state, &self.value);
@@ -1225,6 +1400,43 @@ where
 
                             shell.capture_event();
                         }
+                        keyboard::Key::Named(
+                            named @ (key::Named::ArrowUp
+                            | key::Named::ArrowDown),
+                        ) if self.numeric.is_some() => {
+                            let Some(on_input) = &self.on_input else {
+                                return;
+                            };
+
+                            shell.capture_event();
+
+                            let direction = if named == key::Named::ArrowUp {
+                                1.0
+                            } else {
+                                -1.0
+                            };
+
+                            let steps = if modifiers.shift() {
+                                direction * 10.0
+                            } else {
+                                direction
+                            };
+
+                            let Some((value, cursor)) =
+                                self.step(state.cursor, steps)
+                            else {
+                                return;
+                            };
+
+                            self.value = value;
+                            state.cursor = cursor;
+
+                            let message = (on_input)(self.value.to_string());
+                            shell.publish(message);
+
+                            focus.updated_at = Instant::now();
+                            update_cache(state, &self.value);
+                        }
                         keyboard::Key::Named(key::Named::Escape) => {
                             state.is_focused = None;
                             state.is_dragging = None;
@@ -1239,6 +1451,44 @@ where
                     }
                 }
             }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if self.numeric.is_some()
+                    && cursor.is_over(layout.bounds()) =>
+            {
+                let state = state::<Renderer>(tree);
+
+                let (Some(on_input), Some(focus)) =
+                    (&self.on_input, &mut state.is_focused)
+                else {
+                    return;
+                };
+
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+
+                if y == 0.0 {
+                    return;
+                }
+
+                shell.capture_event();
+
+                let Some((value, cursor)) =
+                    self.step(state.cursor, y.signum() as f64)
+                else {
+                    return;
+                };
+
+                self.value = value;
+                state.cursor = cursor;
+
+                let message = (on_input)(self.value.to_string());
+                shell.publish(message);
+
+                focus.updated_at = Instant::now();
+                update_cache(state, &self.value);
+            }
             Event::Keyboard(keyboard::Event::KeyReleased { key, .. }) => {
                 let state = state::<Renderer>(tree);
 
@@ -1288,16 +1538,21 @@ where
                             return;
                         };
 
-                        let mut editor =
-                            Editor::new(&mut self.value, &mut state.cursor);
-                        editor.paste(Value::new(text));
-
                         focus.updated_at = Instant::now();
                         state.is_pasting = None;
+                        shell.capture_event();
 
-                        let message = (on_input)(editor.contents());
+                        let Some((value, cursor)) = self
+                            .edit(state.cursor, Edit::Paste(Value::new(text)))
+                        else {
+                            return;
+                        };
+
+                        self.value = value;
+                        state.cursor = cursor;
+
+                        let message = (on_input)(self.value.to_string());
                         shell.publish(message);
-                        shell.capture_event();
 
                         update_cache(state, &self.value);
                     }
@@ -1757,6 +2012,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             radius: 2.0.into(),
             width: 1.0,
             color: palette.background.strong.color,
+            ..Border::default()
         },
         icon: palette.background.weak.text,
         placeholder: palette.secondary.base.color,
@@ -1,5 +1,12 @@
 use crate::text_input::{Cursor, Value};
 
+pub enum Edit {
+    Insert(char),
+    Paste(Value),
+    Backspace,
+    Delete,
+}
+
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
@@ -10,8 +17,13 @@ impl<'a> Editor<'a> {
         Editor { value, cursor }
     }
 
-    pub fn contents(&self) -> String {
-        self.value.to_string()
+    pub fn perform(&mut self, edit: Edit) {
+        match edit {
+            Edit::Insert(character) => self.insert(character),
+            Edit::Paste(content) => self.paste(content),
+            Edit::Backspace => self.backspace(),
+            Edit::Delete => self.delete(),
+        }
     }
 
     pub fn insert(&mut self, character: char) {
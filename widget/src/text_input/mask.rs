@@ -0,0 +1,277 @@
+//! Format the value of a text input while it is typed.
+use crate::text_input::Value;
+
+/// An input mask of a [`TextInput`].
+///
+/// A [`Mask`] describes the shape of a value with a pattern, where
+/// every character is either a slot that accepts some kind of input or a
+/// literal. Literals are inserted automatically while typing and the
+/// cursor is kept next to the last typed character.
+///
+/// The special characters of a pattern are:
+///
+/// - `9`: a digit.
+/// - `a`: an alphabetic character.
+/// - `*`: an alphanumeric character.
+/// - `?`: any character.
+/// - `\`: escapes the next character, making it a literal.
+///
+/// Any other character is a literal.
+///
+/// # Example
+/// ```no_run
+/// use iced_widget::text_input::Mask;
+///
+/// let date = Mask::new("99/99/9999");
+/// let phone = Mask::new("(999) 999-9999");
+/// ```
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    slots: Vec<Slot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Any,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(self, grapheme: &str) -> bool {
+        let Some(c) = grapheme.chars().next() else {
+            return false;
+        };
+
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::Alphanumeric => c.is_alphanumeric(),
+            Slot::Any => !c.is_control(),
+            Slot::Literal(_) => false,
+        }
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from the given pattern.
+    pub fn new(pattern: &str) -> Self {
+        let mut chars = pattern.chars();
+        let mut slots = Vec::new();
+
+        while let Some(c) = chars.next() {
+            slots.push(match c {
+                '9' => Slot::Digit,
+                'a' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                '?' => Slot::Any,
+                '\\' => Slot::Literal(chars.next().unwrap_or('\\')),
+                c => Slot::Literal(c),
+            });
+        }
+
+        Self { slots }
+    }
+
+    /// Returns the maximum length of a value that fits the [`Mask`].
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns true if the [`Mask`] has an empty pattern.
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Returns true if the given text fills every slot of the [`Mask`].
+    pub fn is_complete(&self, text: &str) -> bool {
+        let value = Value::new(text);
+
+        value.len() == self.slots.len()
+            && self.slots.iter().zip(value.graphemes()).all(
+                |(slot, grapheme)| match slot {
+                    Slot::Literal(c) => grapheme.starts_with(*c),
+                    slot => slot.accepts(grapheme),
+                },
+            )
+    }
+
+    /// Formats the given [`Value`] with the [`Mask`], returning the new
+    /// [`Value`] and the new position of the cursor at `cursor`.
+    ///
+    /// `previous` is the length of the [`Value`] before it was edited; the
+    /// graphemes after the `cursor` are the ones that were already there.
+    ///
+    /// Literals found in their own position are skipped and inputs that do
+    /// not fit their slot are dropped.
+    pub(super) fn apply(
+        &self,
+        value: &Value,
+        cursor: usize,
+        previous: usize,
+    ) -> (Value, usize) {
+        let mut inputs = value
+            .graphemes()
+            .enumerate()
+            .filter(|(i, grapheme)| {
+                let position = if *i < cursor {
+                    Some(*i)
+                } else {
+                    (*i + previous).checked_sub(value.len())
+                };
+
+                !position
+                    .is_some_and(|position| self.is_literal(position, grapheme))
+            })
+            .map(|(i, grapheme)| (grapheme, i < cursor))
+            .peekable();
+
+        let mut formatted = String::new();
+        let mut count = 0;
+        let mut new_cursor = 0;
+
+        for slot in &self.slots {
+            if inputs.peek().is_none() {
+                break;
+            }
+
+            if let Slot::Literal(c) = slot {
+                formatted.push(*c);
+                count += 1;
+                continue;
+            }
+
+            let Some((grapheme, is_before_cursor)) =
+                inputs.by_ref().find(|(grapheme, _)| slot.accepts(grapheme))
+            else {
+                break;
+            };
+
+            formatted.push_str(grapheme);
+            count += 1;
+
+            if is_before_cursor {
+                new_cursor = count;
+            }
+        }
+
+        // Drop any trailing literals
+        let formatted = Value::new(&formatted);
+        let length = self
+            .slots
+            .iter()
+            .zip(formatted.graphemes())
+            .rposition(|(slot, _)| !matches!(slot, Slot::Literal(_)))
+            .map_or(0, |i| i + 1);
+
+        (formatted.until(length), new_cursor.min(length))
+    }
+
+    /// Returns true if the grapheme is one of the literals that the
+    /// [`Mask`] inserts at the given position.
+    fn is_literal(&self, position: usize, grapheme: &str) -> bool {
+        self.slots
+            .iter()
+            .skip(position)
+            .map_while(|slot| match slot {
+                Slot::Literal(c) => Some(c),
+                _ => None,
+            })
+            .any(|c| grapheme.starts_with(*c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_in(mask: &Mask, text: &str) -> String {
+        text.chars()
+            .fold(Value::new(""), |value, c| {
+                let typed = Value::new(&format!("{value}{c}"));
+
+                mask.apply(&typed, typed.len(), value.len()).0
+            })
+            .to_string()
+    }
+
+    #[test]
+    fn typing_inserts_literals() {
+        let date = Mask::new("99/99/9999");
+
+        assert_eq!(type_in(&date, "1"), "1");
+        assert_eq!(type_in(&date, "12"), "12");
+        assert_eq!(type_in(&date, "123"), "12/3");
+        assert_eq!(type_in(&date, "12/3"), "12/3");
+        assert_eq!(type_in(&date, "12031999"), "12/03/1999");
+        assert_eq!(type_in(&date, "12031999000"), "12/03/1999");
+        assert_eq!(type_in(&date, "1a2"), "12");
+
+        let phone = Mask::new("(999) 999-9999");
+
+        assert_eq!(type_in(&phone, "5551234567"), "(555) 123-4567");
+    }
+
+    #[test]
+    fn literal_characters_fill_free_slots() {
+        let mask = Mask::new("(999) ???");
+
+        assert_eq!(type_in(&mask, "(123) a"), "(123) a");
+        assert_eq!(type_in(&mask, "123 a"), "(123) a");
+        assert_eq!(type_in(&mask, "123a a"), "(123) a a");
+        assert_eq!(type_in(&mask, "123a ) "), "(123) a )");
+        assert_eq!(type_in(&mask, "123a b"), "(123) a b");
+
+        let mask = Mask::new("??/??");
+
+        assert_eq!(type_in(&mask, "a//b"), "a//b");
+    }
+
+    #[test]
+    fn editing_keeps_literals_in_place() {
+        let mask = Mask::new("??/??");
+
+        // Insert `x` at the start of `ab/c`
+        let (value, cursor) = mask.apply(&Value::new("xab/c"), 1, 4);
+
+        assert_eq!(value.to_string(), "xa/bc");
+        assert_eq!(cursor, 1);
+
+        // Delete the `1` of `12/34`
+        let (value, cursor) = mask.apply(&Value::new("2/34"), 0, 5);
+
+        assert_eq!(value.to_string(), "23/4");
+        assert_eq!(cursor, 0);
+
+        // Paste a formatted value
+        let (value, cursor) =
+            Mask::new("99/99").apply(&Value::new("12/34"), 5, 0);
+
+        assert_eq!(value.to_string(), "12/34");
+        assert_eq!(cursor, 5);
+    }
+
+    #[test]
+    fn trailing_literals_are_dropped() {
+        let mask = Mask::new("99--9");
+
+        assert_eq!(type_in(&mask, "12"), "12");
+        assert_eq!(type_in(&mask, "123"), "12--3");
+        assert_eq!(mask.apply(&Value::new(""), 0, 0).0.to_string(), "");
+    }
+
+    #[test]
+    fn escaped_characters_are_literals() {
+        let mask = Mask::new(r"\\9\a");
+
+        assert_eq!(mask.len(), 3);
+        assert_eq!(type_in(&mask, "1"), r"\1");
+        assert!(mask.is_complete(r"\1a"));
+        assert!(!mask.is_complete(r"\1"));
+        assert!(!mask.is_complete(r"\xa"));
+    }
+}
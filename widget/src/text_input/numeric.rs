@@ -0,0 +1,221 @@
+//! Restrict the value of a text input to numbers.
+
+/// The numeric mode of a [`TextInput`].
+///
+/// A [`TextInput`] in numeric mode only accepts numbers of the given
+/// [`Kind`] within its bounds. Its value can be stepped with the arrow
+/// keys and, while focused, with the mouse wheel.
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Numeric {
+    kind: Kind,
+    min: Option<f64>,
+    max: Option<f64>,
+    step: f64,
+}
+
+/// The kind of numbers accepted by a [`Numeric`] text input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Whole numbers.
+    Integer,
+    /// Numbers with a fractional part.
+    Decimal,
+}
+
+impl Numeric {
+    /// Creates a new [`Numeric`] mode of the given [`Kind`].
+    ///
+    /// The default step is `1`.
+    pub fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            min: None,
+            max: None,
+            step: 1.0,
+        }
+    }
+
+    /// Creates a new [`Numeric`] mode that accepts integers.
+    pub fn integer() -> Self {
+        Self::new(Kind::Integer)
+    }
+
+    /// Creates a new [`Numeric`] mode that accepts decimals.
+    pub fn decimal() -> Self {
+        Self::new(Kind::Decimal)
+    }
+
+    /// Sets the minimum value of the [`Numeric`] mode.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`Numeric`] mode.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount a value changes when stepped.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Returns true if the given text is a number accepted by the
+    /// [`Numeric`] mode or the start of one.
+    pub fn accepts(&self, text: &str) -> bool {
+        let digits = text.strip_prefix('-').unwrap_or(text);
+
+        if digits.len() < text.len() && self.min.is_some_and(|min| min >= 0.0) {
+            return false;
+        }
+
+        let is_valid = match self.kind {
+            Kind::Integer => digits.chars().all(|c| c.is_ascii_digit()),
+            Kind::Decimal => {
+                let (integer, fraction) =
+                    digits.split_once('.').unwrap_or((digits, ""));
+
+                integer.chars().all(|c| c.is_ascii_digit())
+                    && fraction.chars().all(|c| c.is_ascii_digit())
+            }
+        };
+
+        if !is_valid {
+            return false;
+        }
+
+        let Ok(number) = text.parse::<f64>() else {
+            // Incomplete numbers, like "-" or "."
+            return true;
+        };
+
+        // Typing more digits only moves a number further away from zero,
+        // so a number past a bound in that direction can never be valid
+        let is_above_max =
+            self.max.is_some_and(|max| number > max && number >= 0.0);
+        let is_below_min =
+            self.min.is_some_and(|min| number < min && number <= 0.0);
+
+        !is_above_max && !is_below_min
+    }
+
+    /// Parses the given text as a number, if valid.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let number = text.parse::<f64>().ok()?;
+
+        (self.accepts(text)
+            && self.min.is_none_or(|min| number >= min)
+            && self.max.is_none_or(|max| number <= max))
+        .then_some(number)
+    }
+
+    /// Steps the number in the given text the given amount of times,
+    /// clamping it to the bounds of the [`Numeric`] mode.
+    pub(super) fn step_by(&self, text: &str, steps: f64) -> String {
+        let number = text.parse::<f64>().unwrap_or_default();
+        let mut number = number + self.step * steps;
+
+        if let Some(min) = self.min {
+            number = number.max(min);
+        }
+
+        if let Some(max) = self.max {
+            number = number.min(max);
+        }
+
+        match self.kind {
+            Kind::Integer => format!("{}", number.round() as i64),
+            Kind::Decimal => {
+                let precision =
+                    decimals(text).max(decimals(&self.step.to_string()));
+
+                format!("{number:.precision$}")
+            }
+        }
+    }
+}
+
+fn decimals(text: &str) -> usize {
+    text.split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_accept_digits_only() {
+        let numeric = Numeric::integer();
+
+        assert!(numeric.accepts(""));
+        assert!(numeric.accepts("-"));
+        assert!(numeric.accepts("42"));
+        assert!(numeric.accepts("-42"));
+        assert!(!numeric.accepts("4.2"));
+        assert!(!numeric.accepts("4a"));
+        assert!(!numeric.accepts("--4"));
+        assert!(!numeric.accepts("+4"));
+    }
+
+    #[test]
+    fn decimals_accept_a_single_point() {
+        let numeric = Numeric::decimal();
+
+        assert!(numeric.accepts("."));
+        assert!(numeric.accepts("-."));
+        assert!(numeric.accepts("4."));
+        assert!(numeric.accepts("-4.25"));
+        assert!(!numeric.accepts("4.2.5"));
+        assert!(!numeric.accepts("4,25"));
+    }
+
+    #[test]
+    fn bounds_reject_numbers_that_cannot_become_valid() {
+        let numeric = Numeric::integer().min(-10.0).max(100.0);
+
+        assert!(numeric.accepts("100"));
+        assert!(!numeric.accepts("101"));
+        assert!(numeric.accepts("-10"));
+        assert!(!numeric.accepts("-11"));
+
+        // Typing more digits may still reach the bounds
+        let numeric = Numeric::integer().min(10.0).max(20.0);
+
+        assert!(numeric.accepts("1"));
+        assert!(!numeric.accepts("-1"));
+        assert!(!numeric.accepts("-"));
+        assert_eq!(numeric.parse("1"), None);
+        assert_eq!(numeric.parse("15"), Some(15.0));
+    }
+
+    #[test]
+    fn step_by_clamps_to_the_bounds() {
+        let numeric = Numeric::integer().min(0.0).max(10.0).step(3.0);
+
+        assert_eq!(numeric.step_by("5", 1.0), "8");
+        assert_eq!(numeric.step_by("8", 1.0), "10");
+        assert_eq!(numeric.step_by("2", -1.0), "0");
+        assert_eq!(numeric.step_by("", 1.0), "3");
+        assert_eq!(numeric.step_by("-", -1.0), "0");
+    }
+
+    #[test]
+    fn step_by_keeps_the_precision() {
+        let numeric = Numeric::decimal().step(0.1);
+
+        assert_eq!(numeric.step_by("1", 1.0), "1.1");
+        assert_eq!(numeric.step_by("1.25", -1.0), "1.15");
+        assert_eq!(numeric.step_by("0.2", 1.0), "0.3");
+
+        let numeric = Numeric::decimal().step(-2.0);
+
+        assert_eq!(numeric.step_by("1.5", 1.0), "3.5");
+        assert_eq!(numeric.step_by("1", -2.0), "-3");
+    }
+}
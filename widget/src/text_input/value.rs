@@ -31,6 +31,13 @@ impl Value {
         self.graphemes.len()
     }
 
+    /// Returns an iterator over the graphemes of the [`Value`].
+    pub(crate) fn graphemes(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.graphemes.iter().map(String::as_str)
+    }
+
     /// Returns the position of the previous start of a word from the given
     /// grapheme `index`.
     pub fn previous_start_of_word(&self, index: usize) -> usize {
@@ -0,0 +1,922 @@
+//! Time pickers let users choose a time of the day from a grid of hours and
+//! minutes displayed below them.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::time_picker;
+//! use iced::widget::time_picker::Time;
+//!
+//! struct State {
+//!     alarm: Option<Time>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     AlarmChanged(Time),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     time_picker(state.alarm, Message::AlarmChanged)
+//!         .placeholder("Alarm")
+//!         .step(15)
+//!         .into()
+//! }
+//! ```
+use crate::calendar;
+use crate::core::alignment;
+use crate::core::border;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::widget::operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length,
+    Padding, Pixels, Point, Rectangle, Shell, Size, Vector,
+};
+use crate::date_picker::{Catalog, Status, Style, StyleFn};
+use crate::overlay::dropdown::Dropdown;
+use crate::picker;
+
+use std::fmt;
+
+/// The amount of columns of the grids of hours and minutes.
+const COLUMNS: usize = 4;
+
+/// The space between the grid of hours and the grid of minutes.
+const GAP: f32 = 9.0;
+
+/// A time of the day, with minute precision and without any time zone
+/// information.
+///
+/// Times are ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    /// The start of the day.
+    pub const MIDNIGHT: Self = Self { hour: 0, minute: 0 };
+
+    /// Creates a new [`Time`] from its hour (0–23) and minute (0–59).
+    ///
+    /// Returns `None` if the time does not exist.
+    pub fn new(hour: u32, minute: u32) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self {
+            hour: hour as u8,
+            minute: minute as u8,
+        })
+    }
+
+    /// Returns the hour of the [`Time`], from 0 to 23.
+    pub fn hour(self) -> u32 {
+        self.hour.into()
+    }
+
+    /// Returns the minute of the [`Time`], from 0 to 59.
+    pub fn minute(self) -> u32 {
+        self.minute.into()
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// A field displaying a [`Time`], which opens a grid of hours and minutes
+/// when clicked.
+///
+/// An hour must be picked first, followed by a minute. The grids can also be
+/// navigated with the arrow keys, and `Enter` picks the focused hour or
+/// minute.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::time_picker;
+/// use iced::widget::time_picker::Time;
+///
+/// struct State {
+///     start: Option<Time>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     StartChanged(Time),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     time_picker(state.start, Message::StartChanged).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    time: Option<Time>,
+    on_change: Box<dyn Fn(Time) -> Message + 'a>,
+    grid: Grid<'a, Theme, Renderer>,
+    format: Option<Box<dyn Fn(Time) -> String + 'a>>,
+    placeholder: Option<String>,
+    width: Length,
+    padding: Padding,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: <Theme as Catalog>::Class<'a>,
+    last_status: Option<Status>,
+}
+
+impl<'a, Message, Theme, Renderer> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of a [`TimePicker`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`TimePicker`] with the given [`Time`], if any, and the
+    /// message produced when a new one is picked.
+    pub fn new(
+        time: Option<Time>,
+        on_change: impl Fn(Time) -> Message + 'a,
+    ) -> Self {
+        Self {
+            time,
+            on_change: Box::new(on_change),
+            grid: Grid {
+                time,
+                step: 5,
+                cell_size: 32.0,
+                padding: Padding::new(8.0),
+                font: None,
+                text_size: None,
+                class: Theme::default_calendar(),
+            },
+            format: None,
+            placeholder: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            font: None,
+            text_size: None,
+            class: <Theme as Catalog>::default(),
+            last_status: None,
+        }
+    }
+
+    /// Sets the placeholder of the [`TimePicker`], shown while no [`Time`] is
+    /// picked.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the function used to display the picked [`Time`].
+    ///
+    /// By default, times are displayed in the 24-hour format (`HH:MM`).
+    pub fn format(mut self, format: impl Fn(Time) -> String + 'a) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the step between the minutes that can be picked.
+    ///
+    /// By default, minutes can be picked in steps of 5.
+    pub fn step(mut self, minutes: u32) -> Self {
+        self.grid.step = minutes.clamp(1, 30) as u8;
+        self
+    }
+
+    /// Sets the width of the [`TimePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TimePicker`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Renderer::Font`] of the [`TimePicker`].
+    ///
+    /// [`Renderer::Font`]: text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        let font = font.into();
+
+        self.font = Some(font);
+        self.grid.font = Some(font);
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_size = Some(size);
+        self.grid.text_size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`TimePicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the grid of hours and minutes of the [`TimePicker`].
+    #[must_use]
+    pub fn grid_style(
+        mut self,
+        style: impl Fn(&Theme) -> calendar::Style + 'a,
+    ) -> Self
+    where
+        <Theme as calendar::Catalog>::Class<'a>:
+            From<calendar::StyleFn<'a, Theme>>,
+    {
+        self.grid.class =
+            (Box::new(style) as calendar::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TimePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the grid of hours and minutes of the
+    /// [`TimePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn grid_class(
+        mut self,
+        class: impl Into<<Theme as calendar::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.grid.class = class.into();
+        self
+    }
+
+    fn label(&self) -> Option<String> {
+        self.time.map(|time| match &self.format {
+            Some(format) => format(time),
+            None => time.to_string(),
+        })
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TimePicker<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<picker::State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(picker::State::<Renderer::Paragraph>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.grid as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.grid as &dyn Widget<_, _, _>]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let label = self
+            .label()
+            .or_else(|| self.placeholder.clone())
+            .unwrap_or_else(|| Time::MIDNIGHT.to_string());
+
+        picker::layout(
+            tree.state
+                .downcast_mut::<picker::State<Renderer::Paragraph>>(),
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.font,
+            self.text_size,
+            &label,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree
+            .state
+            .downcast_mut::<picker::State<Renderer::Paragraph>>();
+
+        operation.focusable(None, layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<picker::State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        if picker::update(state, event, bounds, cursor, shell) {
+            // The grid always opens on the picked time
+            children[0] = Tree::new(&self.grid as &dyn Widget<_, _, _>);
+        }
+
+        let status = picker::status(state, bounds, cursor);
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_status = Some(status);
+        } else if self
+            .last_status
+            .is_some_and(|last_status| last_status != status)
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        picker::draw(
+            renderer,
+            theme,
+            &self.class,
+            self.last_status.unwrap_or(Status::Active),
+            layout.bounds(),
+            self.padding,
+            self.font,
+            self.text_size,
+            self.label(),
+            self.placeholder.as_deref(),
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<picker::State<Renderer::Paragraph>>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(
+            Dropdown::new(
+                &mut self.grid,
+                &mut children[0],
+                &mut state.is_open,
+                &self.on_change,
+            )
+            .overlay(layout.position() + translation, layout.bounds().height),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<TimePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(time_picker: TimePicker<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(time_picker)
+    }
+}
+
+/// The grids of hours and minutes displayed by a [`TimePicker`].
+struct Grid<'a, Theme, Renderer>
+where
+    Theme: calendar::Catalog,
+    Renderer: text::Renderer,
+{
+    time: Option<Time>,
+    step: u8,
+    cell_size: f32,
+    padding: Padding,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: <Theme as calendar::Catalog>::Class<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Hours,
+    Minutes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    part: Part,
+    index: usize,
+}
+
+#[derive(Debug)]
+struct GridState {
+    hour: Option<u8>,
+    focused: Cell,
+    hovered: Option<Cell>,
+    is_focused: bool,
+}
+
+impl operation::Focusable for GridState {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Theme, Renderer> Grid<'_, Theme, Renderer>
+where
+    Theme: calendar::Catalog,
+    Renderer: text::Renderer,
+{
+    fn len(&self, part: Part) -> usize {
+        match part {
+            Part::Hours => 24,
+            Part::Minutes => 60_usize.div_ceil(usize::from(self.step)),
+        }
+    }
+
+    fn value(&self, cell: Cell) -> u8 {
+        match cell.part {
+            Part::Hours => cell.index as u8,
+            Part::Minutes => cell.index as u8 * self.step,
+        }
+    }
+
+    fn cell_bounds(&self, bounds: Rectangle, cell: Cell) -> Rectangle {
+        let inner = bounds.shrink(self.padding);
+        let offset = match cell.part {
+            Part::Hours => 0.0,
+            Part::Minutes => COLUMNS as f32 * self.cell_size + GAP,
+        };
+
+        Rectangle {
+            x: inner.x
+                + offset
+                + (cell.index % COLUMNS) as f32 * self.cell_size,
+            y: inner.y + (cell.index / COLUMNS) as f32 * self.cell_size,
+            width: self.cell_size,
+            height: self.cell_size,
+        }
+    }
+
+    fn cell_at(&self, bounds: Rectangle, position: Point) -> Option<Cell> {
+        [Part::Hours, Part::Minutes]
+            .into_iter()
+            .flat_map(|part| {
+                (0..self.len(part)).map(move |index| Cell { part, index })
+            })
+            .find(|cell| self.cell_bounds(bounds, *cell).contains(position))
+    }
+
+    fn pick(
+        &self,
+        state: &mut GridState,
+        cell: Cell,
+        shell: &mut Shell<'_, Time>,
+    ) {
+        match cell.part {
+            Part::Hours => {
+                state.hour = Some(self.value(cell));
+                state.focused = Cell {
+                    part: Part::Minutes,
+                    index: self
+                        .time
+                        .map_or(0, |time| usize::from(time.minute / self.step)),
+                };
+            }
+            Part::Minutes => {
+                let hour = state
+                    .hour
+                    .or(self.time.map(|time| time.hour))
+                    .unwrap_or_default();
+
+                state.focused = cell;
+                shell.publish(Time {
+                    hour,
+                    minute: self.value(cell),
+                });
+            }
+        }
+    }
+
+    /// Moves the focused [`Cell`] with the given arrow key.
+    fn navigate(&self, cell: Cell, arrow: key::Named) -> Cell {
+        let column = cell.index % COLUMNS;
+        let len = self.len(cell.part);
+
+        let other = |part| {
+            let len = self.len(part);
+            let row = (cell.index / COLUMNS).min((len - 1) / COLUMNS);
+
+            match part {
+                Part::Hours => (row * COLUMNS + COLUMNS - 1).min(len - 1),
+                Part::Minutes => row * COLUMNS,
+            }
+        };
+
+        match arrow {
+            key::Named::ArrowLeft if column > 0 => Cell {
+                index: cell.index - 1,
+                ..cell
+            },
+            key::Named::ArrowLeft if cell.part == Part::Minutes => Cell {
+                part: Part::Hours,
+                index: other(Part::Hours),
+            },
+            key::Named::ArrowRight
+                if column < COLUMNS - 1 && cell.index + 1 < len =>
+            {
+                Cell {
+                    index: cell.index + 1,
+                    ..cell
+                }
+            }
+            key::Named::ArrowRight if cell.part == Part::Hours => Cell {
+                part: Part::Minutes,
+                index: other(Part::Minutes),
+            },
+            key::Named::ArrowUp if cell.index >= COLUMNS => Cell {
+                index: cell.index - COLUMNS,
+                ..cell
+            },
+            key::Named::ArrowDown if cell.index + COLUMNS < len => Cell {
+                index: cell.index + COLUMNS,
+                ..cell
+            },
+            _ => cell,
+        }
+    }
+}
+
+impl<Theme, Renderer> Widget<Time, Theme, Renderer>
+    for Grid<'_, Theme, Renderer>
+where
+    Theme: calendar::Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<GridState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(GridState {
+            hour: None,
+            focused: Cell {
+                part: Part::Hours,
+                index: self.time.map_or(0, |time| usize::from(time.hour)),
+            },
+            hovered: None,
+            is_focused: true,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let rows = self
+            .len(Part::Hours)
+            .max(self.len(Part::Minutes))
+            .div_ceil(COLUMNS);
+
+        let size = Size::new(
+            2.0 * COLUMNS as f32 * self.cell_size + GAP,
+            rows as f32 * self.cell_size,
+        )
+        .expand(self.padding);
+
+        layout::Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<GridState>();
+
+        operation.focusable(None, layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Time>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<GridState>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                if let Some(cell) = self.cell_at(bounds, position) {
+                    self.pick(state, cell, shell);
+                }
+
+                state.is_focused = true;
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let hovered = cursor
+                    .position_over(bounds)
+                    .and_then(|position| self.cell_at(bounds, position));
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if state.is_focused => {
+                match named {
+                    key::Named::Enter | key::Named::Space => {
+                        self.pick(state, state.focused, shell);
+                    }
+                    key::Named::ArrowLeft
+                    | key::Named::ArrowRight
+                    | key::Named::ArrowUp
+                    | key::Named::ArrowDown => {
+                        state.focused = self.navigate(state.focused, *named);
+                    }
+                    _ => return,
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if cursor
+            .position_over(bounds)
+            .and_then(|position| self.cell_at(bounds, position))
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<GridState>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+
+        let separator = Cell {
+            part: Part::Minutes,
+            index: 0,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: self.cell_bounds(bounds, separator).x - GAP / 2.0 - 0.5,
+                    width: 1.0,
+                    ..bounds.shrink(self.padding)
+                },
+                snap: true,
+                ..renderer::Quad::default()
+            },
+            style.border.color,
+        );
+
+        let selected_hour = state.hour.or(self.time.map(|time| time.hour));
+        let selected_minute = self.time.map(|time| time.minute);
+
+        for part in [Part::Hours, Part::Minutes] {
+            for index in 0..self.len(part) {
+                let cell = Cell { part, index };
+                let area = self.cell_bounds(bounds, cell);
+                let value = self.value(cell);
+
+                let is_selected = match part {
+                    Part::Hours => selected_hour == Some(value),
+                    Part::Minutes => selected_minute == Some(value),
+                };
+
+                if is_selected || state.hovered == Some(cell) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: area,
+                            border: border::rounded(style.cell_radius),
+                            ..renderer::Quad::default()
+                        },
+                        if is_selected {
+                            style.selected_background
+                        } else {
+                            style.hovered_background
+                        },
+                    );
+                }
+
+                if state.is_focused && state.focused == cell {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: area.shrink(1.0),
+                            border: Border {
+                                color: style.focused_border,
+                                width: 2.0,
+                                radius: style.cell_radius,
+                                ..Border::default()
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        Color::TRANSPARENT,
+                    );
+                }
+
+                renderer.fill_text(
+                    Text {
+                        content: match part {
+                            Part::Hours => format!("{value:02}"),
+                            Part::Minutes => format!(":{value:02}"),
+                        },
+                        bounds: area.size(),
+                        size,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                    },
+                    area.center(),
+                    if is_selected {
+                        style.selected_text_color
+                    } else {
+                        style.text_color
+                    },
+                    *viewport,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,1299 @@
+//! Toasts display short-lived notifications on top of some content.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::{text, toast, toasts};
+//!
+//! struct State {
+//!    notifications: Vec<Notification>,
+//! }
+//!
+//! struct Notification {
+//!     message: String,
+//!     has_failed: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Dismiss(usize),
+//!     Retry,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let notifications = state.notifications.iter().map(|notification| {
+//!         if notification.has_failed {
+//!             toast("Upload failed")
+//!                 .body(notification.message.as_str())
+//!                 .severity(toast::Severity::Danger)
+//!                 .action("Retry", Message::Retry)
+//!                 .persistent()
+//!         } else {
+//!             toast(notification.message.as_str())
+//!                 .severity(toast::Severity::Success)
+//!         }
+//!     });
+//!
+//!     toasts(text("My app"), notifications, Message::Dismiss)
+//!         .position(toast::Position::TopRight)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Dismiss(index) => {
+//!             state.notifications.remove(index);
+//!         }
+//!         Message::Retry => {
+//!             // ...
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::paragraph;
+use crate::core::text::{self, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Animation, Background, Clipboard, Color, Element, Event, Layout, Length,
+    Padding, Pixels, Point, Rectangle, Shadow, Shell, Size, Theme, Vector,
+    Widget,
+};
+
+/// A notification displayed by a toast [`Manager`].
+///
+/// Toasts are dismissed automatically after a timeout, unless they are
+/// [`persistent`](Self::persistent). The timer is paused while the toast
+/// is hovered.
+#[derive(Debug, Clone)]
+pub struct Toast<'a, Message> {
+    title: text::Fragment<'a>,
+    body: Option<text::Fragment<'a>>,
+    severity: Severity,
+    timeout: Timeout,
+    actions: Vec<(text::Fragment<'a>, Message)>,
+}
+
+impl<'a, Message> Toast<'a, Message> {
+    /// Creates a new [`Toast`] with the given title.
+    pub fn new(title: impl text::IntoFragment<'a>) -> Self {
+        Self {
+            title: title.into_fragment(),
+            body: None,
+            severity: Severity::default(),
+            timeout: Timeout::Default,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Sets the body of the [`Toast`], displayed below its title.
+    pub fn body(mut self, body: impl text::IntoFragment<'a>) -> Self {
+        self.body = Some(body.into_fragment());
+        self
+    }
+
+    /// Sets the [`Severity`] of the [`Toast`].
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets the time the [`Toast`] stays visible before it is dismissed,
+    /// overriding the timeout of its [`Manager`].
+    pub fn timeout(mut self, timeout: impl Into<Duration>) -> Self {
+        self.timeout = Timeout::After(timeout.into());
+        self
+    }
+
+    /// Makes the [`Toast`] stay visible until it is closed by the user.
+    pub fn persistent(mut self) -> Self {
+        self.timeout = Timeout::Never;
+        self
+    }
+
+    /// Adds an action button to the [`Toast`].
+    ///
+    /// Pressing the button will produce the given message and dismiss
+    /// the [`Toast`].
+    pub fn action(
+        mut self,
+        label: impl text::IntoFragment<'a>,
+        on_press: Message,
+    ) -> Self {
+        self.actions.push((label.into_fragment(), on_press));
+        self
+    }
+}
+
+/// The importance of a [`Toast`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Severity {
+    /// A regular notification.
+    #[default]
+    Info,
+    /// An operation has finished successfully.
+    Success,
+    /// Something may need the attention of the user.
+    Warning,
+    /// An operation has failed.
+    Danger,
+}
+
+/// The place of the window where the toasts of a [`Manager`] are stacked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Position {
+    /// The top left corner.
+    TopLeft,
+    /// The center of the top edge.
+    Top,
+    /// The top right corner.
+    TopRight,
+    /// The center of the left edge.
+    Left,
+    /// The center of the right edge.
+    Right,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The center of the bottom edge.
+    Bottom,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+impl Position {
+    fn alignment(self) -> (alignment::Horizontal, alignment::Vertical) {
+        use alignment::{Horizontal, Vertical};
+
+        match self {
+            Position::TopLeft => (Horizontal::Left, Vertical::Top),
+            Position::Top => (Horizontal::Center, Vertical::Top),
+            Position::TopRight => (Horizontal::Right, Vertical::Top),
+            Position::Left => (Horizontal::Left, Vertical::Center),
+            Position::Right => (Horizontal::Right, Vertical::Center),
+            Position::BottomLeft => (Horizontal::Left, Vertical::Bottom),
+            Position::Bottom => (Horizontal::Center, Vertical::Bottom),
+            Position::BottomRight => (Horizontal::Right, Vertical::Bottom),
+        }
+    }
+}
+
+/// A widget that displays a stack of [`Toast`] notifications on top of
+/// some content.
+///
+/// Toasts slide in when they appear and slide out when they are dismissed,
+/// either by the user or by their timeout. Once a toast has left the screen,
+/// the [`Manager`] produces the `on_dismiss` message with its index; which
+/// should be used to remove it from the application state.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{button, toast, toasts};
+///
+/// struct State {
+///    notifications: Vec<String>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Notify,
+///     Dismiss(usize),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     toasts(
+///         button("Notify").on_press(Message::Notify),
+///         state.notifications.iter().map(|notification| {
+///             toast(notification.as_str()).severity(toast::Severity::Warning)
+///         }),
+///         Message::Dismiss,
+///     )
+///     .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::Notify => {
+///             state.notifications.push("Disk space is running low".to_owned());
+///         }
+///         Message::Dismiss(index) => {
+///             state.notifications.remove(index);
+///         }
+///     }
+/// }
+/// ```
+pub struct Manager<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    toasts: Vec<Toast<'a, Message>>,
+    on_dismiss: Box<dyn Fn(usize) -> Message + 'a>,
+    position: Position,
+    timeout: Duration,
+    width: f32,
+    padding: Padding,
+    spacing: f32,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Manager<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default time a [`Toast`] stays visible.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// The default width of a [`Toast`].
+    pub const DEFAULT_WIDTH: f32 = 320.0;
+
+    /// The default padding between the toasts and the edges of the window.
+    pub const DEFAULT_PADDING: Padding = Padding::new(16.0);
+
+    /// Creates a new [`Manager`] that displays the given toasts on top of
+    /// the given content.
+    ///
+    /// The `on_dismiss` closure receives the index of a [`Toast`] once it
+    /// has been dismissed and left the screen.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        toasts: impl IntoIterator<Item = Toast<'a, Message>>,
+        on_dismiss: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            toasts: toasts.into_iter().collect(),
+            on_dismiss: Box::new(on_dismiss),
+            position: Position::default(),
+            timeout: Self::DEFAULT_TIMEOUT,
+            width: Self::DEFAULT_WIDTH,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 8.0,
+            font: None,
+            text_size: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Position`] of the window where the toasts are stacked.
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the time a [`Toast`] stays visible before it is dismissed.
+    pub fn timeout(mut self, timeout: impl Into<Duration>) -> Self {
+        self.timeout = timeout.into();
+        self
+    }
+
+    /// Sets the width of the toasts.
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] between the toasts and the edges of the window.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the stacked toasts.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Renderer::Font`] of the toasts.
+    ///
+    /// [`Renderer::Font`]: text::Renderer
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the text size of the toasts.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the toasts.
+    #[must_use]
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, Severity) -> Style + 'a,
+    ) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the toasts.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Manager<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Makes the entries of the [`State`] match the current toasts.
+    fn sync(&self, state: &mut State<Renderer::Paragraph>) {
+        let now = Instant::now();
+
+        // Dismissed toasts are expected to have been removed by the
+        // application, so the remaining entries keep matching their toasts
+        state.entries.retain(|entry| !entry.is_dismissed);
+        state.entries.truncate(self.toasts.len());
+
+        for toast in &self.toasts[state.entries.len()..] {
+            let timeout = match toast.timeout {
+                Timeout::Default => Some(self.timeout),
+                Timeout::After(timeout) => Some(timeout),
+                Timeout::Never => None,
+            };
+
+            state.entries.push(Entry::new(timeout, now));
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Manager<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::<Renderer::Paragraph>::new();
+        self.sync(&mut state);
+
+        tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.sync(tree.state.downcast_mut::<State<Renderer::Paragraph>>());
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let toasts = (!self.toasts.is_empty()).then(|| {
+            overlay::Element::new(Box::new(Overlay {
+                state,
+                toasts: &self.toasts,
+                on_dismiss: &self.on_dismiss,
+                position: self.position,
+                width: self.width,
+                padding: self.padding,
+                spacing: self.spacing,
+                font: self.font,
+                text_size: self.text_size,
+                class: &self.class,
+            }))
+        });
+
+        let overlays = content.into_iter().chain(toasts).collect::<Vec<_>>();
+
+        (!overlays.is_empty())
+            .then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Manager<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        manager: Manager<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(manager)
+    }
+}
+
+const ACCENT_WIDTH: f32 = 4.0;
+const TOAST_PADDING: f32 = 12.0;
+const BODY_SPACING: f32 = 4.0;
+const ACTIONS_SPACING: f32 = 8.0;
+const ACTION_PADDING: Padding = Padding {
+    top: 4.0,
+    right: 8.0,
+    bottom: 4.0,
+    left: 8.0,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Timeout {
+    Default,
+    After(Duration),
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Timer {
+    Running { deadline: Instant },
+    Paused { remaining: Duration },
+    Never,
+}
+
+impl Timer {
+    fn pause(&mut self, now: Instant) {
+        if let Timer::Running { deadline } = *self {
+            *self = Timer::Paused {
+                remaining: deadline.saturating_duration_since(now),
+            };
+        }
+    }
+
+    fn resume(&mut self, now: Instant) {
+        if let Timer::Paused { remaining } = *self {
+            *self = Timer::Running {
+                deadline: now + remaining,
+            };
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    entries: Vec<Entry<P>>,
+    close: paragraph::Plain<P>,
+    now: Instant,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            close: paragraph::Plain::default(),
+            now: Instant::now(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry<P: text::Paragraph> {
+    visibility: Animation<bool>,
+    timer: Timer,
+    is_hovered: bool,
+    is_closing: bool,
+    is_dismissed: bool,
+    title: paragraph::Plain<P>,
+    body: paragraph::Plain<P>,
+    actions: Vec<paragraph::Plain<P>>,
+}
+
+impl<P: text::Paragraph> Entry<P> {
+    fn new(timeout: Option<Duration>, now: Instant) -> Self {
+        Self {
+            visibility: Animation::new(false).slow().go(true, now),
+            timer: timeout.map_or(Timer::Never, |timeout| Timer::Running {
+                deadline: now + timeout,
+            }),
+            is_hovered: false,
+            is_closing: false,
+            is_dismissed: false,
+            title: paragraph::Plain::default(),
+            body: paragraph::Plain::default(),
+            actions: Vec::new(),
+        }
+    }
+
+    fn close(&mut self, now: Instant) {
+        self.visibility.go_mut(false, now);
+        self.is_closing = true;
+    }
+
+    fn is_hidden(&self, now: Instant) -> bool {
+        self.is_closing && !self.visibility.is_animating(now)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    state: &'b mut State<Renderer::Paragraph>,
+    toasts: &'b [Toast<'a, Message>],
+    on_dismiss: &'b dyn Fn(usize) -> Message,
+    position: Position,
+    width: f32,
+    padding: Padding,
+    spacing: f32,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the current bounds of each toast, taking their enter and exit
+    /// animations into account.
+    fn placements(&self, layout: Layout<'_>) -> Vec<Rectangle> {
+        use alignment::{Horizontal, Vertical};
+
+        let area = layout.bounds().shrink(self.padding);
+        let now = self.state.now;
+        let (horizontal, vertical) = self.position.alignment();
+
+        let toasts: Vec<(Size, f32)> = layout
+            .children()
+            .zip(&self.state.entries)
+            .map(|(layout, entry)| {
+                (
+                    layout.bounds().size(),
+                    entry.visibility.interpolate(0.0, 1.0, now),
+                )
+            })
+            .collect();
+
+        let extent = |(size, progress): (Size, f32)| {
+            (size.height + self.spacing) * progress
+        };
+
+        // The newest toasts are stacked closest to the edge of the window
+        let order: Vec<usize> = if vertical == Vertical::Center {
+            (0..toasts.len()).collect()
+        } else {
+            (0..toasts.len()).rev().collect()
+        };
+
+        let mut y = match vertical {
+            Vertical::Top => area.y,
+            Vertical::Center => {
+                let height = toasts.iter().copied().map(extent).sum::<f32>()
+                    - self.spacing;
+
+                area.center_y() - height.max(0.0) / 2.0
+            }
+            Vertical::Bottom => area.y + area.height,
+        };
+
+        let mut placements = vec![Rectangle::default(); toasts.len()];
+
+        for index in order {
+            let (size, progress) = toasts[index];
+            let hidden = 1.0 - progress;
+
+            let x = match horizontal {
+                Horizontal::Left => {
+                    area.x - hidden * (size.width + self.padding.left)
+                }
+                Horizontal::Center => area.center_x() - size.width / 2.0,
+                Horizontal::Right => {
+                    area.x + area.width - size.width
+                        + hidden * (size.width + self.padding.right)
+                }
+            };
+
+            let slide = if horizontal == Horizontal::Center {
+                size.height + self.padding.top.max(self.padding.bottom)
+            } else {
+                0.0
+            };
+
+            let top = match vertical {
+                Vertical::Top => {
+                    let top = y - hidden * slide;
+                    y += extent(toasts[index]);
+                    top
+                }
+                Vertical::Center => {
+                    let top = y;
+                    y += extent(toasts[index]);
+                    top
+                }
+                Vertical::Bottom => {
+                    let top = y - size.height + hidden * slide;
+                    y -= extent(toasts[index]);
+                    top
+                }
+            };
+
+            placements[index] = Rectangle::new(Point::new(x, top), size);
+        }
+
+        placements
+    }
+
+    fn text<'c>(
+        &self,
+        content: &'c str,
+        renderer: &Renderer,
+        width: f32,
+    ) -> Text<&'c str, Renderer::Font> {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        Text {
+            content,
+            bounds: Size::new(width, f32::INFINITY),
+            size,
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::Word,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let width = self.width.min(bounds.width - self.padding.x());
+
+        let _ =
+            self.state
+                .close
+                .update(self.text("×", renderer, f32::INFINITY));
+
+        let close = self.state.close.min_bounds();
+        let text_width =
+            (width - ACCENT_WIDTH - TOAST_PADDING * 3.0 - close.width).max(0.0);
+
+        let mut entries = std::mem::take(&mut self.state.entries);
+
+        let toasts = self
+            .toasts
+            .iter()
+            .zip(&mut entries)
+            .map(|(toast, entry)| {
+                let left = ACCENT_WIDTH + TOAST_PADDING;
+
+                let _ = entry.title.update(self.text(
+                    &toast.title,
+                    renderer,
+                    text_width,
+                ));
+
+                let title = layout::Node::new(entry.title.min_bounds())
+                    .move_to(Point::new(left, TOAST_PADDING));
+
+                let body = match &toast.body {
+                    Some(body) => {
+                        let _ = entry
+                            .body
+                            .update(self.text(body, renderer, text_width));
+
+                        layout::Node::new(entry.body.min_bounds()).move_to(
+                            Point::new(
+                                left,
+                                title.bounds().y
+                                    + title.bounds().height
+                                    + BODY_SPACING,
+                            ),
+                        )
+                    }
+                    None => layout::Node::new(Size::ZERO).move_to(Point::new(
+                        left,
+                        title.bounds().y + title.bounds().height,
+                    )),
+                };
+
+                let mut bottom = (body.bounds().y + body.bounds().height)
+                    .max(TOAST_PADDING + close.height);
+
+                entry.actions.resize_with(
+                    toast.actions.len(),
+                    paragraph::Plain::default,
+                );
+
+                let mut actions: Vec<layout::Node> = toast
+                    .actions
+                    .iter()
+                    .zip(&mut entry.actions)
+                    .map(|((label, _), paragraph)| {
+                        let _ = paragraph.update(self.text(
+                            label,
+                            renderer,
+                            f32::INFINITY,
+                        ));
+
+                        layout::Node::new(
+                            paragraph.min_bounds().expand(ACTION_PADDING),
+                        )
+                    })
+                    .collect();
+
+                if !actions.is_empty() {
+                    let top = bottom + ACTIONS_SPACING;
+                    let mut right = width - TOAST_PADDING;
+
+                    for action in actions.iter_mut().rev() {
+                        let size = action.size();
+
+                        action.move_to_mut(Point::new(right - size.width, top));
+                        right -= size.width + ACTIONS_SPACING;
+                    }
+
+                    bottom = top
+                        + actions
+                            .iter()
+                            .map(|action| action.size().height)
+                            .fold(0.0, f32::max);
+                }
+
+                let close = layout::Node::new(close).move_to(Point::new(
+                    width - TOAST_PADDING - close.width,
+                    TOAST_PADDING,
+                ));
+
+                layout::Node::with_children(
+                    Size::new(width, bottom + TOAST_PADDING),
+                    [close, title, body].into_iter().chain(actions).collect(),
+                )
+            })
+            .collect();
+
+        self.state.entries = entries;
+
+        layout::Node::with_children(bounds, toasts)
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let now = self.state.now;
+        let placements = self.placements(layout);
+
+        for (((toast, entry), layout), bounds) in self
+            .toasts
+            .iter()
+            .zip(&self.state.entries)
+            .zip(layout.children())
+            .zip(placements)
+        {
+            if entry.is_hidden(now) {
+                continue;
+            }
+
+            let offset = bounds.position() - Point::ORIGIN;
+            let mut parts = layout.children().skip(1);
+
+            let title = parts.next().unwrap().bounds() + offset;
+            let body = parts.next().unwrap().bounds() + offset;
+
+            operation.text(None, title, &toast.title);
+
+            if let Some(content) = &toast.body {
+                operation.text(None, body, content);
+            }
+
+            for ((action, _), layout) in toast.actions.iter().zip(parts) {
+                operation.text(None, layout.bounds() + offset, action);
+            }
+        }
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let now = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                self.state.now = *now;
+                *now
+            }
+            _ => Instant::now(),
+        };
+
+        let placements = self.placements(layout);
+
+        for (index, ((toast, entry), (layout, bounds))) in self
+            .toasts
+            .iter()
+            .zip(&mut self.state.entries)
+            .zip(layout.children().zip(placements))
+            .enumerate()
+        {
+            if entry.is_closing {
+                if entry.is_hidden(now) && !entry.is_dismissed {
+                    entry.is_dismissed = true;
+                    shell.publish((self.on_dismiss)(index));
+                }
+
+                continue;
+            }
+
+            let is_hovered = cursor.is_over(bounds);
+
+            if is_hovered != entry.is_hovered {
+                if is_hovered {
+                    entry.timer.pause(now);
+                } else {
+                    entry.timer.resume(now);
+                }
+
+                entry.is_hovered = is_hovered;
+                shell.request_redraw();
+            }
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if is_hovered =>
+                {
+                    let offset = bounds.position() - Point::ORIGIN;
+                    let mut parts = layout.children();
+
+                    let close = parts.next().unwrap();
+                    let actions = parts.skip(2);
+
+                    if cursor.is_over(close.bounds() + offset) {
+                        entry.close(now);
+                    } else if let Some((_, on_press)) =
+                        toast.actions.iter().zip(actions).find_map(
+                            |(action, layout)| {
+                                cursor
+                                    .is_over(layout.bounds() + offset)
+                                    .then_some(action)
+                            },
+                        )
+                    {
+                        shell.publish(on_press.clone());
+                        entry.close(now);
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+                Event::Window(window::Event::RedrawRequested(_)) => {
+                    if let Timer::Running { deadline } = entry.timer
+                        && deadline <= now
+                    {
+                        entry.close(now);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let is_animating = self.state.entries.iter().any(|entry| {
+                entry.visibility.is_animating(now)
+                    || (entry.is_closing && !entry.is_dismissed)
+            });
+
+            if is_animating {
+                shell.request_redraw();
+            } else if let Some(deadline) = self
+                .state
+                .entries
+                .iter()
+                .filter_map(|entry| match entry.timer {
+                    Timer::Running { deadline } if !entry.is_closing => {
+                        Some(deadline)
+                    }
+                    _ => None,
+                })
+                .min()
+            {
+                shell.request_redraw_at(deadline);
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let now = self.state.now;
+        let placements = self.placements(layout);
+
+        for (((toast, entry), layout), bounds) in self
+            .toasts
+            .iter()
+            .zip(&self.state.entries)
+            .zip(layout.children())
+            .zip(placements)
+        {
+            if entry.is_hidden(now) {
+                continue;
+            }
+
+            let style = theme.style(self.class, toast.severity);
+            let offset = bounds.position() - Point::ORIGIN;
+
+            renderer.with_layer(
+                bounds.expand(style.shadow.blur_radius * 2.0),
+                |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: style.border,
+                            shadow: style.shadow,
+                            ..renderer::Quad::default()
+                        },
+                        style.background,
+                    );
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                width: ACCENT_WIDTH,
+                                ..bounds
+                            },
+                            border: border::rounded(border::Radius {
+                                top_left: style.border.radius.top_left,
+                                bottom_left: style.border.radius.bottom_left,
+                                ..border::Radius::default()
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        style.accent,
+                    );
+
+                    let mut parts = layout.children();
+
+                    let close = parts.next().unwrap().bounds() + offset;
+                    let title = parts.next().unwrap().bounds() + offset;
+                    let body = parts.next().unwrap().bounds() + offset;
+
+                    renderer.fill_paragraph(
+                        self.state.close.raw(),
+                        close.position(),
+                        if cursor.is_over(close) {
+                            style.text_color
+                        } else {
+                            style.text_color.scale_alpha(0.6)
+                        },
+                        bounds,
+                    );
+
+                    renderer.fill_paragraph(
+                        entry.title.raw(),
+                        title.position(),
+                        style.text_color,
+                        bounds,
+                    );
+
+                    if toast.body.is_some() {
+                        renderer.fill_paragraph(
+                            entry.body.raw(),
+                            body.position(),
+                            style.text_color,
+                            bounds,
+                        );
+                    }
+
+                    for (paragraph, layout) in entry.actions.iter().zip(parts) {
+                        let action = layout.bounds() + offset;
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: action,
+                                border: border::rounded(4),
+                                ..renderer::Quad::default()
+                            },
+                            style.accent.scale_alpha(
+                                if cursor.is_over(action) {
+                                    0.25
+                                } else {
+                                    0.12
+                                },
+                            ),
+                        );
+
+                        renderer.fill_paragraph(
+                            paragraph.raw(),
+                            action.position()
+                                + Vector::new(
+                                    ACTION_PADDING.left,
+                                    ACTION_PADDING.top,
+                                ),
+                            style.text_color,
+                            bounds,
+                        );
+                    }
+                },
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let now = self.state.now;
+
+        self.state
+            .entries
+            .iter()
+            .zip(layout.children())
+            .zip(self.placements(layout))
+            .filter(|((entry, _), bounds)| {
+                !entry.is_hidden(now) && cursor.is_over(*bounds)
+            })
+            .map(|((_, layout), bounds)| {
+                let offset = bounds.position() - Point::ORIGIN;
+
+                let is_over_button = layout
+                    .children()
+                    .enumerate()
+                    .filter(|(index, _)| *index == 0 || *index > 2)
+                    .any(|(_, part)| cursor.is_over(part.bounds() + offset));
+
+                if is_over_button {
+                    mouse::Interaction::Pointer
+                } else {
+                    mouse::Interaction::Idle
+                }
+            })
+            .max()
+            .unwrap_or(mouse::Interaction::None)
+    }
+}
+
+/// The appearance of a [`Toast`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the toast.
+    pub background: Background,
+    /// The text [`Color`] of the toast.
+    pub text_color: Color,
+    /// The [`Color`] of the accent strip and the action buttons of the toast.
+    pub accent: Color,
+    /// The [`Border`] of the toast.
+    pub border: Border,
+    /// The [`Shadow`] of the toast.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a toast [`Manager`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given [`Severity`].
+    fn style(&self, class: &Self::Class<'_>, severity: Severity) -> Style;
+}
+
+/// A styling function for a toast [`Manager`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Severity) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, severity: Severity) -> Style {
+        class(self, severity)
+    }
+}
+
+/// The default style of a toast [`Manager`].
+pub fn default(theme: &Theme, severity: Severity) -> Style {
+    let palette = theme.extended_palette();
+
+    let accent = match severity {
+        Severity::Info => palette.primary.base.color,
+        Severity::Success => palette.success.base.color,
+        Severity::Warning => palette.warning.base.color,
+        Severity::Danger => palette.danger.base.color,
+    };
+
+    Style {
+        background: palette.background.base.color.into(),
+        text_color: palette.background.base.text,
+        accent,
+        border: Border {
+            radius: 4.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+            ..Border::default()
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+    }
+}
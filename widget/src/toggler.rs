@@ -445,6 +445,7 @@ where
                     radius: border_radius.into(),
                     width: style.background_border_width,
                     color: style.background_border_color,
+                    ..Border::default()
                 },
                 ..renderer::Quad::default()
             },
@@ -470,6 +471,7 @@ where
                     radius: border_radius.into(),
                     width: style.foreground_border_width,
                     color: style.foreground_border_color,
+                    ..Border::default()
                 },
                 ..renderer::Quad::default()
             },
@@ -0,0 +1,1194 @@
+//! Tree views display hierarchical data as rows that can be expanded and
+//! collapsed.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::tree_view::Node;
+//! use iced::widget::{scrollable, tree_view};
+//!
+//! struct State {
+//!     selected: Vec<u32>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Select(Vec<u32>),
+//!     Toggle(u32, bool),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     scrollable(
+//!         tree_view([
+//!             Node::new(0, "src").children([
+//!                 Node::new(1, "main.rs"),
+//!                 Node::new(2, "lib.rs"),
+//!             ]),
+//!             // The children of this node are loaded when it is expanded
+//!             Node::new(3, "target").lazy(),
+//!         ])
+//!         .selected(state.selected.iter().copied())
+//!         .on_select(Message::Select)
+//!         .on_toggle(Message::Toggle),
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Widget,
+};
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// The padding of a single row.
+const ROW_PADDING: Padding = Padding {
+    top: 2.0,
+    right: 4.0,
+    bottom: 2.0,
+    left: 4.0,
+};
+
+/// The space between the icon and the label of a row.
+const SPACING: f32 = 6.0;
+
+/// The distance the cursor must travel before a row starts being dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The time after which type-ahead search starts over.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A widget displaying a tree of [`Node`]s.
+///
+/// The expanded nodes are remembered by the [`TreeView`] itself, keyed by
+/// their `Key`.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::tree_view::Node;
+/// use iced::widget::tree_view;
+///
+/// struct State {
+///     selected: Option<&'static str>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(Vec<&'static str>),
+///     Reparent(&'static str, Option<&'static str>),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     tree_view([Node::new("scene", "Scene").children([
+///         Node::new("camera", "Camera"),
+///         Node::new("light", "Light"),
+///     ])])
+///     .selected(state.selected)
+///     .on_select(Message::Select)
+///     .on_reparent(Message::Reparent)
+///     .into()
+/// }
+/// ```
+pub struct TreeView<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    nodes: Vec<Node<Key, Renderer::Font>>,
+    selected: Vec<Key>,
+    on_select: Option<Box<dyn Fn(Vec<Key>) -> Message + 'a>>,
+    on_toggle: Option<Box<dyn Fn(Key, bool) -> Message + 'a>>,
+    on_reparent: Option<Box<dyn Fn(Key, Option<Key>) -> Message + 'a>>,
+    is_multi_select: bool,
+    width: Length,
+    indent: f32,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default indentation of every level of a [`TreeView`].
+    pub const DEFAULT_INDENT: f32 = 20.0;
+
+    /// Creates a new [`TreeView`] with the given root nodes.
+    pub fn new(
+        nodes: impl IntoIterator<Item = Node<Key, Renderer::Font>>,
+    ) -> Self {
+        Self {
+            nodes: nodes.into_iter().collect(),
+            selected: Vec::new(),
+            on_select: None,
+            on_toggle: None,
+            on_reparent: None,
+            is_multi_select: false,
+            width: Length::Fill,
+            indent: Self::DEFAULT_INDENT,
+            font: None,
+            text_size: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the keys of the selected nodes of the [`TreeView`].
+    pub fn selected(mut self, selected: impl IntoIterator<Item = Key>) -> Self {
+        self.selected = selected.into_iter().collect();
+        self
+    }
+
+    /// Sets the message produced when the selection of the [`TreeView`]
+    /// changes, either by clicking a row or with the keyboard.
+    ///
+    /// The message receives the keys of the new selection.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Vec<Key>) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message produced when a node is expanded or collapsed.
+    ///
+    /// This can be used to load the children of a [`Node::lazy`] when it
+    /// is expanded.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(Key, bool) -> Message + 'a,
+    ) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the message produced when a node is dragged onto another one,
+    /// enabling drag and drop.
+    ///
+    /// The message receives the key of the dragged node and the key of its
+    /// new parent, or `None` if it was dropped below all the rows.
+    pub fn on_reparent(
+        mut self,
+        on_reparent: impl Fn(Key, Option<Key>) -> Message + 'a,
+    ) -> Self {
+        self.on_reparent = Some(Box::new(on_reparent));
+        self
+    }
+
+    /// Sets whether multiple nodes can be selected at once, using
+    /// `Ctrl` and `Shift`.
+    pub fn multi_select(mut self, is_multi_select: bool) -> Self {
+        self.is_multi_select = is_multi_select;
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the indentation of every level of the [`TreeView`].
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the font of the [`TreeView`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the text size of the [`TreeView`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TreeView`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the visible rows of the [`TreeView`], in order.
+    fn rows(
+        &self,
+        expanded: &HashSet<Key>,
+    ) -> Vec<Row<'_, Key, Renderer::Font>> {
+        fn visit<'b, Key, Font>(
+            nodes: &'b [Node<Key, Font>],
+            depth: usize,
+            parent: Option<usize>,
+            expanded: &HashSet<Key>,
+            rows: &mut Vec<Row<'b, Key, Font>>,
+        ) where
+            Key: Eq + Hash,
+        {
+            for node in nodes {
+                let is_expanded =
+                    node.has_children() && expanded.contains(&node.key);
+
+                rows.push(Row {
+                    node,
+                    depth,
+                    parent,
+                    is_expanded,
+                });
+
+                if is_expanded {
+                    let index = rows.len() - 1;
+
+                    visit(
+                        &node.children,
+                        depth + 1,
+                        Some(index),
+                        expanded,
+                        rows,
+                    );
+                }
+            }
+        }
+
+        let mut rows = Vec::new();
+        visit(&self.nodes, 0, None, expanded, &mut rows);
+
+        rows
+    }
+
+    fn row_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        text::LineHeight::default().to_absolute(size).0 + ROW_PADDING.y()
+    }
+
+    /// Expands or collapses the given node.
+    fn toggle(
+        &self,
+        state: &mut State<Key>,
+        key: &Key,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let is_expanded = !state.expanded.remove(key);
+
+        if is_expanded {
+            let _ = state.expanded.insert(key.clone());
+        }
+
+        if let Some(on_toggle) = &self.on_toggle {
+            shell.publish(on_toggle(key.clone(), is_expanded));
+        }
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    /// Focuses the given row, selecting it or extending the selection to
+    /// it.
+    fn focus(
+        &self,
+        state: &mut State<Key>,
+        rows: &[Row<'_, Key, Renderer::Font>],
+        row: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let key = &rows[row].node.key;
+        let modifiers = state.keyboard_modifiers;
+
+        state.focused = Some(key.clone());
+        shell.request_redraw();
+
+        let Some(on_select) = &self.on_select else {
+            return;
+        };
+
+        let anchor = state.anchor.as_ref().and_then(|anchor| {
+            rows.iter().position(|row| row.node.key == *anchor)
+        });
+
+        let selected = if self.is_multi_select
+            && modifiers.shift()
+            && let Some(anchor) = anchor
+        {
+            rows[anchor.min(row)..=anchor.max(row)]
+                .iter()
+                .map(|row| row.node.key.clone())
+                .collect()
+        } else if self.is_multi_select && modifiers.command() {
+            state.anchor = Some(key.clone());
+
+            let mut selected = self.selected.clone();
+
+            if let Some(index) = selected.iter().position(|k| k == key) {
+                let _ = selected.remove(index);
+            } else {
+                selected.push(key.clone());
+            }
+
+            selected
+        } else {
+            state.anchor = Some(key.clone());
+
+            vec![key.clone()]
+        };
+
+        shell.publish(on_select(selected));
+    }
+
+    /// Returns true if the `descendant` row is the `ancestor` row or one of
+    /// its descendants.
+    fn is_descendant(
+        rows: &[Row<'_, Key, Renderer::Font>],
+        ancestor: usize,
+        descendant: usize,
+    ) -> bool {
+        let mut current = Some(descendant);
+
+        while let Some(row) = current {
+            if row == ancestor {
+                return true;
+            }
+
+            current = rows[row].parent;
+        }
+
+        false
+    }
+}
+
+/// A node of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node<Key, Font = crate::core::Font> {
+    key: Key,
+    label: String,
+    icon: Option<Icon<Font>>,
+    children: Vec<Node<Key, Font>>,
+    is_lazy: bool,
+}
+
+impl<Key, Font> Node<Key, Font> {
+    /// Creates a new [`Node`] with the given key and label.
+    ///
+    /// The key must be unique in the [`TreeView`].
+    pub fn new(key: Key, label: impl Into<String>) -> Self {
+        Self {
+            key,
+            label: label.into(),
+            icon: None,
+            children: Vec::new(),
+            is_lazy: false,
+        }
+    }
+
+    /// Sets the [`Icon`] displayed before the label of the [`Node`].
+    pub fn icon(mut self, font: impl Into<Font>, code_point: char) -> Self {
+        self.icon = Some(Icon {
+            font: font.into(),
+            code_point,
+        });
+        self
+    }
+
+    /// Sets the children of the [`Node`].
+    pub fn children(
+        mut self,
+        children: impl IntoIterator<Item = Node<Key, Font>>,
+    ) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Adds a child to the [`Node`].
+    pub fn push(mut self, child: Node<Key, Font>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Marks the [`Node`] as expandable even if its children have not
+    /// been loaded yet.
+    ///
+    /// Use [`TreeView::on_toggle`] to load them when the [`Node`] is
+    /// expanded.
+    pub fn lazy(mut self) -> Self {
+        self.is_lazy = true;
+        self
+    }
+
+    fn has_children(&self) -> bool {
+        self.is_lazy || !self.children.is_empty()
+    }
+}
+
+/// The icon of a [`Node`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Icon<Font> {
+    /// The font that will be used to display the `code_point`.
+    pub font: Font,
+    /// The unicode code point that will be used as the icon.
+    pub code_point: char,
+}
+
+/// A visible row of a [`TreeView`].
+struct Row<'a, Key, Font> {
+    node: &'a Node<Key, Font>,
+    depth: usize,
+    parent: Option<usize>,
+    is_expanded: bool,
+}
+
+struct State<Key> {
+    expanded: HashSet<Key>,
+    focused: Option<Key>,
+    anchor: Option<Key>,
+    hovered: Option<usize>,
+    is_focused: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+    search: String,
+    last_typed: Option<Instant>,
+    drag: Option<Drag<Key>>,
+}
+
+impl<Key> Default for State<Key> {
+    fn default() -> Self {
+        Self {
+            expanded: HashSet::new(),
+            focused: None,
+            anchor: None,
+            hovered: None,
+            is_focused: false,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            search: String::new(),
+            last_typed: None,
+            drag: None,
+        }
+    }
+}
+
+struct Drag<Key> {
+    key: Key,
+    row: usize,
+    origin: Point,
+    is_dragging: bool,
+}
+
+impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TreeView<'_, Key, Message, Theme, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let rows = self.rows(&state.expanded);
+
+        let width = if self.width == Length::Shrink {
+            let font = self.font.unwrap_or_else(|| renderer.default_font());
+            let size =
+                self.text_size.unwrap_or_else(|| renderer.default_size());
+
+            rows.iter()
+                .map(|row| {
+                    let label = Renderer::Paragraph::with_text(Text {
+                        content: row.node.label.as_str(),
+                        bounds: Size::INFINITE,
+                        size,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        align_x: text::Alignment::Default,
+                        align_y: alignment::Vertical::Top,
+                        shaping: text::Shaping::Advanced,
+                        wrapping: text::Wrapping::None,
+                    })
+                    .min_width();
+
+                    ROW_PADDING.x()
+                        + (row.depth + 1) as f32 * self.indent
+                        + if row.node.icon.is_some() {
+                            size.0 + SPACING
+                        } else {
+                            0.0
+                        }
+                        + label
+                })
+                .fold(0.0, f32::max)
+        } else {
+            0.0
+        };
+
+        let height = rows.len() as f32 * self.row_height(renderer);
+
+        layout::Node::new(limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(width, height),
+        ))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let rows = self.rows(&state.expanded);
+        let bounds = layout.bounds();
+        let row_height = self.row_height(renderer);
+
+        let row_at = |cursor: mouse::Cursor| {
+            let position = cursor.position_over(bounds)?;
+            let row = ((position.y - bounds.y) / row_height) as usize;
+
+            (row < rows.len()).then_some(row)
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let hovered = row_at(cursor);
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+
+                if let Some(drag) = &mut state.drag
+                    && !drag.is_dragging
+                    && position.distance(drag.origin) > DRAG_THRESHOLD
+                {
+                    drag.is_dragging = true;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(row) = row_at(cursor) else {
+                    state.is_focused =
+                        cursor.is_over(bounds) && self.on_select.is_some();
+                    return;
+                };
+
+                let position = cursor.position().unwrap_or_default();
+                let node = rows[row].node;
+
+                let arrow_x = bounds.x
+                    + ROW_PADDING.left
+                    + rows[row].depth as f32 * self.indent;
+
+                state.is_focused = true;
+
+                if node.has_children()
+                    && position.x >= arrow_x
+                    && position.x < arrow_x + self.indent
+                {
+                    self.toggle(state, &node.key, shell);
+                } else {
+                    self.focus(state, &rows, row, shell);
+
+                    if self.on_reparent.is_some() {
+                        state.drag = Some(Drag {
+                            key: node.key.clone(),
+                            row,
+                            origin: position,
+                            is_dragging: false,
+                        });
+                    }
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(drag) = state.drag.take() else {
+                    return;
+                };
+
+                if !drag.is_dragging {
+                    return;
+                }
+
+                shell.request_redraw();
+
+                let Some(on_reparent) = &self.on_reparent else {
+                    return;
+                };
+
+                if !cursor.is_over(bounds) {
+                    return;
+                }
+
+                match row_at(cursor) {
+                    Some(target)
+                        if !Self::is_descendant(&rows, drag.row, target) =>
+                    {
+                        shell.publish(on_reparent(
+                            drag.key,
+                            Some(rows[target].node.key.clone()),
+                        ));
+                    }
+                    Some(_) => {}
+                    None => {
+                        shell.publish(on_reparent(drag.key, None));
+                    }
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = *modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key, text, ..
+            }) if state.is_focused && !rows.is_empty() => {
+                let focused = state.focused.as_ref().and_then(|focused| {
+                    rows.iter().position(|row| row.node.key == *focused)
+                });
+
+                let last = rows.len() - 1;
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowUp) => {
+                        let row =
+                            focused.map_or(0, |row| row.saturating_sub(1));
+
+                        self.focus(state, &rows, row, shell);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowDown) => {
+                        let row = focused.map_or(0, |row| (row + 1).min(last));
+
+                        self.focus(state, &rows, row, shell);
+                    }
+                    keyboard::Key::Named(key::Named::Home) => {
+                        self.focus(state, &rows, 0, shell);
+                    }
+                    keyboard::Key::Named(key::Named::End) => {
+                        self.focus(state, &rows, last, shell);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        let Some(row) = focused else {
+                            return;
+                        };
+
+                        if rows[row].is_expanded {
+                            if rows
+                                .get(row + 1)
+                                .is_some_and(|child| child.parent == Some(row))
+                            {
+                                self.focus(state, &rows, row + 1, shell);
+                            }
+                        } else if rows[row].node.has_children() {
+                            self.toggle(state, &rows[row].node.key, shell);
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::ArrowLeft) => {
+                        let Some(row) = focused else {
+                            return;
+                        };
+
+                        if rows[row].is_expanded {
+                            self.toggle(state, &rows[row].node.key, shell);
+                        } else if let Some(parent) = rows[row].parent {
+                            self.focus(state, &rows, parent, shell);
+                        }
+                    }
+                    _ => {
+                        let Some(text) = text.as_ref().filter(|text| {
+                            !state.keyboard_modifiers.command()
+                                && text.chars().all(|c| !c.is_control())
+                        }) else {
+                            return;
+                        };
+
+                        let now = Instant::now();
+
+                        if state.last_typed.is_none_or(|last_typed| {
+                            now - last_typed > TYPE_AHEAD_TIMEOUT
+                        }) {
+                            state.search.clear();
+                        }
+
+                        state.search.push_str(&text.to_lowercase());
+                        state.last_typed = Some(now);
+
+                        // Search from the focused row if refining the query
+                        // and from the next one otherwise
+                        let start = match focused {
+                            Some(row) if state.search.chars().count() > 1 => {
+                                row
+                            }
+                            Some(row) => row + 1,
+                            None => 0,
+                        };
+
+                        let Some(row) = (0..rows.len())
+                            .map(|offset| (start + offset) % rows.len())
+                            .find(|&row| {
+                                rows[row]
+                                    .node
+                                    .label
+                                    .to_lowercase()
+                                    .starts_with(&state.search)
+                            })
+                        else {
+                            return;
+                        };
+
+                        self.focus(state, &rows, row, shell);
+                    }
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Key>>();
+
+        if state.drag.as_ref().is_some_and(|drag| drag.is_dragging) {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) && state.hovered.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let rows = self.rows(&state.expanded);
+        let bounds = layout.bounds();
+        let row_height = self.row_height(renderer);
+        let style = theme.style(&self.class);
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let drop_target = state
+            .drag
+            .as_ref()
+            .filter(|drag| drag.is_dragging)
+            .and_then(|drag| {
+                let position = cursor.position_over(bounds)?;
+                let row = ((position.y - bounds.y) / row_height) as usize;
+
+                match rows.get(row) {
+                    Some(_) if !Self::is_descendant(&rows, drag.row, row) => {
+                        Some(Some(row))
+                    }
+                    Some(_) => None,
+                    None => Some(None),
+                }
+            });
+
+        let first = ((viewport.y - bounds.y) / row_height).max(0.0) as usize;
+        let last = ((viewport.y + viewport.height - bounds.y) / row_height)
+            .ceil()
+            .max(0.0) as usize;
+
+        for (index, row) in rows
+            .iter()
+            .enumerate()
+            .take(last.min(rows.len()))
+            .skip(first)
+        {
+            let row_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + index as f32 * row_height,
+                width: bounds.width,
+                height: row_height,
+            };
+
+            let is_selected = self.selected.contains(&row.node.key);
+
+            let background = if is_selected {
+                Some(style.selected_background)
+            } else if state.hovered == Some(index) && state.drag.is_none() {
+                style.hovered_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border: Border {
+                            radius: style.border_radius,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            if drop_target == Some(Some(index)) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border: Border {
+                            radius: style.border_radius,
+                            width: 1.0,
+                            color: style.drop_target,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            let text_color = if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            let left = row_bounds.x + ROW_PADDING.left;
+
+            // Indentation guides
+            for depth in 0..row.depth {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (left + (depth as f32 + 0.5) * self.indent)
+                                .floor(),
+                            width: 1.0,
+                            ..row_bounds
+                        },
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    style.guide,
+                );
+            }
+
+            let mut x = left + row.depth as f32 * self.indent;
+
+            let text = |content: String, font, align_x| Text {
+                content,
+                bounds: Size::new(f32::INFINITY, row_height),
+                size,
+                line_height: text::LineHeight::default(),
+                font,
+                align_x,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            };
+
+            if row.node.has_children() {
+                renderer.fill_text(
+                    Text {
+                        shaping: text::Shaping::Basic,
+                        size: size * 0.75,
+                        ..text(
+                            if row.is_expanded {
+                                Renderer::ARROW_DOWN_ICON
+                            } else {
+                                Renderer::SCROLL_RIGHT_ICON
+                            }
+                            .to_string(),
+                            Renderer::ICON_FONT,
+                            text::Alignment::Center,
+                        )
+                    },
+                    Point::new(x + self.indent / 2.0, row_bounds.center_y()),
+                    text_color,
+                    row_bounds,
+                );
+            }
+
+            x += self.indent;
+
+            if let Some(icon) = row.node.icon {
+                renderer.fill_text(
+                    text(
+                        icon.code_point.to_string(),
+                        icon.font,
+                        text::Alignment::Left,
+                    ),
+                    Point::new(x, row_bounds.center_y()),
+                    text_color,
+                    row_bounds,
+                );
+
+                x += size.0 + SPACING;
+            }
+
+            renderer.fill_text(
+                text(row.node.label.clone(), font, text::Alignment::Left),
+                Point::new(x, row_bounds.center_y()),
+                text_color,
+                row_bounds,
+            );
+        }
+
+        if drop_target == Some(None) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + rows.len() as f32 * row_height - 1.0,
+                        height: 2.0,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.drop_target,
+            );
+        }
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<TreeView<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tree_view: TreeView<'a, Key, Message, Theme, Renderer>) -> Self {
+        Element::new(tree_view)
+    }
+}
+
+/// The appearance of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The text [`Color`] of the rows.
+    pub text_color: Color,
+    /// The [`Background`] of a hovered row, if any.
+    pub hovered_background: Option<Background>,
+    /// The [`Background`] of a selected row.
+    pub selected_background: Background,
+    /// The text [`Color`] of a selected row.
+    pub selected_text_color: Color,
+    /// The border radius of the rows.
+    pub border_radius: crate::core::border::Radius,
+    /// The [`Color`] of the indentation guides.
+    pub guide: Color,
+    /// The [`Color`] highlighting where a dragged row will be dropped.
+    pub drop_target: Color,
+}
+
+/// The theme catalog of a [`TreeView`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`TreeView`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`TreeView`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        text_color: palette.background.base.text,
+        hovered_background: Some(palette.background.weak.color.into()),
+        selected_background: palette.primary.weak.color.into(),
+        selected_text_color: palette.primary.weak.text,
+        border_radius: 2.0.into(),
+        guide: palette.background.strong.color,
+        drop_target: palette.primary.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::Harness;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Select(Vec<u32>),
+        Toggle(u32, bool),
+    }
+
+    fn tree_view() -> TreeView<'static, u32, Message> {
+        TreeView::new([
+            Node::new(0, "src")
+                .children([Node::new(1, "main.rs"), Node::new(2, "lib.rs")]),
+            Node::new(3, "target").lazy(),
+            Node::new(4, "Cargo.toml"),
+            Node::new(5, "cargo.lock"),
+        ])
+        .on_select(Message::Select)
+        .on_toggle(Message::Toggle)
+    }
+
+    fn click(
+        harness: &mut Harness<'_, Message>,
+        x: f32,
+        row: usize,
+    ) -> Vec<Message> {
+        let row_height = tree_view().row_height(harness.renderer());
+
+        harness.click(Point::new(x, (row as f32 + 0.5) * row_height))
+    }
+
+    #[test]
+    fn arrows_expand_and_collapse_nodes() {
+        let mut harness = Harness::new(tree_view());
+
+        assert_eq!(
+            click(&mut harness, 100.0, 0),
+            vec![Message::Select(vec![0])]
+        );
+        assert_eq!(
+            harness.press(key::Named::ArrowRight),
+            vec![Message::Toggle(0, true)]
+        );
+        assert_eq!(
+            harness.press(key::Named::ArrowRight),
+            vec![Message::Select(vec![1])]
+        );
+        assert_eq!(
+            harness.press(key::Named::ArrowLeft),
+            vec![Message::Select(vec![0])]
+        );
+        assert_eq!(
+            harness.press(key::Named::ArrowLeft),
+            vec![Message::Toggle(0, false)]
+        );
+        assert_eq!(
+            harness.press(key::Named::ArrowDown),
+            vec![Message::Select(vec![3])]
+        );
+    }
+
+    #[test]
+    fn clicking_the_arrow_toggles_lazy_nodes() {
+        let mut harness = Harness::new(tree_view());
+
+        assert_eq!(
+            click(&mut harness, 10.0, 1),
+            vec![Message::Toggle(3, true)]
+        );
+        assert_eq!(
+            click(&mut harness, 10.0, 1),
+            vec![Message::Toggle(3, false)]
+        );
+        // Leaves have no arrow
+        assert_eq!(
+            click(&mut harness, 10.0, 2),
+            vec![Message::Select(vec![4])]
+        );
+    }
+
+    #[test]
+    fn typing_selects_rows_by_prefix() {
+        let mut harness = Harness::new(tree_view());
+
+        let _ = click(&mut harness, 100.0, 0);
+
+        assert_eq!(harness.type_text("c"), vec![Message::Select(vec![4])]);
+        assert_eq!(
+            harness.type_text("argo.l").last(),
+            Some(&Message::Select(vec![5]))
+        );
+        assert_eq!(harness.type_text("x"), vec![]);
+
+        // The query is reset once the timeout elapses
+        std::thread::sleep(TYPE_AHEAD_TIMEOUT + Duration::from_millis(10));
+
+        assert_eq!(harness.type_text("S"), vec![Message::Select(vec![0])]);
+    }
+}
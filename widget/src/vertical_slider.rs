@@ -47,6 +47,7 @@ use crate::core::{
     self, Clipboard, Element, Event, Length, Pixels, Point, Rectangle, Shell,
     Size, Widget,
 };
+use crate::slider;
 
 /// An vertical bar and a handle that selects a single value from a range of
 /// values.
@@ -260,6 +261,9 @@ where
         let is_dragging = state.is_dragging;
         let current_value = self.value;
 
+        let step =
+            slider::step(self.step, self.shift_step, state.keyboard_modifiers);
+
         let locate = |cursor_position: Point| -> Option<T> {
             let bounds = layout.bounds();
 
@@ -268,62 +272,16 @@ where
             } else if cursor_position.y <= bounds.y {
                 Some(*self.range.end())
             } else {
-                let step = if state.keyboard_modifiers.shift() {
-                    self.shift_step.unwrap_or(self.step)
-                } else {
-                    self.step
-                }
-                .into();
-
-                let start = (*self.range.start()).into();
-                let end = (*self.range.end()).into();
-
                 let percent = 1.0
                     - f64::from(cursor_position.y - bounds.y)
                         / f64::from(bounds.height);
 
-                let steps = (percent * (end - start) / step).round();
-                let value = steps * step + start;
-
-                T::from_f64(value.min(end))
-            }
-        };
-
-        let increment = |value: T| -> Option<T> {
-            let step = if state.keyboard_modifiers.shift() {
-                self.shift_step.unwrap_or(self.step)
-            } else {
-                self.step
-            }
-            .into();
-
-            let steps = (value.into() / step).round();
-            let new_value = step * (steps + 1.0);
-
-            if new_value > (*self.range.end()).into() {
-                return Some(*self.range.end());
+                slider::value_at(percent, &self.range, step)
             }
-
-            T::from_f64(new_value)
         };
 
-        let decrement = |value: T| -> Option<T> {
-            let step = if state.keyboard_modifiers.shift() {
-                self.shift_step.unwrap_or(self.step)
-            } else {
-                self.step
-            }
-            .into();
-
-            let steps = (value.into() / step).round();
-            let new_value = step * (steps - 1.0);
-
-            if new_value < (*self.range.start()).into() {
-                return Some(*self.range.start());
-            }
-
-            T::from_f64(new_value)
-        };
+        let increment = |value: T| slider::increment(value, step, &self.range);
+        let decrement = |value: T| slider::decrement(value, step, &self.range);
 
         let change = |new_value: T| {
             if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
@@ -507,6 +465,7 @@ where
                     radius: handle_border_radius,
                     width: style.handle.border_width,
                     color: style.handle.border_color,
+                    ..Border::default()
                 },
                 ..renderer::Quad::default()
             },
@@ -0,0 +1,704 @@
+//! Display huge lists of items efficiently.
+//!
+//! A [`VirtualList`] only builds and lays out the rows that intersect the
+//! visible area of its parent—usually a [`Scrollable`]. Rows that
+//! scroll out of view are dropped and rebuilt once they become visible
+//! again.
+//!
+//! [`Scrollable`]: crate::Scrollable
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Pixels, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+use std::ops::Range;
+
+/// The height of the visible area assumed before the first [`VirtualList`]
+/// update, when the limits of its layout are unbounded.
+///
+/// It is enough to fill most screens.
+const INITIAL_HEIGHT: f32 = 1080.0;
+
+/// A vertical list of rows that are only built when visible.
+///
+/// Every row is produced on demand by a `view` function, which receives
+/// the index of the row. The total height of the list is computed from
+/// the [`RowHeight`] of its rows; therefore, scrolling offsets and
+/// snapping of a parent [`Scrollable`] behave as if every row existed.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{scrollable, text, virtual_list};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     scrollable(
+///         virtual_list(100_000, |i| text!("Row {i}").into()).row_height(20),
+///     )
+///     .into()
+/// }
+/// ```
+///
+/// [`Scrollable`]: crate::Scrollable
+pub struct VirtualList<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    count: usize,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    key: Option<Box<dyn Fn(usize) -> u64 + 'a>>,
+    row_height: RowHeight,
+    spacing: f32,
+    width: Length,
+    rows: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+/// The height of the rows of a [`VirtualList`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowHeight {
+    /// Every row has the same height.
+    ///
+    /// Rows are laid out with exactly this height and the position of any
+    /// row can be computed directly.
+    Fixed(f32),
+    /// Rows may have different heights.
+    ///
+    /// Rows that have never been visible are assumed to have the estimated
+    /// height. Visible rows are measured and their actual height is
+    /// remembered.
+    Estimated(f32),
+}
+
+impl<'a, Message, Theme, Renderer> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The default estimated height of the rows of a [`VirtualList`].
+    pub const DEFAULT_ROW_HEIGHT: f32 = 30.0;
+
+    /// Creates a new [`VirtualList`] with the given amount of rows and a
+    /// closure that produces the row with the given index.
+    pub fn new(
+        count: usize,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            count,
+            view: Box::new(view),
+            key: None,
+            row_height: RowHeight::Estimated(Self::DEFAULT_ROW_HEIGHT),
+            spacing: 0.0,
+            width: Length::Fill,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Sets the fixed height of every row of the [`VirtualList`].
+    pub fn row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = RowHeight::Fixed(height.into().0);
+        self
+    }
+
+    /// Sets the estimated height of the rows of the [`VirtualList`] that
+    /// have not been measured yet.
+    ///
+    /// This is the default behavior, with an estimate of
+    /// [`DEFAULT_ROW_HEIGHT`](Self::DEFAULT_ROW_HEIGHT).
+    pub fn estimated_row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = RowHeight::Estimated(height.into().0);
+        self
+    }
+
+    /// Sets the function that produces the key of the row with the given
+    /// index.
+    ///
+    /// Keys are used to keep the state of the visible rows attached to the
+    /// right items when rows are inserted or removed. By default, the index
+    /// of a row is used as its key.
+    pub fn key(mut self, key: impl Fn(usize) -> u64 + 'a) -> Self {
+        self.key = Some(Box::new(key));
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Returns the vertical position of the row with the given index.
+    fn offset(&self, state: &State, index: usize) -> f32 {
+        match self.row_height {
+            RowHeight::Fixed(height) => index as f32 * (height + self.spacing),
+            RowHeight::Estimated(_) => state.heights.offset(index),
+        }
+    }
+
+    /// Returns the index of the row at the given vertical position.
+    fn index_at(&self, state: &State, y: f32) -> usize {
+        let index = match self.row_height {
+            RowHeight::Fixed(height) => {
+                (y.max(0.0) / (height + self.spacing).max(1.0)) as usize
+            }
+            RowHeight::Estimated(_) => state.heights.index_at(y),
+        };
+
+        index.min(self.count.saturating_sub(1))
+    }
+}
+
+struct State {
+    range: Option<Range<usize>>,
+    keys: Vec<u64>,
+    heights: Heights,
+}
+
+/// The heights of the rows of a [`VirtualList`] with a
+/// [`RowHeight::Estimated`].
+///
+/// Heights are stored in a Fenwick tree; so measuring a row and finding the
+/// offset of a row both take logarithmic time, no matter the amount of rows.
+#[derive(Debug, Default)]
+struct Heights {
+    spacing: f32,
+    rows: Vec<f32>,
+    tree: Vec<f32>,
+}
+
+impl Heights {
+    /// Resizes the [`Heights`] to the given amount of rows, assuming the
+    /// `estimate` for new rows.
+    fn resize(&mut self, count: usize, estimate: f32, spacing: f32) {
+        if spacing != self.spacing {
+            self.spacing = spacing;
+            self.tree.clear();
+
+            for height in std::mem::take(&mut self.rows) {
+                self.push(height);
+            }
+        }
+
+        // Every node of the tree only covers the rows before it
+        self.rows.truncate(count);
+        self.tree.truncate(count);
+
+        while self.rows.len() < count {
+            self.push(estimate);
+        }
+    }
+
+    fn push(&mut self, height: f32) {
+        let node = self.rows.len() + 1;
+        let covered = node - lowest_bit(node);
+
+        self.tree.push(
+            height + self.spacing + self.offset(node - 1)
+                - self.offset(covered),
+        );
+        self.rows.push(height);
+    }
+
+    /// Sets the measured height of the row with the given index.
+    fn set(&mut self, index: usize, height: f32) {
+        let delta = height - self.rows[index];
+
+        if delta == 0.0 {
+            return;
+        }
+
+        self.rows[index] = height;
+
+        let mut node = index + 1;
+
+        while node <= self.tree.len() {
+            self.tree[node - 1] += delta;
+            node += lowest_bit(node);
+        }
+    }
+
+    /// Returns the vertical position of the row with the given index;
+    /// that is, the sum of the heights and the spacing of every row before
+    /// it.
+    fn offset(&self, index: usize) -> f32 {
+        let mut node = index.min(self.tree.len());
+        let mut offset = 0.0;
+
+        while node > 0 {
+            offset += self.tree[node - 1];
+            node -= lowest_bit(node);
+        }
+
+        offset
+    }
+
+    /// Returns the index of the row at the given vertical position.
+    ///
+    /// The result is the amount of rows if the position is past the last
+    /// one.
+    fn index_at(&self, y: f32) -> usize {
+        let mut index = 0;
+        let mut remaining = y;
+        let mut step = match self.tree.len() {
+            0 => 0,
+            len => 1 << len.ilog2(),
+        };
+
+        while step > 0 {
+            if index + step <= self.tree.len()
+                && self.tree[index + step - 1] <= remaining
+            {
+                index += step;
+                remaining -= self.tree[index - 1];
+            }
+
+            step /= 2;
+        }
+
+        index
+    }
+}
+
+fn lowest_bit(node: usize) -> usize {
+    node & node.wrapping_neg()
+}
+
+/// Returns the height of the area where rows are built before the first
+/// update of a virtual widget, when its visible area is still unknown.
+pub(crate) fn initial_height(limits: &layout::Limits) -> f32 {
+    let height = limits.max().height;
+
+    if height.is_finite() {
+        height
+    } else {
+        INITIAL_HEIGHT
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for VirtualList<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            range: None,
+            keys: Vec::new(),
+            heights: Heights::default(),
+        })
+    }
+
+    fn diff(&self, _tree: &mut Tree) {
+        // Diff is deferred to layout
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+        let limits = limits.width(self.width);
+        let max_width = limits.max().width;
+
+        if let RowHeight::Estimated(estimate) = self.row_height {
+            state.heights.resize(self.count, estimate, self.spacing);
+        }
+
+        let range = match &state.range {
+            Some(range) => range.clone(),
+            None => {
+                let range =
+                    0..self.index_at(state, initial_height(&limits)) + 1;
+
+                state.range = Some(range.clone());
+                range
+            }
+        };
+
+        let range = range.start.min(self.count)..range.end.min(self.count);
+
+        let keys: Vec<u64> = range
+            .clone()
+            .map(|index| {
+                self.key.as_ref().map_or(index as u64, |key| key(index))
+            })
+            .collect();
+
+        self.rows = range.clone().map(|index| (self.view)(index)).collect();
+
+        let mut old: Vec<_> =
+            std::mem::take(children).into_iter().map(Some).collect();
+
+        *children = keys
+            .iter()
+            .zip(&self.rows)
+            .map(|(key, row)| {
+                let old = state
+                    .keys
+                    .iter()
+                    .position(|old_key| old_key == key)
+                    .and_then(|index| old.get_mut(index)?.take());
+
+                match old {
+                    Some(mut tree) => {
+                        tree.diff(row.as_widget());
+                        tree
+                    }
+                    None => Tree::new(row.as_widget()),
+                }
+            })
+            .collect();
+
+        state.keys = keys;
+
+        let mut nodes: Vec<layout::Node> = self
+            .rows
+            .iter_mut()
+            .zip(children.iter_mut())
+            .map(|(row, tree)| {
+                let limits = match self.row_height {
+                    RowHeight::Fixed(height) => layout::Limits::new(
+                        Size::new(0.0, height),
+                        Size::new(max_width, height),
+                    ),
+                    RowHeight::Estimated(_) => layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(max_width, f32::INFINITY),
+                    ),
+                };
+
+                row.as_widget_mut().layout(tree, renderer, &limits)
+            })
+            .collect();
+
+        if let RowHeight::Estimated(_) = self.row_height {
+            for (index, node) in range.clone().zip(&nodes) {
+                state.heights.set(index, node.size().height);
+            }
+        }
+
+        let mut width = 0.0f32;
+
+        for (index, node) in range.zip(&mut nodes) {
+            node.move_to_mut((0.0, self.offset(state, index)));
+            width = width.max(node.size().width);
+        }
+
+        let height = if self.count == 0 {
+            0.0
+        } else {
+            self.offset(state, self.count) - self.spacing
+        };
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(width, height),
+        );
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.rows
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((row, state), layout)| {
+                    row.as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((row, tree), layout) in self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            row.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell,
+                viewport,
+            );
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let top = (viewport.y - bounds.y).max(0.0);
+        let bottom =
+            (viewport.y + viewport.height - bounds.y).min(bounds.height);
+
+        if self.count == 0 || bottom < top {
+            return;
+        }
+
+        let visible =
+            self.index_at(state, top)..self.index_at(state, bottom) + 1;
+
+        if state.range.as_ref().is_none_or(|range| {
+            visible.start < range.start
+                || visible.end > range.end
+                || range.end > self.count
+        }) {
+            // Build some extra rows to avoid rebuilding while scrolling
+            let overscan = viewport.height / 2.0;
+
+            state.range = Some(
+                self.index_at(state, top - overscan)
+                    ..self.index_at(state, bottom + overscan) + 1,
+            );
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((row, tree), layout)| {
+                row.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((row, state), layout) in
+            self.rows.iter().zip(&tree.children).zip(layout.children())
+        {
+            if layout.bounds().intersects(viewport) {
+                row.as_widget().draw(
+                    state, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.rows,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<VirtualList<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(list: VirtualList<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Point;
+
+    type Element = core::Element<'static, (), crate::Theme, crate::Renderer>;
+
+    fn offsets(heights: &[f32], spacing: f32) -> Vec<f32> {
+        std::iter::once(0.0)
+            .chain(heights.iter().scan(0.0, |offset, height| {
+                *offset += height + spacing;
+                Some(*offset)
+            }))
+            .collect()
+    }
+
+    #[test]
+    fn heights_track_offsets() {
+        let mut heights = Heights::default();
+        let mut expected = vec![10.0; 37];
+
+        heights.resize(37, 10.0, 2.0);
+
+        for (index, height) in [(0, 4.0), (36, 7.5), (17, 30.0), (17, 1.0)] {
+            heights.set(index, height);
+            expected[index] = height;
+        }
+
+        for (index, offset) in offsets(&expected, 2.0).into_iter().enumerate() {
+            assert_eq!(heights.offset(index), offset, "offset of {index}");
+        }
+
+        heights.resize(20, 10.0, 2.0);
+        expected.truncate(20);
+
+        heights.resize(50, 5.0, 0.0);
+        expected.resize(50, 5.0);
+
+        for (index, offset) in offsets(&expected, 0.0).into_iter().enumerate() {
+            assert_eq!(heights.offset(index), offset, "offset of {index}");
+        }
+    }
+
+    #[test]
+    fn heights_find_rows() {
+        let mut heights = Heights::default();
+        heights.resize(5, 10.0, 1.0);
+        heights.set(2, 20.0);
+
+        // Rows start at 0, 11, 22, 43 and 54
+        assert_eq!(heights.index_at(-5.0), 0);
+        assert_eq!(heights.index_at(0.0), 0);
+        assert_eq!(heights.index_at(10.5), 0);
+        assert_eq!(heights.index_at(11.0), 1);
+        assert_eq!(heights.index_at(42.0), 2);
+        assert_eq!(heights.index_at(43.0), 3);
+        assert_eq!(heights.index_at(64.0), 4);
+        assert_eq!(heights.index_at(65.0), 5);
+
+        assert_eq!(Heights::default().index_at(100.0), 0);
+    }
+
+    fn layout(list: &mut Element, tree: &mut Tree) -> layout::Node {
+        let renderer =
+            crate::Renderer::new(core::Font::default(), Pixels(16.0));
+
+        list.as_widget_mut().layout(
+            tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, f32::INFINITY)),
+        )
+    }
+
+    #[test]
+    fn first_layout_builds_the_initial_rows() {
+        let mut list: Element =
+            VirtualList::new(10_000, |_| crate::Space::new().height(10).into())
+                .row_height(20)
+                .into();
+
+        let mut tree = Tree::new(list.as_widget());
+        let node = layout(&mut list, &mut tree);
+
+        assert_eq!(node.size().height, 200_000.0);
+        assert_eq!(node.children().len(), 55);
+        assert_eq!(node.children()[54].bounds().y, 1080.0);
+    }
+
+    #[test]
+    fn estimated_rows_are_measured() {
+        let mut list: Element = VirtualList::new(1_000, |i| {
+            crate::Space::new()
+                .height(if i % 2 == 0 { 10 } else { 20 })
+                .into()
+        })
+        .estimated_row_height(100)
+        .spacing(5)
+        .into();
+
+        let mut tree = Tree::new(list.as_widget());
+        let node = layout(&mut list, &mut tree);
+
+        // 11 rows fill the initial height with the estimate
+        assert_eq!(node.children().len(), 11);
+        assert_eq!(
+            node.size().height,
+            6.0 * 10.0 + 5.0 * 20.0 + 989.0 * 100.0 + 999.0 * 5.0
+        );
+
+        let rows: Vec<Point> = node
+            .children()
+            .iter()
+            .map(|row| row.bounds().position())
+            .collect();
+
+        assert_eq!(rows[1], Point::new(0.0, 15.0));
+        assert_eq!(rows[2], Point::new(0.0, 40.0));
+    }
+}
@@ -1769,6 +1769,28 @@ fn run_action<'a, P, C>(
                     );
                 }
             }
+            image::Action::AllocateWithProgress(handle, sender) => {
+                use core::Renderer as _;
+
+                // TODO: Shared image cache in compositor
+                if let Some((_id, window)) = window_manager.iter_mut().next() {
+                    window.renderer.allocate_image_with_progress(
+                        &handle,
+                        move |progress| {
+                            // Every clone of a sender has a slot of its own;
+                            // so no progress is lost
+                            let _ = sender.clone().try_send(progress);
+                        },
+                    );
+                }
+            }
+            image::Action::Evict(handle) => {
+                use core::Renderer as _;
+
+                for (_id, window) in window_manager.iter_mut() {
+                    window.renderer.evict_image(&handle);
+                }
+            }
         },
         Action::LoadFont { bytes, channel } => {
             if let Some(compositor) = compositor {